@@ -0,0 +1,157 @@
+//! Merkle allowlists for nft-operations mint phases, mirroring `AllowlistProof` in the
+//! nft-operations program.
+//!
+//! Leaves hash a wallet with the number of mints it is allowed, and nodes hash their two
+//! children in sorted order, so a proof is only the list of sibling hashes. A node without a
+//! sibling moves up a level unchanged.
+
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use sha2::{Digest, Sha256};
+use solana_pubkey::Pubkey;
+
+use crate::generated::nft_operations::types::AllowlistProof;
+
+const CSV_HEADER: &str = "wallet,allowed_count";
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AllowlistEntry {
+    pub wallet: Pubkey,
+    pub allowed_count: u64,
+}
+
+#[derive(Debug, Eq, PartialEq, thiserror::Error)]
+pub enum AllowlistError {
+    #[error("line {line}: {reason}")]
+    InvalidLine { line: usize, reason: String },
+    #[error("allowlist has no entries")]
+    Empty,
+    #[error("wallet {0} is listed more than once")]
+    DuplicateWallet(Pubkey),
+}
+
+pub fn leaf_hash(wallet: &Pubkey, allowed_count: u64) -> [u8; 32] {
+    hashv(&[&[0], wallet.as_ref(), &allowed_count.to_le_bytes()])
+}
+
+pub fn node_hash(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let (left, right) = if left <= right {
+        (left, right)
+    } else {
+        (right, left)
+    };
+    hashv(&[&[1], left, right])
+}
+
+fn hashv(values: &[&[u8]]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    for value in values {
+        hasher.update(value);
+    }
+    hasher.finalize().into()
+}
+
+/// Merkle tree over an allowlist; set its `root` as the `merkle_root` of a mint phase and pass
+/// `proof(wallet)` as the `allowlist` argument of `mint_asset`.
+#[derive(Clone, Debug)]
+pub struct AllowlistTree {
+    entries: Vec<AllowlistEntry>,
+    layers: Vec<Vec<[u8; 32]>>, // leaves first, root last
+}
+
+impl AllowlistTree {
+    pub fn new(entries: Vec<AllowlistEntry>) -> Result<Self, AllowlistError> {
+        if entries.is_empty() {
+            return Err(AllowlistError::Empty);
+        }
+
+        let mut wallets = HashSet::with_capacity(entries.len());
+        for entry in &entries {
+            if !wallets.insert(entry.wallet) {
+                return Err(AllowlistError::DuplicateWallet(entry.wallet));
+            }
+        }
+
+        let leaves: Vec<[u8; 32]> = entries
+            .iter()
+            .map(|entry| leaf_hash(&entry.wallet, entry.allowed_count))
+            .collect();
+        let mut layers = vec![leaves];
+
+        while layers[layers.len() - 1].len() > 1 {
+            let layer = layers[layers.len() - 1]
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => node_hash(left, right),
+                    [node] => *node,
+                    _ => unreachable!(),
+                })
+                .collect();
+            layers.push(layer);
+        }
+
+        Ok(Self { entries, layers })
+    }
+
+    /// Parses one `wallet,allowed_count` entry per line. Blank lines and a
+    /// `wallet,allowed_count` header are skipped.
+    pub fn from_csv(csv: &str) -> Result<Self, AllowlistError> {
+        let mut entries = Vec::new();
+
+        for (index, line) in csv.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || (index == 0 && line.eq_ignore_ascii_case(CSV_HEADER)) {
+                continue;
+            }
+
+            let invalid = |reason: &str| AllowlistError::InvalidLine {
+                line: index + 1,
+                reason: reason.to_string(),
+            };
+
+            let (wallet, allowed_count) = line
+                .split_once(',')
+                .ok_or_else(|| invalid("expected `wallet,allowed_count`"))?;
+            entries.push(AllowlistEntry {
+                wallet: Pubkey::from_str(wallet.trim()).map_err(|_| invalid("invalid wallet"))?,
+                allowed_count: allowed_count
+                    .trim()
+                    .parse()
+                    .map_err(|_| invalid("invalid allowed count"))?,
+            });
+        }
+
+        Self::new(entries)
+    }
+
+    pub fn root(&self) -> [u8; 32] {
+        self.layers[self.layers.len() - 1][0]
+    }
+
+    pub fn entries(&self) -> &[AllowlistEntry] {
+        &self.entries
+    }
+
+    /// Proof for `wallet`, or `None` when it is not on the allowlist.
+    pub fn proof(&self, wallet: &Pubkey) -> Option<AllowlistProof> {
+        let mut index = self
+            .entries
+            .iter()
+            .position(|entry| entry.wallet == *wallet)?;
+        let allowed_count = self.entries[index].allowed_count;
+
+        let mut proof = Vec::with_capacity(self.layers.len() - 1);
+        for layer in &self.layers[..self.layers.len() - 1] {
+            if let Some(sibling) = layer.get(index ^ 1) {
+                proof.push(*sibling);
+            }
+            index /= 2;
+        }
+
+        Some(AllowlistProof {
+            allowed_count,
+            proof,
+        })
+    }
+}
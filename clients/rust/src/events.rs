@@ -0,0 +1,144 @@
+//! Decoders for the Anchor events emitted by the three programs.
+//!
+//! sol-mind-protocol uses `emit!`, so its events are logged as `Program data: <base64>`;
+//! base64-decode the log payload and pass the bytes to [`decode_sol_mind_protocol_event`].
+//! nft-operations and dac-manager use `emit_cpi!`, so their events are the data of an inner
+//! instruction the program invokes on itself, prefixed with [`EVENT_IX_TAG`]. Every decoder
+//! accepts both the log payload and the inner instruction data.
+
+use borsh::BorshDeserialize;
+
+/// Prefix Anchor puts in front of the event bytes of an `emit_cpi!` self-invocation.
+pub const EVENT_IX_TAG: [u8; 8] = [228, 69, 165, 46, 81, 203, 154, 29];
+
+/// An event type together with its Anchor discriminator (`sha256("event:<Name>")[..8]`).
+pub trait Event: BorshDeserialize {
+    const DISCRIMINATOR: [u8; 8];
+}
+
+/// Splits event bytes into discriminator and payload, dropping the `emit_cpi!` tag if present.
+fn split_event(data: &[u8]) -> Option<(&[u8], &[u8])> {
+    let data = data.strip_prefix(&EVENT_IX_TAG[..]).unwrap_or(data);
+    if data.len() < 8 {
+        return None;
+    }
+
+    Some(data.split_at(8))
+}
+
+macro_rules! program_events {
+    ($(#[$meta:meta])* $enum:ident, $decode:ident, $program:ident {
+        $($event:ident = $discriminator:expr,)*
+    }) => {
+        $(
+            impl Event for crate::generated::$program::types::$event {
+                const DISCRIMINATOR: [u8; 8] = $discriminator;
+            }
+        )*
+
+        $(#[$meta])*
+        #[derive(Clone, Debug, Eq, PartialEq)]
+        pub enum $enum {
+            $($event(crate::generated::$program::types::$event),)*
+        }
+
+        /// Decodes an event from its log payload or `emit_cpi!` instruction data. Returns
+        /// `None` for bytes that are not an event of this program.
+        pub fn $decode(data: &[u8]) -> Option<$enum> {
+            use crate::generated::$program::types;
+
+            let (discriminator, mut payload) = split_event(data)?;
+            $(
+                if discriminator == <types::$event as Event>::DISCRIMINATOR {
+                    return BorshDeserialize::deserialize(&mut payload).ok().map($enum::$event);
+                }
+            )*
+
+            None
+        }
+    };
+}
+
+program_events! {
+    /// Events logged by the sol-mind-protocol program.
+    SolMindProtocolEvent, decode_sol_mind_protocol_event, sol_mind_protocol {
+        ProtocolInitialized = [173, 122, 168, 254, 9, 118, 76, 132],
+        FeeStatsInitialized = [241, 51, 201, 136, 2, 121, 48, 147],
+        AdminAdded = [23, 13, 37, 90, 130, 53, 75, 251],
+        AdminRemoved = [59, 133, 36, 27, 156, 79, 75, 146],
+        AdminRotated = [21, 142, 227, 252, 22, 194, 172, 220],
+        ProposalCreated = [186, 8, 160, 108, 81, 13, 51, 206],
+        ProposalApproved = [70, 49, 155, 228, 157, 43, 88, 49],
+        ProposalExecuted = [92, 213, 189, 201, 101, 83, 111, 83],
+        ProposalCancelled = [253, 59, 104, 46, 129, 78, 9, 14],
+        ApprovalThresholdUpdated = [236, 23, 185, 243, 94, 212, 152, 206],
+        WhitelistTransferAddrAdded = [129, 127, 193, 84, 33, 3, 87, 247],
+        WhitelistTransferAddrRemoved = [196, 63, 237, 103, 159, 188, 29, 141],
+        ProtocolFeesTransferred = [230, 155, 159, 175, 4, 129, 45, 71],
+        ProtocolFeesDistributed = [40, 79, 148, 12, 160, 223, 149, 93],
+        RevenueSharesUpdated = [251, 65, 42, 177, 10, 86, 199, 94],
+        ProjectFeesTransferred = [240, 10, 230, 194, 177, 83, 188, 188],
+        TreasuryPolicySet = [98, 125, 24, 254, 4, 78, 125, 205],
+        TreasuryPolicyClosed = [187, 210, 198, 246, 174, 10, 137, 116],
+        ProjectTokenFeesTransferred = [194, 182, 195, 66, 14, 54, 235, 234],
+        ProtocolTokenFeesTransferred = [170, 86, 66, 139, 126, 79, 36, 81],
+        FeesUpdateScheduled = [133, 212, 100, 84, 176, 144, 230, 198],
+        FeesUpdateCancelled = [90, 86, 139, 158, 115, 143, 60, 8],
+        ProtocolPauseUpdated = [18, 112, 97, 19, 182, 70, 162, 226],
+        OperationPauseUpdated = [18, 193, 28, 223, 176, 242, 68, 83],
+        ProtocolFeePaid = [224, 102, 140, 88, 121, 78, 18, 63],
+        ProjectCreated = [192, 10, 163, 29, 185, 31, 67, 168],
+        ProjectUpdated = [16, 110, 74, 3, 76, 227, 179, 225],
+        ProjectRenamed = [92, 58, 126, 103, 117, 84, 165, 30],
+        ProjectOwnerProposed = [195, 96, 72, 63, 117, 156, 37, 1],
+        ProjectOwnershipTransferred = [121, 53, 172, 193, 3, 210, 243, 215],
+        ProjectClosed = [99, 119, 201, 52, 106, 26, 76, 87],
+        ProjectResourceRegistered = [26, 52, 99, 221, 56, 142, 247, 13],
+        ProjectResourceUnregistered = [52, 18, 240, 65, 249, 194, 174, 143],
+        ProjectRoleGranted = [151, 43, 205, 21, 162, 52, 201, 104],
+        ProjectRoleRevoked = [121, 101, 22, 49, 249, 134, 30, 40],
+        ProjectFeeOverrideSet = [153, 50, 100, 200, 22, 103, 79, 12],
+        ProjectFeeOverrideClosed = [198, 206, 26, 49, 129, 22, 146, 164],
+        ProtocolConfigMigrated = [30, 232, 133, 208, 55, 91, 175, 0],
+        ProjectConfigMigrated = [122, 210, 183, 176, 71, 32, 192, 156],
+        ProgramRegistered = [211, 177, 222, 158, 113, 85, 224, 7],
+        ProgramUnregistered = [209, 43, 48, 137, 58, 168, 162, 48],
+        GenericFeeCharged = [134, 230, 148, 52, 131, 212, 190, 15],
+        ReferrerRegistered = [106, 198, 28, 51, 115, 46, 57, 3],
+        ReferrerUpdated = [93, 92, 217, 183, 151, 78, 43, 114],
+        ReferrerRemoved = [198, 181, 24, 81, 243, 65, 3, 38],
+        ReferralFeesClaimed = [118, 130, 122, 41, 74, 34, 240, 48],
+        SubscriptionPlanSet = [215, 210, 149, 23, 253, 235, 94, 110],
+        SubscriptionPlanRemoved = [29, 157, 138, 168, 105, 108, 227, 244],
+        SubscriptionPaid = [204, 65, 145, 54, 154, 163, 113, 229],
+    }
+}
+
+program_events! {
+    /// Events emitted by the nft-operations program.
+    NftOperationsEvent, decode_nft_operations_event, nft_operations {
+        MinterConfigCreated = [11, 184, 163, 172, 96, 135, 138, 77],
+        PublicMintSet = [197, 186, 17, 104, 131, 107, 99, 220],
+        MintPhasesSet = [94, 20, 187, 5, 138, 144, 151, 70],
+        WalletMintLimitSet = [221, 240, 232, 158, 22, 177, 53, 206],
+        AssetMinted = [103, 66, 89, 42, 62, 44, 211, 1],
+        MinterConfigClosed = [29, 237, 105, 56, 244, 127, 39, 148],
+        MintRecordClosed = [194, 75, 96, 60, 51, 66, 95, 58],
+        TradeHubCreated = [228, 31, 239, 207, 223, 128, 210, 0],
+        TradeHubClosed = [118, 210, 37, 37, 133, 28, 120, 184],
+        AssetListed = [39, 64, 97, 162, 189, 150, 150, 2],
+        AssetDelisted = [180, 63, 23, 35, 249, 249, 228, 87],
+        AssetPurchased = [221, 170, 90, 143, 119, 11, 112, 113],
+    }
+}
+
+program_events! {
+    /// Events emitted by the dac-manager program.
+    DacManagerEvent, decode_dac_manager_event, dac_manager {
+        ComputeNodeRegistered = [85, 184, 140, 131, 156, 44, 199, 205],
+        ComputeNodeClaimed = [143, 195, 244, 201, 136, 170, 32, 191],
+        AgentCreated = [237, 44, 61, 111, 90, 251, 241, 34],
+        AgentActivated = [24, 143, 97, 203, 139, 203, 132, 134],
+        TaskStatusChanged = [84, 107, 140, 190, 30, 139, 171, 35],
+    }
+}
@@ -0,0 +1,107 @@
+//! Off-chain fee quotes, mirroring `ProtocolConfig::calculate_fee_amount` in the
+//! sol-mind-protocol program.
+
+use crate::generated::sol_mind_protocol::{
+    accounts::{ProjectFeeOverride, ProtocolConfig},
+    types::{Fee, FeeAdjustment, FeeType, FeesStructure, Operation},
+};
+
+pub const MAX_FEE_BPS: u64 = 10_000;
+
+/// Fee charged for `fee` on `base_amount`, or `None` where the program would reject the
+/// calculation (a `Percentage` fee without a base amount, or an overflow).
+pub fn quote_fee(fee: &Fee, base_amount: Option<u64>) -> Option<u64> {
+    match fee.fee_type {
+        FeeType::Fixed => Some(fee.amount),
+        FeeType::Percentage => bps_of(base_amount?, fee.amount),
+        FeeType::Hybrid { bps, min, max } => {
+            let variable = bps_of(base_amount.unwrap_or(0), u64::from(bps))?;
+            let mut amount = fee.amount.checked_add(variable)?;
+
+            if let Some(min) = min {
+                amount = amount.max(min);
+            }
+            if let Some(max) = max {
+                amount = amount.min(max);
+            }
+
+            Some(amount)
+        }
+    }
+}
+
+/// Fees in effect at the unix timestamp `now`, taking a due pending schedule into account.
+pub fn current_fees(protocol_config: &ProtocolConfig, now: i64) -> &FeesStructure {
+    match &protocol_config.pending_fees {
+        Some(pending) if now >= pending.effective_at => &pending.fees,
+        _ => &protocol_config.fees,
+    }
+}
+
+pub fn get_fee(fees: &FeesStructure, operation: Operation) -> &Fee {
+    match operation {
+        Operation::CreateProject => &fees.create_project,
+        Operation::CreateMinterConfig => &fees.create_minter_config,
+        Operation::CreateTradeHub => &fees.create_trade_hub,
+        Operation::TradeNFT => &fees.trade_nft,
+        Operation::MintAsset => &fees.mint_asset,
+        Operation::Generic => &fees.generic_operation,
+    }
+}
+
+/// Protocol fee the program will charge for `operation` at the unix timestamp `now`.
+pub fn quote_protocol_fee(
+    protocol_config: &ProtocolConfig,
+    operation: Operation,
+    base_amount: Option<u64>,
+    now: i64,
+) -> Option<u64> {
+    quote_fee(
+        get_fee(current_fees(protocol_config, now), operation),
+        base_amount,
+    )
+}
+
+/// Adjustment a project fee override applies to `operation` at the unix timestamp `now`.
+/// Each adjustment only applies from its `effective_at`.
+pub fn get_fee_adjustment(
+    fee_override: &ProjectFeeOverride,
+    operation: Operation,
+    now: i64,
+) -> Option<&FeeAdjustment> {
+    if fee_override
+        .expires_at
+        .is_some_and(|expires_at| now >= expires_at)
+    {
+        return None;
+    }
+
+    fee_override
+        .overrides
+        .iter()
+        .find(|entry| entry.operation == operation)
+        .filter(|entry| now >= entry.effective_at)
+        .map(|entry| &entry.adjustment)
+}
+
+/// Protocol fee charged to a project for `operation`, applying its fee override if it has one.
+pub fn quote_project_fee(
+    protocol_config: &ProtocolConfig,
+    fee_override: Option<&ProjectFeeOverride>,
+    operation: Operation,
+    base_amount: Option<u64>,
+    now: i64,
+) -> Option<u64> {
+    match fee_override.and_then(|fee_override| get_fee_adjustment(fee_override, operation, now)) {
+        Some(FeeAdjustment::Override { fee }) => quote_fee(fee, base_amount),
+        Some(FeeAdjustment::Discount { bps }) => {
+            let amount = quote_protocol_fee(protocol_config, operation, base_amount, now)?;
+            amount.checked_sub(bps_of(amount, u64::from(*bps))?)
+        }
+        None => quote_protocol_fee(protocol_config, operation, base_amount, now),
+    }
+}
+
+fn bps_of(amount: u64, bps: u64) -> Option<u64> {
+    amount.checked_mul(bps)?.checked_div(MAX_FEE_BPS)
+}
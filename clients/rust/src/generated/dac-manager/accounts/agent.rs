@@ -14,6 +14,11 @@ use solana_pubkey::Pubkey;
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Agent {
     pub discriminator: [u8; 8],
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub protocol_config: Pubkey,
     pub agent_id: u64,
     #[cfg_attr(
         feature = "serde",
@@ -33,7 +38,7 @@ pub struct Agent {
 pub const AGENT_DISCRIMINATOR: [u8; 8] = [47, 166, 112, 147, 155, 197, 86, 7];
 
 impl Agent {
-    pub const LEN: usize = 83;
+    pub const LEN: usize = 115;
 
     #[inline(always)]
     pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
@@ -18,6 +18,11 @@ pub struct ComputeNodeInfo {
         feature = "serde",
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
     )]
+    pub protocol_config: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
     pub owner: Pubkey,
     #[cfg_attr(
         feature = "serde",
@@ -31,6 +31,12 @@ pub enum DacManagerError {
     /// 6006 - Agent is not public or the owner of the compute node
     #[error("Agent is not public or the owner of the compute node")]
     AgentNotPublicOrComputeNodeOwner = 0x1776,
+    /// 6007 - Operation is paused by the protocol
+    #[error("Operation is paused by the protocol")]
+    OperationPaused = 0x1777,
+    /// 6008 - Account belongs to another protocol instance
+    #[error("Account belongs to another protocol instance")]
+    ProtocolConfigMismatch = 0x1778,
 }
 
 impl From<DacManagerError> for solana_program_error::ProgramError {
@@ -18,6 +18,12 @@ pub struct ActivateAgent {
     pub compute_node: solana_pubkey::Pubkey,
 
     pub agent: solana_pubkey::Pubkey,
+
+    pub protocol_config: solana_pubkey::Pubkey,
+
+    pub event_authority: solana_pubkey::Pubkey,
+
+    pub program: solana_pubkey::Pubkey,
 }
 
 impl ActivateAgent {
@@ -34,13 +40,25 @@ impl ActivateAgent {
         args: ActivateAgentInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(self.payer, true));
         accounts.push(solana_instruction::AccountMeta::new(
             self.compute_node,
             true,
         ));
         accounts.push(solana_instruction::AccountMeta::new(self.agent, false));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.protocol_config,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.event_authority,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let mut data = ActivateAgentInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -97,11 +115,17 @@ impl ActivateAgentInstructionArgs {
 ///   0. `[writable, signer]` payer
 ///   1. `[writable, signer]` compute_node
 ///   2. `[writable]` agent
+///   3. `[]` protocol_config
+///   4. `[optional]` event_authority (default to `BHcpkvnDd6vX8BQvZ4hMfHykf8ww9Q6tyypaBM4o4ZKr`)
+///   5. `[optional]` program (default to `DeXj8mQDYUnLC2mX5xiRqgvYt193sBhJWRZTBkRLg79M`)
 #[derive(Clone, Debug, Default)]
 pub struct ActivateAgentBuilder {
     payer: Option<solana_pubkey::Pubkey>,
     compute_node: Option<solana_pubkey::Pubkey>,
     agent: Option<solana_pubkey::Pubkey>,
+    protocol_config: Option<solana_pubkey::Pubkey>,
+    event_authority: Option<solana_pubkey::Pubkey>,
+    program: Option<solana_pubkey::Pubkey>,
     agent_id: Option<u64>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -126,6 +150,23 @@ impl ActivateAgentBuilder {
         self
     }
     #[inline(always)]
+    pub fn protocol_config(&mut self, protocol_config: solana_pubkey::Pubkey) -> &mut Self {
+        self.protocol_config = Some(protocol_config);
+        self
+    }
+    /// `[optional account, default to 'BHcpkvnDd6vX8BQvZ4hMfHykf8ww9Q6tyypaBM4o4ZKr']`
+    #[inline(always)]
+    pub fn event_authority(&mut self, event_authority: solana_pubkey::Pubkey) -> &mut Self {
+        self.event_authority = Some(event_authority);
+        self
+    }
+    /// `[optional account, default to 'DeXj8mQDYUnLC2mX5xiRqgvYt193sBhJWRZTBkRLg79M']`
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
+    #[inline(always)]
     pub fn agent_id(&mut self, agent_id: u64) -> &mut Self {
         self.agent_id = Some(agent_id);
         self
@@ -151,6 +192,13 @@ impl ActivateAgentBuilder {
             payer: self.payer.expect("payer is not set"),
             compute_node: self.compute_node.expect("compute_node is not set"),
             agent: self.agent.expect("agent is not set"),
+            protocol_config: self.protocol_config.expect("protocol_config is not set"),
+            event_authority: self.event_authority.unwrap_or(solana_pubkey::pubkey!(
+                "BHcpkvnDd6vX8BQvZ4hMfHykf8ww9Q6tyypaBM4o4ZKr"
+            )),
+            program: self.program.unwrap_or(solana_pubkey::pubkey!(
+                "DeXj8mQDYUnLC2mX5xiRqgvYt193sBhJWRZTBkRLg79M"
+            )),
         };
         let args = ActivateAgentInstructionArgs {
             agent_id: self.agent_id.clone().expect("agent_id is not set"),
@@ -167,6 +215,12 @@ pub struct ActivateAgentCpiAccounts<'a, 'b> {
     pub compute_node: &'b solana_account_info::AccountInfo<'a>,
 
     pub agent: &'b solana_account_info::AccountInfo<'a>,
+
+    pub protocol_config: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
 }
 
 /// `activate_agent` CPI instruction.
@@ -179,6 +233,12 @@ pub struct ActivateAgentCpi<'a, 'b> {
     pub compute_node: &'b solana_account_info::AccountInfo<'a>,
 
     pub agent: &'b solana_account_info::AccountInfo<'a>,
+
+    pub protocol_config: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: ActivateAgentInstructionArgs,
 }
@@ -194,6 +254,9 @@ impl<'a, 'b> ActivateAgentCpi<'a, 'b> {
             payer: accounts.payer,
             compute_node: accounts.compute_node,
             agent: accounts.agent,
+            protocol_config: accounts.protocol_config,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
             __args: args,
         }
     }
@@ -220,13 +283,25 @@ impl<'a, 'b> ActivateAgentCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(*self.payer.key, true));
         accounts.push(solana_instruction::AccountMeta::new(
             *self.compute_node.key,
             true,
         ));
         accounts.push(solana_instruction::AccountMeta::new(*self.agent.key, false));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.protocol_config.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.event_authority.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -243,11 +318,14 @@ impl<'a, 'b> ActivateAgentCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(7 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.payer.clone());
         account_infos.push(self.compute_node.clone());
         account_infos.push(self.agent.clone());
+        account_infos.push(self.protocol_config.clone());
+        account_infos.push(self.event_authority.clone());
+        account_infos.push(self.program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -267,6 +345,9 @@ impl<'a, 'b> ActivateAgentCpi<'a, 'b> {
 ///   0. `[writable, signer]` payer
 ///   1. `[writable, signer]` compute_node
 ///   2. `[writable]` agent
+///   3. `[]` protocol_config
+///   4. `[]` event_authority
+///   5. `[]` program
 #[derive(Clone, Debug)]
 pub struct ActivateAgentCpiBuilder<'a, 'b> {
     instruction: Box<ActivateAgentCpiBuilderInstruction<'a, 'b>>,
@@ -279,6 +360,9 @@ impl<'a, 'b> ActivateAgentCpiBuilder<'a, 'b> {
             payer: None,
             compute_node: None,
             agent: None,
+            protocol_config: None,
+            event_authority: None,
+            program: None,
             agent_id: None,
             __remaining_accounts: Vec::new(),
         });
@@ -303,6 +387,27 @@ impl<'a, 'b> ActivateAgentCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn protocol_config(
+        &mut self,
+        protocol_config: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.protocol_config = Some(protocol_config);
+        self
+    }
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(&mut self, program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
+    #[inline(always)]
     pub fn agent_id(&mut self, agent_id: u64) -> &mut Self {
         self.instruction.agent_id = Some(agent_id);
         self
@@ -359,6 +464,18 @@ impl<'a, 'b> ActivateAgentCpiBuilder<'a, 'b> {
                 .expect("compute_node is not set"),
 
             agent: self.instruction.agent.expect("agent is not set"),
+
+            protocol_config: self
+                .instruction
+                .protocol_config
+                .expect("protocol_config is not set"),
+
+            event_authority: self
+                .instruction
+                .event_authority
+                .expect("event_authority is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -374,6 +491,9 @@ struct ActivateAgentCpiBuilderInstruction<'a, 'b> {
     payer: Option<&'b solana_account_info::AccountInfo<'a>>,
     compute_node: Option<&'b solana_account_info::AccountInfo<'a>>,
     agent: Option<&'b solana_account_info::AccountInfo<'a>>,
+    protocol_config: Option<&'b solana_account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_account_info::AccountInfo<'a>>,
     agent_id: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
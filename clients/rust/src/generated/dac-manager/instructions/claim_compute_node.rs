@@ -19,7 +19,13 @@ pub struct ClaimComputeNode {
 
     pub compute_node_info: solana_pubkey::Pubkey,
 
+    pub protocol_config: solana_pubkey::Pubkey,
+
     pub system_program: solana_pubkey::Pubkey,
+
+    pub event_authority: solana_pubkey::Pubkey,
+
+    pub program: solana_pubkey::Pubkey,
 }
 
 impl ClaimComputeNode {
@@ -36,7 +42,7 @@ impl ClaimComputeNode {
         args: ClaimComputeNodeInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(self.payer, true));
         accounts.push(solana_instruction::AccountMeta::new(
             self.compute_node,
@@ -46,10 +52,22 @@ impl ClaimComputeNode {
             self.compute_node_info,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.protocol_config,
+            false,
+        ));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.system_program,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.event_authority,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let mut data = ClaimComputeNodeInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -106,13 +124,19 @@ impl ClaimComputeNodeInstructionArgs {
 ///   0. `[writable, signer]` payer
 ///   1. `[writable, signer]` compute_node
 ///   2. `[writable]` compute_node_info
-///   3. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   3. `[]` protocol_config
+///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   5. `[optional]` event_authority (default to `BHcpkvnDd6vX8BQvZ4hMfHykf8ww9Q6tyypaBM4o4ZKr`)
+///   6. `[optional]` program (default to `DeXj8mQDYUnLC2mX5xiRqgvYt193sBhJWRZTBkRLg79M`)
 #[derive(Clone, Debug, Default)]
 pub struct ClaimComputeNodeBuilder {
     payer: Option<solana_pubkey::Pubkey>,
     compute_node: Option<solana_pubkey::Pubkey>,
     compute_node_info: Option<solana_pubkey::Pubkey>,
+    protocol_config: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
+    event_authority: Option<solana_pubkey::Pubkey>,
+    program: Option<solana_pubkey::Pubkey>,
     node_info_cid: Option<String>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -136,12 +160,29 @@ impl ClaimComputeNodeBuilder {
         self.compute_node_info = Some(compute_node_info);
         self
     }
+    #[inline(always)]
+    pub fn protocol_config(&mut self, protocol_config: solana_pubkey::Pubkey) -> &mut Self {
+        self.protocol_config = Some(protocol_config);
+        self
+    }
     /// `[optional account, default to '11111111111111111111111111111111']`
     #[inline(always)]
     pub fn system_program(&mut self, system_program: solana_pubkey::Pubkey) -> &mut Self {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account, default to 'BHcpkvnDd6vX8BQvZ4hMfHykf8ww9Q6tyypaBM4o4ZKr']`
+    #[inline(always)]
+    pub fn event_authority(&mut self, event_authority: solana_pubkey::Pubkey) -> &mut Self {
+        self.event_authority = Some(event_authority);
+        self
+    }
+    /// `[optional account, default to 'DeXj8mQDYUnLC2mX5xiRqgvYt193sBhJWRZTBkRLg79M']`
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
     #[inline(always)]
     pub fn node_info_cid(&mut self, node_info_cid: String) -> &mut Self {
         self.node_info_cid = Some(node_info_cid);
@@ -170,9 +211,16 @@ impl ClaimComputeNodeBuilder {
             compute_node_info: self
                 .compute_node_info
                 .expect("compute_node_info is not set"),
+            protocol_config: self.protocol_config.expect("protocol_config is not set"),
             system_program: self
                 .system_program
                 .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
+            event_authority: self.event_authority.unwrap_or(solana_pubkey::pubkey!(
+                "BHcpkvnDd6vX8BQvZ4hMfHykf8ww9Q6tyypaBM4o4ZKr"
+            )),
+            program: self.program.unwrap_or(solana_pubkey::pubkey!(
+                "DeXj8mQDYUnLC2mX5xiRqgvYt193sBhJWRZTBkRLg79M"
+            )),
         };
         let args = ClaimComputeNodeInstructionArgs {
             node_info_cid: self
@@ -193,7 +241,13 @@ pub struct ClaimComputeNodeCpiAccounts<'a, 'b> {
 
     pub compute_node_info: &'b solana_account_info::AccountInfo<'a>,
 
+    pub protocol_config: &'b solana_account_info::AccountInfo<'a>,
+
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
 }
 
 /// `claim_compute_node` CPI instruction.
@@ -207,7 +261,13 @@ pub struct ClaimComputeNodeCpi<'a, 'b> {
 
     pub compute_node_info: &'b solana_account_info::AccountInfo<'a>,
 
+    pub protocol_config: &'b solana_account_info::AccountInfo<'a>,
+
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: ClaimComputeNodeInstructionArgs,
 }
@@ -223,7 +283,10 @@ impl<'a, 'b> ClaimComputeNodeCpi<'a, 'b> {
             payer: accounts.payer,
             compute_node: accounts.compute_node,
             compute_node_info: accounts.compute_node_info,
+            protocol_config: accounts.protocol_config,
             system_program: accounts.system_program,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
             __args: args,
         }
     }
@@ -250,7 +313,7 @@ impl<'a, 'b> ClaimComputeNodeCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(*self.payer.key, true));
         accounts.push(solana_instruction::AccountMeta::new(
             *self.compute_node.key,
@@ -260,10 +323,22 @@ impl<'a, 'b> ClaimComputeNodeCpi<'a, 'b> {
             *self.compute_node_info.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.protocol_config.key,
+            false,
+        ));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.system_program.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.event_authority.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -280,12 +355,15 @@ impl<'a, 'b> ClaimComputeNodeCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(8 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.payer.clone());
         account_infos.push(self.compute_node.clone());
         account_infos.push(self.compute_node_info.clone());
+        account_infos.push(self.protocol_config.clone());
         account_infos.push(self.system_program.clone());
+        account_infos.push(self.event_authority.clone());
+        account_infos.push(self.program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -305,7 +383,10 @@ impl<'a, 'b> ClaimComputeNodeCpi<'a, 'b> {
 ///   0. `[writable, signer]` payer
 ///   1. `[writable, signer]` compute_node
 ///   2. `[writable]` compute_node_info
-///   3. `[]` system_program
+///   3. `[]` protocol_config
+///   4. `[]` system_program
+///   5. `[]` event_authority
+///   6. `[]` program
 #[derive(Clone, Debug)]
 pub struct ClaimComputeNodeCpiBuilder<'a, 'b> {
     instruction: Box<ClaimComputeNodeCpiBuilderInstruction<'a, 'b>>,
@@ -318,7 +399,10 @@ impl<'a, 'b> ClaimComputeNodeCpiBuilder<'a, 'b> {
             payer: None,
             compute_node: None,
             compute_node_info: None,
+            protocol_config: None,
             system_program: None,
+            event_authority: None,
+            program: None,
             node_info_cid: None,
             __remaining_accounts: Vec::new(),
         });
@@ -346,6 +430,14 @@ impl<'a, 'b> ClaimComputeNodeCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn protocol_config(
+        &mut self,
+        protocol_config: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.protocol_config = Some(protocol_config);
+        self
+    }
+    #[inline(always)]
     pub fn system_program(
         &mut self,
         system_program: &'b solana_account_info::AccountInfo<'a>,
@@ -354,6 +446,19 @@ impl<'a, 'b> ClaimComputeNodeCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(&mut self, program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
+    #[inline(always)]
     pub fn node_info_cid(&mut self, node_info_cid: String) -> &mut Self {
         self.instruction.node_info_cid = Some(node_info_cid);
         self
@@ -414,10 +519,22 @@ impl<'a, 'b> ClaimComputeNodeCpiBuilder<'a, 'b> {
                 .compute_node_info
                 .expect("compute_node_info is not set"),
 
+            protocol_config: self
+                .instruction
+                .protocol_config
+                .expect("protocol_config is not set"),
+
             system_program: self
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            event_authority: self
+                .instruction
+                .event_authority
+                .expect("event_authority is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -433,7 +550,10 @@ struct ClaimComputeNodeCpiBuilderInstruction<'a, 'b> {
     payer: Option<&'b solana_account_info::AccountInfo<'a>>,
     compute_node: Option<&'b solana_account_info::AccountInfo<'a>>,
     compute_node_info: Option<&'b solana_account_info::AccountInfo<'a>>,
+    protocol_config: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_account_info::AccountInfo<'a>>,
     node_info_cid: Option<String>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
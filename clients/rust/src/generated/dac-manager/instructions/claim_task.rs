@@ -21,7 +21,13 @@ pub struct ClaimTask {
 
     pub agent: solana_pubkey::Pubkey,
 
+    pub protocol_config: solana_pubkey::Pubkey,
+
     pub system_program: solana_pubkey::Pubkey,
+
+    pub event_authority: solana_pubkey::Pubkey,
+
+    pub program: solana_pubkey::Pubkey,
 }
 
 impl ClaimTask {
@@ -34,7 +40,7 @@ impl ClaimTask {
         &self,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(self.payer, true));
         accounts.push(solana_instruction::AccountMeta::new(
             self.compute_node,
@@ -42,10 +48,22 @@ impl ClaimTask {
         ));
         accounts.push(solana_instruction::AccountMeta::new(self.task_data, false));
         accounts.push(solana_instruction::AccountMeta::new(self.agent, false));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.protocol_config,
+            false,
+        ));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.system_program,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.event_authority,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let data = ClaimTaskInstructionData::new().try_to_vec().unwrap();
 
@@ -89,14 +107,20 @@ impl Default for ClaimTaskInstructionData {
 ///   1. `[writable, signer]` compute_node
 ///   2. `[writable]` task_data
 ///   3. `[writable]` agent
-///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   4. `[]` protocol_config
+///   5. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   6. `[optional]` event_authority (default to `BHcpkvnDd6vX8BQvZ4hMfHykf8ww9Q6tyypaBM4o4ZKr`)
+///   7. `[optional]` program (default to `DeXj8mQDYUnLC2mX5xiRqgvYt193sBhJWRZTBkRLg79M`)
 #[derive(Clone, Debug, Default)]
 pub struct ClaimTaskBuilder {
     payer: Option<solana_pubkey::Pubkey>,
     compute_node: Option<solana_pubkey::Pubkey>,
     task_data: Option<solana_pubkey::Pubkey>,
     agent: Option<solana_pubkey::Pubkey>,
+    protocol_config: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
+    event_authority: Option<solana_pubkey::Pubkey>,
+    program: Option<solana_pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -124,12 +148,29 @@ impl ClaimTaskBuilder {
         self.agent = Some(agent);
         self
     }
+    #[inline(always)]
+    pub fn protocol_config(&mut self, protocol_config: solana_pubkey::Pubkey) -> &mut Self {
+        self.protocol_config = Some(protocol_config);
+        self
+    }
     /// `[optional account, default to '11111111111111111111111111111111']`
     #[inline(always)]
     pub fn system_program(&mut self, system_program: solana_pubkey::Pubkey) -> &mut Self {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account, default to 'BHcpkvnDd6vX8BQvZ4hMfHykf8ww9Q6tyypaBM4o4ZKr']`
+    #[inline(always)]
+    pub fn event_authority(&mut self, event_authority: solana_pubkey::Pubkey) -> &mut Self {
+        self.event_authority = Some(event_authority);
+        self
+    }
+    /// `[optional account, default to 'DeXj8mQDYUnLC2mX5xiRqgvYt193sBhJWRZTBkRLg79M']`
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(&mut self, account: solana_instruction::AccountMeta) -> &mut Self {
@@ -152,9 +193,16 @@ impl ClaimTaskBuilder {
             compute_node: self.compute_node.expect("compute_node is not set"),
             task_data: self.task_data.expect("task_data is not set"),
             agent: self.agent.expect("agent is not set"),
+            protocol_config: self.protocol_config.expect("protocol_config is not set"),
             system_program: self
                 .system_program
                 .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
+            event_authority: self.event_authority.unwrap_or(solana_pubkey::pubkey!(
+                "BHcpkvnDd6vX8BQvZ4hMfHykf8ww9Q6tyypaBM4o4ZKr"
+            )),
+            program: self.program.unwrap_or(solana_pubkey::pubkey!(
+                "DeXj8mQDYUnLC2mX5xiRqgvYt193sBhJWRZTBkRLg79M"
+            )),
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -171,7 +219,13 @@ pub struct ClaimTaskCpiAccounts<'a, 'b> {
 
     pub agent: &'b solana_account_info::AccountInfo<'a>,
 
+    pub protocol_config: &'b solana_account_info::AccountInfo<'a>,
+
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
 }
 
 /// `claim_task` CPI instruction.
@@ -187,7 +241,13 @@ pub struct ClaimTaskCpi<'a, 'b> {
 
     pub agent: &'b solana_account_info::AccountInfo<'a>,
 
+    pub protocol_config: &'b solana_account_info::AccountInfo<'a>,
+
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
 }
 
 impl<'a, 'b> ClaimTaskCpi<'a, 'b> {
@@ -201,7 +261,10 @@ impl<'a, 'b> ClaimTaskCpi<'a, 'b> {
             compute_node: accounts.compute_node,
             task_data: accounts.task_data,
             agent: accounts.agent,
+            protocol_config: accounts.protocol_config,
             system_program: accounts.system_program,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
         }
     }
     #[inline(always)]
@@ -227,7 +290,7 @@ impl<'a, 'b> ClaimTaskCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(*self.payer.key, true));
         accounts.push(solana_instruction::AccountMeta::new(
             *self.compute_node.key,
@@ -238,10 +301,22 @@ impl<'a, 'b> ClaimTaskCpi<'a, 'b> {
             false,
         ));
         accounts.push(solana_instruction::AccountMeta::new(*self.agent.key, false));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.protocol_config.key,
+            false,
+        ));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.system_program.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.event_authority.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -256,13 +331,16 @@ impl<'a, 'b> ClaimTaskCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(9 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.payer.clone());
         account_infos.push(self.compute_node.clone());
         account_infos.push(self.task_data.clone());
         account_infos.push(self.agent.clone());
+        account_infos.push(self.protocol_config.clone());
         account_infos.push(self.system_program.clone());
+        account_infos.push(self.event_authority.clone());
+        account_infos.push(self.program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -283,7 +361,10 @@ impl<'a, 'b> ClaimTaskCpi<'a, 'b> {
 ///   1. `[writable, signer]` compute_node
 ///   2. `[writable]` task_data
 ///   3. `[writable]` agent
-///   4. `[]` system_program
+///   4. `[]` protocol_config
+///   5. `[]` system_program
+///   6. `[]` event_authority
+///   7. `[]` program
 #[derive(Clone, Debug)]
 pub struct ClaimTaskCpiBuilder<'a, 'b> {
     instruction: Box<ClaimTaskCpiBuilderInstruction<'a, 'b>>,
@@ -297,7 +378,10 @@ impl<'a, 'b> ClaimTaskCpiBuilder<'a, 'b> {
             compute_node: None,
             task_data: None,
             agent: None,
+            protocol_config: None,
             system_program: None,
+            event_authority: None,
+            program: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -326,6 +410,14 @@ impl<'a, 'b> ClaimTaskCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn protocol_config(
+        &mut self,
+        protocol_config: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.protocol_config = Some(protocol_config);
+        self
+    }
+    #[inline(always)]
     pub fn system_program(
         &mut self,
         system_program: &'b solana_account_info::AccountInfo<'a>,
@@ -333,6 +425,19 @@ impl<'a, 'b> ClaimTaskCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(&mut self, program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -381,10 +486,22 @@ impl<'a, 'b> ClaimTaskCpiBuilder<'a, 'b> {
 
             agent: self.instruction.agent.expect("agent is not set"),
 
+            protocol_config: self
+                .instruction
+                .protocol_config
+                .expect("protocol_config is not set"),
+
             system_program: self
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            event_authority: self
+                .instruction
+                .event_authority
+                .expect("event_authority is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -400,7 +517,10 @@ struct ClaimTaskCpiBuilderInstruction<'a, 'b> {
     compute_node: Option<&'b solana_account_info::AccountInfo<'a>>,
     task_data: Option<&'b solana_account_info::AccountInfo<'a>>,
     agent: Option<&'b solana_account_info::AccountInfo<'a>>,
+    protocol_config: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
@@ -23,7 +23,13 @@ pub struct CreateAgent {
 
     pub compute_node_info: solana_pubkey::Pubkey,
 
+    pub protocol_config: solana_pubkey::Pubkey,
+
     pub system_program: solana_pubkey::Pubkey,
+
+    pub event_authority: solana_pubkey::Pubkey,
+
+    pub program: solana_pubkey::Pubkey,
 }
 
 impl CreateAgent {
@@ -37,7 +43,7 @@ impl CreateAgent {
         args: CreateAgentInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(self.payer, true));
         accounts.push(solana_instruction::AccountMeta::new(self.owner, true));
         accounts.push(solana_instruction::AccountMeta::new(self.agent, false));
@@ -46,10 +52,22 @@ impl CreateAgent {
             self.compute_node_info,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.protocol_config,
+            false,
+        ));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.system_program,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.event_authority,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let mut data = CreateAgentInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -109,7 +127,10 @@ impl CreateAgentInstructionArgs {
 ///   2. `[writable]` agent
 ///   3. `[writable]` task_data
 ///   4. `[]` compute_node_info
-///   5. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   5. `[]` protocol_config
+///   6. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   7. `[optional]` event_authority (default to `BHcpkvnDd6vX8BQvZ4hMfHykf8ww9Q6tyypaBM4o4ZKr`)
+///   8. `[optional]` program (default to `DeXj8mQDYUnLC2mX5xiRqgvYt193sBhJWRZTBkRLg79M`)
 #[derive(Clone, Debug, Default)]
 pub struct CreateAgentBuilder {
     payer: Option<solana_pubkey::Pubkey>,
@@ -117,7 +138,10 @@ pub struct CreateAgentBuilder {
     agent: Option<solana_pubkey::Pubkey>,
     task_data: Option<solana_pubkey::Pubkey>,
     compute_node_info: Option<solana_pubkey::Pubkey>,
+    protocol_config: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
+    event_authority: Option<solana_pubkey::Pubkey>,
+    program: Option<solana_pubkey::Pubkey>,
     agent_id: Option<u64>,
     public: Option<bool>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
@@ -152,12 +176,29 @@ impl CreateAgentBuilder {
         self.compute_node_info = Some(compute_node_info);
         self
     }
+    #[inline(always)]
+    pub fn protocol_config(&mut self, protocol_config: solana_pubkey::Pubkey) -> &mut Self {
+        self.protocol_config = Some(protocol_config);
+        self
+    }
     /// `[optional account, default to '11111111111111111111111111111111']`
     #[inline(always)]
     pub fn system_program(&mut self, system_program: solana_pubkey::Pubkey) -> &mut Self {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account, default to 'BHcpkvnDd6vX8BQvZ4hMfHykf8ww9Q6tyypaBM4o4ZKr']`
+    #[inline(always)]
+    pub fn event_authority(&mut self, event_authority: solana_pubkey::Pubkey) -> &mut Self {
+        self.event_authority = Some(event_authority);
+        self
+    }
+    /// `[optional account, default to 'DeXj8mQDYUnLC2mX5xiRqgvYt193sBhJWRZTBkRLg79M']`
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
     #[inline(always)]
     pub fn agent_id(&mut self, agent_id: u64) -> &mut Self {
         self.agent_id = Some(agent_id);
@@ -193,9 +234,16 @@ impl CreateAgentBuilder {
             compute_node_info: self
                 .compute_node_info
                 .expect("compute_node_info is not set"),
+            protocol_config: self.protocol_config.expect("protocol_config is not set"),
             system_program: self
                 .system_program
                 .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
+            event_authority: self.event_authority.unwrap_or(solana_pubkey::pubkey!(
+                "BHcpkvnDd6vX8BQvZ4hMfHykf8ww9Q6tyypaBM4o4ZKr"
+            )),
+            program: self.program.unwrap_or(solana_pubkey::pubkey!(
+                "DeXj8mQDYUnLC2mX5xiRqgvYt193sBhJWRZTBkRLg79M"
+            )),
         };
         let args = CreateAgentInstructionArgs {
             agent_id: self.agent_id.clone().expect("agent_id is not set"),
@@ -218,7 +266,13 @@ pub struct CreateAgentCpiAccounts<'a, 'b> {
 
     pub compute_node_info: &'b solana_account_info::AccountInfo<'a>,
 
+    pub protocol_config: &'b solana_account_info::AccountInfo<'a>,
+
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
 }
 
 /// `create_agent` CPI instruction.
@@ -236,7 +290,13 @@ pub struct CreateAgentCpi<'a, 'b> {
 
     pub compute_node_info: &'b solana_account_info::AccountInfo<'a>,
 
+    pub protocol_config: &'b solana_account_info::AccountInfo<'a>,
+
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: CreateAgentInstructionArgs,
 }
@@ -254,7 +314,10 @@ impl<'a, 'b> CreateAgentCpi<'a, 'b> {
             agent: accounts.agent,
             task_data: accounts.task_data,
             compute_node_info: accounts.compute_node_info,
+            protocol_config: accounts.protocol_config,
             system_program: accounts.system_program,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
             __args: args,
         }
     }
@@ -281,7 +344,7 @@ impl<'a, 'b> CreateAgentCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(*self.payer.key, true));
         accounts.push(solana_instruction::AccountMeta::new(*self.owner.key, true));
         accounts.push(solana_instruction::AccountMeta::new(*self.agent.key, false));
@@ -293,10 +356,22 @@ impl<'a, 'b> CreateAgentCpi<'a, 'b> {
             *self.compute_node_info.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.protocol_config.key,
+            false,
+        ));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.system_program.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.event_authority.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -313,14 +388,17 @@ impl<'a, 'b> CreateAgentCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(10 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.payer.clone());
         account_infos.push(self.owner.clone());
         account_infos.push(self.agent.clone());
         account_infos.push(self.task_data.clone());
         account_infos.push(self.compute_node_info.clone());
+        account_infos.push(self.protocol_config.clone());
         account_infos.push(self.system_program.clone());
+        account_infos.push(self.event_authority.clone());
+        account_infos.push(self.program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -342,7 +420,10 @@ impl<'a, 'b> CreateAgentCpi<'a, 'b> {
 ///   2. `[writable]` agent
 ///   3. `[writable]` task_data
 ///   4. `[]` compute_node_info
-///   5. `[]` system_program
+///   5. `[]` protocol_config
+///   6. `[]` system_program
+///   7. `[]` event_authority
+///   8. `[]` program
 #[derive(Clone, Debug)]
 pub struct CreateAgentCpiBuilder<'a, 'b> {
     instruction: Box<CreateAgentCpiBuilderInstruction<'a, 'b>>,
@@ -357,7 +438,10 @@ impl<'a, 'b> CreateAgentCpiBuilder<'a, 'b> {
             agent: None,
             task_data: None,
             compute_node_info: None,
+            protocol_config: None,
             system_program: None,
+            event_authority: None,
+            program: None,
             agent_id: None,
             public: None,
             __remaining_accounts: Vec::new(),
@@ -393,6 +477,14 @@ impl<'a, 'b> CreateAgentCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn protocol_config(
+        &mut self,
+        protocol_config: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.protocol_config = Some(protocol_config);
+        self
+    }
+    #[inline(always)]
     pub fn system_program(
         &mut self,
         system_program: &'b solana_account_info::AccountInfo<'a>,
@@ -401,6 +493,19 @@ impl<'a, 'b> CreateAgentCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(&mut self, program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
+    #[inline(always)]
     pub fn agent_id(&mut self, agent_id: u64) -> &mut Self {
         self.instruction.agent_id = Some(agent_id);
         self
@@ -468,10 +573,22 @@ impl<'a, 'b> CreateAgentCpiBuilder<'a, 'b> {
                 .compute_node_info
                 .expect("compute_node_info is not set"),
 
+            protocol_config: self
+                .instruction
+                .protocol_config
+                .expect("protocol_config is not set"),
+
             system_program: self
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            event_authority: self
+                .instruction
+                .event_authority
+                .expect("event_authority is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -489,7 +606,10 @@ struct CreateAgentCpiBuilderInstruction<'a, 'b> {
     agent: Option<&'b solana_account_info::AccountInfo<'a>>,
     task_data: Option<&'b solana_account_info::AccountInfo<'a>>,
     compute_node_info: Option<&'b solana_account_info::AccountInfo<'a>>,
+    protocol_config: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_account_info::AccountInfo<'a>>,
     agent_id: Option<u64>,
     public: Option<bool>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
@@ -21,7 +21,13 @@ pub struct SubmitTaskResult {
 
     pub agent: solana_pubkey::Pubkey,
 
+    pub protocol_config: solana_pubkey::Pubkey,
+
     pub system_program: solana_pubkey::Pubkey,
+
+    pub event_authority: solana_pubkey::Pubkey,
+
+    pub program: solana_pubkey::Pubkey,
 }
 
 impl SubmitTaskResult {
@@ -38,7 +44,7 @@ impl SubmitTaskResult {
         args: SubmitTaskResultInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(self.payer, true));
         accounts.push(solana_instruction::AccountMeta::new(
             self.compute_node,
@@ -46,10 +52,22 @@ impl SubmitTaskResult {
         ));
         accounts.push(solana_instruction::AccountMeta::new(self.task_data, false));
         accounts.push(solana_instruction::AccountMeta::new(self.agent, false));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.protocol_config,
+            false,
+        ));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.system_program,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.event_authority,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let mut data = SubmitTaskResultInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -107,14 +125,20 @@ impl SubmitTaskResultInstructionArgs {
 ///   1. `[writable, signer]` compute_node
 ///   2. `[writable]` task_data
 ///   3. `[writable]` agent
-///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   4. `[]` protocol_config
+///   5. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   6. `[optional]` event_authority (default to `BHcpkvnDd6vX8BQvZ4hMfHykf8ww9Q6tyypaBM4o4ZKr`)
+///   7. `[optional]` program (default to `DeXj8mQDYUnLC2mX5xiRqgvYt193sBhJWRZTBkRLg79M`)
 #[derive(Clone, Debug, Default)]
 pub struct SubmitTaskResultBuilder {
     payer: Option<solana_pubkey::Pubkey>,
     compute_node: Option<solana_pubkey::Pubkey>,
     task_data: Option<solana_pubkey::Pubkey>,
     agent: Option<solana_pubkey::Pubkey>,
+    protocol_config: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
+    event_authority: Option<solana_pubkey::Pubkey>,
+    program: Option<solana_pubkey::Pubkey>,
     result: Option<Vec<u8>>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -143,12 +167,29 @@ impl SubmitTaskResultBuilder {
         self.agent = Some(agent);
         self
     }
+    #[inline(always)]
+    pub fn protocol_config(&mut self, protocol_config: solana_pubkey::Pubkey) -> &mut Self {
+        self.protocol_config = Some(protocol_config);
+        self
+    }
     /// `[optional account, default to '11111111111111111111111111111111']`
     #[inline(always)]
     pub fn system_program(&mut self, system_program: solana_pubkey::Pubkey) -> &mut Self {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account, default to 'BHcpkvnDd6vX8BQvZ4hMfHykf8ww9Q6tyypaBM4o4ZKr']`
+    #[inline(always)]
+    pub fn event_authority(&mut self, event_authority: solana_pubkey::Pubkey) -> &mut Self {
+        self.event_authority = Some(event_authority);
+        self
+    }
+    /// `[optional account, default to 'DeXj8mQDYUnLC2mX5xiRqgvYt193sBhJWRZTBkRLg79M']`
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
     #[inline(always)]
     pub fn result(&mut self, result: Vec<u8>) -> &mut Self {
         self.result = Some(result);
@@ -176,9 +217,16 @@ impl SubmitTaskResultBuilder {
             compute_node: self.compute_node.expect("compute_node is not set"),
             task_data: self.task_data.expect("task_data is not set"),
             agent: self.agent.expect("agent is not set"),
+            protocol_config: self.protocol_config.expect("protocol_config is not set"),
             system_program: self
                 .system_program
                 .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
+            event_authority: self.event_authority.unwrap_or(solana_pubkey::pubkey!(
+                "BHcpkvnDd6vX8BQvZ4hMfHykf8ww9Q6tyypaBM4o4ZKr"
+            )),
+            program: self.program.unwrap_or(solana_pubkey::pubkey!(
+                "DeXj8mQDYUnLC2mX5xiRqgvYt193sBhJWRZTBkRLg79M"
+            )),
         };
         let args = SubmitTaskResultInstructionArgs {
             result: self.result.clone().expect("result is not set"),
@@ -198,7 +246,13 @@ pub struct SubmitTaskResultCpiAccounts<'a, 'b> {
 
     pub agent: &'b solana_account_info::AccountInfo<'a>,
 
+    pub protocol_config: &'b solana_account_info::AccountInfo<'a>,
+
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
 }
 
 /// `submit_task_result` CPI instruction.
@@ -214,7 +268,13 @@ pub struct SubmitTaskResultCpi<'a, 'b> {
 
     pub agent: &'b solana_account_info::AccountInfo<'a>,
 
+    pub protocol_config: &'b solana_account_info::AccountInfo<'a>,
+
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: SubmitTaskResultInstructionArgs,
 }
@@ -231,7 +291,10 @@ impl<'a, 'b> SubmitTaskResultCpi<'a, 'b> {
             compute_node: accounts.compute_node,
             task_data: accounts.task_data,
             agent: accounts.agent,
+            protocol_config: accounts.protocol_config,
             system_program: accounts.system_program,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
             __args: args,
         }
     }
@@ -258,7 +321,7 @@ impl<'a, 'b> SubmitTaskResultCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(*self.payer.key, true));
         accounts.push(solana_instruction::AccountMeta::new(
             *self.compute_node.key,
@@ -269,10 +332,22 @@ impl<'a, 'b> SubmitTaskResultCpi<'a, 'b> {
             false,
         ));
         accounts.push(solana_instruction::AccountMeta::new(*self.agent.key, false));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.protocol_config.key,
+            false,
+        ));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.system_program.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.event_authority.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -289,13 +364,16 @@ impl<'a, 'b> SubmitTaskResultCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(9 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.payer.clone());
         account_infos.push(self.compute_node.clone());
         account_infos.push(self.task_data.clone());
         account_infos.push(self.agent.clone());
+        account_infos.push(self.protocol_config.clone());
         account_infos.push(self.system_program.clone());
+        account_infos.push(self.event_authority.clone());
+        account_infos.push(self.program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -316,7 +394,10 @@ impl<'a, 'b> SubmitTaskResultCpi<'a, 'b> {
 ///   1. `[writable, signer]` compute_node
 ///   2. `[writable]` task_data
 ///   3. `[writable]` agent
-///   4. `[]` system_program
+///   4. `[]` protocol_config
+///   5. `[]` system_program
+///   6. `[]` event_authority
+///   7. `[]` program
 #[derive(Clone, Debug)]
 pub struct SubmitTaskResultCpiBuilder<'a, 'b> {
     instruction: Box<SubmitTaskResultCpiBuilderInstruction<'a, 'b>>,
@@ -330,7 +411,10 @@ impl<'a, 'b> SubmitTaskResultCpiBuilder<'a, 'b> {
             compute_node: None,
             task_data: None,
             agent: None,
+            protocol_config: None,
             system_program: None,
+            event_authority: None,
+            program: None,
             result: None,
             __remaining_accounts: Vec::new(),
         });
@@ -360,6 +444,14 @@ impl<'a, 'b> SubmitTaskResultCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn protocol_config(
+        &mut self,
+        protocol_config: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.protocol_config = Some(protocol_config);
+        self
+    }
+    #[inline(always)]
     pub fn system_program(
         &mut self,
         system_program: &'b solana_account_info::AccountInfo<'a>,
@@ -368,6 +460,19 @@ impl<'a, 'b> SubmitTaskResultCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(&mut self, program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
+    #[inline(always)]
     pub fn result(&mut self, result: Vec<u8>) -> &mut Self {
         self.instruction.result = Some(result);
         self
@@ -423,10 +528,22 @@ impl<'a, 'b> SubmitTaskResultCpiBuilder<'a, 'b> {
 
             agent: self.instruction.agent.expect("agent is not set"),
 
+            protocol_config: self
+                .instruction
+                .protocol_config
+                .expect("protocol_config is not set"),
+
             system_program: self
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            event_authority: self
+                .instruction
+                .event_authority
+                .expect("event_authority is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -443,7 +560,10 @@ struct SubmitTaskResultCpiBuilderInstruction<'a, 'b> {
     compute_node: Option<&'b solana_account_info::AccountInfo<'a>>,
     task_data: Option<&'b solana_account_info::AccountInfo<'a>>,
     agent: Option<&'b solana_account_info::AccountInfo<'a>>,
+    protocol_config: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_account_info::AccountInfo<'a>>,
     result: Option<Vec<u8>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
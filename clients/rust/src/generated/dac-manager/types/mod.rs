@@ -5,10 +5,20 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+pub(crate) mod r#agent_activated;
+pub(crate) mod r#agent_created;
 pub(crate) mod r#agent_status;
+pub(crate) mod r#compute_node_claimed;
+pub(crate) mod r#compute_node_registered;
 pub(crate) mod r#compute_node_status;
 pub(crate) mod r#task_status;
+pub(crate) mod r#task_status_changed;
 
+pub use self::r#agent_activated::*;
+pub use self::r#agent_created::*;
 pub use self::r#agent_status::*;
+pub use self::r#compute_node_claimed::*;
+pub use self::r#compute_node_registered::*;
 pub use self::r#compute_node_status::*;
 pub use self::r#task_status::*;
+pub use self::r#task_status_changed::*;
@@ -9,6 +9,6 @@ pub(crate) mod dac_manager;
 
 pub mod types {
     pub use super::dac_manager::types::*;
-    pub use super::nft_operations::types::AssetsConfig;
+    pub use super::nft_operations::types::{AllowlistProof, AssetsConfig, MintPhase};
     pub use super::sol_mind_protocol::types::*;
 }
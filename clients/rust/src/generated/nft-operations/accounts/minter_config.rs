@@ -6,6 +6,7 @@
 //!
 
 use crate::generated::types::AssetsConfig;
+use crate::generated::types::MintPhase;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use solana_pubkey::Pubkey;
@@ -21,6 +22,9 @@ pub struct MinterConfig {
     pub assets_config: Option<AssetsConfig>,
     pub collection: Option<Pubkey>,
     pub bump: u8,
+    pub public_mint: bool,
+    pub phases: Vec<MintPhase>,
+    pub wallet_mint_limit: u64,
 }
 
 pub const MINTER_CONFIG_DISCRIMINATOR: [u8; 8] = [78, 211, 23, 6, 233, 19, 19, 236];
@@ -6,13 +6,17 @@
 //!
 
 pub(crate) mod r#listing;
+pub(crate) mod r#mint_record;
 pub(crate) mod r#minter_config;
 pub(crate) mod r#project_config;
 pub(crate) mod r#protocol_config;
+pub(crate) mod r#referrer;
 pub(crate) mod r#trade_hub;
 
 pub use self::r#listing::*;
+pub use self::r#mint_record::*;
 pub use self::r#minter_config::*;
 pub use self::r#project_config::*;
 pub use self::r#protocol_config::*;
+pub use self::r#referrer::*;
 pub use self::r#trade_hub::*;
@@ -23,7 +23,13 @@ pub struct ProjectConfig {
         feature = "serde",
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
     )]
+    pub creator: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
     pub owner: Pubkey,
+    pub pending_owner: Option<Pubkey>,
     pub name: String,
     pub description: String,
     #[cfg_attr(
@@ -31,8 +37,13 @@ pub struct ProjectConfig {
         serde(with = "serde_with::As::<Vec<serde_with::DisplayFromStr>>")
     )]
     pub autthorities: Vec<Pubkey>,
+    pub minter_configs_count: u64,
+    pub trade_hubs_count: u64,
     pub treasury_bump: u8,
     pub bump: u8,
+    pub version: u8,
+    pub created_epoch: u64,
+    pub roles_count: u64,
 }
 
 pub const PROJECT_CONFIG_DISCRIMINATOR: [u8; 8] = [187, 239, 0, 110, 5, 15, 245, 65];
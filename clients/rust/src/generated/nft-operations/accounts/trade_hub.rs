@@ -21,6 +21,7 @@ pub struct TradeHub {
     pub name: String,
     pub fee_bps: u64,
     pub bump: u8,
+    pub listings_count: u64,
 }
 
 pub const TRADE_HUB_DISCRIMINATOR: [u8; 8] = [2, 183, 109, 61, 76, 192, 54, 252];
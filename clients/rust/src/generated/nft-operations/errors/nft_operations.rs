@@ -46,6 +46,54 @@ pub enum NftOperationsError {
     /// 6011 - Math operation overflow
     #[error("Math operation overflow")]
     MathOverflow = 0x177B,
+    /// 6012 - Operation is paused by the protocol
+    #[error("Operation is paused by the protocol")]
+    OperationPaused = 0x177C,
+    /// 6013 - Protocol config is not the one of the project
+    #[error("Protocol config is not the one of the project")]
+    InvalidProtocolConfig = 0x177D,
+    /// 6014 - Project subscription is delinquent
+    #[error("Project subscription is delinquent")]
+    SubscriptionDelinquent = 0x177E,
+    /// 6015 - Mint phases must be ordered, non-overlapping windows within the limit
+    #[error("Mint phases must be ordered, non-overlapping windows within the limit")]
+    InvalidMintPhases = 0x177F,
+    /// 6016 - No mint phase is active
+    #[error("No mint phase is active")]
+    MintPhaseNotActive = 0x1780,
+    /// 6017 - Mint phase supply cap reached
+    #[error("Mint phase supply cap reached")]
+    PhaseSupplyReached = 0x1781,
+    /// 6018 - Mint phase requires an allowlist proof
+    #[error("Mint phase requires an allowlist proof")]
+    AllowlistProofRequired = 0x1782,
+    /// 6019 - Wallet is not on the allowlist
+    #[error("Wallet is not on the allowlist")]
+    NotAllowlisted = 0x1783,
+    /// 6020 - Mint requires the mint record of the wallet
+    #[error("Mint requires the mint record of the wallet")]
+    MintRecordRequired = 0x1784,
+    /// 6021 - Wallet minted its allowlist allowance
+    #[error("Wallet minted its allowlist allowance")]
+    AllowlistMintLimitReached = 0x1785,
+    /// 6022 - Wallet reached the mint limit of the minter config
+    #[error("Wallet reached the mint limit of the minter config")]
+    WalletMintLimitReached = 0x1786,
+    /// 6023 - Minting has not ended for the minter config
+    #[error("Minting has not ended for the minter config")]
+    MintingNotEnded = 0x1787,
+    /// 6024 - Trade hub still has open listings
+    #[error("Trade hub still has open listings")]
+    TradeHubHasListings = 0x1788,
+    /// 6025 - Trade hub does not belong to the project
+    #[error("Trade hub does not belong to the project")]
+    InvalidTradeHubProject = 0x1789,
+    /// 6026 - Minting has ended for the minter config
+    #[error("Minting has ended for the minter config")]
+    MintingEnded = 0x178A,
+    /// 6027 - Trade hub was created before its listings were counted
+    #[error("Trade hub was created before its listings were counted")]
+    TradeHubListingsUncounted = 0x178B,
 }
 
 impl From<NftOperationsError> for solana_program_error::ProgramError {
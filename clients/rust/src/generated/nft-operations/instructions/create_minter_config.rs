@@ -18,6 +18,8 @@ pub struct CreateMinterConfig {
 
     pub authority: solana_pubkey::Pubkey,
 
+    pub authority_role: solana_pubkey::Pubkey,
+
     pub collection: Option<solana_pubkey::Pubkey>,
 
     pub minter_config: solana_pubkey::Pubkey,
@@ -28,9 +30,33 @@ pub struct CreateMinterConfig {
 
     pub protocol_treasury: solana_pubkey::Pubkey,
 
+    pub project_fee_override: solana_pubkey::Pubkey,
+
+    pub subscription: solana_pubkey::Pubkey,
+
+    pub fee_authority: solana_pubkey::Pubkey,
+
+    pub protocol_fee_stats: solana_pubkey::Pubkey,
+
+    pub project_fee_stats: solana_pubkey::Pubkey,
+
+    pub fee_mint: Option<solana_pubkey::Pubkey>,
+
+    pub fee_payer_token_account: Option<solana_pubkey::Pubkey>,
+
+    pub protocol_treasury_token_account: Option<solana_pubkey::Pubkey>,
+
+    pub token_program: Option<solana_pubkey::Pubkey>,
+
+    pub sol_mind_protocol_program: solana_pubkey::Pubkey,
+
     pub system_program: solana_pubkey::Pubkey,
 
     pub mpl_core_program: solana_pubkey::Pubkey,
+
+    pub event_authority: solana_pubkey::Pubkey,
+
+    pub program: solana_pubkey::Pubkey,
 }
 
 impl CreateMinterConfig {
@@ -47,9 +73,13 @@ impl CreateMinterConfig {
         args: CreateMinterConfigInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(22 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(self.payer, true));
         accounts.push(solana_instruction::AccountMeta::new(self.authority, true));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.authority_role,
+            false,
+        ));
         if let Some(collection) = self.collection {
             accounts.push(solana_instruction::AccountMeta::new(collection, true));
         } else {
@@ -62,7 +92,7 @@ impl CreateMinterConfig {
             self.minter_config,
             false,
         ));
-        accounts.push(solana_instruction::AccountMeta::new_readonly(
+        accounts.push(solana_instruction::AccountMeta::new(
             self.project_config,
             false,
         ));
@@ -74,6 +104,73 @@ impl CreateMinterConfig {
             self.protocol_treasury,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.project_fee_override,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.subscription,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.fee_authority,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            self.protocol_fee_stats,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            self.project_fee_stats,
+            false,
+        ));
+        if let Some(fee_mint) = self.fee_mint {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                fee_mint, false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::NFT_OPERATIONS_ID,
+                false,
+            ));
+        }
+        if let Some(fee_payer_token_account) = self.fee_payer_token_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                fee_payer_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::NFT_OPERATIONS_ID,
+                false,
+            ));
+        }
+        if let Some(protocol_treasury_token_account) = self.protocol_treasury_token_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                protocol_treasury_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::NFT_OPERATIONS_ID,
+                false,
+            ));
+        }
+        if let Some(token_program) = self.token_program {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                token_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::NFT_OPERATIONS_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.sol_mind_protocol_program,
+            false,
+        ));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.system_program,
             false,
@@ -82,6 +179,14 @@ impl CreateMinterConfig {
             self.mpl_core_program,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.event_authority,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let mut data = CreateMinterConfigInstructionData::new()
             .try_to_vec()
@@ -144,24 +249,50 @@ impl CreateMinterConfigInstructionArgs {
 ///
 ///   0. `[writable, signer]` payer
 ///   1. `[writable, signer]` authority
-///   2. `[writable, signer, optional]` collection
-///   3. `[writable]` minter_config
-///   4. `[]` project_config
-///   5. `[]` protocol_config
-///   6. `[writable]` protocol_treasury
-///   7. `[optional]` system_program (default to `11111111111111111111111111111111`)
-///   8. `[optional]` mpl_core_program (default to `CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d`)
+///   2. `[]` authority_role
+///   3. `[writable, signer, optional]` collection
+///   4. `[writable]` minter_config
+///   5. `[writable]` project_config
+///   6. `[]` protocol_config
+///   7. `[writable]` protocol_treasury
+///   8. `[]` project_fee_override
+///   9. `[]` subscription
+///   10. `[]` fee_authority
+///   11. `[writable]` protocol_fee_stats
+///   12. `[writable]` project_fee_stats
+///   13. `[optional]` fee_mint
+///   14. `[writable, optional]` fee_payer_token_account
+///   15. `[writable, optional]` protocol_treasury_token_account
+///   16. `[optional]` token_program
+///   17. `[optional]` sol_mind_protocol_program (default to `7GoSe63kVLi3Bt2mkPHUFVbmTcsZvp7QEUHjDB6GvLgV`)
+///   18. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   19. `[optional]` mpl_core_program (default to `CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d`)
+///   20. `[optional]` event_authority (default to `Ausr478rARUXg8Bj6pnZjYHpVxxEASFyamPEg2vwSNdt`)
+///   21. `[optional]` program (default to `Anv3tR3s1eQqQE1a5wnhtYdi6dkjv9rTn34V6pom9C9q`)
 #[derive(Clone, Debug, Default)]
 pub struct CreateMinterConfigBuilder {
     payer: Option<solana_pubkey::Pubkey>,
     authority: Option<solana_pubkey::Pubkey>,
+    authority_role: Option<solana_pubkey::Pubkey>,
     collection: Option<solana_pubkey::Pubkey>,
     minter_config: Option<solana_pubkey::Pubkey>,
     project_config: Option<solana_pubkey::Pubkey>,
     protocol_config: Option<solana_pubkey::Pubkey>,
     protocol_treasury: Option<solana_pubkey::Pubkey>,
+    project_fee_override: Option<solana_pubkey::Pubkey>,
+    subscription: Option<solana_pubkey::Pubkey>,
+    fee_authority: Option<solana_pubkey::Pubkey>,
+    protocol_fee_stats: Option<solana_pubkey::Pubkey>,
+    project_fee_stats: Option<solana_pubkey::Pubkey>,
+    fee_mint: Option<solana_pubkey::Pubkey>,
+    fee_payer_token_account: Option<solana_pubkey::Pubkey>,
+    protocol_treasury_token_account: Option<solana_pubkey::Pubkey>,
+    token_program: Option<solana_pubkey::Pubkey>,
+    sol_mind_protocol_program: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
     mpl_core_program: Option<solana_pubkey::Pubkey>,
+    event_authority: Option<solana_pubkey::Pubkey>,
+    program: Option<solana_pubkey::Pubkey>,
     name: Option<String>,
     mint_price: Option<u64>,
     max_supply: Option<u64>,
@@ -185,6 +316,11 @@ impl CreateMinterConfigBuilder {
         self.authority = Some(authority);
         self
     }
+    #[inline(always)]
+    pub fn authority_role(&mut self, authority_role: solana_pubkey::Pubkey) -> &mut Self {
+        self.authority_role = Some(authority_role);
+        self
+    }
     /// `[optional account]`
     #[inline(always)]
     pub fn collection(&mut self, collection: Option<solana_pubkey::Pubkey>) -> &mut Self {
@@ -211,6 +347,73 @@ impl CreateMinterConfigBuilder {
         self.protocol_treasury = Some(protocol_treasury);
         self
     }
+    #[inline(always)]
+    pub fn project_fee_override(
+        &mut self,
+        project_fee_override: solana_pubkey::Pubkey,
+    ) -> &mut Self {
+        self.project_fee_override = Some(project_fee_override);
+        self
+    }
+    #[inline(always)]
+    pub fn subscription(&mut self, subscription: solana_pubkey::Pubkey) -> &mut Self {
+        self.subscription = Some(subscription);
+        self
+    }
+    #[inline(always)]
+    pub fn fee_authority(&mut self, fee_authority: solana_pubkey::Pubkey) -> &mut Self {
+        self.fee_authority = Some(fee_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn protocol_fee_stats(&mut self, protocol_fee_stats: solana_pubkey::Pubkey) -> &mut Self {
+        self.protocol_fee_stats = Some(protocol_fee_stats);
+        self
+    }
+    #[inline(always)]
+    pub fn project_fee_stats(&mut self, project_fee_stats: solana_pubkey::Pubkey) -> &mut Self {
+        self.project_fee_stats = Some(project_fee_stats);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn fee_mint(&mut self, fee_mint: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.fee_mint = fee_mint;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn fee_payer_token_account(
+        &mut self,
+        fee_payer_token_account: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.fee_payer_token_account = fee_payer_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn protocol_treasury_token_account(
+        &mut self,
+        protocol_treasury_token_account: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.protocol_treasury_token_account = protocol_treasury_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.token_program = token_program;
+        self
+    }
+    /// `[optional account, default to '7GoSe63kVLi3Bt2mkPHUFVbmTcsZvp7QEUHjDB6GvLgV']`
+    #[inline(always)]
+    pub fn sol_mind_protocol_program(
+        &mut self,
+        sol_mind_protocol_program: solana_pubkey::Pubkey,
+    ) -> &mut Self {
+        self.sol_mind_protocol_program = Some(sol_mind_protocol_program);
+        self
+    }
     /// `[optional account, default to '11111111111111111111111111111111']`
     #[inline(always)]
     pub fn system_program(&mut self, system_program: solana_pubkey::Pubkey) -> &mut Self {
@@ -223,6 +426,18 @@ impl CreateMinterConfigBuilder {
         self.mpl_core_program = Some(mpl_core_program);
         self
     }
+    /// `[optional account, default to 'Ausr478rARUXg8Bj6pnZjYHpVxxEASFyamPEg2vwSNdt']`
+    #[inline(always)]
+    pub fn event_authority(&mut self, event_authority: solana_pubkey::Pubkey) -> &mut Self {
+        self.event_authority = Some(event_authority);
+        self
+    }
+    /// `[optional account, default to 'Anv3tR3s1eQqQE1a5wnhtYdi6dkjv9rTn34V6pom9C9q']`
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
     #[inline(always)]
     pub fn name(&mut self, name: String) -> &mut Self {
         self.name = Some(name);
@@ -276,6 +491,7 @@ impl CreateMinterConfigBuilder {
         let accounts = CreateMinterConfig {
             payer: self.payer.expect("payer is not set"),
             authority: self.authority.expect("authority is not set"),
+            authority_role: self.authority_role.expect("authority_role is not set"),
             collection: self.collection,
             minter_config: self.minter_config.expect("minter_config is not set"),
             project_config: self.project_config.expect("project_config is not set"),
@@ -283,12 +499,36 @@ impl CreateMinterConfigBuilder {
             protocol_treasury: self
                 .protocol_treasury
                 .expect("protocol_treasury is not set"),
+            project_fee_override: self
+                .project_fee_override
+                .expect("project_fee_override is not set"),
+            subscription: self.subscription.expect("subscription is not set"),
+            fee_authority: self.fee_authority.expect("fee_authority is not set"),
+            protocol_fee_stats: self
+                .protocol_fee_stats
+                .expect("protocol_fee_stats is not set"),
+            project_fee_stats: self
+                .project_fee_stats
+                .expect("project_fee_stats is not set"),
+            fee_mint: self.fee_mint,
+            fee_payer_token_account: self.fee_payer_token_account,
+            protocol_treasury_token_account: self.protocol_treasury_token_account,
+            token_program: self.token_program,
+            sol_mind_protocol_program: self.sol_mind_protocol_program.unwrap_or(
+                solana_pubkey::pubkey!("7GoSe63kVLi3Bt2mkPHUFVbmTcsZvp7QEUHjDB6GvLgV"),
+            ),
             system_program: self
                 .system_program
                 .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
             mpl_core_program: self.mpl_core_program.unwrap_or(solana_pubkey::pubkey!(
                 "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
             )),
+            event_authority: self.event_authority.unwrap_or(solana_pubkey::pubkey!(
+                "Ausr478rARUXg8Bj6pnZjYHpVxxEASFyamPEg2vwSNdt"
+            )),
+            program: self.program.unwrap_or(solana_pubkey::pubkey!(
+                "Anv3tR3s1eQqQE1a5wnhtYdi6dkjv9rTn34V6pom9C9q"
+            )),
         };
         let args = CreateMinterConfigInstructionArgs {
             name: self.name.clone().expect("name is not set"),
@@ -309,6 +549,8 @@ pub struct CreateMinterConfigCpiAccounts<'a, 'b> {
 
     pub authority: &'b solana_account_info::AccountInfo<'a>,
 
+    pub authority_role: &'b solana_account_info::AccountInfo<'a>,
+
     pub collection: Option<&'b solana_account_info::AccountInfo<'a>>,
 
     pub minter_config: &'b solana_account_info::AccountInfo<'a>,
@@ -319,9 +561,33 @@ pub struct CreateMinterConfigCpiAccounts<'a, 'b> {
 
     pub protocol_treasury: &'b solana_account_info::AccountInfo<'a>,
 
+    pub project_fee_override: &'b solana_account_info::AccountInfo<'a>,
+
+    pub subscription: &'b solana_account_info::AccountInfo<'a>,
+
+    pub fee_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub protocol_fee_stats: &'b solana_account_info::AccountInfo<'a>,
+
+    pub project_fee_stats: &'b solana_account_info::AccountInfo<'a>,
+
+    pub fee_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub fee_payer_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub protocol_treasury_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub sol_mind_protocol_program: &'b solana_account_info::AccountInfo<'a>,
+
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub mpl_core_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
 }
 
 /// `create_minter_config` CPI instruction.
@@ -333,6 +599,8 @@ pub struct CreateMinterConfigCpi<'a, 'b> {
 
     pub authority: &'b solana_account_info::AccountInfo<'a>,
 
+    pub authority_role: &'b solana_account_info::AccountInfo<'a>,
+
     pub collection: Option<&'b solana_account_info::AccountInfo<'a>>,
 
     pub minter_config: &'b solana_account_info::AccountInfo<'a>,
@@ -343,9 +611,33 @@ pub struct CreateMinterConfigCpi<'a, 'b> {
 
     pub protocol_treasury: &'b solana_account_info::AccountInfo<'a>,
 
+    pub project_fee_override: &'b solana_account_info::AccountInfo<'a>,
+
+    pub subscription: &'b solana_account_info::AccountInfo<'a>,
+
+    pub fee_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub protocol_fee_stats: &'b solana_account_info::AccountInfo<'a>,
+
+    pub project_fee_stats: &'b solana_account_info::AccountInfo<'a>,
+
+    pub fee_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub fee_payer_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub protocol_treasury_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub sol_mind_protocol_program: &'b solana_account_info::AccountInfo<'a>,
+
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub mpl_core_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: CreateMinterConfigInstructionArgs,
 }
@@ -360,13 +652,26 @@ impl<'a, 'b> CreateMinterConfigCpi<'a, 'b> {
             __program: program,
             payer: accounts.payer,
             authority: accounts.authority,
+            authority_role: accounts.authority_role,
             collection: accounts.collection,
             minter_config: accounts.minter_config,
             project_config: accounts.project_config,
             protocol_config: accounts.protocol_config,
             protocol_treasury: accounts.protocol_treasury,
+            project_fee_override: accounts.project_fee_override,
+            subscription: accounts.subscription,
+            fee_authority: accounts.fee_authority,
+            protocol_fee_stats: accounts.protocol_fee_stats,
+            project_fee_stats: accounts.project_fee_stats,
+            fee_mint: accounts.fee_mint,
+            fee_payer_token_account: accounts.fee_payer_token_account,
+            protocol_treasury_token_account: accounts.protocol_treasury_token_account,
+            token_program: accounts.token_program,
+            sol_mind_protocol_program: accounts.sol_mind_protocol_program,
             system_program: accounts.system_program,
             mpl_core_program: accounts.mpl_core_program,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
             __args: args,
         }
     }
@@ -393,12 +698,16 @@ impl<'a, 'b> CreateMinterConfigCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(22 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(*self.payer.key, true));
         accounts.push(solana_instruction::AccountMeta::new(
             *self.authority.key,
             true,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.authority_role.key,
+            false,
+        ));
         if let Some(collection) = self.collection {
             accounts.push(solana_instruction::AccountMeta::new(*collection.key, true));
         } else {
@@ -411,7 +720,7 @@ impl<'a, 'b> CreateMinterConfigCpi<'a, 'b> {
             *self.minter_config.key,
             false,
         ));
-        accounts.push(solana_instruction::AccountMeta::new_readonly(
+        accounts.push(solana_instruction::AccountMeta::new(
             *self.project_config.key,
             false,
         ));
@@ -423,6 +732,74 @@ impl<'a, 'b> CreateMinterConfigCpi<'a, 'b> {
             *self.protocol_treasury.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.project_fee_override.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.subscription.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.fee_authority.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            *self.protocol_fee_stats.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            *self.project_fee_stats.key,
+            false,
+        ));
+        if let Some(fee_mint) = self.fee_mint {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *fee_mint.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::NFT_OPERATIONS_ID,
+                false,
+            ));
+        }
+        if let Some(fee_payer_token_account) = self.fee_payer_token_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *fee_payer_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::NFT_OPERATIONS_ID,
+                false,
+            ));
+        }
+        if let Some(protocol_treasury_token_account) = self.protocol_treasury_token_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *protocol_treasury_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::NFT_OPERATIONS_ID,
+                false,
+            ));
+        }
+        if let Some(token_program) = self.token_program {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *token_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::NFT_OPERATIONS_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.sol_mind_protocol_program.key,
+            false,
+        ));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.system_program.key,
             false,
@@ -431,6 +808,14 @@ impl<'a, 'b> CreateMinterConfigCpi<'a, 'b> {
             *self.mpl_core_program.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.event_authority.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -449,10 +834,11 @@ impl<'a, 'b> CreateMinterConfigCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(10 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(23 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.payer.clone());
         account_infos.push(self.authority.clone());
+        account_infos.push(self.authority_role.clone());
         if let Some(collection) = self.collection {
             account_infos.push(collection.clone());
         }
@@ -460,8 +846,28 @@ impl<'a, 'b> CreateMinterConfigCpi<'a, 'b> {
         account_infos.push(self.project_config.clone());
         account_infos.push(self.protocol_config.clone());
         account_infos.push(self.protocol_treasury.clone());
+        account_infos.push(self.project_fee_override.clone());
+        account_infos.push(self.subscription.clone());
+        account_infos.push(self.fee_authority.clone());
+        account_infos.push(self.protocol_fee_stats.clone());
+        account_infos.push(self.project_fee_stats.clone());
+        if let Some(fee_mint) = self.fee_mint {
+            account_infos.push(fee_mint.clone());
+        }
+        if let Some(fee_payer_token_account) = self.fee_payer_token_account {
+            account_infos.push(fee_payer_token_account.clone());
+        }
+        if let Some(protocol_treasury_token_account) = self.protocol_treasury_token_account {
+            account_infos.push(protocol_treasury_token_account.clone());
+        }
+        if let Some(token_program) = self.token_program {
+            account_infos.push(token_program.clone());
+        }
+        account_infos.push(self.sol_mind_protocol_program.clone());
         account_infos.push(self.system_program.clone());
         account_infos.push(self.mpl_core_program.clone());
+        account_infos.push(self.event_authority.clone());
+        account_infos.push(self.program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -480,13 +886,26 @@ impl<'a, 'b> CreateMinterConfigCpi<'a, 'b> {
 ///
 ///   0. `[writable, signer]` payer
 ///   1. `[writable, signer]` authority
-///   2. `[writable, signer, optional]` collection
-///   3. `[writable]` minter_config
-///   4. `[]` project_config
-///   5. `[]` protocol_config
-///   6. `[writable]` protocol_treasury
-///   7. `[]` system_program
-///   8. `[]` mpl_core_program
+///   2. `[]` authority_role
+///   3. `[writable, signer, optional]` collection
+///   4. `[writable]` minter_config
+///   5. `[writable]` project_config
+///   6. `[]` protocol_config
+///   7. `[writable]` protocol_treasury
+///   8. `[]` project_fee_override
+///   9. `[]` subscription
+///   10. `[]` fee_authority
+///   11. `[writable]` protocol_fee_stats
+///   12. `[writable]` project_fee_stats
+///   13. `[optional]` fee_mint
+///   14. `[writable, optional]` fee_payer_token_account
+///   15. `[writable, optional]` protocol_treasury_token_account
+///   16. `[optional]` token_program
+///   17. `[]` sol_mind_protocol_program
+///   18. `[]` system_program
+///   19. `[]` mpl_core_program
+///   20. `[]` event_authority
+///   21. `[]` program
 #[derive(Clone, Debug)]
 pub struct CreateMinterConfigCpiBuilder<'a, 'b> {
     instruction: Box<CreateMinterConfigCpiBuilderInstruction<'a, 'b>>,
@@ -498,13 +917,26 @@ impl<'a, 'b> CreateMinterConfigCpiBuilder<'a, 'b> {
             __program: program,
             payer: None,
             authority: None,
+            authority_role: None,
             collection: None,
             minter_config: None,
             project_config: None,
             protocol_config: None,
             protocol_treasury: None,
+            project_fee_override: None,
+            subscription: None,
+            fee_authority: None,
+            protocol_fee_stats: None,
+            project_fee_stats: None,
+            fee_mint: None,
+            fee_payer_token_account: None,
+            protocol_treasury_token_account: None,
+            token_program: None,
+            sol_mind_protocol_program: None,
             system_program: None,
             mpl_core_program: None,
+            event_authority: None,
+            program: None,
             name: None,
             mint_price: None,
             max_supply: None,
@@ -525,6 +957,14 @@ impl<'a, 'b> CreateMinterConfigCpiBuilder<'a, 'b> {
         self.instruction.authority = Some(authority);
         self
     }
+    #[inline(always)]
+    pub fn authority_role(
+        &mut self,
+        authority_role: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.authority_role = Some(authority_role);
+        self
+    }
     /// `[optional account]`
     #[inline(always)]
     pub fn collection(
@@ -567,6 +1007,90 @@ impl<'a, 'b> CreateMinterConfigCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn project_fee_override(
+        &mut self,
+        project_fee_override: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.project_fee_override = Some(project_fee_override);
+        self
+    }
+    #[inline(always)]
+    pub fn subscription(
+        &mut self,
+        subscription: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.subscription = Some(subscription);
+        self
+    }
+    #[inline(always)]
+    pub fn fee_authority(
+        &mut self,
+        fee_authority: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.fee_authority = Some(fee_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn protocol_fee_stats(
+        &mut self,
+        protocol_fee_stats: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.protocol_fee_stats = Some(protocol_fee_stats);
+        self
+    }
+    #[inline(always)]
+    pub fn project_fee_stats(
+        &mut self,
+        project_fee_stats: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.project_fee_stats = Some(project_fee_stats);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn fee_mint(
+        &mut self,
+        fee_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.fee_mint = fee_mint;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn fee_payer_token_account(
+        &mut self,
+        fee_payer_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.fee_payer_token_account = fee_payer_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn protocol_treasury_token_account(
+        &mut self,
+        protocol_treasury_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.protocol_treasury_token_account = protocol_treasury_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.token_program = token_program;
+        self
+    }
+    #[inline(always)]
+    pub fn sol_mind_protocol_program(
+        &mut self,
+        sol_mind_protocol_program: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.sol_mind_protocol_program = Some(sol_mind_protocol_program);
+        self
+    }
+    #[inline(always)]
     pub fn system_program(
         &mut self,
         system_program: &'b solana_account_info::AccountInfo<'a>,
@@ -583,6 +1107,19 @@ impl<'a, 'b> CreateMinterConfigCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(&mut self, program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
+    #[inline(always)]
     pub fn name(&mut self, name: String) -> &mut Self {
         self.instruction.name = Some(name);
         self
@@ -672,6 +1209,11 @@ impl<'a, 'b> CreateMinterConfigCpiBuilder<'a, 'b> {
 
             authority: self.instruction.authority.expect("authority is not set"),
 
+            authority_role: self
+                .instruction
+                .authority_role
+                .expect("authority_role is not set"),
+
             collection: self.instruction.collection,
 
             minter_config: self
@@ -694,6 +1236,44 @@ impl<'a, 'b> CreateMinterConfigCpiBuilder<'a, 'b> {
                 .protocol_treasury
                 .expect("protocol_treasury is not set"),
 
+            project_fee_override: self
+                .instruction
+                .project_fee_override
+                .expect("project_fee_override is not set"),
+
+            subscription: self
+                .instruction
+                .subscription
+                .expect("subscription is not set"),
+
+            fee_authority: self
+                .instruction
+                .fee_authority
+                .expect("fee_authority is not set"),
+
+            protocol_fee_stats: self
+                .instruction
+                .protocol_fee_stats
+                .expect("protocol_fee_stats is not set"),
+
+            project_fee_stats: self
+                .instruction
+                .project_fee_stats
+                .expect("project_fee_stats is not set"),
+
+            fee_mint: self.instruction.fee_mint,
+
+            fee_payer_token_account: self.instruction.fee_payer_token_account,
+
+            protocol_treasury_token_account: self.instruction.protocol_treasury_token_account,
+
+            token_program: self.instruction.token_program,
+
+            sol_mind_protocol_program: self
+                .instruction
+                .sol_mind_protocol_program
+                .expect("sol_mind_protocol_program is not set"),
+
             system_program: self
                 .instruction
                 .system_program
@@ -703,6 +1283,13 @@ impl<'a, 'b> CreateMinterConfigCpiBuilder<'a, 'b> {
                 .instruction
                 .mpl_core_program
                 .expect("mpl_core_program is not set"),
+
+            event_authority: self
+                .instruction
+                .event_authority
+                .expect("event_authority is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -717,13 +1304,26 @@ struct CreateMinterConfigCpiBuilderInstruction<'a, 'b> {
     __program: &'b solana_account_info::AccountInfo<'a>,
     payer: Option<&'b solana_account_info::AccountInfo<'a>>,
     authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    authority_role: Option<&'b solana_account_info::AccountInfo<'a>>,
     collection: Option<&'b solana_account_info::AccountInfo<'a>>,
     minter_config: Option<&'b solana_account_info::AccountInfo<'a>>,
     project_config: Option<&'b solana_account_info::AccountInfo<'a>>,
     protocol_config: Option<&'b solana_account_info::AccountInfo<'a>>,
     protocol_treasury: Option<&'b solana_account_info::AccountInfo<'a>>,
+    project_fee_override: Option<&'b solana_account_info::AccountInfo<'a>>,
+    subscription: Option<&'b solana_account_info::AccountInfo<'a>>,
+    fee_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    protocol_fee_stats: Option<&'b solana_account_info::AccountInfo<'a>>,
+    project_fee_stats: Option<&'b solana_account_info::AccountInfo<'a>>,
+    fee_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
+    fee_payer_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    protocol_treasury_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    sol_mind_protocol_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     mpl_core_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_account_info::AccountInfo<'a>>,
     name: Option<String>,
     mint_price: Option<u64>,
     max_supply: Option<u64>,
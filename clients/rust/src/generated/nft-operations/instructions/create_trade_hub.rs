@@ -17,6 +17,8 @@ pub struct CreateTradeHub {
 
     pub authority: solana_pubkey::Pubkey,
 
+    pub authority_role: solana_pubkey::Pubkey,
+
     pub trade_hub: solana_pubkey::Pubkey,
 
     pub project_config: solana_pubkey::Pubkey,
@@ -25,7 +27,31 @@ pub struct CreateTradeHub {
 
     pub protocol_treasury: solana_pubkey::Pubkey,
 
+    pub project_fee_override: solana_pubkey::Pubkey,
+
+    pub subscription: solana_pubkey::Pubkey,
+
+    pub fee_authority: solana_pubkey::Pubkey,
+
+    pub protocol_fee_stats: solana_pubkey::Pubkey,
+
+    pub project_fee_stats: solana_pubkey::Pubkey,
+
+    pub fee_mint: Option<solana_pubkey::Pubkey>,
+
+    pub fee_payer_token_account: Option<solana_pubkey::Pubkey>,
+
+    pub protocol_treasury_token_account: Option<solana_pubkey::Pubkey>,
+
+    pub token_program: Option<solana_pubkey::Pubkey>,
+
+    pub sol_mind_protocol_program: solana_pubkey::Pubkey,
+
     pub system_program: solana_pubkey::Pubkey,
+
+    pub event_authority: solana_pubkey::Pubkey,
+
+    pub program: solana_pubkey::Pubkey,
 }
 
 impl CreateTradeHub {
@@ -42,11 +68,15 @@ impl CreateTradeHub {
         args: CreateTradeHubInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(20 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(self.payer, true));
         accounts.push(solana_instruction::AccountMeta::new(self.authority, true));
-        accounts.push(solana_instruction::AccountMeta::new(self.trade_hub, false));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.authority_role,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(self.trade_hub, false));
+        accounts.push(solana_instruction::AccountMeta::new(
             self.project_config,
             false,
         ));
@@ -58,10 +88,85 @@ impl CreateTradeHub {
             self.protocol_treasury,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.project_fee_override,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.subscription,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.fee_authority,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            self.protocol_fee_stats,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            self.project_fee_stats,
+            false,
+        ));
+        if let Some(fee_mint) = self.fee_mint {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                fee_mint, false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::NFT_OPERATIONS_ID,
+                false,
+            ));
+        }
+        if let Some(fee_payer_token_account) = self.fee_payer_token_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                fee_payer_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::NFT_OPERATIONS_ID,
+                false,
+            ));
+        }
+        if let Some(protocol_treasury_token_account) = self.protocol_treasury_token_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                protocol_treasury_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::NFT_OPERATIONS_ID,
+                false,
+            ));
+        }
+        if let Some(token_program) = self.token_program {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                token_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::NFT_OPERATIONS_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.sol_mind_protocol_program,
+            false,
+        ));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.system_program,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.event_authority,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let mut data = CreateTradeHubInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -118,20 +223,46 @@ impl CreateTradeHubInstructionArgs {
 ///
 ///   0. `[writable, signer]` payer
 ///   1. `[writable, signer]` authority
-///   2. `[writable]` trade_hub
-///   3. `[]` project_config
-///   4. `[]` protocol_config
-///   5. `[writable]` protocol_treasury
-///   6. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   2. `[]` authority_role
+///   3. `[writable]` trade_hub
+///   4. `[writable]` project_config
+///   5. `[]` protocol_config
+///   6. `[writable]` protocol_treasury
+///   7. `[]` project_fee_override
+///   8. `[]` subscription
+///   9. `[]` fee_authority
+///   10. `[writable]` protocol_fee_stats
+///   11. `[writable]` project_fee_stats
+///   12. `[optional]` fee_mint
+///   13. `[writable, optional]` fee_payer_token_account
+///   14. `[writable, optional]` protocol_treasury_token_account
+///   15. `[optional]` token_program
+///   16. `[optional]` sol_mind_protocol_program (default to `7GoSe63kVLi3Bt2mkPHUFVbmTcsZvp7QEUHjDB6GvLgV`)
+///   17. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   18. `[optional]` event_authority (default to `Ausr478rARUXg8Bj6pnZjYHpVxxEASFyamPEg2vwSNdt`)
+///   19. `[optional]` program (default to `Anv3tR3s1eQqQE1a5wnhtYdi6dkjv9rTn34V6pom9C9q`)
 #[derive(Clone, Debug, Default)]
 pub struct CreateTradeHubBuilder {
     payer: Option<solana_pubkey::Pubkey>,
     authority: Option<solana_pubkey::Pubkey>,
+    authority_role: Option<solana_pubkey::Pubkey>,
     trade_hub: Option<solana_pubkey::Pubkey>,
     project_config: Option<solana_pubkey::Pubkey>,
     protocol_config: Option<solana_pubkey::Pubkey>,
     protocol_treasury: Option<solana_pubkey::Pubkey>,
+    project_fee_override: Option<solana_pubkey::Pubkey>,
+    subscription: Option<solana_pubkey::Pubkey>,
+    fee_authority: Option<solana_pubkey::Pubkey>,
+    protocol_fee_stats: Option<solana_pubkey::Pubkey>,
+    project_fee_stats: Option<solana_pubkey::Pubkey>,
+    fee_mint: Option<solana_pubkey::Pubkey>,
+    fee_payer_token_account: Option<solana_pubkey::Pubkey>,
+    protocol_treasury_token_account: Option<solana_pubkey::Pubkey>,
+    token_program: Option<solana_pubkey::Pubkey>,
+    sol_mind_protocol_program: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
+    event_authority: Option<solana_pubkey::Pubkey>,
+    program: Option<solana_pubkey::Pubkey>,
     name: Option<String>,
     fee_bps: Option<u64>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
@@ -152,6 +283,11 @@ impl CreateTradeHubBuilder {
         self
     }
     #[inline(always)]
+    pub fn authority_role(&mut self, authority_role: solana_pubkey::Pubkey) -> &mut Self {
+        self.authority_role = Some(authority_role);
+        self
+    }
+    #[inline(always)]
     pub fn trade_hub(&mut self, trade_hub: solana_pubkey::Pubkey) -> &mut Self {
         self.trade_hub = Some(trade_hub);
         self
@@ -171,12 +307,91 @@ impl CreateTradeHubBuilder {
         self.protocol_treasury = Some(protocol_treasury);
         self
     }
+    #[inline(always)]
+    pub fn project_fee_override(
+        &mut self,
+        project_fee_override: solana_pubkey::Pubkey,
+    ) -> &mut Self {
+        self.project_fee_override = Some(project_fee_override);
+        self
+    }
+    #[inline(always)]
+    pub fn subscription(&mut self, subscription: solana_pubkey::Pubkey) -> &mut Self {
+        self.subscription = Some(subscription);
+        self
+    }
+    #[inline(always)]
+    pub fn fee_authority(&mut self, fee_authority: solana_pubkey::Pubkey) -> &mut Self {
+        self.fee_authority = Some(fee_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn protocol_fee_stats(&mut self, protocol_fee_stats: solana_pubkey::Pubkey) -> &mut Self {
+        self.protocol_fee_stats = Some(protocol_fee_stats);
+        self
+    }
+    #[inline(always)]
+    pub fn project_fee_stats(&mut self, project_fee_stats: solana_pubkey::Pubkey) -> &mut Self {
+        self.project_fee_stats = Some(project_fee_stats);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn fee_mint(&mut self, fee_mint: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.fee_mint = fee_mint;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn fee_payer_token_account(
+        &mut self,
+        fee_payer_token_account: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.fee_payer_token_account = fee_payer_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn protocol_treasury_token_account(
+        &mut self,
+        protocol_treasury_token_account: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.protocol_treasury_token_account = protocol_treasury_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.token_program = token_program;
+        self
+    }
+    /// `[optional account, default to '7GoSe63kVLi3Bt2mkPHUFVbmTcsZvp7QEUHjDB6GvLgV']`
+    #[inline(always)]
+    pub fn sol_mind_protocol_program(
+        &mut self,
+        sol_mind_protocol_program: solana_pubkey::Pubkey,
+    ) -> &mut Self {
+        self.sol_mind_protocol_program = Some(sol_mind_protocol_program);
+        self
+    }
     /// `[optional account, default to '11111111111111111111111111111111']`
     #[inline(always)]
     pub fn system_program(&mut self, system_program: solana_pubkey::Pubkey) -> &mut Self {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account, default to 'Ausr478rARUXg8Bj6pnZjYHpVxxEASFyamPEg2vwSNdt']`
+    #[inline(always)]
+    pub fn event_authority(&mut self, event_authority: solana_pubkey::Pubkey) -> &mut Self {
+        self.event_authority = Some(event_authority);
+        self
+    }
+    /// `[optional account, default to 'Anv3tR3s1eQqQE1a5wnhtYdi6dkjv9rTn34V6pom9C9q']`
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
     #[inline(always)]
     pub fn name(&mut self, name: String) -> &mut Self {
         self.name = Some(name);
@@ -207,15 +422,40 @@ impl CreateTradeHubBuilder {
         let accounts = CreateTradeHub {
             payer: self.payer.expect("payer is not set"),
             authority: self.authority.expect("authority is not set"),
+            authority_role: self.authority_role.expect("authority_role is not set"),
             trade_hub: self.trade_hub.expect("trade_hub is not set"),
             project_config: self.project_config.expect("project_config is not set"),
             protocol_config: self.protocol_config.expect("protocol_config is not set"),
             protocol_treasury: self
                 .protocol_treasury
                 .expect("protocol_treasury is not set"),
+            project_fee_override: self
+                .project_fee_override
+                .expect("project_fee_override is not set"),
+            subscription: self.subscription.expect("subscription is not set"),
+            fee_authority: self.fee_authority.expect("fee_authority is not set"),
+            protocol_fee_stats: self
+                .protocol_fee_stats
+                .expect("protocol_fee_stats is not set"),
+            project_fee_stats: self
+                .project_fee_stats
+                .expect("project_fee_stats is not set"),
+            fee_mint: self.fee_mint,
+            fee_payer_token_account: self.fee_payer_token_account,
+            protocol_treasury_token_account: self.protocol_treasury_token_account,
+            token_program: self.token_program,
+            sol_mind_protocol_program: self.sol_mind_protocol_program.unwrap_or(
+                solana_pubkey::pubkey!("7GoSe63kVLi3Bt2mkPHUFVbmTcsZvp7QEUHjDB6GvLgV"),
+            ),
             system_program: self
                 .system_program
                 .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
+            event_authority: self.event_authority.unwrap_or(solana_pubkey::pubkey!(
+                "Ausr478rARUXg8Bj6pnZjYHpVxxEASFyamPEg2vwSNdt"
+            )),
+            program: self.program.unwrap_or(solana_pubkey::pubkey!(
+                "Anv3tR3s1eQqQE1a5wnhtYdi6dkjv9rTn34V6pom9C9q"
+            )),
         };
         let args = CreateTradeHubInstructionArgs {
             name: self.name.clone().expect("name is not set"),
@@ -232,6 +472,8 @@ pub struct CreateTradeHubCpiAccounts<'a, 'b> {
 
     pub authority: &'b solana_account_info::AccountInfo<'a>,
 
+    pub authority_role: &'b solana_account_info::AccountInfo<'a>,
+
     pub trade_hub: &'b solana_account_info::AccountInfo<'a>,
 
     pub project_config: &'b solana_account_info::AccountInfo<'a>,
@@ -240,7 +482,31 @@ pub struct CreateTradeHubCpiAccounts<'a, 'b> {
 
     pub protocol_treasury: &'b solana_account_info::AccountInfo<'a>,
 
+    pub project_fee_override: &'b solana_account_info::AccountInfo<'a>,
+
+    pub subscription: &'b solana_account_info::AccountInfo<'a>,
+
+    pub fee_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub protocol_fee_stats: &'b solana_account_info::AccountInfo<'a>,
+
+    pub project_fee_stats: &'b solana_account_info::AccountInfo<'a>,
+
+    pub fee_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub fee_payer_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub protocol_treasury_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub sol_mind_protocol_program: &'b solana_account_info::AccountInfo<'a>,
+
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
 }
 
 /// `create_trade_hub` CPI instruction.
@@ -252,6 +518,8 @@ pub struct CreateTradeHubCpi<'a, 'b> {
 
     pub authority: &'b solana_account_info::AccountInfo<'a>,
 
+    pub authority_role: &'b solana_account_info::AccountInfo<'a>,
+
     pub trade_hub: &'b solana_account_info::AccountInfo<'a>,
 
     pub project_config: &'b solana_account_info::AccountInfo<'a>,
@@ -260,7 +528,31 @@ pub struct CreateTradeHubCpi<'a, 'b> {
 
     pub protocol_treasury: &'b solana_account_info::AccountInfo<'a>,
 
+    pub project_fee_override: &'b solana_account_info::AccountInfo<'a>,
+
+    pub subscription: &'b solana_account_info::AccountInfo<'a>,
+
+    pub fee_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub protocol_fee_stats: &'b solana_account_info::AccountInfo<'a>,
+
+    pub project_fee_stats: &'b solana_account_info::AccountInfo<'a>,
+
+    pub fee_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub fee_payer_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub protocol_treasury_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub sol_mind_protocol_program: &'b solana_account_info::AccountInfo<'a>,
+
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: CreateTradeHubInstructionArgs,
 }
@@ -275,11 +567,24 @@ impl<'a, 'b> CreateTradeHubCpi<'a, 'b> {
             __program: program,
             payer: accounts.payer,
             authority: accounts.authority,
+            authority_role: accounts.authority_role,
             trade_hub: accounts.trade_hub,
             project_config: accounts.project_config,
             protocol_config: accounts.protocol_config,
             protocol_treasury: accounts.protocol_treasury,
+            project_fee_override: accounts.project_fee_override,
+            subscription: accounts.subscription,
+            fee_authority: accounts.fee_authority,
+            protocol_fee_stats: accounts.protocol_fee_stats,
+            project_fee_stats: accounts.project_fee_stats,
+            fee_mint: accounts.fee_mint,
+            fee_payer_token_account: accounts.fee_payer_token_account,
+            protocol_treasury_token_account: accounts.protocol_treasury_token_account,
+            token_program: accounts.token_program,
+            sol_mind_protocol_program: accounts.sol_mind_protocol_program,
             system_program: accounts.system_program,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
             __args: args,
         }
     }
@@ -306,17 +611,21 @@ impl<'a, 'b> CreateTradeHubCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(20 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(*self.payer.key, true));
         accounts.push(solana_instruction::AccountMeta::new(
             *self.authority.key,
             true,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.authority_role.key,
+            false,
+        ));
         accounts.push(solana_instruction::AccountMeta::new(
             *self.trade_hub.key,
             false,
         ));
-        accounts.push(solana_instruction::AccountMeta::new_readonly(
+        accounts.push(solana_instruction::AccountMeta::new(
             *self.project_config.key,
             false,
         ));
@@ -328,10 +637,86 @@ impl<'a, 'b> CreateTradeHubCpi<'a, 'b> {
             *self.protocol_treasury.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.project_fee_override.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.subscription.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.fee_authority.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            *self.protocol_fee_stats.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            *self.project_fee_stats.key,
+            false,
+        ));
+        if let Some(fee_mint) = self.fee_mint {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *fee_mint.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::NFT_OPERATIONS_ID,
+                false,
+            ));
+        }
+        if let Some(fee_payer_token_account) = self.fee_payer_token_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *fee_payer_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::NFT_OPERATIONS_ID,
+                false,
+            ));
+        }
+        if let Some(protocol_treasury_token_account) = self.protocol_treasury_token_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *protocol_treasury_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::NFT_OPERATIONS_ID,
+                false,
+            ));
+        }
+        if let Some(token_program) = self.token_program {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *token_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::NFT_OPERATIONS_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.sol_mind_protocol_program.key,
+            false,
+        ));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.system_program.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.event_authority.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -348,15 +733,36 @@ impl<'a, 'b> CreateTradeHubCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(21 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.payer.clone());
         account_infos.push(self.authority.clone());
+        account_infos.push(self.authority_role.clone());
         account_infos.push(self.trade_hub.clone());
         account_infos.push(self.project_config.clone());
         account_infos.push(self.protocol_config.clone());
         account_infos.push(self.protocol_treasury.clone());
+        account_infos.push(self.project_fee_override.clone());
+        account_infos.push(self.subscription.clone());
+        account_infos.push(self.fee_authority.clone());
+        account_infos.push(self.protocol_fee_stats.clone());
+        account_infos.push(self.project_fee_stats.clone());
+        if let Some(fee_mint) = self.fee_mint {
+            account_infos.push(fee_mint.clone());
+        }
+        if let Some(fee_payer_token_account) = self.fee_payer_token_account {
+            account_infos.push(fee_payer_token_account.clone());
+        }
+        if let Some(protocol_treasury_token_account) = self.protocol_treasury_token_account {
+            account_infos.push(protocol_treasury_token_account.clone());
+        }
+        if let Some(token_program) = self.token_program {
+            account_infos.push(token_program.clone());
+        }
+        account_infos.push(self.sol_mind_protocol_program.clone());
         account_infos.push(self.system_program.clone());
+        account_infos.push(self.event_authority.clone());
+        account_infos.push(self.program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -375,11 +781,24 @@ impl<'a, 'b> CreateTradeHubCpi<'a, 'b> {
 ///
 ///   0. `[writable, signer]` payer
 ///   1. `[writable, signer]` authority
-///   2. `[writable]` trade_hub
-///   3. `[]` project_config
-///   4. `[]` protocol_config
-///   5. `[writable]` protocol_treasury
-///   6. `[]` system_program
+///   2. `[]` authority_role
+///   3. `[writable]` trade_hub
+///   4. `[writable]` project_config
+///   5. `[]` protocol_config
+///   6. `[writable]` protocol_treasury
+///   7. `[]` project_fee_override
+///   8. `[]` subscription
+///   9. `[]` fee_authority
+///   10. `[writable]` protocol_fee_stats
+///   11. `[writable]` project_fee_stats
+///   12. `[optional]` fee_mint
+///   13. `[writable, optional]` fee_payer_token_account
+///   14. `[writable, optional]` protocol_treasury_token_account
+///   15. `[optional]` token_program
+///   16. `[]` sol_mind_protocol_program
+///   17. `[]` system_program
+///   18. `[]` event_authority
+///   19. `[]` program
 #[derive(Clone, Debug)]
 pub struct CreateTradeHubCpiBuilder<'a, 'b> {
     instruction: Box<CreateTradeHubCpiBuilderInstruction<'a, 'b>>,
@@ -391,11 +810,24 @@ impl<'a, 'b> CreateTradeHubCpiBuilder<'a, 'b> {
             __program: program,
             payer: None,
             authority: None,
+            authority_role: None,
             trade_hub: None,
             project_config: None,
             protocol_config: None,
             protocol_treasury: None,
+            project_fee_override: None,
+            subscription: None,
+            fee_authority: None,
+            protocol_fee_stats: None,
+            project_fee_stats: None,
+            fee_mint: None,
+            fee_payer_token_account: None,
+            protocol_treasury_token_account: None,
+            token_program: None,
+            sol_mind_protocol_program: None,
             system_program: None,
+            event_authority: None,
+            program: None,
             name: None,
             fee_bps: None,
             __remaining_accounts: Vec::new(),
@@ -413,6 +845,14 @@ impl<'a, 'b> CreateTradeHubCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn authority_role(
+        &mut self,
+        authority_role: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.authority_role = Some(authority_role);
+        self
+    }
+    #[inline(always)]
     pub fn trade_hub(&mut self, trade_hub: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
         self.instruction.trade_hub = Some(trade_hub);
         self
@@ -442,6 +882,90 @@ impl<'a, 'b> CreateTradeHubCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn project_fee_override(
+        &mut self,
+        project_fee_override: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.project_fee_override = Some(project_fee_override);
+        self
+    }
+    #[inline(always)]
+    pub fn subscription(
+        &mut self,
+        subscription: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.subscription = Some(subscription);
+        self
+    }
+    #[inline(always)]
+    pub fn fee_authority(
+        &mut self,
+        fee_authority: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.fee_authority = Some(fee_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn protocol_fee_stats(
+        &mut self,
+        protocol_fee_stats: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.protocol_fee_stats = Some(protocol_fee_stats);
+        self
+    }
+    #[inline(always)]
+    pub fn project_fee_stats(
+        &mut self,
+        project_fee_stats: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.project_fee_stats = Some(project_fee_stats);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn fee_mint(
+        &mut self,
+        fee_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.fee_mint = fee_mint;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn fee_payer_token_account(
+        &mut self,
+        fee_payer_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.fee_payer_token_account = fee_payer_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn protocol_treasury_token_account(
+        &mut self,
+        protocol_treasury_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.protocol_treasury_token_account = protocol_treasury_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.token_program = token_program;
+        self
+    }
+    #[inline(always)]
+    pub fn sol_mind_protocol_program(
+        &mut self,
+        sol_mind_protocol_program: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.sol_mind_protocol_program = Some(sol_mind_protocol_program);
+        self
+    }
+    #[inline(always)]
     pub fn system_program(
         &mut self,
         system_program: &'b solana_account_info::AccountInfo<'a>,
@@ -450,6 +974,19 @@ impl<'a, 'b> CreateTradeHubCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(&mut self, program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
+    #[inline(always)]
     pub fn name(&mut self, name: String) -> &mut Self {
         self.instruction.name = Some(name);
         self
@@ -508,6 +1045,11 @@ impl<'a, 'b> CreateTradeHubCpiBuilder<'a, 'b> {
 
             authority: self.instruction.authority.expect("authority is not set"),
 
+            authority_role: self
+                .instruction
+                .authority_role
+                .expect("authority_role is not set"),
+
             trade_hub: self.instruction.trade_hub.expect("trade_hub is not set"),
 
             project_config: self
@@ -525,10 +1067,55 @@ impl<'a, 'b> CreateTradeHubCpiBuilder<'a, 'b> {
                 .protocol_treasury
                 .expect("protocol_treasury is not set"),
 
+            project_fee_override: self
+                .instruction
+                .project_fee_override
+                .expect("project_fee_override is not set"),
+
+            subscription: self
+                .instruction
+                .subscription
+                .expect("subscription is not set"),
+
+            fee_authority: self
+                .instruction
+                .fee_authority
+                .expect("fee_authority is not set"),
+
+            protocol_fee_stats: self
+                .instruction
+                .protocol_fee_stats
+                .expect("protocol_fee_stats is not set"),
+
+            project_fee_stats: self
+                .instruction
+                .project_fee_stats
+                .expect("project_fee_stats is not set"),
+
+            fee_mint: self.instruction.fee_mint,
+
+            fee_payer_token_account: self.instruction.fee_payer_token_account,
+
+            protocol_treasury_token_account: self.instruction.protocol_treasury_token_account,
+
+            token_program: self.instruction.token_program,
+
+            sol_mind_protocol_program: self
+                .instruction
+                .sol_mind_protocol_program
+                .expect("sol_mind_protocol_program is not set"),
+
             system_program: self
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            event_authority: self
+                .instruction
+                .event_authority
+                .expect("event_authority is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -543,11 +1130,24 @@ struct CreateTradeHubCpiBuilderInstruction<'a, 'b> {
     __program: &'b solana_account_info::AccountInfo<'a>,
     payer: Option<&'b solana_account_info::AccountInfo<'a>>,
     authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    authority_role: Option<&'b solana_account_info::AccountInfo<'a>>,
     trade_hub: Option<&'b solana_account_info::AccountInfo<'a>>,
     project_config: Option<&'b solana_account_info::AccountInfo<'a>>,
     protocol_config: Option<&'b solana_account_info::AccountInfo<'a>>,
     protocol_treasury: Option<&'b solana_account_info::AccountInfo<'a>>,
+    project_fee_override: Option<&'b solana_account_info::AccountInfo<'a>>,
+    subscription: Option<&'b solana_account_info::AccountInfo<'a>>,
+    fee_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    protocol_fee_stats: Option<&'b solana_account_info::AccountInfo<'a>>,
+    project_fee_stats: Option<&'b solana_account_info::AccountInfo<'a>>,
+    fee_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
+    fee_payer_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    protocol_treasury_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    sol_mind_protocol_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_account_info::AccountInfo<'a>>,
     name: Option<String>,
     fee_bps: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
@@ -28,6 +28,10 @@ pub struct DelistAsset {
     pub system_program: solana_pubkey::Pubkey,
 
     pub mpl_core_program: solana_pubkey::Pubkey,
+
+    pub event_authority: solana_pubkey::Pubkey,
+
+    pub program: solana_pubkey::Pubkey,
 }
 
 impl DelistAsset {
@@ -40,7 +44,7 @@ impl DelistAsset {
         &self,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(self.payer, true));
         accounts.push(solana_instruction::AccountMeta::new(self.owner, true));
         accounts.push(solana_instruction::AccountMeta::new(self.asset, false));
@@ -53,16 +57,21 @@ impl DelistAsset {
             ));
         }
         accounts.push(solana_instruction::AccountMeta::new(self.listing, false));
+        accounts.push(solana_instruction::AccountMeta::new(self.trade_hub, false));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
-            self.trade_hub,
+            self.system_program,
             false,
         ));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
-            self.system_program,
+            self.mpl_core_program,
             false,
         ));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
-            self.mpl_core_program,
+            self.event_authority,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program,
             false,
         ));
         accounts.extend_from_slice(remaining_accounts);
@@ -109,9 +118,11 @@ impl Default for DelistAssetInstructionData {
 ///   2. `[writable]` asset
 ///   3. `[writable, optional]` collection
 ///   4. `[writable]` listing
-///   5. `[]` trade_hub
+///   5. `[writable]` trade_hub
 ///   6. `[optional]` system_program (default to `11111111111111111111111111111111`)
 ///   7. `[optional]` mpl_core_program (default to `CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d`)
+///   8. `[optional]` event_authority (default to `Ausr478rARUXg8Bj6pnZjYHpVxxEASFyamPEg2vwSNdt`)
+///   9. `[optional]` program (default to `Anv3tR3s1eQqQE1a5wnhtYdi6dkjv9rTn34V6pom9C9q`)
 #[derive(Clone, Debug, Default)]
 pub struct DelistAssetBuilder {
     payer: Option<solana_pubkey::Pubkey>,
@@ -122,6 +133,8 @@ pub struct DelistAssetBuilder {
     trade_hub: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
     mpl_core_program: Option<solana_pubkey::Pubkey>,
+    event_authority: Option<solana_pubkey::Pubkey>,
+    program: Option<solana_pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -172,6 +185,18 @@ impl DelistAssetBuilder {
         self.mpl_core_program = Some(mpl_core_program);
         self
     }
+    /// `[optional account, default to 'Ausr478rARUXg8Bj6pnZjYHpVxxEASFyamPEg2vwSNdt']`
+    #[inline(always)]
+    pub fn event_authority(&mut self, event_authority: solana_pubkey::Pubkey) -> &mut Self {
+        self.event_authority = Some(event_authority);
+        self
+    }
+    /// `[optional account, default to 'Anv3tR3s1eQqQE1a5wnhtYdi6dkjv9rTn34V6pom9C9q']`
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(&mut self, account: solana_instruction::AccountMeta) -> &mut Self {
@@ -202,6 +227,12 @@ impl DelistAssetBuilder {
             mpl_core_program: self.mpl_core_program.unwrap_or(solana_pubkey::pubkey!(
                 "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
             )),
+            event_authority: self.event_authority.unwrap_or(solana_pubkey::pubkey!(
+                "Ausr478rARUXg8Bj6pnZjYHpVxxEASFyamPEg2vwSNdt"
+            )),
+            program: self.program.unwrap_or(solana_pubkey::pubkey!(
+                "Anv3tR3s1eQqQE1a5wnhtYdi6dkjv9rTn34V6pom9C9q"
+            )),
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -225,6 +256,10 @@ pub struct DelistAssetCpiAccounts<'a, 'b> {
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub mpl_core_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
 }
 
 /// `delist_asset` CPI instruction.
@@ -247,6 +282,10 @@ pub struct DelistAssetCpi<'a, 'b> {
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub mpl_core_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
 }
 
 impl<'a, 'b> DelistAssetCpi<'a, 'b> {
@@ -264,6 +303,8 @@ impl<'a, 'b> DelistAssetCpi<'a, 'b> {
             trade_hub: accounts.trade_hub,
             system_program: accounts.system_program,
             mpl_core_program: accounts.mpl_core_program,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
         }
     }
     #[inline(always)]
@@ -289,7 +330,7 @@ impl<'a, 'b> DelistAssetCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(*self.payer.key, true));
         accounts.push(solana_instruction::AccountMeta::new(*self.owner.key, true));
         accounts.push(solana_instruction::AccountMeta::new(*self.asset.key, false));
@@ -305,7 +346,7 @@ impl<'a, 'b> DelistAssetCpi<'a, 'b> {
             *self.listing.key,
             false,
         ));
-        accounts.push(solana_instruction::AccountMeta::new_readonly(
+        accounts.push(solana_instruction::AccountMeta::new(
             *self.trade_hub.key,
             false,
         ));
@@ -317,6 +358,14 @@ impl<'a, 'b> DelistAssetCpi<'a, 'b> {
             *self.mpl_core_program.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.event_authority.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -331,7 +380,7 @@ impl<'a, 'b> DelistAssetCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(9 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(11 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.payer.clone());
         account_infos.push(self.owner.clone());
@@ -343,6 +392,8 @@ impl<'a, 'b> DelistAssetCpi<'a, 'b> {
         account_infos.push(self.trade_hub.clone());
         account_infos.push(self.system_program.clone());
         account_infos.push(self.mpl_core_program.clone());
+        account_infos.push(self.event_authority.clone());
+        account_infos.push(self.program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -364,9 +415,11 @@ impl<'a, 'b> DelistAssetCpi<'a, 'b> {
 ///   2. `[writable]` asset
 ///   3. `[writable, optional]` collection
 ///   4. `[writable]` listing
-///   5. `[]` trade_hub
+///   5. `[writable]` trade_hub
 ///   6. `[]` system_program
 ///   7. `[]` mpl_core_program
+///   8. `[]` event_authority
+///   9. `[]` program
 #[derive(Clone, Debug)]
 pub struct DelistAssetCpiBuilder<'a, 'b> {
     instruction: Box<DelistAssetCpiBuilderInstruction<'a, 'b>>,
@@ -384,6 +437,8 @@ impl<'a, 'b> DelistAssetCpiBuilder<'a, 'b> {
             trade_hub: None,
             system_program: None,
             mpl_core_program: None,
+            event_authority: None,
+            program: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -438,6 +493,19 @@ impl<'a, 'b> DelistAssetCpiBuilder<'a, 'b> {
         self.instruction.mpl_core_program = Some(mpl_core_program);
         self
     }
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(&mut self, program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -496,6 +564,13 @@ impl<'a, 'b> DelistAssetCpiBuilder<'a, 'b> {
                 .instruction
                 .mpl_core_program
                 .expect("mpl_core_program is not set"),
+
+            event_authority: self
+                .instruction
+                .event_authority
+                .expect("event_authority is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -515,6 +590,8 @@ struct DelistAssetCpiBuilderInstruction<'a, 'b> {
     trade_hub: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     mpl_core_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
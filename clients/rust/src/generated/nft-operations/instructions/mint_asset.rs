@@ -5,6 +5,7 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+use crate::generated::types::AllowlistProof;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 
@@ -19,21 +20,51 @@ pub struct MintAsset {
 
     pub authority: solana_pubkey::Pubkey,
 
+    pub authority_role: solana_pubkey::Pubkey,
+
     pub mint: solana_pubkey::Pubkey,
 
     pub collection: Option<solana_pubkey::Pubkey>,
 
     pub minter_config: solana_pubkey::Pubkey,
 
+    pub mint_record: Option<solana_pubkey::Pubkey>,
+
     pub project_config: solana_pubkey::Pubkey,
 
+    pub treasury: solana_pubkey::Pubkey,
+
     pub protocol_config: solana_pubkey::Pubkey,
 
     pub protocol_treasury: solana_pubkey::Pubkey,
 
+    pub project_fee_override: solana_pubkey::Pubkey,
+
+    pub referrer: Option<solana_pubkey::Pubkey>,
+
+    pub fee_authority: solana_pubkey::Pubkey,
+
+    pub protocol_fee_stats: solana_pubkey::Pubkey,
+
+    pub project_fee_stats: solana_pubkey::Pubkey,
+
+    pub fee_mint: Option<solana_pubkey::Pubkey>,
+
+    pub fee_payer_token_account: Option<solana_pubkey::Pubkey>,
+
+    pub protocol_treasury_token_account: Option<solana_pubkey::Pubkey>,
+
+    pub token_program: Option<solana_pubkey::Pubkey>,
+
+    pub sol_mind_protocol_program: solana_pubkey::Pubkey,
+
     pub system_program: solana_pubkey::Pubkey,
 
     pub mpl_core_program: solana_pubkey::Pubkey,
+
+    pub event_authority: solana_pubkey::Pubkey,
+
+    pub program: solana_pubkey::Pubkey,
 }
 
 impl MintAsset {
@@ -47,10 +78,14 @@ impl MintAsset {
         args: MintAssetInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(26 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(self.payer, true));
         accounts.push(solana_instruction::AccountMeta::new(self.owner, true));
         accounts.push(solana_instruction::AccountMeta::new(self.authority, true));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.authority_role,
+            false,
+        ));
         accounts.push(solana_instruction::AccountMeta::new(self.mint, true));
         if let Some(collection) = self.collection {
             accounts.push(solana_instruction::AccountMeta::new(collection, false));
@@ -64,10 +99,19 @@ impl MintAsset {
             self.minter_config,
             false,
         ));
+        if let Some(mint_record) = self.mint_record {
+            accounts.push(solana_instruction::AccountMeta::new(mint_record, false));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::NFT_OPERATIONS_ID,
+                false,
+            ));
+        }
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.project_config,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new(self.treasury, false));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.protocol_config,
             false,
@@ -76,6 +120,77 @@ impl MintAsset {
             self.protocol_treasury,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.project_fee_override,
+            false,
+        ));
+        if let Some(referrer) = self.referrer {
+            accounts.push(solana_instruction::AccountMeta::new(referrer, false));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::NFT_OPERATIONS_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.fee_authority,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            self.protocol_fee_stats,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            self.project_fee_stats,
+            false,
+        ));
+        if let Some(fee_mint) = self.fee_mint {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                fee_mint, false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::NFT_OPERATIONS_ID,
+                false,
+            ));
+        }
+        if let Some(fee_payer_token_account) = self.fee_payer_token_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                fee_payer_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::NFT_OPERATIONS_ID,
+                false,
+            ));
+        }
+        if let Some(protocol_treasury_token_account) = self.protocol_treasury_token_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                protocol_treasury_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::NFT_OPERATIONS_ID,
+                false,
+            ));
+        }
+        if let Some(token_program) = self.token_program {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                token_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::NFT_OPERATIONS_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.sol_mind_protocol_program,
+            false,
+        ));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.system_program,
             false,
@@ -84,6 +199,14 @@ impl MintAsset {
             self.mpl_core_program,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.event_authority,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let mut data = MintAssetInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -127,6 +250,7 @@ pub struct MintAssetInstructionArgs {
     pub name: Option<String>,
     pub uri: Option<String>,
     pub plugins: Option<Vec<Vec<u8>>>,
+    pub allowlist: Option<AllowlistProof>,
 }
 
 impl MintAssetInstructionArgs {
@@ -142,30 +266,61 @@ impl MintAssetInstructionArgs {
 ///   0. `[writable, signer]` payer
 ///   1. `[writable, signer]` owner
 ///   2. `[writable, signer]` authority
-///   3. `[writable, signer]` mint
-///   4. `[writable, optional]` collection
-///   5. `[writable]` minter_config
-///   6. `[]` project_config
-///   7. `[]` protocol_config
-///   8. `[writable]` protocol_treasury
-///   9. `[optional]` system_program (default to `11111111111111111111111111111111`)
-///   10. `[optional]` mpl_core_program (default to `CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d`)
+///   3. `[]` authority_role
+///   4. `[writable, signer]` mint
+///   5. `[writable, optional]` collection
+///   6. `[writable]` minter_config
+///   7. `[writable, optional]` mint_record
+///   8. `[]` project_config
+///   9. `[writable]` treasury
+///   10. `[]` protocol_config
+///   11. `[writable]` protocol_treasury
+///   12. `[]` project_fee_override
+///   13. `[writable, optional]` referrer
+///   14. `[]` fee_authority
+///   15. `[writable]` protocol_fee_stats
+///   16. `[writable]` project_fee_stats
+///   17. `[optional]` fee_mint
+///   18. `[writable, optional]` fee_payer_token_account
+///   19. `[writable, optional]` protocol_treasury_token_account
+///   20. `[optional]` token_program
+///   21. `[optional]` sol_mind_protocol_program (default to `7GoSe63kVLi3Bt2mkPHUFVbmTcsZvp7QEUHjDB6GvLgV`)
+///   22. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   23. `[optional]` mpl_core_program (default to `CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d`)
+///   24. `[optional]` event_authority (default to `Ausr478rARUXg8Bj6pnZjYHpVxxEASFyamPEg2vwSNdt`)
+///   25. `[optional]` program (default to `Anv3tR3s1eQqQE1a5wnhtYdi6dkjv9rTn34V6pom9C9q`)
 #[derive(Clone, Debug, Default)]
 pub struct MintAssetBuilder {
     payer: Option<solana_pubkey::Pubkey>,
     owner: Option<solana_pubkey::Pubkey>,
     authority: Option<solana_pubkey::Pubkey>,
+    authority_role: Option<solana_pubkey::Pubkey>,
     mint: Option<solana_pubkey::Pubkey>,
     collection: Option<solana_pubkey::Pubkey>,
     minter_config: Option<solana_pubkey::Pubkey>,
+    mint_record: Option<solana_pubkey::Pubkey>,
     project_config: Option<solana_pubkey::Pubkey>,
+    treasury: Option<solana_pubkey::Pubkey>,
     protocol_config: Option<solana_pubkey::Pubkey>,
     protocol_treasury: Option<solana_pubkey::Pubkey>,
+    project_fee_override: Option<solana_pubkey::Pubkey>,
+    referrer: Option<solana_pubkey::Pubkey>,
+    fee_authority: Option<solana_pubkey::Pubkey>,
+    protocol_fee_stats: Option<solana_pubkey::Pubkey>,
+    project_fee_stats: Option<solana_pubkey::Pubkey>,
+    fee_mint: Option<solana_pubkey::Pubkey>,
+    fee_payer_token_account: Option<solana_pubkey::Pubkey>,
+    protocol_treasury_token_account: Option<solana_pubkey::Pubkey>,
+    token_program: Option<solana_pubkey::Pubkey>,
+    sol_mind_protocol_program: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
     mpl_core_program: Option<solana_pubkey::Pubkey>,
+    event_authority: Option<solana_pubkey::Pubkey>,
+    program: Option<solana_pubkey::Pubkey>,
     name: Option<String>,
     uri: Option<String>,
     plugins: Option<Vec<Vec<u8>>>,
+    allowlist: Option<AllowlistProof>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -189,6 +344,11 @@ impl MintAssetBuilder {
         self
     }
     #[inline(always)]
+    pub fn authority_role(&mut self, authority_role: solana_pubkey::Pubkey) -> &mut Self {
+        self.authority_role = Some(authority_role);
+        self
+    }
+    #[inline(always)]
     pub fn mint(&mut self, mint: solana_pubkey::Pubkey) -> &mut Self {
         self.mint = Some(mint);
         self
@@ -204,12 +364,23 @@ impl MintAssetBuilder {
         self.minter_config = Some(minter_config);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn mint_record(&mut self, mint_record: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.mint_record = mint_record;
+        self
+    }
     #[inline(always)]
     pub fn project_config(&mut self, project_config: solana_pubkey::Pubkey) -> &mut Self {
         self.project_config = Some(project_config);
         self
     }
     #[inline(always)]
+    pub fn treasury(&mut self, treasury: solana_pubkey::Pubkey) -> &mut Self {
+        self.treasury = Some(treasury);
+        self
+    }
+    #[inline(always)]
     pub fn protocol_config(&mut self, protocol_config: solana_pubkey::Pubkey) -> &mut Self {
         self.protocol_config = Some(protocol_config);
         self
@@ -219,6 +390,74 @@ impl MintAssetBuilder {
         self.protocol_treasury = Some(protocol_treasury);
         self
     }
+    #[inline(always)]
+    pub fn project_fee_override(
+        &mut self,
+        project_fee_override: solana_pubkey::Pubkey,
+    ) -> &mut Self {
+        self.project_fee_override = Some(project_fee_override);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn referrer(&mut self, referrer: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.referrer = referrer;
+        self
+    }
+    #[inline(always)]
+    pub fn fee_authority(&mut self, fee_authority: solana_pubkey::Pubkey) -> &mut Self {
+        self.fee_authority = Some(fee_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn protocol_fee_stats(&mut self, protocol_fee_stats: solana_pubkey::Pubkey) -> &mut Self {
+        self.protocol_fee_stats = Some(protocol_fee_stats);
+        self
+    }
+    #[inline(always)]
+    pub fn project_fee_stats(&mut self, project_fee_stats: solana_pubkey::Pubkey) -> &mut Self {
+        self.project_fee_stats = Some(project_fee_stats);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn fee_mint(&mut self, fee_mint: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.fee_mint = fee_mint;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn fee_payer_token_account(
+        &mut self,
+        fee_payer_token_account: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.fee_payer_token_account = fee_payer_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn protocol_treasury_token_account(
+        &mut self,
+        protocol_treasury_token_account: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.protocol_treasury_token_account = protocol_treasury_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.token_program = token_program;
+        self
+    }
+    /// `[optional account, default to '7GoSe63kVLi3Bt2mkPHUFVbmTcsZvp7QEUHjDB6GvLgV']`
+    #[inline(always)]
+    pub fn sol_mind_protocol_program(
+        &mut self,
+        sol_mind_protocol_program: solana_pubkey::Pubkey,
+    ) -> &mut Self {
+        self.sol_mind_protocol_program = Some(sol_mind_protocol_program);
+        self
+    }
     /// `[optional account, default to '11111111111111111111111111111111']`
     #[inline(always)]
     pub fn system_program(&mut self, system_program: solana_pubkey::Pubkey) -> &mut Self {
@@ -231,6 +470,18 @@ impl MintAssetBuilder {
         self.mpl_core_program = Some(mpl_core_program);
         self
     }
+    /// `[optional account, default to 'Ausr478rARUXg8Bj6pnZjYHpVxxEASFyamPEg2vwSNdt']`
+    #[inline(always)]
+    pub fn event_authority(&mut self, event_authority: solana_pubkey::Pubkey) -> &mut Self {
+        self.event_authority = Some(event_authority);
+        self
+    }
+    /// `[optional account, default to 'Anv3tR3s1eQqQE1a5wnhtYdi6dkjv9rTn34V6pom9C9q']`
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
     /// `[optional argument]`
     #[inline(always)]
     pub fn name(&mut self, name: String) -> &mut Self {
@@ -249,6 +500,12 @@ impl MintAssetBuilder {
         self.plugins = Some(plugins);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn allowlist(&mut self, allowlist: AllowlistProof) -> &mut Self {
+        self.allowlist = Some(allowlist);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(&mut self, account: solana_instruction::AccountMeta) -> &mut Self {
@@ -270,25 +527,53 @@ impl MintAssetBuilder {
             payer: self.payer.expect("payer is not set"),
             owner: self.owner.expect("owner is not set"),
             authority: self.authority.expect("authority is not set"),
+            authority_role: self.authority_role.expect("authority_role is not set"),
             mint: self.mint.expect("mint is not set"),
             collection: self.collection,
             minter_config: self.minter_config.expect("minter_config is not set"),
+            mint_record: self.mint_record,
             project_config: self.project_config.expect("project_config is not set"),
+            treasury: self.treasury.expect("treasury is not set"),
             protocol_config: self.protocol_config.expect("protocol_config is not set"),
             protocol_treasury: self
                 .protocol_treasury
                 .expect("protocol_treasury is not set"),
+            project_fee_override: self
+                .project_fee_override
+                .expect("project_fee_override is not set"),
+            referrer: self.referrer,
+            fee_authority: self.fee_authority.expect("fee_authority is not set"),
+            protocol_fee_stats: self
+                .protocol_fee_stats
+                .expect("protocol_fee_stats is not set"),
+            project_fee_stats: self
+                .project_fee_stats
+                .expect("project_fee_stats is not set"),
+            fee_mint: self.fee_mint,
+            fee_payer_token_account: self.fee_payer_token_account,
+            protocol_treasury_token_account: self.protocol_treasury_token_account,
+            token_program: self.token_program,
+            sol_mind_protocol_program: self.sol_mind_protocol_program.unwrap_or(
+                solana_pubkey::pubkey!("7GoSe63kVLi3Bt2mkPHUFVbmTcsZvp7QEUHjDB6GvLgV"),
+            ),
             system_program: self
                 .system_program
                 .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
             mpl_core_program: self.mpl_core_program.unwrap_or(solana_pubkey::pubkey!(
                 "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
             )),
+            event_authority: self.event_authority.unwrap_or(solana_pubkey::pubkey!(
+                "Ausr478rARUXg8Bj6pnZjYHpVxxEASFyamPEg2vwSNdt"
+            )),
+            program: self.program.unwrap_or(solana_pubkey::pubkey!(
+                "Anv3tR3s1eQqQE1a5wnhtYdi6dkjv9rTn34V6pom9C9q"
+            )),
         };
         let args = MintAssetInstructionArgs {
             name: self.name.clone(),
             uri: self.uri.clone(),
             plugins: self.plugins.clone(),
+            allowlist: self.allowlist.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -303,21 +588,51 @@ pub struct MintAssetCpiAccounts<'a, 'b> {
 
     pub authority: &'b solana_account_info::AccountInfo<'a>,
 
+    pub authority_role: &'b solana_account_info::AccountInfo<'a>,
+
     pub mint: &'b solana_account_info::AccountInfo<'a>,
 
     pub collection: Option<&'b solana_account_info::AccountInfo<'a>>,
 
     pub minter_config: &'b solana_account_info::AccountInfo<'a>,
 
+    pub mint_record: Option<&'b solana_account_info::AccountInfo<'a>>,
+
     pub project_config: &'b solana_account_info::AccountInfo<'a>,
 
+    pub treasury: &'b solana_account_info::AccountInfo<'a>,
+
     pub protocol_config: &'b solana_account_info::AccountInfo<'a>,
 
     pub protocol_treasury: &'b solana_account_info::AccountInfo<'a>,
 
+    pub project_fee_override: &'b solana_account_info::AccountInfo<'a>,
+
+    pub referrer: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub fee_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub protocol_fee_stats: &'b solana_account_info::AccountInfo<'a>,
+
+    pub project_fee_stats: &'b solana_account_info::AccountInfo<'a>,
+
+    pub fee_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub fee_payer_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub protocol_treasury_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub sol_mind_protocol_program: &'b solana_account_info::AccountInfo<'a>,
+
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub mpl_core_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
 }
 
 /// `mint_asset` CPI instruction.
@@ -331,21 +646,51 @@ pub struct MintAssetCpi<'a, 'b> {
 
     pub authority: &'b solana_account_info::AccountInfo<'a>,
 
+    pub authority_role: &'b solana_account_info::AccountInfo<'a>,
+
     pub mint: &'b solana_account_info::AccountInfo<'a>,
 
     pub collection: Option<&'b solana_account_info::AccountInfo<'a>>,
 
     pub minter_config: &'b solana_account_info::AccountInfo<'a>,
 
+    pub mint_record: Option<&'b solana_account_info::AccountInfo<'a>>,
+
     pub project_config: &'b solana_account_info::AccountInfo<'a>,
 
+    pub treasury: &'b solana_account_info::AccountInfo<'a>,
+
     pub protocol_config: &'b solana_account_info::AccountInfo<'a>,
 
     pub protocol_treasury: &'b solana_account_info::AccountInfo<'a>,
 
+    pub project_fee_override: &'b solana_account_info::AccountInfo<'a>,
+
+    pub referrer: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub fee_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub protocol_fee_stats: &'b solana_account_info::AccountInfo<'a>,
+
+    pub project_fee_stats: &'b solana_account_info::AccountInfo<'a>,
+
+    pub fee_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub fee_payer_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub protocol_treasury_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub sol_mind_protocol_program: &'b solana_account_info::AccountInfo<'a>,
+
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub mpl_core_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: MintAssetInstructionArgs,
 }
@@ -361,14 +706,29 @@ impl<'a, 'b> MintAssetCpi<'a, 'b> {
             payer: accounts.payer,
             owner: accounts.owner,
             authority: accounts.authority,
+            authority_role: accounts.authority_role,
             mint: accounts.mint,
             collection: accounts.collection,
             minter_config: accounts.minter_config,
+            mint_record: accounts.mint_record,
             project_config: accounts.project_config,
+            treasury: accounts.treasury,
             protocol_config: accounts.protocol_config,
             protocol_treasury: accounts.protocol_treasury,
+            project_fee_override: accounts.project_fee_override,
+            referrer: accounts.referrer,
+            fee_authority: accounts.fee_authority,
+            protocol_fee_stats: accounts.protocol_fee_stats,
+            project_fee_stats: accounts.project_fee_stats,
+            fee_mint: accounts.fee_mint,
+            fee_payer_token_account: accounts.fee_payer_token_account,
+            protocol_treasury_token_account: accounts.protocol_treasury_token_account,
+            token_program: accounts.token_program,
+            sol_mind_protocol_program: accounts.sol_mind_protocol_program,
             system_program: accounts.system_program,
             mpl_core_program: accounts.mpl_core_program,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
             __args: args,
         }
     }
@@ -395,13 +755,17 @@ impl<'a, 'b> MintAssetCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(26 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(*self.payer.key, true));
         accounts.push(solana_instruction::AccountMeta::new(*self.owner.key, true));
         accounts.push(solana_instruction::AccountMeta::new(
             *self.authority.key,
             true,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.authority_role.key,
+            false,
+        ));
         accounts.push(solana_instruction::AccountMeta::new(*self.mint.key, true));
         if let Some(collection) = self.collection {
             accounts.push(solana_instruction::AccountMeta::new(*collection.key, false));
@@ -415,10 +779,25 @@ impl<'a, 'b> MintAssetCpi<'a, 'b> {
             *self.minter_config.key,
             false,
         ));
+        if let Some(mint_record) = self.mint_record {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *mint_record.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::NFT_OPERATIONS_ID,
+                false,
+            ));
+        }
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.project_config.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            *self.treasury.key,
+            false,
+        ));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.protocol_config.key,
             false,
@@ -427,6 +806,78 @@ impl<'a, 'b> MintAssetCpi<'a, 'b> {
             *self.protocol_treasury.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.project_fee_override.key,
+            false,
+        ));
+        if let Some(referrer) = self.referrer {
+            accounts.push(solana_instruction::AccountMeta::new(*referrer.key, false));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::NFT_OPERATIONS_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.fee_authority.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            *self.protocol_fee_stats.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            *self.project_fee_stats.key,
+            false,
+        ));
+        if let Some(fee_mint) = self.fee_mint {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *fee_mint.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::NFT_OPERATIONS_ID,
+                false,
+            ));
+        }
+        if let Some(fee_payer_token_account) = self.fee_payer_token_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *fee_payer_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::NFT_OPERATIONS_ID,
+                false,
+            ));
+        }
+        if let Some(protocol_treasury_token_account) = self.protocol_treasury_token_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *protocol_treasury_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::NFT_OPERATIONS_ID,
+                false,
+            ));
+        }
+        if let Some(token_program) = self.token_program {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *token_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::NFT_OPERATIONS_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.sol_mind_protocol_program.key,
+            false,
+        ));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.system_program.key,
             false,
@@ -435,6 +886,14 @@ impl<'a, 'b> MintAssetCpi<'a, 'b> {
             *self.mpl_core_program.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.event_authority.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -451,21 +910,48 @@ impl<'a, 'b> MintAssetCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(12 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(27 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.payer.clone());
         account_infos.push(self.owner.clone());
         account_infos.push(self.authority.clone());
+        account_infos.push(self.authority_role.clone());
         account_infos.push(self.mint.clone());
         if let Some(collection) = self.collection {
             account_infos.push(collection.clone());
         }
         account_infos.push(self.minter_config.clone());
+        if let Some(mint_record) = self.mint_record {
+            account_infos.push(mint_record.clone());
+        }
         account_infos.push(self.project_config.clone());
+        account_infos.push(self.treasury.clone());
         account_infos.push(self.protocol_config.clone());
         account_infos.push(self.protocol_treasury.clone());
+        account_infos.push(self.project_fee_override.clone());
+        if let Some(referrer) = self.referrer {
+            account_infos.push(referrer.clone());
+        }
+        account_infos.push(self.fee_authority.clone());
+        account_infos.push(self.protocol_fee_stats.clone());
+        account_infos.push(self.project_fee_stats.clone());
+        if let Some(fee_mint) = self.fee_mint {
+            account_infos.push(fee_mint.clone());
+        }
+        if let Some(fee_payer_token_account) = self.fee_payer_token_account {
+            account_infos.push(fee_payer_token_account.clone());
+        }
+        if let Some(protocol_treasury_token_account) = self.protocol_treasury_token_account {
+            account_infos.push(protocol_treasury_token_account.clone());
+        }
+        if let Some(token_program) = self.token_program {
+            account_infos.push(token_program.clone());
+        }
+        account_infos.push(self.sol_mind_protocol_program.clone());
         account_infos.push(self.system_program.clone());
         account_infos.push(self.mpl_core_program.clone());
+        account_infos.push(self.event_authority.clone());
+        account_infos.push(self.program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -485,14 +971,29 @@ impl<'a, 'b> MintAssetCpi<'a, 'b> {
 ///   0. `[writable, signer]` payer
 ///   1. `[writable, signer]` owner
 ///   2. `[writable, signer]` authority
-///   3. `[writable, signer]` mint
-///   4. `[writable, optional]` collection
-///   5. `[writable]` minter_config
-///   6. `[]` project_config
-///   7. `[]` protocol_config
-///   8. `[writable]` protocol_treasury
-///   9. `[]` system_program
-///   10. `[]` mpl_core_program
+///   3. `[]` authority_role
+///   4. `[writable, signer]` mint
+///   5. `[writable, optional]` collection
+///   6. `[writable]` minter_config
+///   7. `[writable, optional]` mint_record
+///   8. `[]` project_config
+///   9. `[writable]` treasury
+///   10. `[]` protocol_config
+///   11. `[writable]` protocol_treasury
+///   12. `[]` project_fee_override
+///   13. `[writable, optional]` referrer
+///   14. `[]` fee_authority
+///   15. `[writable]` protocol_fee_stats
+///   16. `[writable]` project_fee_stats
+///   17. `[optional]` fee_mint
+///   18. `[writable, optional]` fee_payer_token_account
+///   19. `[writable, optional]` protocol_treasury_token_account
+///   20. `[optional]` token_program
+///   21. `[]` sol_mind_protocol_program
+///   22. `[]` system_program
+///   23. `[]` mpl_core_program
+///   24. `[]` event_authority
+///   25. `[]` program
 #[derive(Clone, Debug)]
 pub struct MintAssetCpiBuilder<'a, 'b> {
     instruction: Box<MintAssetCpiBuilderInstruction<'a, 'b>>,
@@ -505,17 +1006,33 @@ impl<'a, 'b> MintAssetCpiBuilder<'a, 'b> {
             payer: None,
             owner: None,
             authority: None,
+            authority_role: None,
             mint: None,
             collection: None,
             minter_config: None,
+            mint_record: None,
             project_config: None,
+            treasury: None,
             protocol_config: None,
             protocol_treasury: None,
+            project_fee_override: None,
+            referrer: None,
+            fee_authority: None,
+            protocol_fee_stats: None,
+            project_fee_stats: None,
+            fee_mint: None,
+            fee_payer_token_account: None,
+            protocol_treasury_token_account: None,
+            token_program: None,
+            sol_mind_protocol_program: None,
             system_program: None,
             mpl_core_program: None,
+            event_authority: None,
+            program: None,
             name: None,
             uri: None,
             plugins: None,
+            allowlist: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -536,6 +1053,14 @@ impl<'a, 'b> MintAssetCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn authority_role(
+        &mut self,
+        authority_role: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.authority_role = Some(authority_role);
+        self
+    }
+    #[inline(always)]
     pub fn mint(&mut self, mint: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
         self.instruction.mint = Some(mint);
         self
@@ -557,6 +1082,15 @@ impl<'a, 'b> MintAssetCpiBuilder<'a, 'b> {
         self.instruction.minter_config = Some(minter_config);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn mint_record(
+        &mut self,
+        mint_record: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.mint_record = mint_record;
+        self
+    }
     #[inline(always)]
     pub fn project_config(
         &mut self,
@@ -566,6 +1100,11 @@ impl<'a, 'b> MintAssetCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn treasury(&mut self, treasury: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.treasury = Some(treasury);
+        self
+    }
+    #[inline(always)]
     pub fn protocol_config(
         &mut self,
         protocol_config: &'b solana_account_info::AccountInfo<'a>,
@@ -582,6 +1121,91 @@ impl<'a, 'b> MintAssetCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn project_fee_override(
+        &mut self,
+        project_fee_override: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.project_fee_override = Some(project_fee_override);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn referrer(
+        &mut self,
+        referrer: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.referrer = referrer;
+        self
+    }
+    #[inline(always)]
+    pub fn fee_authority(
+        &mut self,
+        fee_authority: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.fee_authority = Some(fee_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn protocol_fee_stats(
+        &mut self,
+        protocol_fee_stats: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.protocol_fee_stats = Some(protocol_fee_stats);
+        self
+    }
+    #[inline(always)]
+    pub fn project_fee_stats(
+        &mut self,
+        project_fee_stats: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.project_fee_stats = Some(project_fee_stats);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn fee_mint(
+        &mut self,
+        fee_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.fee_mint = fee_mint;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn fee_payer_token_account(
+        &mut self,
+        fee_payer_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.fee_payer_token_account = fee_payer_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn protocol_treasury_token_account(
+        &mut self,
+        protocol_treasury_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.protocol_treasury_token_account = protocol_treasury_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.token_program = token_program;
+        self
+    }
+    #[inline(always)]
+    pub fn sol_mind_protocol_program(
+        &mut self,
+        sol_mind_protocol_program: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.sol_mind_protocol_program = Some(sol_mind_protocol_program);
+        self
+    }
+    #[inline(always)]
     pub fn system_program(
         &mut self,
         system_program: &'b solana_account_info::AccountInfo<'a>,
@@ -597,6 +1221,19 @@ impl<'a, 'b> MintAssetCpiBuilder<'a, 'b> {
         self.instruction.mpl_core_program = Some(mpl_core_program);
         self
     }
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(&mut self, program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
     /// `[optional argument]`
     #[inline(always)]
     pub fn name(&mut self, name: String) -> &mut Self {
@@ -615,6 +1252,12 @@ impl<'a, 'b> MintAssetCpiBuilder<'a, 'b> {
         self.instruction.plugins = Some(plugins);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn allowlist(&mut self, allowlist: AllowlistProof) -> &mut Self {
+        self.instruction.allowlist = Some(allowlist);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -653,6 +1296,7 @@ impl<'a, 'b> MintAssetCpiBuilder<'a, 'b> {
             name: self.instruction.name.clone(),
             uri: self.instruction.uri.clone(),
             plugins: self.instruction.plugins.clone(),
+            allowlist: self.instruction.allowlist.clone(),
         };
         let instruction = MintAssetCpi {
             __program: self.instruction.__program,
@@ -663,6 +1307,11 @@ impl<'a, 'b> MintAssetCpiBuilder<'a, 'b> {
 
             authority: self.instruction.authority.expect("authority is not set"),
 
+            authority_role: self
+                .instruction
+                .authority_role
+                .expect("authority_role is not set"),
+
             mint: self.instruction.mint.expect("mint is not set"),
 
             collection: self.instruction.collection,
@@ -672,11 +1321,15 @@ impl<'a, 'b> MintAssetCpiBuilder<'a, 'b> {
                 .minter_config
                 .expect("minter_config is not set"),
 
+            mint_record: self.instruction.mint_record,
+
             project_config: self
                 .instruction
                 .project_config
                 .expect("project_config is not set"),
 
+            treasury: self.instruction.treasury.expect("treasury is not set"),
+
             protocol_config: self
                 .instruction
                 .protocol_config
@@ -687,6 +1340,41 @@ impl<'a, 'b> MintAssetCpiBuilder<'a, 'b> {
                 .protocol_treasury
                 .expect("protocol_treasury is not set"),
 
+            project_fee_override: self
+                .instruction
+                .project_fee_override
+                .expect("project_fee_override is not set"),
+
+            referrer: self.instruction.referrer,
+
+            fee_authority: self
+                .instruction
+                .fee_authority
+                .expect("fee_authority is not set"),
+
+            protocol_fee_stats: self
+                .instruction
+                .protocol_fee_stats
+                .expect("protocol_fee_stats is not set"),
+
+            project_fee_stats: self
+                .instruction
+                .project_fee_stats
+                .expect("project_fee_stats is not set"),
+
+            fee_mint: self.instruction.fee_mint,
+
+            fee_payer_token_account: self.instruction.fee_payer_token_account,
+
+            protocol_treasury_token_account: self.instruction.protocol_treasury_token_account,
+
+            token_program: self.instruction.token_program,
+
+            sol_mind_protocol_program: self
+                .instruction
+                .sol_mind_protocol_program
+                .expect("sol_mind_protocol_program is not set"),
+
             system_program: self
                 .instruction
                 .system_program
@@ -696,6 +1384,13 @@ impl<'a, 'b> MintAssetCpiBuilder<'a, 'b> {
                 .instruction
                 .mpl_core_program
                 .expect("mpl_core_program is not set"),
+
+            event_authority: self
+                .instruction
+                .event_authority
+                .expect("event_authority is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -711,17 +1406,33 @@ struct MintAssetCpiBuilderInstruction<'a, 'b> {
     payer: Option<&'b solana_account_info::AccountInfo<'a>>,
     owner: Option<&'b solana_account_info::AccountInfo<'a>>,
     authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    authority_role: Option<&'b solana_account_info::AccountInfo<'a>>,
     mint: Option<&'b solana_account_info::AccountInfo<'a>>,
     collection: Option<&'b solana_account_info::AccountInfo<'a>>,
     minter_config: Option<&'b solana_account_info::AccountInfo<'a>>,
+    mint_record: Option<&'b solana_account_info::AccountInfo<'a>>,
     project_config: Option<&'b solana_account_info::AccountInfo<'a>>,
+    treasury: Option<&'b solana_account_info::AccountInfo<'a>>,
     protocol_config: Option<&'b solana_account_info::AccountInfo<'a>>,
     protocol_treasury: Option<&'b solana_account_info::AccountInfo<'a>>,
+    project_fee_override: Option<&'b solana_account_info::AccountInfo<'a>>,
+    referrer: Option<&'b solana_account_info::AccountInfo<'a>>,
+    fee_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    protocol_fee_stats: Option<&'b solana_account_info::AccountInfo<'a>>,
+    project_fee_stats: Option<&'b solana_account_info::AccountInfo<'a>>,
+    fee_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
+    fee_payer_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    protocol_treasury_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    sol_mind_protocol_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     mpl_core_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_account_info::AccountInfo<'a>>,
     name: Option<String>,
     uri: Option<String>,
     plugins: Option<Vec<Vec<u8>>>,
+    allowlist: Option<AllowlistProof>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
@@ -5,16 +5,28 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+pub(crate) mod r#close_mint_record;
+pub(crate) mod r#close_minter_config;
+pub(crate) mod r#close_trade_hub;
 pub(crate) mod r#create_minter_config;
 pub(crate) mod r#create_trade_hub;
 pub(crate) mod r#delist_asset;
 pub(crate) mod r#list_asset;
 pub(crate) mod r#mint_asset;
 pub(crate) mod r#purchase_asset;
+pub(crate) mod r#set_mint_phases;
+pub(crate) mod r#set_public_mint;
+pub(crate) mod r#set_wallet_mint_limit;
 
+pub use self::r#close_mint_record::*;
+pub use self::r#close_minter_config::*;
+pub use self::r#close_trade_hub::*;
 pub use self::r#create_minter_config::*;
 pub use self::r#create_trade_hub::*;
 pub use self::r#delist_asset::*;
 pub use self::r#list_asset::*;
 pub use self::r#mint_asset::*;
 pub use self::r#purchase_asset::*;
+pub use self::r#set_mint_phases::*;
+pub use self::r#set_public_mint::*;
+pub use self::r#set_wallet_mint_limit::*;
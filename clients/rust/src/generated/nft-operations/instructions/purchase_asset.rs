@@ -33,9 +33,33 @@ pub struct PurchaseAsset {
 
     pub protocol_treasury: solana_pubkey::Pubkey,
 
+    pub project_fee_override: solana_pubkey::Pubkey,
+
+    pub referrer: Option<solana_pubkey::Pubkey>,
+
+    pub fee_authority: solana_pubkey::Pubkey,
+
+    pub protocol_fee_stats: solana_pubkey::Pubkey,
+
+    pub project_fee_stats: solana_pubkey::Pubkey,
+
+    pub fee_mint: Option<solana_pubkey::Pubkey>,
+
+    pub fee_payer_token_account: Option<solana_pubkey::Pubkey>,
+
+    pub protocol_treasury_token_account: Option<solana_pubkey::Pubkey>,
+
+    pub token_program: Option<solana_pubkey::Pubkey>,
+
+    pub sol_mind_protocol_program: solana_pubkey::Pubkey,
+
     pub system_program: solana_pubkey::Pubkey,
 
     pub mpl_core_program: solana_pubkey::Pubkey,
+
+    pub event_authority: solana_pubkey::Pubkey,
+
+    pub program: solana_pubkey::Pubkey,
 }
 
 impl PurchaseAsset {
@@ -52,7 +76,7 @@ impl PurchaseAsset {
         args: PurchaseAssetInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(24 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(self.buyer, true));
         accounts.push(solana_instruction::AccountMeta::new(self.owner, false));
         accounts.push(solana_instruction::AccountMeta::new(self.asset, false));
@@ -65,10 +89,7 @@ impl PurchaseAsset {
             ));
         }
         accounts.push(solana_instruction::AccountMeta::new(self.listing, false));
-        accounts.push(solana_instruction::AccountMeta::new_readonly(
-            self.trade_hub,
-            false,
-        ));
+        accounts.push(solana_instruction::AccountMeta::new(self.trade_hub, false));
         accounts.push(solana_instruction::AccountMeta::new(self.treasury, false));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.project_config,
@@ -82,6 +103,77 @@ impl PurchaseAsset {
             self.protocol_treasury,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.project_fee_override,
+            false,
+        ));
+        if let Some(referrer) = self.referrer {
+            accounts.push(solana_instruction::AccountMeta::new(referrer, false));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::NFT_OPERATIONS_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.fee_authority,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            self.protocol_fee_stats,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            self.project_fee_stats,
+            false,
+        ));
+        if let Some(fee_mint) = self.fee_mint {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                fee_mint, false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::NFT_OPERATIONS_ID,
+                false,
+            ));
+        }
+        if let Some(fee_payer_token_account) = self.fee_payer_token_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                fee_payer_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::NFT_OPERATIONS_ID,
+                false,
+            ));
+        }
+        if let Some(protocol_treasury_token_account) = self.protocol_treasury_token_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                protocol_treasury_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::NFT_OPERATIONS_ID,
+                false,
+            ));
+        }
+        if let Some(token_program) = self.token_program {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                token_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::NFT_OPERATIONS_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.sol_mind_protocol_program,
+            false,
+        ));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.system_program,
             false,
@@ -90,6 +182,14 @@ impl PurchaseAsset {
             self.mpl_core_program,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.event_authority,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let mut data = PurchaseAssetInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -148,13 +248,25 @@ impl PurchaseAssetInstructionArgs {
 ///   2. `[writable]` asset
 ///   3. `[writable, optional]` collection
 ///   4. `[writable]` listing
-///   5. `[]` trade_hub
+///   5. `[writable]` trade_hub
 ///   6. `[writable]` treasury
 ///   7. `[]` project_config
 ///   8. `[]` protocol_config
 ///   9. `[writable]` protocol_treasury
-///   10. `[optional]` system_program (default to `11111111111111111111111111111111`)
-///   11. `[optional]` mpl_core_program (default to `CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d`)
+///   10. `[]` project_fee_override
+///   11. `[writable, optional]` referrer
+///   12. `[]` fee_authority
+///   13. `[writable]` protocol_fee_stats
+///   14. `[writable]` project_fee_stats
+///   15. `[optional]` fee_mint
+///   16. `[writable, optional]` fee_payer_token_account
+///   17. `[writable, optional]` protocol_treasury_token_account
+///   18. `[optional]` token_program
+///   19. `[optional]` sol_mind_protocol_program (default to `7GoSe63kVLi3Bt2mkPHUFVbmTcsZvp7QEUHjDB6GvLgV`)
+///   20. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   21. `[optional]` mpl_core_program (default to `CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d`)
+///   22. `[optional]` event_authority (default to `Ausr478rARUXg8Bj6pnZjYHpVxxEASFyamPEg2vwSNdt`)
+///   23. `[optional]` program (default to `Anv3tR3s1eQqQE1a5wnhtYdi6dkjv9rTn34V6pom9C9q`)
 #[derive(Clone, Debug, Default)]
 pub struct PurchaseAssetBuilder {
     buyer: Option<solana_pubkey::Pubkey>,
@@ -167,8 +279,20 @@ pub struct PurchaseAssetBuilder {
     project_config: Option<solana_pubkey::Pubkey>,
     protocol_config: Option<solana_pubkey::Pubkey>,
     protocol_treasury: Option<solana_pubkey::Pubkey>,
+    project_fee_override: Option<solana_pubkey::Pubkey>,
+    referrer: Option<solana_pubkey::Pubkey>,
+    fee_authority: Option<solana_pubkey::Pubkey>,
+    protocol_fee_stats: Option<solana_pubkey::Pubkey>,
+    project_fee_stats: Option<solana_pubkey::Pubkey>,
+    fee_mint: Option<solana_pubkey::Pubkey>,
+    fee_payer_token_account: Option<solana_pubkey::Pubkey>,
+    protocol_treasury_token_account: Option<solana_pubkey::Pubkey>,
+    token_program: Option<solana_pubkey::Pubkey>,
+    sol_mind_protocol_program: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
     mpl_core_program: Option<solana_pubkey::Pubkey>,
+    event_authority: Option<solana_pubkey::Pubkey>,
+    program: Option<solana_pubkey::Pubkey>,
     max_price: Option<u64>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -228,6 +352,74 @@ impl PurchaseAssetBuilder {
         self.protocol_treasury = Some(protocol_treasury);
         self
     }
+    #[inline(always)]
+    pub fn project_fee_override(
+        &mut self,
+        project_fee_override: solana_pubkey::Pubkey,
+    ) -> &mut Self {
+        self.project_fee_override = Some(project_fee_override);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn referrer(&mut self, referrer: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.referrer = referrer;
+        self
+    }
+    #[inline(always)]
+    pub fn fee_authority(&mut self, fee_authority: solana_pubkey::Pubkey) -> &mut Self {
+        self.fee_authority = Some(fee_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn protocol_fee_stats(&mut self, protocol_fee_stats: solana_pubkey::Pubkey) -> &mut Self {
+        self.protocol_fee_stats = Some(protocol_fee_stats);
+        self
+    }
+    #[inline(always)]
+    pub fn project_fee_stats(&mut self, project_fee_stats: solana_pubkey::Pubkey) -> &mut Self {
+        self.project_fee_stats = Some(project_fee_stats);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn fee_mint(&mut self, fee_mint: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.fee_mint = fee_mint;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn fee_payer_token_account(
+        &mut self,
+        fee_payer_token_account: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.fee_payer_token_account = fee_payer_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn protocol_treasury_token_account(
+        &mut self,
+        protocol_treasury_token_account: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.protocol_treasury_token_account = protocol_treasury_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.token_program = token_program;
+        self
+    }
+    /// `[optional account, default to '7GoSe63kVLi3Bt2mkPHUFVbmTcsZvp7QEUHjDB6GvLgV']`
+    #[inline(always)]
+    pub fn sol_mind_protocol_program(
+        &mut self,
+        sol_mind_protocol_program: solana_pubkey::Pubkey,
+    ) -> &mut Self {
+        self.sol_mind_protocol_program = Some(sol_mind_protocol_program);
+        self
+    }
     /// `[optional account, default to '11111111111111111111111111111111']`
     #[inline(always)]
     pub fn system_program(&mut self, system_program: solana_pubkey::Pubkey) -> &mut Self {
@@ -240,6 +432,18 @@ impl PurchaseAssetBuilder {
         self.mpl_core_program = Some(mpl_core_program);
         self
     }
+    /// `[optional account, default to 'Ausr478rARUXg8Bj6pnZjYHpVxxEASFyamPEg2vwSNdt']`
+    #[inline(always)]
+    pub fn event_authority(&mut self, event_authority: solana_pubkey::Pubkey) -> &mut Self {
+        self.event_authority = Some(event_authority);
+        self
+    }
+    /// `[optional account, default to 'Anv3tR3s1eQqQE1a5wnhtYdi6dkjv9rTn34V6pom9C9q']`
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
     #[inline(always)]
     pub fn max_price(&mut self, max_price: u64) -> &mut Self {
         self.max_price = Some(max_price);
@@ -275,12 +479,36 @@ impl PurchaseAssetBuilder {
             protocol_treasury: self
                 .protocol_treasury
                 .expect("protocol_treasury is not set"),
+            project_fee_override: self
+                .project_fee_override
+                .expect("project_fee_override is not set"),
+            referrer: self.referrer,
+            fee_authority: self.fee_authority.expect("fee_authority is not set"),
+            protocol_fee_stats: self
+                .protocol_fee_stats
+                .expect("protocol_fee_stats is not set"),
+            project_fee_stats: self
+                .project_fee_stats
+                .expect("project_fee_stats is not set"),
+            fee_mint: self.fee_mint,
+            fee_payer_token_account: self.fee_payer_token_account,
+            protocol_treasury_token_account: self.protocol_treasury_token_account,
+            token_program: self.token_program,
+            sol_mind_protocol_program: self.sol_mind_protocol_program.unwrap_or(
+                solana_pubkey::pubkey!("7GoSe63kVLi3Bt2mkPHUFVbmTcsZvp7QEUHjDB6GvLgV"),
+            ),
             system_program: self
                 .system_program
                 .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
             mpl_core_program: self.mpl_core_program.unwrap_or(solana_pubkey::pubkey!(
                 "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
             )),
+            event_authority: self.event_authority.unwrap_or(solana_pubkey::pubkey!(
+                "Ausr478rARUXg8Bj6pnZjYHpVxxEASFyamPEg2vwSNdt"
+            )),
+            program: self.program.unwrap_or(solana_pubkey::pubkey!(
+                "Anv3tR3s1eQqQE1a5wnhtYdi6dkjv9rTn34V6pom9C9q"
+            )),
         };
         let args = PurchaseAssetInstructionArgs {
             max_price: self.max_price.clone().expect("max_price is not set"),
@@ -312,9 +540,33 @@ pub struct PurchaseAssetCpiAccounts<'a, 'b> {
 
     pub protocol_treasury: &'b solana_account_info::AccountInfo<'a>,
 
+    pub project_fee_override: &'b solana_account_info::AccountInfo<'a>,
+
+    pub referrer: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub fee_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub protocol_fee_stats: &'b solana_account_info::AccountInfo<'a>,
+
+    pub project_fee_stats: &'b solana_account_info::AccountInfo<'a>,
+
+    pub fee_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub fee_payer_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub protocol_treasury_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub sol_mind_protocol_program: &'b solana_account_info::AccountInfo<'a>,
+
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub mpl_core_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
 }
 
 /// `purchase_asset` CPI instruction.
@@ -342,9 +594,33 @@ pub struct PurchaseAssetCpi<'a, 'b> {
 
     pub protocol_treasury: &'b solana_account_info::AccountInfo<'a>,
 
+    pub project_fee_override: &'b solana_account_info::AccountInfo<'a>,
+
+    pub referrer: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub fee_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub protocol_fee_stats: &'b solana_account_info::AccountInfo<'a>,
+
+    pub project_fee_stats: &'b solana_account_info::AccountInfo<'a>,
+
+    pub fee_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub fee_payer_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub protocol_treasury_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub sol_mind_protocol_program: &'b solana_account_info::AccountInfo<'a>,
+
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub mpl_core_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: PurchaseAssetInstructionArgs,
 }
@@ -367,8 +643,20 @@ impl<'a, 'b> PurchaseAssetCpi<'a, 'b> {
             project_config: accounts.project_config,
             protocol_config: accounts.protocol_config,
             protocol_treasury: accounts.protocol_treasury,
+            project_fee_override: accounts.project_fee_override,
+            referrer: accounts.referrer,
+            fee_authority: accounts.fee_authority,
+            protocol_fee_stats: accounts.protocol_fee_stats,
+            project_fee_stats: accounts.project_fee_stats,
+            fee_mint: accounts.fee_mint,
+            fee_payer_token_account: accounts.fee_payer_token_account,
+            protocol_treasury_token_account: accounts.protocol_treasury_token_account,
+            token_program: accounts.token_program,
+            sol_mind_protocol_program: accounts.sol_mind_protocol_program,
             system_program: accounts.system_program,
             mpl_core_program: accounts.mpl_core_program,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
             __args: args,
         }
     }
@@ -395,7 +683,7 @@ impl<'a, 'b> PurchaseAssetCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(24 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(*self.buyer.key, true));
         accounts.push(solana_instruction::AccountMeta::new(*self.owner.key, false));
         accounts.push(solana_instruction::AccountMeta::new(*self.asset.key, false));
@@ -411,7 +699,7 @@ impl<'a, 'b> PurchaseAssetCpi<'a, 'b> {
             *self.listing.key,
             false,
         ));
-        accounts.push(solana_instruction::AccountMeta::new_readonly(
+        accounts.push(solana_instruction::AccountMeta::new(
             *self.trade_hub.key,
             false,
         ));
@@ -431,6 +719,78 @@ impl<'a, 'b> PurchaseAssetCpi<'a, 'b> {
             *self.protocol_treasury.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.project_fee_override.key,
+            false,
+        ));
+        if let Some(referrer) = self.referrer {
+            accounts.push(solana_instruction::AccountMeta::new(*referrer.key, false));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::NFT_OPERATIONS_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.fee_authority.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            *self.protocol_fee_stats.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            *self.project_fee_stats.key,
+            false,
+        ));
+        if let Some(fee_mint) = self.fee_mint {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *fee_mint.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::NFT_OPERATIONS_ID,
+                false,
+            ));
+        }
+        if let Some(fee_payer_token_account) = self.fee_payer_token_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *fee_payer_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::NFT_OPERATIONS_ID,
+                false,
+            ));
+        }
+        if let Some(protocol_treasury_token_account) = self.protocol_treasury_token_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *protocol_treasury_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::NFT_OPERATIONS_ID,
+                false,
+            ));
+        }
+        if let Some(token_program) = self.token_program {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *token_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::NFT_OPERATIONS_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.sol_mind_protocol_program.key,
+            false,
+        ));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.system_program.key,
             false,
@@ -439,6 +799,14 @@ impl<'a, 'b> PurchaseAssetCpi<'a, 'b> {
             *self.mpl_core_program.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.event_authority.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -455,7 +823,7 @@ impl<'a, 'b> PurchaseAssetCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(13 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(25 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.buyer.clone());
         account_infos.push(self.owner.clone());
@@ -469,8 +837,30 @@ impl<'a, 'b> PurchaseAssetCpi<'a, 'b> {
         account_infos.push(self.project_config.clone());
         account_infos.push(self.protocol_config.clone());
         account_infos.push(self.protocol_treasury.clone());
+        account_infos.push(self.project_fee_override.clone());
+        if let Some(referrer) = self.referrer {
+            account_infos.push(referrer.clone());
+        }
+        account_infos.push(self.fee_authority.clone());
+        account_infos.push(self.protocol_fee_stats.clone());
+        account_infos.push(self.project_fee_stats.clone());
+        if let Some(fee_mint) = self.fee_mint {
+            account_infos.push(fee_mint.clone());
+        }
+        if let Some(fee_payer_token_account) = self.fee_payer_token_account {
+            account_infos.push(fee_payer_token_account.clone());
+        }
+        if let Some(protocol_treasury_token_account) = self.protocol_treasury_token_account {
+            account_infos.push(protocol_treasury_token_account.clone());
+        }
+        if let Some(token_program) = self.token_program {
+            account_infos.push(token_program.clone());
+        }
+        account_infos.push(self.sol_mind_protocol_program.clone());
         account_infos.push(self.system_program.clone());
         account_infos.push(self.mpl_core_program.clone());
+        account_infos.push(self.event_authority.clone());
+        account_infos.push(self.program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -492,13 +882,25 @@ impl<'a, 'b> PurchaseAssetCpi<'a, 'b> {
 ///   2. `[writable]` asset
 ///   3. `[writable, optional]` collection
 ///   4. `[writable]` listing
-///   5. `[]` trade_hub
+///   5. `[writable]` trade_hub
 ///   6. `[writable]` treasury
 ///   7. `[]` project_config
 ///   8. `[]` protocol_config
 ///   9. `[writable]` protocol_treasury
-///   10. `[]` system_program
-///   11. `[]` mpl_core_program
+///   10. `[]` project_fee_override
+///   11. `[writable, optional]` referrer
+///   12. `[]` fee_authority
+///   13. `[writable]` protocol_fee_stats
+///   14. `[writable]` project_fee_stats
+///   15. `[optional]` fee_mint
+///   16. `[writable, optional]` fee_payer_token_account
+///   17. `[writable, optional]` protocol_treasury_token_account
+///   18. `[optional]` token_program
+///   19. `[]` sol_mind_protocol_program
+///   20. `[]` system_program
+///   21. `[]` mpl_core_program
+///   22. `[]` event_authority
+///   23. `[]` program
 #[derive(Clone, Debug)]
 pub struct PurchaseAssetCpiBuilder<'a, 'b> {
     instruction: Box<PurchaseAssetCpiBuilderInstruction<'a, 'b>>,
@@ -518,8 +920,20 @@ impl<'a, 'b> PurchaseAssetCpiBuilder<'a, 'b> {
             project_config: None,
             protocol_config: None,
             protocol_treasury: None,
+            project_fee_override: None,
+            referrer: None,
+            fee_authority: None,
+            protocol_fee_stats: None,
+            project_fee_stats: None,
+            fee_mint: None,
+            fee_payer_token_account: None,
+            protocol_treasury_token_account: None,
+            token_program: None,
+            sol_mind_protocol_program: None,
             system_program: None,
             mpl_core_program: None,
+            event_authority: None,
+            program: None,
             max_price: None,
             __remaining_accounts: Vec::new(),
         });
@@ -589,6 +1003,91 @@ impl<'a, 'b> PurchaseAssetCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn project_fee_override(
+        &mut self,
+        project_fee_override: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.project_fee_override = Some(project_fee_override);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn referrer(
+        &mut self,
+        referrer: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.referrer = referrer;
+        self
+    }
+    #[inline(always)]
+    pub fn fee_authority(
+        &mut self,
+        fee_authority: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.fee_authority = Some(fee_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn protocol_fee_stats(
+        &mut self,
+        protocol_fee_stats: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.protocol_fee_stats = Some(protocol_fee_stats);
+        self
+    }
+    #[inline(always)]
+    pub fn project_fee_stats(
+        &mut self,
+        project_fee_stats: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.project_fee_stats = Some(project_fee_stats);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn fee_mint(
+        &mut self,
+        fee_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.fee_mint = fee_mint;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn fee_payer_token_account(
+        &mut self,
+        fee_payer_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.fee_payer_token_account = fee_payer_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn protocol_treasury_token_account(
+        &mut self,
+        protocol_treasury_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.protocol_treasury_token_account = protocol_treasury_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.token_program = token_program;
+        self
+    }
+    #[inline(always)]
+    pub fn sol_mind_protocol_program(
+        &mut self,
+        sol_mind_protocol_program: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.sol_mind_protocol_program = Some(sol_mind_protocol_program);
+        self
+    }
+    #[inline(always)]
     pub fn system_program(
         &mut self,
         system_program: &'b solana_account_info::AccountInfo<'a>,
@@ -605,6 +1104,19 @@ impl<'a, 'b> PurchaseAssetCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(&mut self, program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
+    #[inline(always)]
     pub fn max_price(&mut self, max_price: u64) -> &mut Self {
         self.instruction.max_price = Some(max_price);
         self
@@ -682,6 +1194,41 @@ impl<'a, 'b> PurchaseAssetCpiBuilder<'a, 'b> {
                 .protocol_treasury
                 .expect("protocol_treasury is not set"),
 
+            project_fee_override: self
+                .instruction
+                .project_fee_override
+                .expect("project_fee_override is not set"),
+
+            referrer: self.instruction.referrer,
+
+            fee_authority: self
+                .instruction
+                .fee_authority
+                .expect("fee_authority is not set"),
+
+            protocol_fee_stats: self
+                .instruction
+                .protocol_fee_stats
+                .expect("protocol_fee_stats is not set"),
+
+            project_fee_stats: self
+                .instruction
+                .project_fee_stats
+                .expect("project_fee_stats is not set"),
+
+            fee_mint: self.instruction.fee_mint,
+
+            fee_payer_token_account: self.instruction.fee_payer_token_account,
+
+            protocol_treasury_token_account: self.instruction.protocol_treasury_token_account,
+
+            token_program: self.instruction.token_program,
+
+            sol_mind_protocol_program: self
+                .instruction
+                .sol_mind_protocol_program
+                .expect("sol_mind_protocol_program is not set"),
+
             system_program: self
                 .instruction
                 .system_program
@@ -691,6 +1238,13 @@ impl<'a, 'b> PurchaseAssetCpiBuilder<'a, 'b> {
                 .instruction
                 .mpl_core_program
                 .expect("mpl_core_program is not set"),
+
+            event_authority: self
+                .instruction
+                .event_authority
+                .expect("event_authority is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -713,8 +1267,20 @@ struct PurchaseAssetCpiBuilderInstruction<'a, 'b> {
     project_config: Option<&'b solana_account_info::AccountInfo<'a>>,
     protocol_config: Option<&'b solana_account_info::AccountInfo<'a>>,
     protocol_treasury: Option<&'b solana_account_info::AccountInfo<'a>>,
+    project_fee_override: Option<&'b solana_account_info::AccountInfo<'a>>,
+    referrer: Option<&'b solana_account_info::AccountInfo<'a>>,
+    fee_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    protocol_fee_stats: Option<&'b solana_account_info::AccountInfo<'a>>,
+    project_fee_stats: Option<&'b solana_account_info::AccountInfo<'a>>,
+    fee_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
+    fee_payer_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    protocol_treasury_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    sol_mind_protocol_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     mpl_core_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_account_info::AccountInfo<'a>>,
     max_price: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
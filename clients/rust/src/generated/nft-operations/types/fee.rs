@@ -8,10 +8,12 @@
 use crate::generated::types::FeeType;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
+use solana_pubkey::Pubkey;
 
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Fee {
     pub amount: u64,
     pub fee_type: FeeType,
+    pub mint: Option<Pubkey>,
 }
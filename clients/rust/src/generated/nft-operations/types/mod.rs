@@ -5,12 +5,48 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+pub(crate) mod r#allowlist_proof;
+pub(crate) mod r#asset_delisted;
+pub(crate) mod r#asset_listed;
+pub(crate) mod r#asset_minted;
+pub(crate) mod r#asset_purchased;
 pub(crate) mod r#assets_config;
 pub(crate) mod r#fee;
 pub(crate) mod r#fee_type;
 pub(crate) mod r#fees_structure;
+pub(crate) mod r#mint_phase;
+pub(crate) mod r#mint_phases_set;
+pub(crate) mod r#mint_record_closed;
+pub(crate) mod r#minter_config_closed;
+pub(crate) mod r#minter_config_created;
+pub(crate) mod r#pending_fees;
+pub(crate) mod r#public_mint_set;
+pub(crate) mod r#revenue_share;
+pub(crate) mod r#subscription_plan;
+pub(crate) mod r#trade_hub_closed;
+pub(crate) mod r#trade_hub_created;
+pub(crate) mod r#wallet_mint_limit_set;
+pub(crate) mod r#whitelist_entry;
 
+pub use self::r#allowlist_proof::*;
+pub use self::r#asset_delisted::*;
+pub use self::r#asset_listed::*;
+pub use self::r#asset_minted::*;
+pub use self::r#asset_purchased::*;
 pub use self::r#assets_config::*;
 pub use self::r#fee::*;
 pub use self::r#fee_type::*;
 pub use self::r#fees_structure::*;
+pub use self::r#mint_phase::*;
+pub use self::r#mint_phases_set::*;
+pub use self::r#mint_record_closed::*;
+pub use self::r#minter_config_closed::*;
+pub use self::r#minter_config_created::*;
+pub use self::r#pending_fees::*;
+pub use self::r#public_mint_set::*;
+pub use self::r#revenue_share::*;
+pub use self::r#subscription_plan::*;
+pub use self::r#trade_hub_closed::*;
+pub use self::r#trade_hub_created::*;
+pub use self::r#wallet_mint_limit_set::*;
+pub use self::r#whitelist_entry::*;
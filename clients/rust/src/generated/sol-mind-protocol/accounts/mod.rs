@@ -5,8 +5,26 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+pub(crate) mod r#fee_stats;
 pub(crate) mod r#project_config;
+pub(crate) mod r#project_fee_override;
+pub(crate) mod r#project_registry_entry;
+pub(crate) mod r#project_role;
+pub(crate) mod r#proposal;
 pub(crate) mod r#protocol_config;
+pub(crate) mod r#referrer;
+pub(crate) mod r#registered_program;
+pub(crate) mod r#subscription;
+pub(crate) mod r#treasury_policy;
 
+pub use self::r#fee_stats::*;
 pub use self::r#project_config::*;
+pub use self::r#project_fee_override::*;
+pub use self::r#project_registry_entry::*;
+pub use self::r#project_role::*;
+pub use self::r#proposal::*;
 pub use self::r#protocol_config::*;
+pub use self::r#referrer::*;
+pub use self::r#registered_program::*;
+pub use self::r#subscription::*;
+pub use self::r#treasury_policy::*;
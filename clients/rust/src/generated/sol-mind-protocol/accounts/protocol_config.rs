@@ -6,6 +6,10 @@
 //!
 
 use crate::generated::types::FeesStructure;
+use crate::generated::types::PendingFees;
+use crate::generated::types::RevenueShare;
+use crate::generated::types::SubscriptionPlan;
+use crate::generated::types::WhitelistEntry;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use solana_pubkey::Pubkey;
@@ -19,13 +23,19 @@ pub struct ProtocolConfig {
         serde(with = "serde_with::As::<Vec<serde_with::DisplayFromStr>>")
     )]
     pub admins: Vec<Pubkey>,
-    #[cfg_attr(
-        feature = "serde",
-        serde(with = "serde_with::As::<Vec<serde_with::DisplayFromStr>>")
-    )]
-    pub whitelist_transfer_addrs: Vec<Pubkey>,
+    pub whitelist_transfer_addrs: Vec<WhitelistEntry>,
+    pub revenue_shares: Vec<RevenueShare>,
     pub fees: FeesStructure,
+    pub pending_fees: Option<PendingFees>,
+    pub paused: bool,
+    pub paused_operations: u16,
+    pub approval_threshold: u8,
+    pub proposal_count: u64,
+    pub project_count: u64,
     pub bump: u8,
+    pub version: u8,
+    pub instance_id: u64,
+    pub subscription_plans: Vec<SubscriptionPlan>,
 }
 
 pub const PROTOCOL_CONFIG_DISCRIMINATOR: [u8; 8] = [207, 91, 250, 28, 152, 179, 215, 209];
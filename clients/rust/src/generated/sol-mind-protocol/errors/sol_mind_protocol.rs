@@ -25,6 +25,213 @@ pub enum SolMindProtocolError {
     /// 6004 - Fee calculation overflow
     #[error("Fee calculation overflow")]
     FeeCalculationOverflow = 0x1774,
+    /// 6005 - Admin already exists
+    #[error("Admin already exists")]
+    AdminAlreadyExists = 0x1775,
+    /// 6006 - Admin not found
+    #[error("Admin not found")]
+    AdminNotFound = 0x1776,
+    /// 6007 - Maximum number of admins reached
+    #[error("Maximum number of admins reached")]
+    MaxAdminsReached = 0x1777,
+    /// 6008 - Cannot remove the last admin
+    #[error("Cannot remove the last admin")]
+    CannotRemoveLastAdmin = 0x1778,
+    /// 6009 - Operation requires admin approval through a proposal
+    #[error("Operation requires admin approval through a proposal")]
+    ApprovalRequired = 0x1779,
+    /// 6010 - Approval threshold must be between 1 and the number of admins
+    #[error("Approval threshold must be between 1 and the number of admins")]
+    InvalidApprovalThreshold = 0x177A,
+    /// 6011 - Admin already approved this proposal
+    #[error("Admin already approved this proposal")]
+    ProposalAlreadyApproved = 0x177B,
+    /// 6012 - Proposal does not have enough approvals
+    #[error("Proposal does not have enough approvals")]
+    ProposalThresholdNotMet = 0x177C,
+    /// 6013 - Destination account does not match the proposal
+    #[error("Destination account does not match the proposal")]
+    ProposalDestinationMismatch = 0x177D,
+    /// 6014 - Math operation overflow
+    #[error("Math operation overflow")]
+    MathOverflow = 0x177E,
+    /// 6015 - Address is already whitelisted
+    #[error("Address is already whitelisted")]
+    WhitelistAddressAlreadyExists = 0x177F,
+    /// 6016 - Address is not whitelisted
+    #[error("Address is not whitelisted")]
+    WhitelistAddressNotFound = 0x1780,
+    /// 6017 - Maximum number of whitelisted addresses reached
+    #[error("Maximum number of whitelisted addresses reached")]
+    MaxWhitelistAddressesReached = 0x1781,
+    /// 6018 - Whitelist label is too long
+    #[error("Whitelist label is too long")]
+    WhitelistLabelTooLong = 0x1782,
+    /// 6019 - Transfer amount exceeds the destination cap
+    #[error("Transfer amount exceeds the destination cap")]
+    TransferCapExceeded = 0x1783,
+    /// 6020 - No pending fee update to cancel
+    #[error("No pending fee update to cancel")]
+    NoPendingFees = 0x1784,
+    /// 6021 - Operation is paused
+    #[error("Operation is paused")]
+    OperationPaused = 0x1785,
+    /// 6022 - Project name is too long
+    #[error("Project name is too long")]
+    ProjectNameTooLong = 0x1786,
+    /// 6023 - Project description is too long
+    #[error("Project description is too long")]
+    ProjectDescriptionTooLong = 0x1787,
+    /// 6024 - Maximum number of project authorities reached
+    #[error("Maximum number of project authorities reached")]
+    MaxAuthoritiesReached = 0x1788,
+    /// 6025 - Signer is not the pending project owner
+    #[error("Signer is not the pending project owner")]
+    NotPendingOwner = 0x1789,
+    /// 6026 - Project still has minter configs or trade hubs
+    #[error("Project still has minter configs or trade hubs")]
+    ProjectHasResources = 0x178A,
+    /// 6027 - Fee is paid in tokens but the token accounts were not provided
+    #[error("Fee is paid in tokens but the token accounts were not provided")]
+    FeeTokenAccountsRequired = 0x178B,
+    /// 6028 - Mint does not match the fee mint
+    #[error("Mint does not match the fee mint")]
+    InvalidFeeMint = 0x178C,
+    /// 6029 - Token account is not the treasury associated token account
+    #[error("Token account is not the treasury associated token account")]
+    InvalidTreasuryTokenAccount = 0x178D,
+    /// 6030 - Fee basis points cannot exceed 10000
+    #[error("Fee basis points cannot exceed 10000")]
+    InvalidFeeBps = 0x178E,
+    /// 6031 - Fee minimum cannot exceed its maximum
+    #[error("Fee minimum cannot exceed its maximum")]
+    InvalidFeeBounds = 0x178F,
+    /// 6032 - Maximum number of fee overrides reached
+    #[error("Maximum number of fee overrides reached")]
+    MaxFeeOverridesReached = 0x1790,
+    /// 6033 - Operation already has a fee override
+    #[error("Operation already has a fee override")]
+    DuplicateFeeOverride = 0x1791,
+    /// 6034 - Fee override expiry must be in the future
+    #[error("Fee override expiry must be in the future")]
+    InvalidFeeOverrideExpiry = 0x1792,
+    /// 6035 - Fee override account is not owned by the protocol
+    #[error("Fee override account is not owned by the protocol")]
+    InvalidFeeOverrideAccount = 0x1793,
+    /// 6036 - Maximum number of revenue shares reached
+    #[error("Maximum number of revenue shares reached")]
+    MaxRevenueSharesReached = 0x1794,
+    /// 6037 - Revenue recipient is listed more than once
+    #[error("Revenue recipient is listed more than once")]
+    DuplicateRevenueRecipient = 0x1795,
+    /// 6038 - Revenue shares must add up to 10000 bps
+    #[error("Revenue shares must add up to 10000 bps")]
+    InvalidRevenueShares = 0x1796,
+    /// 6039 - No revenue shares are configured
+    #[error("No revenue shares are configured")]
+    NoRevenueShares = 0x1797,
+    /// 6040 - Recipient accounts do not match the revenue shares
+    #[error("Recipient accounts do not match the revenue shares")]
+    RevenueRecipientMismatch = 0x1798,
+    /// 6041 - Treasury has nothing to distribute
+    #[error("Treasury has nothing to distribute")]
+    NothingToDistribute = 0x1799,
+    /// 6042 - Account already uses the current layout version
+    #[error("Account already uses the current layout version")]
+    AlreadyMigrated = 0x179A,
+    /// 6043 - Account is not a project config of this protocol
+    #[error("Account is not a project config of this protocol")]
+    InvalidProjectConfig = 0x179B,
+    /// 6044 - Program cannot be registered to charge fees
+    #[error("Program cannot be registered to charge fees")]
+    InvalidRegisteredProgram = 0x179C,
+    /// 6045 - Calling program is not registered to charge fees
+    #[error("Calling program is not registered to charge fees")]
+    ProgramNotRegistered = 0x179D,
+    /// 6046 - Grantee already holds this role
+    #[error("Grantee already holds this role")]
+    RoleAlreadyGranted = 0x179E,
+    /// 6047 - Grantee does not hold this role
+    #[error("Grantee does not hold this role")]
+    RoleNotGranted = 0x179F,
+    /// 6048 - Account is not a project role PDA
+    #[error("Account is not a project role PDA")]
+    InvalidProjectRoleAccount = 0x17A0,
+    /// 6049 - Treasury withdrawals by treasurers require a treasury policy
+    #[error("Treasury withdrawals by treasurers require a treasury policy")]
+    TreasuryPolicyRequired = 0x17A1,
+    /// 6050 - Destination is not allowed by the treasury policy
+    #[error("Destination is not allowed by the treasury policy")]
+    TreasuryDestinationNotAllowed = 0x17A2,
+    /// 6051 - Withdrawal exceeds the treasury policy epoch cap
+    #[error("Withdrawal exceeds the treasury policy epoch cap")]
+    TreasuryEpochCapExceeded = 0x17A3,
+    /// 6052 - Maximum number of treasury destinations reached
+    #[error("Maximum number of treasury destinations reached")]
+    MaxTreasuryDestinationsReached = 0x17A4,
+    /// 6053 - Treasury destination is listed more than once
+    #[error("Treasury destination is listed more than once")]
+    DuplicateTreasuryDestination = 0x17A5,
+    /// 6054 - No referral fees to claim
+    #[error("No referral fees to claim")]
+    NothingToClaim = 0x17A6,
+    /// 6055 - Maximum number of subscription plans reached
+    #[error("Maximum number of subscription plans reached")]
+    MaxSubscriptionPlansReached = 0x17A7,
+    /// 6056 - Subscription plan not found
+    #[error("Subscription plan not found")]
+    SubscriptionPlanNotFound = 0x17A8,
+    /// 6057 - Subscriptions must be paid for at least one epoch
+    #[error("Subscriptions must be paid for at least one epoch")]
+    InvalidSubscriptionEpochs = 0x17A9,
+    /// 6058 - Subscription plan can only change once the paid period has ended
+    #[error("Subscription plan can only change once the paid period has ended")]
+    SubscriptionPlanChangeNotAllowed = 0x17AA,
+    /// 6059 - Account is not a subscription PDA
+    #[error("Account is not a subscription PDA")]
+    InvalidSubscriptionAccount = 0x17AB,
+    /// 6060 - Proposal has expired
+    #[error("Proposal has expired")]
+    ProposalExpired = 0x17AC,
+    /// 6061 - Fees paid in tokens must be fixed amounts
+    #[error("Fees paid in tokens must be fixed amounts")]
+    TokenFeeMustBeFixed = 0x17AD,
+    /// 6062 - Account required by the proposal action was not provided
+    #[error("Account required by the proposal action was not provided")]
+    ProposalAccountMissing = 0x17AE,
+    /// 6063 - Program is already registered
+    #[error("Program is already registered")]
+    ProgramAlreadyRegistered = 0x17AF,
+    /// 6064 - Only an admin of instance 0 can initialize another protocol instance
+    #[error("Only an admin of instance 0 can initialize another protocol instance")]
+    RootAdminRequired = 0x17B0,
+    /// 6065 - Referral share exceeds the maximum referral basis points
+    #[error("Referral share exceeds the maximum referral basis points")]
+    ReferralShareTooHigh = 0x17B1,
+    /// 6066 - Referrer is already registered
+    #[error("Referrer is already registered")]
+    ReferrerAlreadyRegistered = 0x17B2,
+    /// 6067 - Referrer is not registered
+    #[error("Referrer is not registered")]
+    ReferrerNotRegistered = 0x17B3,
+    /// 6068 - Fee payer can't be its own referrer
+    #[error("Fee payer can't be its own referrer")]
+    SelfReferral = 0x17B4,
+    /// 6069 - Account is not the payer of the closed account's rent
+    #[error("Account is not the payer of the closed account's rent")]
+    InvalidRentPayer = 0x17B5,
+    /// 6070 - Config must be migrated before it can be updated
+    #[error("Config must be migrated before it can be updated")]
+    ConfigNotMigrated = 0x17B6,
+    /// 6071 - Account is not an uncounted minter config, trade hub or role PDA of the project
+    #[error("Account is not an uncounted minter config, trade hub or role PDA of the project")]
+    InvalidProjectAccount = 0x17B7,
+    /// 6072 - Project still has role PDAs
+    #[error("Project still has role PDAs")]
+    ProjectHasRoles = 0x17B8,
+    /// 6073 - Project still has a fee override
+    #[error("Project still has a fee override")]
+    ProjectHasFeeOverride = 0x17B9,
 }
 
 impl From<SolMindProtocolError> for solana_program_error::ProgramError {
@@ -7,7 +7,6 @@
 
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
-use solana_pubkey::Pubkey;
 
 pub const CREATE_PROJECT_DISCRIMINATOR: [u8; 8] = [148, 219, 181, 42, 221, 114, 145, 190];
 
@@ -18,12 +17,28 @@ pub struct CreateProject {
 
     pub project_config: solana_pubkey::Pubkey,
 
+    pub project_registry: solana_pubkey::Pubkey,
+
     pub treasury: solana_pubkey::Pubkey,
 
     pub protocol_config: solana_pubkey::Pubkey,
 
     pub protocol_treasury: solana_pubkey::Pubkey,
 
+    pub project_fee_stats: solana_pubkey::Pubkey,
+
+    pub protocol_fee_stats: solana_pubkey::Pubkey,
+
+    pub referrer: Option<solana_pubkey::Pubkey>,
+
+    pub fee_mint: Option<solana_pubkey::Pubkey>,
+
+    pub fee_payer_token_account: Option<solana_pubkey::Pubkey>,
+
+    pub protocol_treasury_token_account: Option<solana_pubkey::Pubkey>,
+
+    pub token_program: Option<solana_pubkey::Pubkey>,
+
     pub system_program: solana_pubkey::Pubkey,
 }
 
@@ -41,14 +56,18 @@ impl CreateProject {
         args: CreateProjectInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(self.owner, true));
         accounts.push(solana_instruction::AccountMeta::new(
             self.project_config,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            self.project_registry,
+            false,
+        ));
         accounts.push(solana_instruction::AccountMeta::new(self.treasury, false));
-        accounts.push(solana_instruction::AccountMeta::new_readonly(
+        accounts.push(solana_instruction::AccountMeta::new(
             self.protocol_config,
             false,
         ));
@@ -56,6 +75,65 @@ impl CreateProject {
             self.protocol_treasury,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            self.project_fee_stats,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            self.protocol_fee_stats,
+            false,
+        ));
+        if let Some(referrer) = self.referrer {
+            accounts.push(solana_instruction::AccountMeta::new(referrer, false));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SOL_MIND_PROTOCOL_ID,
+                false,
+            ));
+        }
+        if let Some(fee_mint) = self.fee_mint {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                fee_mint, false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SOL_MIND_PROTOCOL_ID,
+                false,
+            ));
+        }
+        if let Some(fee_payer_token_account) = self.fee_payer_token_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                fee_payer_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SOL_MIND_PROTOCOL_ID,
+                false,
+            ));
+        }
+        if let Some(protocol_treasury_token_account) = self.protocol_treasury_token_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                protocol_treasury_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SOL_MIND_PROTOCOL_ID,
+                false,
+            ));
+        }
+        if let Some(token_program) = self.token_program {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                token_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SOL_MIND_PROTOCOL_ID,
+                false,
+            ));
+        }
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.system_program,
             false,
@@ -100,10 +178,8 @@ impl Default for CreateProjectInstructionData {
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CreateProjectInstructionArgs {
-    pub project_id: u64,
     pub name: String,
     pub description: String,
-    pub authorities: Vec<Pubkey>,
 }
 
 impl CreateProjectInstructionArgs {
@@ -118,22 +194,36 @@ impl CreateProjectInstructionArgs {
 ///
 ///   0. `[writable, signer]` owner
 ///   1. `[writable]` project_config
-///   2. `[writable]` treasury
-///   3. `[]` protocol_config
-///   4. `[writable]` protocol_treasury
-///   5. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   2. `[writable]` project_registry
+///   3. `[writable]` treasury
+///   4. `[writable]` protocol_config
+///   5. `[writable]` protocol_treasury
+///   6. `[writable]` project_fee_stats
+///   7. `[writable]` protocol_fee_stats
+///   8. `[writable, optional]` referrer
+///   9. `[optional]` fee_mint
+///   10. `[writable, optional]` fee_payer_token_account
+///   11. `[writable, optional]` protocol_treasury_token_account
+///   12. `[optional]` token_program
+///   13. `[optional]` system_program (default to `11111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
 pub struct CreateProjectBuilder {
     owner: Option<solana_pubkey::Pubkey>,
     project_config: Option<solana_pubkey::Pubkey>,
+    project_registry: Option<solana_pubkey::Pubkey>,
     treasury: Option<solana_pubkey::Pubkey>,
     protocol_config: Option<solana_pubkey::Pubkey>,
     protocol_treasury: Option<solana_pubkey::Pubkey>,
+    project_fee_stats: Option<solana_pubkey::Pubkey>,
+    protocol_fee_stats: Option<solana_pubkey::Pubkey>,
+    referrer: Option<solana_pubkey::Pubkey>,
+    fee_mint: Option<solana_pubkey::Pubkey>,
+    fee_payer_token_account: Option<solana_pubkey::Pubkey>,
+    protocol_treasury_token_account: Option<solana_pubkey::Pubkey>,
+    token_program: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
-    project_id: Option<u64>,
     name: Option<String>,
     description: Option<String>,
-    authorities: Option<Vec<Pubkey>>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -152,6 +242,11 @@ impl CreateProjectBuilder {
         self
     }
     #[inline(always)]
+    pub fn project_registry(&mut self, project_registry: solana_pubkey::Pubkey) -> &mut Self {
+        self.project_registry = Some(project_registry);
+        self
+    }
+    #[inline(always)]
     pub fn treasury(&mut self, treasury: solana_pubkey::Pubkey) -> &mut Self {
         self.treasury = Some(treasury);
         self
@@ -166,15 +261,56 @@ impl CreateProjectBuilder {
         self.protocol_treasury = Some(protocol_treasury);
         self
     }
-    /// `[optional account, default to '11111111111111111111111111111111']`
     #[inline(always)]
-    pub fn system_program(&mut self, system_program: solana_pubkey::Pubkey) -> &mut Self {
-        self.system_program = Some(system_program);
+    pub fn project_fee_stats(&mut self, project_fee_stats: solana_pubkey::Pubkey) -> &mut Self {
+        self.project_fee_stats = Some(project_fee_stats);
+        self
+    }
+    #[inline(always)]
+    pub fn protocol_fee_stats(&mut self, protocol_fee_stats: solana_pubkey::Pubkey) -> &mut Self {
+        self.protocol_fee_stats = Some(protocol_fee_stats);
         self
     }
+    /// `[optional account]`
     #[inline(always)]
-    pub fn project_id(&mut self, project_id: u64) -> &mut Self {
-        self.project_id = Some(project_id);
+    pub fn referrer(&mut self, referrer: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.referrer = referrer;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn fee_mint(&mut self, fee_mint: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.fee_mint = fee_mint;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn fee_payer_token_account(
+        &mut self,
+        fee_payer_token_account: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.fee_payer_token_account = fee_payer_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn protocol_treasury_token_account(
+        &mut self,
+        protocol_treasury_token_account: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.protocol_treasury_token_account = protocol_treasury_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.token_program = token_program;
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
         self
     }
     #[inline(always)]
@@ -187,11 +323,6 @@ impl CreateProjectBuilder {
         self.description = Some(description);
         self
     }
-    #[inline(always)]
-    pub fn authorities(&mut self, authorities: Vec<Pubkey>) -> &mut Self {
-        self.authorities = Some(authorities);
-        self
-    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(&mut self, account: solana_instruction::AccountMeta) -> &mut Self {
@@ -212,20 +343,30 @@ impl CreateProjectBuilder {
         let accounts = CreateProject {
             owner: self.owner.expect("owner is not set"),
             project_config: self.project_config.expect("project_config is not set"),
+            project_registry: self.project_registry.expect("project_registry is not set"),
             treasury: self.treasury.expect("treasury is not set"),
             protocol_config: self.protocol_config.expect("protocol_config is not set"),
             protocol_treasury: self
                 .protocol_treasury
                 .expect("protocol_treasury is not set"),
+            project_fee_stats: self
+                .project_fee_stats
+                .expect("project_fee_stats is not set"),
+            protocol_fee_stats: self
+                .protocol_fee_stats
+                .expect("protocol_fee_stats is not set"),
+            referrer: self.referrer,
+            fee_mint: self.fee_mint,
+            fee_payer_token_account: self.fee_payer_token_account,
+            protocol_treasury_token_account: self.protocol_treasury_token_account,
+            token_program: self.token_program,
             system_program: self
                 .system_program
                 .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
         };
         let args = CreateProjectInstructionArgs {
-            project_id: self.project_id.clone().expect("project_id is not set"),
             name: self.name.clone().expect("name is not set"),
             description: self.description.clone().expect("description is not set"),
-            authorities: self.authorities.clone().expect("authorities is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -238,12 +379,28 @@ pub struct CreateProjectCpiAccounts<'a, 'b> {
 
     pub project_config: &'b solana_account_info::AccountInfo<'a>,
 
+    pub project_registry: &'b solana_account_info::AccountInfo<'a>,
+
     pub treasury: &'b solana_account_info::AccountInfo<'a>,
 
     pub protocol_config: &'b solana_account_info::AccountInfo<'a>,
 
     pub protocol_treasury: &'b solana_account_info::AccountInfo<'a>,
 
+    pub project_fee_stats: &'b solana_account_info::AccountInfo<'a>,
+
+    pub protocol_fee_stats: &'b solana_account_info::AccountInfo<'a>,
+
+    pub referrer: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub fee_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub fee_payer_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub protocol_treasury_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
 }
 
@@ -256,12 +413,28 @@ pub struct CreateProjectCpi<'a, 'b> {
 
     pub project_config: &'b solana_account_info::AccountInfo<'a>,
 
+    pub project_registry: &'b solana_account_info::AccountInfo<'a>,
+
     pub treasury: &'b solana_account_info::AccountInfo<'a>,
 
     pub protocol_config: &'b solana_account_info::AccountInfo<'a>,
 
     pub protocol_treasury: &'b solana_account_info::AccountInfo<'a>,
 
+    pub project_fee_stats: &'b solana_account_info::AccountInfo<'a>,
+
+    pub protocol_fee_stats: &'b solana_account_info::AccountInfo<'a>,
+
+    pub referrer: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub fee_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub fee_payer_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub protocol_treasury_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: CreateProjectInstructionArgs,
@@ -277,9 +450,17 @@ impl<'a, 'b> CreateProjectCpi<'a, 'b> {
             __program: program,
             owner: accounts.owner,
             project_config: accounts.project_config,
+            project_registry: accounts.project_registry,
             treasury: accounts.treasury,
             protocol_config: accounts.protocol_config,
             protocol_treasury: accounts.protocol_treasury,
+            project_fee_stats: accounts.project_fee_stats,
+            protocol_fee_stats: accounts.protocol_fee_stats,
+            referrer: accounts.referrer,
+            fee_mint: accounts.fee_mint,
+            fee_payer_token_account: accounts.fee_payer_token_account,
+            protocol_treasury_token_account: accounts.protocol_treasury_token_account,
+            token_program: accounts.token_program,
             system_program: accounts.system_program,
             __args: args,
         }
@@ -307,17 +488,21 @@ impl<'a, 'b> CreateProjectCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(*self.owner.key, true));
         accounts.push(solana_instruction::AccountMeta::new(
             *self.project_config.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            *self.project_registry.key,
+            false,
+        ));
         accounts.push(solana_instruction::AccountMeta::new(
             *self.treasury.key,
             false,
         ));
-        accounts.push(solana_instruction::AccountMeta::new_readonly(
+        accounts.push(solana_instruction::AccountMeta::new(
             *self.protocol_config.key,
             false,
         ));
@@ -325,6 +510,66 @@ impl<'a, 'b> CreateProjectCpi<'a, 'b> {
             *self.protocol_treasury.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            *self.project_fee_stats.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            *self.protocol_fee_stats.key,
+            false,
+        ));
+        if let Some(referrer) = self.referrer {
+            accounts.push(solana_instruction::AccountMeta::new(*referrer.key, false));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SOL_MIND_PROTOCOL_ID,
+                false,
+            ));
+        }
+        if let Some(fee_mint) = self.fee_mint {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *fee_mint.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SOL_MIND_PROTOCOL_ID,
+                false,
+            ));
+        }
+        if let Some(fee_payer_token_account) = self.fee_payer_token_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *fee_payer_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SOL_MIND_PROTOCOL_ID,
+                false,
+            ));
+        }
+        if let Some(protocol_treasury_token_account) = self.protocol_treasury_token_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *protocol_treasury_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SOL_MIND_PROTOCOL_ID,
+                false,
+            ));
+        }
+        if let Some(token_program) = self.token_program {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *token_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SOL_MIND_PROTOCOL_ID,
+                false,
+            ));
+        }
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.system_program.key,
             false,
@@ -345,13 +590,31 @@ impl<'a, 'b> CreateProjectCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(15 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.owner.clone());
         account_infos.push(self.project_config.clone());
+        account_infos.push(self.project_registry.clone());
         account_infos.push(self.treasury.clone());
         account_infos.push(self.protocol_config.clone());
         account_infos.push(self.protocol_treasury.clone());
+        account_infos.push(self.project_fee_stats.clone());
+        account_infos.push(self.protocol_fee_stats.clone());
+        if let Some(referrer) = self.referrer {
+            account_infos.push(referrer.clone());
+        }
+        if let Some(fee_mint) = self.fee_mint {
+            account_infos.push(fee_mint.clone());
+        }
+        if let Some(fee_payer_token_account) = self.fee_payer_token_account {
+            account_infos.push(fee_payer_token_account.clone());
+        }
+        if let Some(protocol_treasury_token_account) = self.protocol_treasury_token_account {
+            account_infos.push(protocol_treasury_token_account.clone());
+        }
+        if let Some(token_program) = self.token_program {
+            account_infos.push(token_program.clone());
+        }
         account_infos.push(self.system_program.clone());
         remaining_accounts
             .iter()
@@ -371,10 +634,18 @@ impl<'a, 'b> CreateProjectCpi<'a, 'b> {
 ///
 ///   0. `[writable, signer]` owner
 ///   1. `[writable]` project_config
-///   2. `[writable]` treasury
-///   3. `[]` protocol_config
-///   4. `[writable]` protocol_treasury
-///   5. `[]` system_program
+///   2. `[writable]` project_registry
+///   3. `[writable]` treasury
+///   4. `[writable]` protocol_config
+///   5. `[writable]` protocol_treasury
+///   6. `[writable]` project_fee_stats
+///   7. `[writable]` protocol_fee_stats
+///   8. `[writable, optional]` referrer
+///   9. `[optional]` fee_mint
+///   10. `[writable, optional]` fee_payer_token_account
+///   11. `[writable, optional]` protocol_treasury_token_account
+///   12. `[optional]` token_program
+///   13. `[]` system_program
 #[derive(Clone, Debug)]
 pub struct CreateProjectCpiBuilder<'a, 'b> {
     instruction: Box<CreateProjectCpiBuilderInstruction<'a, 'b>>,
@@ -386,14 +657,20 @@ impl<'a, 'b> CreateProjectCpiBuilder<'a, 'b> {
             __program: program,
             owner: None,
             project_config: None,
+            project_registry: None,
             treasury: None,
             protocol_config: None,
             protocol_treasury: None,
+            project_fee_stats: None,
+            protocol_fee_stats: None,
+            referrer: None,
+            fee_mint: None,
+            fee_payer_token_account: None,
+            protocol_treasury_token_account: None,
+            token_program: None,
             system_program: None,
-            project_id: None,
             name: None,
             description: None,
-            authorities: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -412,6 +689,14 @@ impl<'a, 'b> CreateProjectCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn project_registry(
+        &mut self,
+        project_registry: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.project_registry = Some(project_registry);
+        self
+    }
+    #[inline(always)]
     pub fn treasury(&mut self, treasury: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
         self.instruction.treasury = Some(treasury);
         self
@@ -433,16 +718,72 @@ impl<'a, 'b> CreateProjectCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
-    pub fn system_program(
+    pub fn project_fee_stats(
         &mut self,
-        system_program: &'b solana_account_info::AccountInfo<'a>,
+        project_fee_stats: &'b solana_account_info::AccountInfo<'a>,
     ) -> &mut Self {
-        self.instruction.system_program = Some(system_program);
+        self.instruction.project_fee_stats = Some(project_fee_stats);
         self
     }
     #[inline(always)]
-    pub fn project_id(&mut self, project_id: u64) -> &mut Self {
-        self.instruction.project_id = Some(project_id);
+    pub fn protocol_fee_stats(
+        &mut self,
+        protocol_fee_stats: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.protocol_fee_stats = Some(protocol_fee_stats);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn referrer(
+        &mut self,
+        referrer: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.referrer = referrer;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn fee_mint(
+        &mut self,
+        fee_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.fee_mint = fee_mint;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn fee_payer_token_account(
+        &mut self,
+        fee_payer_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.fee_payer_token_account = fee_payer_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn protocol_treasury_token_account(
+        &mut self,
+        protocol_treasury_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.protocol_treasury_token_account = protocol_treasury_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.token_program = token_program;
+        self
+    }
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
         self
     }
     #[inline(always)]
@@ -455,11 +796,6 @@ impl<'a, 'b> CreateProjectCpiBuilder<'a, 'b> {
         self.instruction.description = Some(description);
         self
     }
-    #[inline(always)]
-    pub fn authorities(&mut self, authorities: Vec<Pubkey>) -> &mut Self {
-        self.instruction.authorities = Some(authorities);
-        self
-    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -495,22 +831,12 @@ impl<'a, 'b> CreateProjectCpiBuilder<'a, 'b> {
     #[allow(clippy::vec_init_then_push)]
     pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> solana_program_error::ProgramResult {
         let args = CreateProjectInstructionArgs {
-            project_id: self
-                .instruction
-                .project_id
-                .clone()
-                .expect("project_id is not set"),
             name: self.instruction.name.clone().expect("name is not set"),
             description: self
                 .instruction
                 .description
                 .clone()
                 .expect("description is not set"),
-            authorities: self
-                .instruction
-                .authorities
-                .clone()
-                .expect("authorities is not set"),
         };
         let instruction = CreateProjectCpi {
             __program: self.instruction.__program,
@@ -522,6 +848,11 @@ impl<'a, 'b> CreateProjectCpiBuilder<'a, 'b> {
                 .project_config
                 .expect("project_config is not set"),
 
+            project_registry: self
+                .instruction
+                .project_registry
+                .expect("project_registry is not set"),
+
             treasury: self.instruction.treasury.expect("treasury is not set"),
 
             protocol_config: self
@@ -534,6 +865,26 @@ impl<'a, 'b> CreateProjectCpiBuilder<'a, 'b> {
                 .protocol_treasury
                 .expect("protocol_treasury is not set"),
 
+            project_fee_stats: self
+                .instruction
+                .project_fee_stats
+                .expect("project_fee_stats is not set"),
+
+            protocol_fee_stats: self
+                .instruction
+                .protocol_fee_stats
+                .expect("protocol_fee_stats is not set"),
+
+            referrer: self.instruction.referrer,
+
+            fee_mint: self.instruction.fee_mint,
+
+            fee_payer_token_account: self.instruction.fee_payer_token_account,
+
+            protocol_treasury_token_account: self.instruction.protocol_treasury_token_account,
+
+            token_program: self.instruction.token_program,
+
             system_program: self
                 .instruction
                 .system_program
@@ -552,14 +903,20 @@ struct CreateProjectCpiBuilderInstruction<'a, 'b> {
     __program: &'b solana_account_info::AccountInfo<'a>,
     owner: Option<&'b solana_account_info::AccountInfo<'a>>,
     project_config: Option<&'b solana_account_info::AccountInfo<'a>>,
+    project_registry: Option<&'b solana_account_info::AccountInfo<'a>>,
     treasury: Option<&'b solana_account_info::AccountInfo<'a>>,
     protocol_config: Option<&'b solana_account_info::AccountInfo<'a>>,
     protocol_treasury: Option<&'b solana_account_info::AccountInfo<'a>>,
+    project_fee_stats: Option<&'b solana_account_info::AccountInfo<'a>>,
+    protocol_fee_stats: Option<&'b solana_account_info::AccountInfo<'a>>,
+    referrer: Option<&'b solana_account_info::AccountInfo<'a>>,
+    fee_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
+    fee_payer_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    protocol_treasury_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
-    project_id: Option<u64>,
     name: Option<String>,
     description: Option<String>,
-    authorities: Option<Vec<Pubkey>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
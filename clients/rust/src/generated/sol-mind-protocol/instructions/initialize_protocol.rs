@@ -19,6 +19,12 @@ pub struct InitializeProtocol {
 
     pub protocol_config: solana_pubkey::Pubkey,
 
+    pub fee_stats: solana_pubkey::Pubkey,
+
+    pub root_protocol_config: Option<solana_pubkey::Pubkey>,
+
+    pub root_admin: Option<solana_pubkey::Pubkey>,
+
     pub system_program: solana_pubkey::Pubkey,
 }
 
@@ -36,12 +42,34 @@ impl InitializeProtocol {
         args: InitializeProtocolInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(self.payer, true));
         accounts.push(solana_instruction::AccountMeta::new(
             self.protocol_config,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new(self.fee_stats, false));
+        if let Some(root_protocol_config) = self.root_protocol_config {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                root_protocol_config,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SOL_MIND_PROTOCOL_ID,
+                false,
+            ));
+        }
+        if let Some(root_admin) = self.root_admin {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                root_admin, true,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SOL_MIND_PROTOCOL_ID,
+                false,
+            ));
+        }
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.system_program,
             false,
@@ -88,6 +116,7 @@ impl Default for InitializeProtocolInstructionData {
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitializeProtocolInstructionArgs {
+    pub instance_id: u64,
     pub admins: Vec<Pubkey>,
     pub whitelist_transfer_addrs: Vec<Pubkey>,
     pub fees: FeesStructure,
@@ -105,12 +134,19 @@ impl InitializeProtocolInstructionArgs {
 ///
 ///   0. `[writable, signer]` payer
 ///   1. `[writable]` protocol_config
-///   2. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   2. `[writable]` fee_stats
+///   3. `[optional]` root_protocol_config
+///   4. `[signer, optional]` root_admin
+///   5. `[optional]` system_program (default to `11111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
 pub struct InitializeProtocolBuilder {
     payer: Option<solana_pubkey::Pubkey>,
     protocol_config: Option<solana_pubkey::Pubkey>,
+    fee_stats: Option<solana_pubkey::Pubkey>,
+    root_protocol_config: Option<solana_pubkey::Pubkey>,
+    root_admin: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
+    instance_id: Option<u64>,
     admins: Option<Vec<Pubkey>>,
     whitelist_transfer_addrs: Option<Vec<Pubkey>>,
     fees: Option<FeesStructure>,
@@ -131,6 +167,26 @@ impl InitializeProtocolBuilder {
         self.protocol_config = Some(protocol_config);
         self
     }
+    #[inline(always)]
+    pub fn fee_stats(&mut self, fee_stats: solana_pubkey::Pubkey) -> &mut Self {
+        self.fee_stats = Some(fee_stats);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn root_protocol_config(
+        &mut self,
+        root_protocol_config: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.root_protocol_config = root_protocol_config;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn root_admin(&mut self, root_admin: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.root_admin = root_admin;
+        self
+    }
     /// `[optional account, default to '11111111111111111111111111111111']`
     #[inline(always)]
     pub fn system_program(&mut self, system_program: solana_pubkey::Pubkey) -> &mut Self {
@@ -138,6 +194,11 @@ impl InitializeProtocolBuilder {
         self
     }
     #[inline(always)]
+    pub fn instance_id(&mut self, instance_id: u64) -> &mut Self {
+        self.instance_id = Some(instance_id);
+        self
+    }
+    #[inline(always)]
     pub fn admins(&mut self, admins: Vec<Pubkey>) -> &mut Self {
         self.admins = Some(admins);
         self
@@ -172,11 +233,15 @@ impl InitializeProtocolBuilder {
         let accounts = InitializeProtocol {
             payer: self.payer.expect("payer is not set"),
             protocol_config: self.protocol_config.expect("protocol_config is not set"),
+            fee_stats: self.fee_stats.expect("fee_stats is not set"),
+            root_protocol_config: self.root_protocol_config,
+            root_admin: self.root_admin,
             system_program: self
                 .system_program
                 .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
         };
         let args = InitializeProtocolInstructionArgs {
+            instance_id: self.instance_id.clone().expect("instance_id is not set"),
             admins: self.admins.clone().expect("admins is not set"),
             whitelist_transfer_addrs: self
                 .whitelist_transfer_addrs
@@ -195,6 +260,12 @@ pub struct InitializeProtocolCpiAccounts<'a, 'b> {
 
     pub protocol_config: &'b solana_account_info::AccountInfo<'a>,
 
+    pub fee_stats: &'b solana_account_info::AccountInfo<'a>,
+
+    pub root_protocol_config: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub root_admin: Option<&'b solana_account_info::AccountInfo<'a>>,
+
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
 }
 
@@ -207,6 +278,12 @@ pub struct InitializeProtocolCpi<'a, 'b> {
 
     pub protocol_config: &'b solana_account_info::AccountInfo<'a>,
 
+    pub fee_stats: &'b solana_account_info::AccountInfo<'a>,
+
+    pub root_protocol_config: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub root_admin: Option<&'b solana_account_info::AccountInfo<'a>>,
+
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: InitializeProtocolInstructionArgs,
@@ -222,6 +299,9 @@ impl<'a, 'b> InitializeProtocolCpi<'a, 'b> {
             __program: program,
             payer: accounts.payer,
             protocol_config: accounts.protocol_config,
+            fee_stats: accounts.fee_stats,
+            root_protocol_config: accounts.root_protocol_config,
+            root_admin: accounts.root_admin,
             system_program: accounts.system_program,
             __args: args,
         }
@@ -249,12 +329,38 @@ impl<'a, 'b> InitializeProtocolCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(*self.payer.key, true));
         accounts.push(solana_instruction::AccountMeta::new(
             *self.protocol_config.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            *self.fee_stats.key,
+            false,
+        ));
+        if let Some(root_protocol_config) = self.root_protocol_config {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *root_protocol_config.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SOL_MIND_PROTOCOL_ID,
+                false,
+            ));
+        }
+        if let Some(root_admin) = self.root_admin {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *root_admin.key,
+                true,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SOL_MIND_PROTOCOL_ID,
+                false,
+            ));
+        }
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.system_program.key,
             false,
@@ -277,10 +383,17 @@ impl<'a, 'b> InitializeProtocolCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(7 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.payer.clone());
         account_infos.push(self.protocol_config.clone());
+        account_infos.push(self.fee_stats.clone());
+        if let Some(root_protocol_config) = self.root_protocol_config {
+            account_infos.push(root_protocol_config.clone());
+        }
+        if let Some(root_admin) = self.root_admin {
+            account_infos.push(root_admin.clone());
+        }
         account_infos.push(self.system_program.clone());
         remaining_accounts
             .iter()
@@ -300,7 +413,10 @@ impl<'a, 'b> InitializeProtocolCpi<'a, 'b> {
 ///
 ///   0. `[writable, signer]` payer
 ///   1. `[writable]` protocol_config
-///   2. `[]` system_program
+///   2. `[writable]` fee_stats
+///   3. `[optional]` root_protocol_config
+///   4. `[signer, optional]` root_admin
+///   5. `[]` system_program
 #[derive(Clone, Debug)]
 pub struct InitializeProtocolCpiBuilder<'a, 'b> {
     instruction: Box<InitializeProtocolCpiBuilderInstruction<'a, 'b>>,
@@ -312,7 +428,11 @@ impl<'a, 'b> InitializeProtocolCpiBuilder<'a, 'b> {
             __program: program,
             payer: None,
             protocol_config: None,
+            fee_stats: None,
+            root_protocol_config: None,
+            root_admin: None,
             system_program: None,
+            instance_id: None,
             admins: None,
             whitelist_transfer_addrs: None,
             fees: None,
@@ -334,6 +454,29 @@ impl<'a, 'b> InitializeProtocolCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn fee_stats(&mut self, fee_stats: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.fee_stats = Some(fee_stats);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn root_protocol_config(
+        &mut self,
+        root_protocol_config: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.root_protocol_config = root_protocol_config;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn root_admin(
+        &mut self,
+        root_admin: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.root_admin = root_admin;
+        self
+    }
+    #[inline(always)]
     pub fn system_program(
         &mut self,
         system_program: &'b solana_account_info::AccountInfo<'a>,
@@ -342,6 +485,11 @@ impl<'a, 'b> InitializeProtocolCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn instance_id(&mut self, instance_id: u64) -> &mut Self {
+        self.instruction.instance_id = Some(instance_id);
+        self
+    }
+    #[inline(always)]
     pub fn admins(&mut self, admins: Vec<Pubkey>) -> &mut Self {
         self.instruction.admins = Some(admins);
         self
@@ -391,6 +539,11 @@ impl<'a, 'b> InitializeProtocolCpiBuilder<'a, 'b> {
     #[allow(clippy::vec_init_then_push)]
     pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> solana_program_error::ProgramResult {
         let args = InitializeProtocolInstructionArgs {
+            instance_id: self
+                .instruction
+                .instance_id
+                .clone()
+                .expect("instance_id is not set"),
             admins: self.instruction.admins.clone().expect("admins is not set"),
             whitelist_transfer_addrs: self
                 .instruction
@@ -409,6 +562,12 @@ impl<'a, 'b> InitializeProtocolCpiBuilder<'a, 'b> {
                 .protocol_config
                 .expect("protocol_config is not set"),
 
+            fee_stats: self.instruction.fee_stats.expect("fee_stats is not set"),
+
+            root_protocol_config: self.instruction.root_protocol_config,
+
+            root_admin: self.instruction.root_admin,
+
             system_program: self
                 .instruction
                 .system_program
@@ -427,7 +586,11 @@ struct InitializeProtocolCpiBuilderInstruction<'a, 'b> {
     __program: &'b solana_account_info::AccountInfo<'a>,
     payer: Option<&'b solana_account_info::AccountInfo<'a>>,
     protocol_config: Option<&'b solana_account_info::AccountInfo<'a>>,
+    fee_stats: Option<&'b solana_account_info::AccountInfo<'a>>,
+    root_protocol_config: Option<&'b solana_account_info::AccountInfo<'a>>,
+    root_admin: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    instance_id: Option<u64>,
     admins: Option<Vec<Pubkey>>,
     whitelist_transfer_addrs: Option<Vec<Pubkey>>,
     fees: Option<FeesStructure>,
@@ -5,16 +5,104 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+pub(crate) mod r#accept_owner;
+pub(crate) mod r#add_admin;
+pub(crate) mod r#add_whitelist_transfer_addr;
+pub(crate) mod r#approve_proposal;
+pub(crate) mod r#cancel_pending_fees;
+pub(crate) mod r#cancel_proposal;
+pub(crate) mod r#charge_generic_fee;
+pub(crate) mod r#claim_referral_fees;
+pub(crate) mod r#close_project;
+pub(crate) mod r#close_project_fee_override;
+pub(crate) mod r#close_treasury_policy;
 pub(crate) mod r#create_project;
+pub(crate) mod r#create_proposal;
+pub(crate) mod r#distribute_protocol_fees;
+pub(crate) mod r#execute_proposal;
+pub(crate) mod r#grant_project_role;
+pub(crate) mod r#initialize_project_fee_stats;
 pub(crate) mod r#initialize_protocol;
+pub(crate) mod r#initialize_protocol_fee_stats;
+pub(crate) mod r#migrate_project_config;
+pub(crate) mod r#migrate_protocol_config;
+pub(crate) mod r#pay_subscription;
+pub(crate) mod r#propose_owner;
+pub(crate) mod r#record_fees;
+pub(crate) mod r#register_program;
+pub(crate) mod r#register_project_resource;
+pub(crate) mod r#register_referrer;
+pub(crate) mod r#remove_admin;
+pub(crate) mod r#remove_referrer;
+pub(crate) mod r#remove_subscription_plan;
+pub(crate) mod r#remove_whitelist_transfer_addr;
+pub(crate) mod r#rename_project;
+pub(crate) mod r#revoke_project_role;
+pub(crate) mod r#rotate_admin;
+pub(crate) mod r#set_operation_paused;
+pub(crate) mod r#set_project_fee_override;
+pub(crate) mod r#set_protocol_paused;
+pub(crate) mod r#set_revenue_shares;
+pub(crate) mod r#set_subscription_plan;
+pub(crate) mod r#set_treasury_policy;
 pub(crate) mod r#transfer_project_fees;
+pub(crate) mod r#transfer_project_token_fees;
 pub(crate) mod r#transfer_protocol_fees;
+pub(crate) mod r#transfer_protocol_token_fees;
+pub(crate) mod r#unregister_program;
+pub(crate) mod r#unregister_project_resource;
 pub(crate) mod r#update_fees;
+pub(crate) mod r#update_project;
+pub(crate) mod r#update_referrer;
 pub(crate) mod r#update_single_fee;
 
+pub use self::r#accept_owner::*;
+pub use self::r#add_admin::*;
+pub use self::r#add_whitelist_transfer_addr::*;
+pub use self::r#approve_proposal::*;
+pub use self::r#cancel_pending_fees::*;
+pub use self::r#cancel_proposal::*;
+pub use self::r#charge_generic_fee::*;
+pub use self::r#claim_referral_fees::*;
+pub use self::r#close_project::*;
+pub use self::r#close_project_fee_override::*;
+pub use self::r#close_treasury_policy::*;
 pub use self::r#create_project::*;
+pub use self::r#create_proposal::*;
+pub use self::r#distribute_protocol_fees::*;
+pub use self::r#execute_proposal::*;
+pub use self::r#grant_project_role::*;
+pub use self::r#initialize_project_fee_stats::*;
 pub use self::r#initialize_protocol::*;
+pub use self::r#initialize_protocol_fee_stats::*;
+pub use self::r#migrate_project_config::*;
+pub use self::r#migrate_protocol_config::*;
+pub use self::r#pay_subscription::*;
+pub use self::r#propose_owner::*;
+pub use self::r#record_fees::*;
+pub use self::r#register_program::*;
+pub use self::r#register_project_resource::*;
+pub use self::r#register_referrer::*;
+pub use self::r#remove_admin::*;
+pub use self::r#remove_referrer::*;
+pub use self::r#remove_subscription_plan::*;
+pub use self::r#remove_whitelist_transfer_addr::*;
+pub use self::r#rename_project::*;
+pub use self::r#revoke_project_role::*;
+pub use self::r#rotate_admin::*;
+pub use self::r#set_operation_paused::*;
+pub use self::r#set_project_fee_override::*;
+pub use self::r#set_protocol_paused::*;
+pub use self::r#set_revenue_shares::*;
+pub use self::r#set_subscription_plan::*;
+pub use self::r#set_treasury_policy::*;
 pub use self::r#transfer_project_fees::*;
+pub use self::r#transfer_project_token_fees::*;
 pub use self::r#transfer_protocol_fees::*;
+pub use self::r#transfer_protocol_token_fees::*;
+pub use self::r#unregister_program::*;
+pub use self::r#unregister_project_resource::*;
 pub use self::r#update_fees::*;
+pub use self::r#update_project::*;
+pub use self::r#update_referrer::*;
 pub use self::r#update_single_fee::*;
@@ -13,7 +13,9 @@ pub const TRANSFER_PROJECT_FEES_DISCRIMINATOR: [u8; 8] = [197, 83, 232, 147, 84,
 /// Accounts.
 #[derive(Debug)]
 pub struct TransferProjectFees {
-    pub owner: solana_pubkey::Pubkey,
+    pub authority: solana_pubkey::Pubkey,
+
+    pub authority_role: solana_pubkey::Pubkey,
 
     pub to: solana_pubkey::Pubkey,
 
@@ -21,6 +23,10 @@ pub struct TransferProjectFees {
 
     pub treasury: solana_pubkey::Pubkey,
 
+    pub fee_stats: solana_pubkey::Pubkey,
+
+    pub treasury_policy: Option<solana_pubkey::Pubkey>,
+
     pub system_program: solana_pubkey::Pubkey,
 }
 
@@ -38,14 +44,27 @@ impl TransferProjectFees {
         args: TransferProjectFeesInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
-        accounts.push(solana_instruction::AccountMeta::new(self.owner, true));
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        accounts.push(solana_instruction::AccountMeta::new(self.authority, true));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.authority_role,
+            false,
+        ));
         accounts.push(solana_instruction::AccountMeta::new(self.to, false));
         accounts.push(solana_instruction::AccountMeta::new(
             self.project_config,
             false,
         ));
         accounts.push(solana_instruction::AccountMeta::new(self.treasury, false));
+        accounts.push(solana_instruction::AccountMeta::new(self.fee_stats, false));
+        if let Some(treasury_policy) = self.treasury_policy {
+            accounts.push(solana_instruction::AccountMeta::new(treasury_policy, false));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SOL_MIND_PROTOCOL_ID,
+                false,
+            ));
+        }
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.system_program,
             false,
@@ -105,17 +124,23 @@ impl TransferProjectFeesInstructionArgs {
 ///
 /// ### Accounts:
 ///
-///   0. `[writable, signer]` owner
-///   1. `[writable]` to
-///   2. `[writable]` project_config
-///   3. `[writable]` treasury
-///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   0. `[writable, signer]` authority
+///   1. `[]` authority_role
+///   2. `[writable]` to
+///   3. `[writable]` project_config
+///   4. `[writable]` treasury
+///   5. `[writable]` fee_stats
+///   6. `[writable, optional]` treasury_policy
+///   7. `[optional]` system_program (default to `11111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
 pub struct TransferProjectFeesBuilder {
-    owner: Option<solana_pubkey::Pubkey>,
+    authority: Option<solana_pubkey::Pubkey>,
+    authority_role: Option<solana_pubkey::Pubkey>,
     to: Option<solana_pubkey::Pubkey>,
     project_config: Option<solana_pubkey::Pubkey>,
     treasury: Option<solana_pubkey::Pubkey>,
+    fee_stats: Option<solana_pubkey::Pubkey>,
+    treasury_policy: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
     amount: Option<u64>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
@@ -126,8 +151,13 @@ impl TransferProjectFeesBuilder {
         Self::default()
     }
     #[inline(always)]
-    pub fn owner(&mut self, owner: solana_pubkey::Pubkey) -> &mut Self {
-        self.owner = Some(owner);
+    pub fn authority(&mut self, authority: solana_pubkey::Pubkey) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    #[inline(always)]
+    pub fn authority_role(&mut self, authority_role: solana_pubkey::Pubkey) -> &mut Self {
+        self.authority_role = Some(authority_role);
         self
     }
     #[inline(always)]
@@ -145,6 +175,17 @@ impl TransferProjectFeesBuilder {
         self.treasury = Some(treasury);
         self
     }
+    #[inline(always)]
+    pub fn fee_stats(&mut self, fee_stats: solana_pubkey::Pubkey) -> &mut Self {
+        self.fee_stats = Some(fee_stats);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn treasury_policy(&mut self, treasury_policy: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.treasury_policy = treasury_policy;
+        self
+    }
     /// `[optional account, default to '11111111111111111111111111111111']`
     #[inline(always)]
     pub fn system_program(&mut self, system_program: solana_pubkey::Pubkey) -> &mut Self {
@@ -174,10 +215,13 @@ impl TransferProjectFeesBuilder {
     #[allow(clippy::clone_on_copy)]
     pub fn instruction(&self) -> solana_instruction::Instruction {
         let accounts = TransferProjectFees {
-            owner: self.owner.expect("owner is not set"),
+            authority: self.authority.expect("authority is not set"),
+            authority_role: self.authority_role.expect("authority_role is not set"),
             to: self.to.expect("to is not set"),
             project_config: self.project_config.expect("project_config is not set"),
             treasury: self.treasury.expect("treasury is not set"),
+            fee_stats: self.fee_stats.expect("fee_stats is not set"),
+            treasury_policy: self.treasury_policy,
             system_program: self
                 .system_program
                 .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
@@ -192,7 +236,9 @@ impl TransferProjectFeesBuilder {
 
 /// `transfer_project_fees` CPI accounts.
 pub struct TransferProjectFeesCpiAccounts<'a, 'b> {
-    pub owner: &'b solana_account_info::AccountInfo<'a>,
+    pub authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub authority_role: &'b solana_account_info::AccountInfo<'a>,
 
     pub to: &'b solana_account_info::AccountInfo<'a>,
 
@@ -200,6 +246,10 @@ pub struct TransferProjectFeesCpiAccounts<'a, 'b> {
 
     pub treasury: &'b solana_account_info::AccountInfo<'a>,
 
+    pub fee_stats: &'b solana_account_info::AccountInfo<'a>,
+
+    pub treasury_policy: Option<&'b solana_account_info::AccountInfo<'a>>,
+
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
 }
 
@@ -208,7 +258,9 @@ pub struct TransferProjectFeesCpi<'a, 'b> {
     /// The program to invoke.
     pub __program: &'b solana_account_info::AccountInfo<'a>,
 
-    pub owner: &'b solana_account_info::AccountInfo<'a>,
+    pub authority: &'b solana_account_info::AccountInfo<'a>,
+
+    pub authority_role: &'b solana_account_info::AccountInfo<'a>,
 
     pub to: &'b solana_account_info::AccountInfo<'a>,
 
@@ -216,6 +268,10 @@ pub struct TransferProjectFeesCpi<'a, 'b> {
 
     pub treasury: &'b solana_account_info::AccountInfo<'a>,
 
+    pub fee_stats: &'b solana_account_info::AccountInfo<'a>,
+
+    pub treasury_policy: Option<&'b solana_account_info::AccountInfo<'a>>,
+
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: TransferProjectFeesInstructionArgs,
@@ -229,10 +285,13 @@ impl<'a, 'b> TransferProjectFeesCpi<'a, 'b> {
     ) -> Self {
         Self {
             __program: program,
-            owner: accounts.owner,
+            authority: accounts.authority,
+            authority_role: accounts.authority_role,
             to: accounts.to,
             project_config: accounts.project_config,
             treasury: accounts.treasury,
+            fee_stats: accounts.fee_stats,
+            treasury_policy: accounts.treasury_policy,
             system_program: accounts.system_program,
             __args: args,
         }
@@ -260,8 +319,15 @@ impl<'a, 'b> TransferProjectFeesCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
-        accounts.push(solana_instruction::AccountMeta::new(*self.owner.key, true));
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        accounts.push(solana_instruction::AccountMeta::new(
+            *self.authority.key,
+            true,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.authority_role.key,
+            false,
+        ));
         accounts.push(solana_instruction::AccountMeta::new(*self.to.key, false));
         accounts.push(solana_instruction::AccountMeta::new(
             *self.project_config.key,
@@ -271,6 +337,21 @@ impl<'a, 'b> TransferProjectFeesCpi<'a, 'b> {
             *self.treasury.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            *self.fee_stats.key,
+            false,
+        ));
+        if let Some(treasury_policy) = self.treasury_policy {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *treasury_policy.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SOL_MIND_PROTOCOL_ID,
+                false,
+            ));
+        }
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.system_program.key,
             false,
@@ -293,12 +374,17 @@ impl<'a, 'b> TransferProjectFeesCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(9 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
-        account_infos.push(self.owner.clone());
+        account_infos.push(self.authority.clone());
+        account_infos.push(self.authority_role.clone());
         account_infos.push(self.to.clone());
         account_infos.push(self.project_config.clone());
         account_infos.push(self.treasury.clone());
+        account_infos.push(self.fee_stats.clone());
+        if let Some(treasury_policy) = self.treasury_policy {
+            account_infos.push(treasury_policy.clone());
+        }
         account_infos.push(self.system_program.clone());
         remaining_accounts
             .iter()
@@ -316,11 +402,14 @@ impl<'a, 'b> TransferProjectFeesCpi<'a, 'b> {
 ///
 /// ### Accounts:
 ///
-///   0. `[writable, signer]` owner
-///   1. `[writable]` to
-///   2. `[writable]` project_config
-///   3. `[writable]` treasury
-///   4. `[]` system_program
+///   0. `[writable, signer]` authority
+///   1. `[]` authority_role
+///   2. `[writable]` to
+///   3. `[writable]` project_config
+///   4. `[writable]` treasury
+///   5. `[writable]` fee_stats
+///   6. `[writable, optional]` treasury_policy
+///   7. `[]` system_program
 #[derive(Clone, Debug)]
 pub struct TransferProjectFeesCpiBuilder<'a, 'b> {
     instruction: Box<TransferProjectFeesCpiBuilderInstruction<'a, 'b>>,
@@ -330,10 +419,13 @@ impl<'a, 'b> TransferProjectFeesCpiBuilder<'a, 'b> {
     pub fn new(program: &'b solana_account_info::AccountInfo<'a>) -> Self {
         let instruction = Box::new(TransferProjectFeesCpiBuilderInstruction {
             __program: program,
-            owner: None,
+            authority: None,
+            authority_role: None,
             to: None,
             project_config: None,
             treasury: None,
+            fee_stats: None,
+            treasury_policy: None,
             system_program: None,
             amount: None,
             __remaining_accounts: Vec::new(),
@@ -341,8 +433,16 @@ impl<'a, 'b> TransferProjectFeesCpiBuilder<'a, 'b> {
         Self { instruction }
     }
     #[inline(always)]
-    pub fn owner(&mut self, owner: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
-        self.instruction.owner = Some(owner);
+    pub fn authority(&mut self, authority: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.authority = Some(authority);
+        self
+    }
+    #[inline(always)]
+    pub fn authority_role(
+        &mut self,
+        authority_role: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.authority_role = Some(authority_role);
         self
     }
     #[inline(always)]
@@ -364,6 +464,20 @@ impl<'a, 'b> TransferProjectFeesCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn fee_stats(&mut self, fee_stats: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.fee_stats = Some(fee_stats);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn treasury_policy(
+        &mut self,
+        treasury_policy: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.treasury_policy = treasury_policy;
+        self
+    }
+    #[inline(always)]
     pub fn system_program(
         &mut self,
         system_program: &'b solana_account_info::AccountInfo<'a>,
@@ -416,7 +530,12 @@ impl<'a, 'b> TransferProjectFeesCpiBuilder<'a, 'b> {
         let instruction = TransferProjectFeesCpi {
             __program: self.instruction.__program,
 
-            owner: self.instruction.owner.expect("owner is not set"),
+            authority: self.instruction.authority.expect("authority is not set"),
+
+            authority_role: self
+                .instruction
+                .authority_role
+                .expect("authority_role is not set"),
 
             to: self.instruction.to.expect("to is not set"),
 
@@ -427,6 +546,10 @@ impl<'a, 'b> TransferProjectFeesCpiBuilder<'a, 'b> {
 
             treasury: self.instruction.treasury.expect("treasury is not set"),
 
+            fee_stats: self.instruction.fee_stats.expect("fee_stats is not set"),
+
+            treasury_policy: self.instruction.treasury_policy,
+
             system_program: self
                 .instruction
                 .system_program
@@ -443,10 +566,13 @@ impl<'a, 'b> TransferProjectFeesCpiBuilder<'a, 'b> {
 #[derive(Clone, Debug)]
 struct TransferProjectFeesCpiBuilderInstruction<'a, 'b> {
     __program: &'b solana_account_info::AccountInfo<'a>,
-    owner: Option<&'b solana_account_info::AccountInfo<'a>>,
+    authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    authority_role: Option<&'b solana_account_info::AccountInfo<'a>>,
     to: Option<&'b solana_account_info::AccountInfo<'a>>,
     project_config: Option<&'b solana_account_info::AccountInfo<'a>>,
     treasury: Option<&'b solana_account_info::AccountInfo<'a>>,
+    fee_stats: Option<&'b solana_account_info::AccountInfo<'a>>,
+    treasury_policy: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     amount: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
@@ -21,6 +21,8 @@ pub struct TransferProtocolFees {
 
     pub treasury: solana_pubkey::Pubkey,
 
+    pub fee_stats: solana_pubkey::Pubkey,
+
     pub system_program: solana_pubkey::Pubkey,
 }
 
@@ -38,7 +40,7 @@ impl TransferProtocolFees {
         args: TransferProtocolFeesInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(self.admin, true));
         accounts.push(solana_instruction::AccountMeta::new(self.to, false));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
@@ -46,6 +48,7 @@ impl TransferProtocolFees {
             false,
         ));
         accounts.push(solana_instruction::AccountMeta::new(self.treasury, false));
+        accounts.push(solana_instruction::AccountMeta::new(self.fee_stats, false));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.system_program,
             false,
@@ -109,13 +112,15 @@ impl TransferProtocolFeesInstructionArgs {
 ///   1. `[writable]` to
 ///   2. `[]` protocol_config
 ///   3. `[writable]` treasury
-///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   4. `[writable]` fee_stats
+///   5. `[optional]` system_program (default to `11111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
 pub struct TransferProtocolFeesBuilder {
     admin: Option<solana_pubkey::Pubkey>,
     to: Option<solana_pubkey::Pubkey>,
     protocol_config: Option<solana_pubkey::Pubkey>,
     treasury: Option<solana_pubkey::Pubkey>,
+    fee_stats: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
     amount: Option<u64>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
@@ -145,6 +150,11 @@ impl TransferProtocolFeesBuilder {
         self.treasury = Some(treasury);
         self
     }
+    #[inline(always)]
+    pub fn fee_stats(&mut self, fee_stats: solana_pubkey::Pubkey) -> &mut Self {
+        self.fee_stats = Some(fee_stats);
+        self
+    }
     /// `[optional account, default to '11111111111111111111111111111111']`
     #[inline(always)]
     pub fn system_program(&mut self, system_program: solana_pubkey::Pubkey) -> &mut Self {
@@ -178,6 +188,7 @@ impl TransferProtocolFeesBuilder {
             to: self.to.expect("to is not set"),
             protocol_config: self.protocol_config.expect("protocol_config is not set"),
             treasury: self.treasury.expect("treasury is not set"),
+            fee_stats: self.fee_stats.expect("fee_stats is not set"),
             system_program: self
                 .system_program
                 .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
@@ -200,6 +211,8 @@ pub struct TransferProtocolFeesCpiAccounts<'a, 'b> {
 
     pub treasury: &'b solana_account_info::AccountInfo<'a>,
 
+    pub fee_stats: &'b solana_account_info::AccountInfo<'a>,
+
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
 }
 
@@ -216,6 +229,8 @@ pub struct TransferProtocolFeesCpi<'a, 'b> {
 
     pub treasury: &'b solana_account_info::AccountInfo<'a>,
 
+    pub fee_stats: &'b solana_account_info::AccountInfo<'a>,
+
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: TransferProtocolFeesInstructionArgs,
@@ -233,6 +248,7 @@ impl<'a, 'b> TransferProtocolFeesCpi<'a, 'b> {
             to: accounts.to,
             protocol_config: accounts.protocol_config,
             treasury: accounts.treasury,
+            fee_stats: accounts.fee_stats,
             system_program: accounts.system_program,
             __args: args,
         }
@@ -260,7 +276,7 @@ impl<'a, 'b> TransferProtocolFeesCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(*self.admin.key, true));
         accounts.push(solana_instruction::AccountMeta::new(*self.to.key, false));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
@@ -271,6 +287,10 @@ impl<'a, 'b> TransferProtocolFeesCpi<'a, 'b> {
             *self.treasury.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            *self.fee_stats.key,
+            false,
+        ));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.system_program.key,
             false,
@@ -293,12 +313,13 @@ impl<'a, 'b> TransferProtocolFeesCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(7 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.admin.clone());
         account_infos.push(self.to.clone());
         account_infos.push(self.protocol_config.clone());
         account_infos.push(self.treasury.clone());
+        account_infos.push(self.fee_stats.clone());
         account_infos.push(self.system_program.clone());
         remaining_accounts
             .iter()
@@ -320,7 +341,8 @@ impl<'a, 'b> TransferProtocolFeesCpi<'a, 'b> {
 ///   1. `[writable]` to
 ///   2. `[]` protocol_config
 ///   3. `[writable]` treasury
-///   4. `[]` system_program
+///   4. `[writable]` fee_stats
+///   5. `[]` system_program
 #[derive(Clone, Debug)]
 pub struct TransferProtocolFeesCpiBuilder<'a, 'b> {
     instruction: Box<TransferProtocolFeesCpiBuilderInstruction<'a, 'b>>,
@@ -334,6 +356,7 @@ impl<'a, 'b> TransferProtocolFeesCpiBuilder<'a, 'b> {
             to: None,
             protocol_config: None,
             treasury: None,
+            fee_stats: None,
             system_program: None,
             amount: None,
             __remaining_accounts: Vec::new(),
@@ -364,6 +387,11 @@ impl<'a, 'b> TransferProtocolFeesCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn fee_stats(&mut self, fee_stats: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.fee_stats = Some(fee_stats);
+        self
+    }
+    #[inline(always)]
     pub fn system_program(
         &mut self,
         system_program: &'b solana_account_info::AccountInfo<'a>,
@@ -427,6 +455,8 @@ impl<'a, 'b> TransferProtocolFeesCpiBuilder<'a, 'b> {
 
             treasury: self.instruction.treasury.expect("treasury is not set"),
 
+            fee_stats: self.instruction.fee_stats.expect("fee_stats is not set"),
+
             system_program: self
                 .instruction
                 .system_program
@@ -447,6 +477,7 @@ struct TransferProtocolFeesCpiBuilderInstruction<'a, 'b> {
     to: Option<&'b solana_account_info::AccountInfo<'a>>,
     protocol_config: Option<&'b solana_account_info::AccountInfo<'a>>,
     treasury: Option<&'b solana_account_info::AccountInfo<'a>>,
+    fee_stats: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     amount: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
@@ -5,12 +5,136 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+pub(crate) mod r#admin_added;
+pub(crate) mod r#admin_removed;
+pub(crate) mod r#admin_rotated;
+pub(crate) mod r#approval_threshold_updated;
 pub(crate) mod r#fee;
+pub(crate) mod r#fee_adjustment;
+pub(crate) mod r#fee_payment;
+pub(crate) mod r#fee_stats_initialized;
+pub(crate) mod r#fee_totals;
 pub(crate) mod r#fee_type;
 pub(crate) mod r#fees_structure;
+pub(crate) mod r#fees_update_cancelled;
+pub(crate) mod r#fees_update_scheduled;
+pub(crate) mod r#generic_fee_charged;
 pub(crate) mod r#operation;
+pub(crate) mod r#operation_fee_override;
+pub(crate) mod r#operation_fee_totals;
+pub(crate) mod r#operation_pause_updated;
+pub(crate) mod r#pending_fees;
+pub(crate) mod r#program_registered;
+pub(crate) mod r#program_unregistered;
+pub(crate) mod r#project_closed;
+pub(crate) mod r#project_config_migrated;
+pub(crate) mod r#project_created;
+pub(crate) mod r#project_fee_override_closed;
+pub(crate) mod r#project_fee_override_set;
+pub(crate) mod r#project_fees_transferred;
+pub(crate) mod r#project_owner_proposed;
+pub(crate) mod r#project_ownership_transferred;
+pub(crate) mod r#project_renamed;
+pub(crate) mod r#project_resource;
+pub(crate) mod r#project_resource_registered;
+pub(crate) mod r#project_resource_unregistered;
+pub(crate) mod r#project_role_granted;
+pub(crate) mod r#project_role_revoked;
+pub(crate) mod r#project_token_fees_transferred;
+pub(crate) mod r#project_updated;
+pub(crate) mod r#proposal_action;
+pub(crate) mod r#proposal_approved;
+pub(crate) mod r#proposal_cancelled;
+pub(crate) mod r#proposal_created;
+pub(crate) mod r#proposal_executed;
+pub(crate) mod r#protocol_config_migrated;
+pub(crate) mod r#protocol_fee_paid;
+pub(crate) mod r#protocol_fees_distributed;
+pub(crate) mod r#protocol_fees_transferred;
+pub(crate) mod r#protocol_initialized;
+pub(crate) mod r#protocol_pause_updated;
+pub(crate) mod r#protocol_token_fees_transferred;
+pub(crate) mod r#referral_fees_claimed;
+pub(crate) mod r#referrer_registered;
+pub(crate) mod r#referrer_removed;
+pub(crate) mod r#referrer_updated;
+pub(crate) mod r#revenue_share;
+pub(crate) mod r#revenue_shares_updated;
+pub(crate) mod r#role;
+pub(crate) mod r#scheduled_fee_override;
+pub(crate) mod r#subscription_paid;
+pub(crate) mod r#subscription_plan;
+pub(crate) mod r#subscription_plan_removed;
+pub(crate) mod r#subscription_plan_set;
+pub(crate) mod r#treasury_policy_closed;
+pub(crate) mod r#treasury_policy_set;
+pub(crate) mod r#whitelist_entry;
+pub(crate) mod r#whitelist_transfer_addr_added;
+pub(crate) mod r#whitelist_transfer_addr_removed;
 
+pub use self::r#admin_added::*;
+pub use self::r#admin_removed::*;
+pub use self::r#admin_rotated::*;
+pub use self::r#approval_threshold_updated::*;
 pub use self::r#fee::*;
+pub use self::r#fee_adjustment::*;
+pub use self::r#fee_payment::*;
+pub use self::r#fee_stats_initialized::*;
+pub use self::r#fee_totals::*;
 pub use self::r#fee_type::*;
 pub use self::r#fees_structure::*;
+pub use self::r#fees_update_cancelled::*;
+pub use self::r#fees_update_scheduled::*;
+pub use self::r#generic_fee_charged::*;
 pub use self::r#operation::*;
+pub use self::r#operation_fee_override::*;
+pub use self::r#operation_fee_totals::*;
+pub use self::r#operation_pause_updated::*;
+pub use self::r#pending_fees::*;
+pub use self::r#program_registered::*;
+pub use self::r#program_unregistered::*;
+pub use self::r#project_closed::*;
+pub use self::r#project_config_migrated::*;
+pub use self::r#project_created::*;
+pub use self::r#project_fee_override_closed::*;
+pub use self::r#project_fee_override_set::*;
+pub use self::r#project_fees_transferred::*;
+pub use self::r#project_owner_proposed::*;
+pub use self::r#project_ownership_transferred::*;
+pub use self::r#project_renamed::*;
+pub use self::r#project_resource::*;
+pub use self::r#project_resource_registered::*;
+pub use self::r#project_resource_unregistered::*;
+pub use self::r#project_role_granted::*;
+pub use self::r#project_role_revoked::*;
+pub use self::r#project_token_fees_transferred::*;
+pub use self::r#project_updated::*;
+pub use self::r#proposal_action::*;
+pub use self::r#proposal_approved::*;
+pub use self::r#proposal_cancelled::*;
+pub use self::r#proposal_created::*;
+pub use self::r#proposal_executed::*;
+pub use self::r#protocol_config_migrated::*;
+pub use self::r#protocol_fee_paid::*;
+pub use self::r#protocol_fees_distributed::*;
+pub use self::r#protocol_fees_transferred::*;
+pub use self::r#protocol_initialized::*;
+pub use self::r#protocol_pause_updated::*;
+pub use self::r#protocol_token_fees_transferred::*;
+pub use self::r#referral_fees_claimed::*;
+pub use self::r#referrer_registered::*;
+pub use self::r#referrer_removed::*;
+pub use self::r#referrer_updated::*;
+pub use self::r#revenue_share::*;
+pub use self::r#revenue_shares_updated::*;
+pub use self::r#role::*;
+pub use self::r#scheduled_fee_override::*;
+pub use self::r#subscription_paid::*;
+pub use self::r#subscription_plan::*;
+pub use self::r#subscription_plan_removed::*;
+pub use self::r#subscription_plan_set::*;
+pub use self::r#treasury_policy_closed::*;
+pub use self::r#treasury_policy_set::*;
+pub use self::r#whitelist_entry::*;
+pub use self::r#whitelist_transfer_addr_added::*;
+pub use self::r#whitelist_transfer_addr_removed::*;
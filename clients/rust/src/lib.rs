@@ -2,6 +2,10 @@
 
 pub(crate) mod generated;
 
+pub mod allowlist;
+pub mod events;
+pub mod fees;
+
 pub use generated::sol_mind_protocol::*;
 
 pub mod nft_operations {
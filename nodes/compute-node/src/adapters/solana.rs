@@ -100,7 +100,7 @@ impl SolanaAdapter {
                 MemcmpEncodedBytes::Bytes(AGENT_DISCRIMINATOR.to_vec()),
             )),
             RpcFilterType::Memcmp(Memcmp::new(
-                8 + 8 + 32,
+                8 + 32 + 8 + 32,
                 MemcmpEncodedBytes::Bytes(node_pubkey.as_ref().to_vec()),
             )),
         ];
@@ -253,7 +253,7 @@ impl SolanaAdapter {
                 MemcmpEncodedBytes::Bytes(COMPUTE_NODE_INFO_DISCRIMINATOR.to_vec()),
             )),
             RpcFilterType::Memcmp(Memcmp::new(
-                8 + 32,
+                8 + 32 + 32,
                 MemcmpEncodedBytes::Bytes(node_pubkey.as_ref().to_vec()),
             )),
         ];
@@ -314,7 +314,7 @@ impl SolanaAdapter {
                 MemcmpEncodedBytes::Bytes(AGENT_DISCRIMINATOR.to_vec()),
             )),
             RpcFilterType::Memcmp(Memcmp::new(
-                8 + 8 + 32,
+                8 + 32 + 8 + 32,
                 MemcmpEncodedBytes::Bytes(compute_node_pubkey.as_ref().to_vec()),
             )),
         ];
@@ -1,9 +1,13 @@
 use anchor_lang::prelude::*;
+use sol_mind_protocol::{Operation, ProtocolConfig};
 
+use crate::events::AgentActivated;
 use crate::{
-    errors::ErrorCode, state::{Agent, AgentStatus}
+    errors::ErrorCode,
+    state::{Agent, AgentStatus},
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(agent_id: u64)]
 pub struct ActivateAgent<'info> {
@@ -14,17 +18,30 @@ pub struct ActivateAgent<'info> {
     #[account(
         mut,
         has_one = compute_node.key() @ ErrorCode::Unauthorized,
+        has_one = protocol_config @ ErrorCode::ProtocolConfigMismatch,
         seeds = [b"agent", agent.owner.as_ref(), agent_id.to_le_bytes().as_ref()],
         bump = agent.bump,
     )]
     pub agent: Account<'info, Agent>,
+    #[account(
+        seeds = [
+            b"sol-mind-protocol",
+            ProtocolConfig::instance_seed(protocol_config.instance_id).as_slice(),
+        ],
+        bump = protocol_config.bump,
+        seeds::program = sol_mind_protocol::ID,
+        constraint = !protocol_config.is_operation_paused(Operation::Generic) @ ErrorCode::OperationPaused,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
 }
 
 impl<'info> ActivateAgent<'info> {
-    pub fn activate_agent(
-        &mut self,
-    ) -> Result<()> {
+    pub fn activate_agent(&mut self) -> Result<AgentActivated> {
         self.agent.status = AgentStatus::Active;
-        Ok(())
+
+        Ok(AgentActivated {
+            agent: self.agent.key(),
+            compute_node: self.compute_node.key(),
+        })
     }
 }
@@ -1,8 +1,11 @@
 use anchor_lang::prelude::*;
+use sol_mind_protocol::{Operation, ProtocolConfig};
 
 use crate::errors::ErrorCode;
+use crate::events::ComputeNodeClaimed;
 use crate::{ComputeNodeInfo, ComputeNodeStatus};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct ClaimComputeNode<'info> {
     #[account(mut)]
@@ -11,6 +14,7 @@ pub struct ClaimComputeNode<'info> {
     pub compute_node: Signer<'info>,
     #[account(
         mut,
+        has_one = protocol_config @ ErrorCode::ProtocolConfigMismatch,
         seeds = [
             b"compute_node",
             compute_node_info.node_pubkey.as_ref(),
@@ -18,12 +22,22 @@ pub struct ClaimComputeNode<'info> {
         bump = compute_node_info.bump,
     )]
     pub compute_node_info: Account<'info, ComputeNodeInfo>,
+    #[account(
+        seeds = [
+            b"sol-mind-protocol",
+            ProtocolConfig::instance_seed(protocol_config.instance_id).as_slice(),
+        ],
+        bump = protocol_config.bump,
+        seeds::program = sol_mind_protocol::ID,
+        constraint = !protocol_config.is_operation_paused(Operation::Generic) @ ErrorCode::OperationPaused,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
 
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> ClaimComputeNode<'info> {
-    pub fn claim_compute_node(&mut self, node_info_cid: String) -> Result<()> {
+    pub fn claim_compute_node(&mut self, node_info_cid: String) -> Result<ComputeNodeClaimed> {
         require!(
             self.compute_node.key() == self.compute_node_info.node_pubkey,
             ErrorCode::Unauthorized
@@ -33,9 +47,13 @@ impl<'info> ClaimComputeNode<'info> {
             ErrorCode::InvalidNodeStatus
         );
 
-        self.compute_node_info.node_info_cid = Some(node_info_cid);
+        self.compute_node_info.node_info_cid = Some(node_info_cid.clone());
         self.compute_node_info.status = ComputeNodeStatus::Approved;
 
-        Ok(())
+        Ok(ComputeNodeClaimed {
+            compute_node_info: self.compute_node_info.key(),
+            node_pubkey: self.compute_node_info.node_pubkey,
+            node_info_cid,
+        })
     }
 }
@@ -1,9 +1,14 @@
 use anchor_lang::prelude::*;
+use sol_mind_protocol::{Operation, ProtocolConfig};
 
+use crate::events::AgentCreated;
 use crate::{
-    AgentStatus, TaskData, errors::ErrorCode, state::{Agent, ComputeNodeInfo, ComputeNodeStatus, TaskStatus}
+    errors::ErrorCode,
+    state::{Agent, ComputeNodeInfo, ComputeNodeStatus, TaskStatus},
+    AgentStatus, TaskData,
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(agent_id: u64)]
 pub struct CreateAgent<'info> {
@@ -29,6 +34,7 @@ pub struct CreateAgent<'info> {
     pub task_data: Account<'info, TaskData>,
     #[account(
         constraint = compute_node_info.status == ComputeNodeStatus::Approved @ ErrorCode::ComputeNodeNotApproved,
+        has_one = protocol_config @ ErrorCode::ProtocolConfigMismatch,
         seeds = [
             b"compute_node",
             compute_node_info.node_pubkey.as_ref()
@@ -36,6 +42,16 @@ pub struct CreateAgent<'info> {
         bump = compute_node_info.bump,
     )]
     pub compute_node_info: Account<'info, ComputeNodeInfo>,
+    #[account(
+        seeds = [
+            b"sol-mind-protocol",
+            ProtocolConfig::instance_seed(protocol_config.instance_id).as_slice(),
+        ],
+        bump = protocol_config.bump,
+        seeds::program = sol_mind_protocol::ID,
+        constraint = !protocol_config.is_operation_paused(Operation::Generic) @ ErrorCode::OperationPaused,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
 
     pub system_program: Program<'info, System>,
 }
@@ -46,8 +62,9 @@ impl<'info> CreateAgent<'info> {
         agent_id: u64,
         public: bool,
         bumps: &CreateAgentBumps,
-    ) -> Result<()> {
+    ) -> Result<AgentCreated> {
         self.agent.set_inner(Agent {
+            protocol_config: self.protocol_config.key(),
             agent_id,
             owner: self.owner.key(),
             compute_node: self.compute_node_info.node_pubkey,
@@ -64,6 +81,12 @@ impl<'info> CreateAgent<'info> {
             bump: bumps.task_data,
         });
 
-        Ok(())
+        Ok(AgentCreated {
+            agent: self.agent.key(),
+            owner: self.owner.key(),
+            agent_id,
+            compute_node: self.compute_node_info.node_pubkey,
+            public,
+        })
     }
 }
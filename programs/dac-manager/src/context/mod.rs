@@ -1,13 +1,13 @@
+pub mod activate_agent;
 pub mod claim_compute_node;
 pub mod create_agent;
 pub mod register_compute_node;
 pub mod submit_task;
 pub mod submit_task_result;
-pub mod activate_agent;
 
+pub use activate_agent::*;
 pub use claim_compute_node::*;
 pub use create_agent::*;
 pub use register_compute_node::*;
 pub use submit_task::*;
 pub use submit_task_result::*;
-pub use activate_agent::*;
\ No newline at end of file
@@ -1,7 +1,11 @@
 use anchor_lang::prelude::*;
+use sol_mind_protocol::{Operation, ProtocolConfig};
 
+use crate::errors::ErrorCode;
+use crate::events::ComputeNodeRegistered;
 use crate::{ComputeNodeInfo, ComputeNodeStatus};
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(node_pubkey: Pubkey)]
 pub struct RegisterComputeNode<'info> {
@@ -17,6 +21,16 @@ pub struct RegisterComputeNode<'info> {
         bump,
     )]
     pub compute_node_info: Account<'info, ComputeNodeInfo>,
+    #[account(
+        seeds = [
+            b"sol-mind-protocol",
+            ProtocolConfig::instance_seed(protocol_config.instance_id).as_slice(),
+        ],
+        bump = protocol_config.bump,
+        seeds::program = sol_mind_protocol::ID,
+        constraint = !protocol_config.is_operation_paused(Operation::Generic) @ ErrorCode::OperationPaused,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
 
     pub system_program: Program<'info, System>,
 }
@@ -26,8 +40,9 @@ impl<'info> RegisterComputeNode<'info> {
         &mut self,
         node_pubkey: Pubkey,
         bumps: &RegisterComputeNodeBumps,
-    ) -> Result<()> {
+    ) -> Result<ComputeNodeRegistered> {
         self.compute_node_info.set_inner(ComputeNodeInfo {
+            protocol_config: self.protocol_config.key(),
             owner: self.owner.key(),
             node_pubkey: node_pubkey,
             node_info_cid: None,
@@ -35,6 +50,10 @@ impl<'info> RegisterComputeNode<'info> {
             bump: bumps.compute_node_info,
         });
 
-        Ok(())
+        Ok(ComputeNodeRegistered {
+            compute_node_info: self.compute_node_info.key(),
+            owner: self.owner.key(),
+            node_pubkey,
+        })
     }
 }
@@ -1,8 +1,11 @@
 use anchor_lang::prelude::*;
+use sol_mind_protocol::{Operation, ProtocolConfig};
 
 use crate::errors::ErrorCode;
+use crate::events::TaskStatusChanged;
 use crate::state::{Agent, TaskData, TaskStatus};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct SubmitTask<'info> {
     #[account(mut)]
@@ -17,16 +20,27 @@ pub struct SubmitTask<'info> {
     pub task_data: Account<'info, TaskData>,
     #[account(
         mut,
+        has_one = protocol_config @ ErrorCode::ProtocolConfigMismatch,
         seeds = [b"agent", agent.owner.as_ref(), agent.agent_id.to_le_bytes().as_ref()],
         bump = agent.bump,
     )]
     pub agent: Account<'info, Agent>,
+    #[account(
+        seeds = [
+            b"sol-mind-protocol",
+            ProtocolConfig::instance_seed(protocol_config.instance_id).as_slice(),
+        ],
+        bump = protocol_config.bump,
+        seeds::program = sol_mind_protocol::ID,
+        constraint = !protocol_config.is_operation_paused(Operation::Generic) @ ErrorCode::OperationPaused,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
 
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> SubmitTask<'info> {
-    pub fn submit_task(&mut self, data: Vec<u8>) -> Result<()> {
+    pub fn submit_task(&mut self, data: Vec<u8>) -> Result<TaskStatusChanged> {
         require!(
             self.submitter.key() == self.agent.owner
                 || self.submitter.key() == self.agent.compute_node,
@@ -45,6 +59,8 @@ impl<'info> SubmitTask<'info> {
             bump: self.task_data.bump,
         });
 
-        Ok(())
+        Ok(self
+            .task_data
+            .status_changed(self.agent.key(), self.task_data.key()))
     }
 }
@@ -1,8 +1,11 @@
 use anchor_lang::prelude::*;
+use sol_mind_protocol::{Operation, ProtocolConfig};
 
 use crate::errors::ErrorCode;
+use crate::events::TaskStatusChanged;
 use crate::state::{Agent, TaskData, TaskStatus};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct SubmitTaskResult<'info> {
     #[account(mut)]
@@ -18,16 +21,27 @@ pub struct SubmitTaskResult<'info> {
     #[account(
         mut,
         has_one = compute_node.key() @ ErrorCode::Unauthorized,
+        has_one = protocol_config @ ErrorCode::ProtocolConfigMismatch,
         seeds = [b"agent", agent.owner.as_ref(), agent.agent_id.to_le_bytes().as_ref()],
         bump = agent.bump,
     )]
     pub agent: Account<'info, Agent>,
+    #[account(
+        seeds = [
+            b"sol-mind-protocol",
+            ProtocolConfig::instance_seed(protocol_config.instance_id).as_slice(),
+        ],
+        bump = protocol_config.bump,
+        seeds::program = sol_mind_protocol::ID,
+        constraint = !protocol_config.is_operation_paused(Operation::Generic) @ ErrorCode::OperationPaused,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
 
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> SubmitTaskResult<'info> {
-    pub fn claim_task(&mut self) -> Result<()> {
+    pub fn claim_task(&mut self) -> Result<TaskStatusChanged> {
         require!(
             self.task_data.status == TaskStatus::Pending,
             ErrorCode::InvalidTaskStatus
@@ -36,10 +50,12 @@ impl<'info> SubmitTaskResult<'info> {
         self.task_data.status = TaskStatus::Processing;
         self.task_data.compute_node = self.compute_node.key();
 
-        Ok(())
+        Ok(self
+            .task_data
+            .status_changed(self.agent.key(), self.task_data.key()))
     }
 
-    pub fn submit_task_result(&mut self, result: Vec<u8>) -> Result<()> {
+    pub fn submit_task_result(&mut self, result: Vec<u8>) -> Result<TaskStatusChanged> {
         require!(
             self.task_data.status == TaskStatus::Processing,
             ErrorCode::InvalidTaskStatus
@@ -48,6 +64,8 @@ impl<'info> SubmitTaskResult<'info> {
         self.task_data.result = result;
         self.task_data.status = TaskStatus::Ready;
 
-        Ok(())
+        Ok(self
+            .task_data
+            .status_changed(self.agent.key(), self.task_data.key()))
     }
 }
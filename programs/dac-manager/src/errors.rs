@@ -16,4 +16,8 @@ pub enum ErrorCode {
     ComputeNodeMismatch,
     #[msg("Agent is not public or the owner of the compute node")]
     AgentNotPublicOrComputeNodeOwner,
+    #[msg("Operation is paused by the protocol")]
+    OperationPaused,
+    #[msg("Account belongs to another protocol instance")]
+    ProtocolConfigMismatch,
 }
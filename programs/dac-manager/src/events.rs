@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::state::TaskStatus;
+
+#[event]
+pub struct ComputeNodeRegistered {
+    pub compute_node_info: Pubkey,
+    pub owner: Pubkey,
+    pub node_pubkey: Pubkey,
+}
+
+#[event]
+pub struct ComputeNodeClaimed {
+    pub compute_node_info: Pubkey,
+    pub node_pubkey: Pubkey,
+    pub node_info_cid: String,
+}
+
+#[event]
+pub struct AgentCreated {
+    pub agent: Pubkey,
+    pub owner: Pubkey,
+    pub agent_id: u64,
+    pub compute_node: Pubkey,
+    pub public: bool,
+}
+
+#[event]
+pub struct AgentActivated {
+    pub agent: Pubkey,
+    pub compute_node: Pubkey,
+}
+
+#[event]
+pub struct TaskStatusChanged {
+    pub agent: Pubkey,
+    pub task_data: Pubkey,
+    pub compute_node: Pubkey,
+    pub status: TaskStatus,
+}
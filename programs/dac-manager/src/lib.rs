@@ -4,6 +4,7 @@ declare_id!("DeXj8mQDYUnLC2mX5xiRqgvYt193sBhJWRZTBkRLg79M");
 
 pub mod context;
 pub mod errors;
+pub mod events;
 pub mod state;
 
 pub use context::*;
@@ -17,30 +18,53 @@ pub mod dac_manager {
         ctx: Context<RegisterComputeNode>,
         node_pubkey: Pubkey,
     ) -> Result<()> {
-        ctx.accounts.register_compute_node(node_pubkey, &ctx.bumps)
+        let event = ctx
+            .accounts
+            .register_compute_node(node_pubkey, &ctx.bumps)?;
+
+        emit_cpi!(event);
+        Ok(())
     }
 
     pub fn claim_compute_node(ctx: Context<ClaimComputeNode>, node_info_cid: String) -> Result<()> {
-        ctx.accounts.claim_compute_node(node_info_cid)
+        let event = ctx.accounts.claim_compute_node(node_info_cid)?;
+
+        emit_cpi!(event);
+        Ok(())
     }
 
     pub fn create_agent(ctx: Context<CreateAgent>, agent_id: u64, public: bool) -> Result<()> {
-        ctx.accounts.create_agent(agent_id, public, &ctx.bumps)
+        let event = ctx.accounts.create_agent(agent_id, public, &ctx.bumps)?;
+
+        emit_cpi!(event);
+        Ok(())
     }
-    
+
     pub fn activate_agent(ctx: Context<ActivateAgent>, agent_id: u64) -> Result<()> {
-        ctx.accounts.activate_agent()
+        let event = ctx.accounts.activate_agent()?;
+
+        emit_cpi!(event);
+        Ok(())
     }
 
     pub fn submit_task(ctx: Context<SubmitTask>, data: Vec<u8>) -> Result<()> {
-        ctx.accounts.submit_task(data)
+        let event = ctx.accounts.submit_task(data)?;
+
+        emit_cpi!(event);
+        Ok(())
     }
 
     pub fn claim_task(ctx: Context<SubmitTaskResult>) -> Result<()> {
-        ctx.accounts.claim_task()
+        let event = ctx.accounts.claim_task()?;
+
+        emit_cpi!(event);
+        Ok(())
     }
 
     pub fn submit_task_result(ctx: Context<SubmitTaskResult>, result: Vec<u8>) -> Result<()> {
-        ctx.accounts.submit_task_result(result)
+        let event = ctx.accounts.submit_task_result(result)?;
+
+        emit_cpi!(event);
+        Ok(())
     }
 }
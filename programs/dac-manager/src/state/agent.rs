@@ -10,6 +10,7 @@ pub enum AgentStatus {
 #[account]
 #[derive(InitSpace)]
 pub struct Agent {
+    pub protocol_config: Pubkey, // protocol instance the agent belongs to
     pub agent_id: u64,
     pub owner: Pubkey,
     pub compute_node: Pubkey,
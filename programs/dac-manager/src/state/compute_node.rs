@@ -11,6 +11,7 @@ pub enum ComputeNodeStatus {
 #[account]
 #[derive(InitSpace)]
 pub struct ComputeNodeInfo {
+    pub protocol_config: Pubkey, // protocol instance the node is registered with
     pub owner: Pubkey,
     pub node_pubkey: Pubkey,
     #[max_len(32)]
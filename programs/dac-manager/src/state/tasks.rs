@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::events::TaskStatusChanged;
+
 #[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
 pub enum TaskStatus {
     Ready,
@@ -18,3 +20,14 @@ pub struct TaskData {
     pub result: Vec<u8>,
     pub bump: u8,
 }
+
+impl TaskData {
+    pub fn status_changed(&self, agent: Pubkey, task_data: Pubkey) -> TaskStatusChanged {
+        TaskStatusChanged {
+            agent,
+            task_data,
+            compute_node: self.compute_node,
+            status: self.status,
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::MintRecordClosed;
+use crate::state::{MintRecord, MinterConfig};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseMintRecord<'info> {
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+    /// CHECK: Minter config of the record, only read while it has not been closed
+    #[account(address = mint_record.minter_config)]
+    pub minter_config: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = wallet,
+        seeds = [b"mint_record", mint_record.minter_config.as_ref(), wallet.key().as_ref()],
+        bump = mint_record.bump,
+    )]
+    pub mint_record: Account<'info, MintRecord>,
+}
+
+impl<'info> CloseMintRecord<'info> {
+    /// Refunds the rent of the record to its wallet once minting has ended, so the wallet
+    /// cannot reset its count while it can still mint. Records of a closed minter config can
+    /// always be closed.
+    pub fn close_mint_record(&mut self) -> Result<MintRecordClosed> {
+        if self.minter_config.owner == &crate::ID {
+            let minter_config = {
+                let data = self.minter_config.try_borrow_data()?;
+                MinterConfig::try_deserialize(&mut &data[..])?
+            };
+            require!(
+                minter_config.minting_ended(Clock::get()?.unix_timestamp),
+                ErrorCode::MintingNotEnded
+            );
+        }
+
+        Ok(MintRecordClosed {
+            minter_config: self.minter_config.key(),
+            wallet: self.wallet.key(),
+            minted: self.mint_record.minted,
+        })
+    }
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use sol_mind_protocol::cpi::accounts::UnregisterProjectResource;
+use sol_mind_protocol::program::SolMindProtocol;
+use sol_mind_protocol::{ProjectConfig, ProjectResource, ProjectRole, Role};
+
+use crate::errors::ErrorCode;
+use crate::events::MinterConfigClosed;
+use crate::helpers::unregister_resource;
+use crate::state::MinterConfig;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseMinterConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: Role PDA of the authority, only read when it has been created
+    #[account(
+        seeds = [b"project_role", project_config.key().as_ref(), authority.key().as_ref()],
+        bump,
+        seeds::program = sol_mind_protocol::ID,
+        constraint = ProjectRole::grants(&authority_role, Role::Minter)? @ ErrorCode::Unauthorized,
+    )]
+    pub authority_role: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            b"minter_config",
+            project_config.key().as_ref(),
+            minter_config.name.as_bytes(),
+        ],
+        bump = minter_config.bump,
+    )]
+    pub minter_config: Account<'info, MinterConfig>,
+    #[account(
+        mut,
+        seeds = [
+            b"project",
+            project_config.creator.as_ref(),
+            project_config.protocol_config.as_ref(),
+            &project_config.project_id.to_le_bytes(),
+        ],
+        bump = project_config.bump,
+        seeds::program = sol_mind_protocol::ID,
+    )]
+    pub project_config: Account<'info, ProjectConfig>,
+
+    /// CHECK: PDA signing unregister_project_resource on behalf of this program
+    #[account(seeds = [b"fee_authority"], bump)]
+    pub fee_authority: UncheckedAccount<'info>,
+
+    pub sol_mind_protocol_program: Program<'info, SolMindProtocol>,
+}
+
+impl<'info> CloseMinterConfig<'info> {
+    /// Closes the minter config and releases it from the project, so the project can be
+    /// closed once it has no minter configs or trade hubs left.
+    pub fn close_minter_config(
+        &mut self,
+        bumps: &CloseMinterConfigBumps,
+    ) -> Result<MinterConfigClosed> {
+        unregister_resource(
+            self.sol_mind_protocol_program.to_account_info(),
+            UnregisterProjectResource {
+                fee_authority: self.fee_authority.to_account_info(),
+                authority: self.authority.to_account_info(),
+                authority_role: self.authority_role.to_account_info(),
+                project_config: self.project_config.to_account_info(),
+            },
+            bumps.fee_authority,
+            ProjectResource::MinterConfig,
+        )?;
+
+        Ok(MinterConfigClosed {
+            project_config: self.project_config.key(),
+            minter_config: self.minter_config.key(),
+            authority: self.authority.key(),
+        })
+    }
+}
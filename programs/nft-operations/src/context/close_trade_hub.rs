@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+
+use sol_mind_protocol::cpi::accounts::UnregisterProjectResource;
+use sol_mind_protocol::program::SolMindProtocol;
+use sol_mind_protocol::{ProjectConfig, ProjectResource, ProjectRole, Role};
+
+use crate::errors::ErrorCode;
+use crate::events::TradeHubClosed;
+use crate::helpers::unregister_resource;
+use crate::state::TradeHub;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseTradeHub<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: Role PDA of the authority, only read when it has been created
+    #[account(
+        seeds = [b"project_role", project_config.key().as_ref(), authority.key().as_ref()],
+        bump,
+        seeds::program = sol_mind_protocol::ID,
+        constraint = ProjectRole::grants(&authority_role, Role::HubManager)? @ ErrorCode::Unauthorized,
+    )]
+    pub authority_role: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            b"trade_hub",
+            trade_hub.name.as_bytes(),
+            project_config.key().as_ref(),
+        ],
+        bump = trade_hub.bump,
+        constraint = TradeHub::counts_listings(&trade_hub.to_account_info())
+            @ ErrorCode::TradeHubListingsUncounted,
+        constraint = trade_hub.listings_count == 0 @ ErrorCode::TradeHubHasListings,
+    )]
+    pub trade_hub: Account<'info, TradeHub>,
+    #[account(
+        mut,
+        seeds = [
+            b"project",
+            project_config.creator.as_ref(),
+            project_config.protocol_config.as_ref(),
+            &project_config.project_id.to_le_bytes(),
+        ],
+        bump = project_config.bump,
+        seeds::program = sol_mind_protocol::ID,
+    )]
+    pub project_config: Account<'info, ProjectConfig>,
+
+    /// CHECK: PDA signing unregister_project_resource on behalf of this program
+    #[account(seeds = [b"fee_authority"], bump)]
+    pub fee_authority: UncheckedAccount<'info>,
+
+    pub sol_mind_protocol_program: Program<'info, SolMindProtocol>,
+}
+
+impl<'info> CloseTradeHub<'info> {
+    /// Closes a trade hub without open listings, since listed assets stay frozen under the
+    /// hub until they are delisted or sold. A hub of the first release can't be closed, as its
+    /// listings were never counted.
+    pub fn close_trade_hub(&mut self, bumps: &CloseTradeHubBumps) -> Result<TradeHubClosed> {
+        unregister_resource(
+            self.sol_mind_protocol_program.to_account_info(),
+            UnregisterProjectResource {
+                fee_authority: self.fee_authority.to_account_info(),
+                authority: self.authority.to_account_info(),
+                authority_role: self.authority_role.to_account_info(),
+                project_config: self.project_config.to_account_info(),
+            },
+            bumps.fee_authority,
+            ProjectResource::TradeHub,
+        )?;
+
+        Ok(TradeHubClosed {
+            project_config: self.project_config.key(),
+            trade_hub: self.trade_hub.key(),
+            authority: self.authority.key(),
+        })
+    }
+}
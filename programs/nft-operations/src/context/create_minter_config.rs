@@ -1,22 +1,36 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use mpl_core::{instructions::CreateCollectionV1CpiBuilder, types::PluginAuthorityPair};
-use sol_mind_protocol::helpers::pay_protocol_fee;
-use sol_mind_protocol::{Operation, ProjectConfig, ProtocolConfig};
+use sol_mind_protocol::cpi::accounts::{RecordFees, RegisterProjectResource};
+use sol_mind_protocol::helpers::{pay_protocol_fee, FeeTokenAccounts};
+use sol_mind_protocol::program::SolMindProtocol;
+use sol_mind_protocol::{
+    Operation, ProjectConfig, ProjectFeeOverride, ProjectResource, ProjectRole, ProtocolConfig,
+    Role, Subscription,
+};
 
 use crate::errors::ErrorCode;
+use crate::events::MinterConfigCreated;
+use crate::helpers::{record_charged_fees, register_resource};
 use crate::state::{AssetsConfig, MinterConfig};
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(name: String)]
 pub struct CreateMinterConfig<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: Role PDA of the authority, only read when it has been created
     #[account(
-        mut,
-        constraint = project_config.check_authorities(authority.key) @ ErrorCode::Unauthorized
+        seeds = [b"project_role", project_config.key().as_ref(), authority.key().as_ref()],
+        bump,
+        seeds::program = sol_mind_protocol::ID,
+        constraint = ProjectRole::grants(&authority_role, Role::Minter)? @ ErrorCode::Unauthorized,
     )]
-    pub authority: Signer<'info>,
+    pub authority_role: UncheckedAccount<'info>,
     /// CHECK: Collection account is validated by mpl_core program (optional)
     #[account(mut)]
     pub collection: Option<Signer<'info>>,
@@ -33,9 +47,10 @@ pub struct CreateMinterConfig<'info> {
     )]
     pub minter_config: Account<'info, MinterConfig>,
     #[account(
+        mut,
         seeds = [
             b"project",
-            project_config.owner.as_ref(),
+            project_config.creator.as_ref(),
             project_config.protocol_config.as_ref(),
             &project_config.project_id.to_le_bytes(),
         ],
@@ -45,9 +60,8 @@ pub struct CreateMinterConfig<'info> {
     pub project_config: Account<'info, ProjectConfig>,
 
     #[account(
-        seeds = [b"sol-mind-protocol"],
-        bump = protocol_config.bump,
-        seeds::program = sol_mind_protocol::ID,
+        address = project_config.protocol_config @ ErrorCode::InvalidProtocolConfig,
+        constraint = !protocol_config.is_operation_paused(Operation::CreateMinterConfig) @ ErrorCode::OperationPaused,
     )]
     pub protocol_config: Account<'info, ProtocolConfig>,
 
@@ -59,6 +73,41 @@ pub struct CreateMinterConfig<'info> {
     )]
     pub protocol_treasury: SystemAccount<'info>,
 
+    /// CHECK: Fee override PDA of the project, only read when it has been created
+    #[account(
+        seeds = [b"fee_override", project_config.key().as_ref()],
+        bump,
+        seeds::program = sol_mind_protocol::ID,
+    )]
+    pub project_fee_override: UncheckedAccount<'info>,
+
+    /// CHECK: Subscription PDA of the project, only read when it has been created
+    #[account(
+        seeds = [b"subscription", project_config.key().as_ref()],
+        bump,
+        seeds::program = sol_mind_protocol::ID,
+        constraint = !Subscription::is_delinquent(&subscription, &protocol_config, &project_config)? @ ErrorCode::SubscriptionDelinquent,
+    )]
+    pub subscription: UncheckedAccount<'info>,
+
+    /// CHECK: PDA signing record_fees and register_project_resource on behalf of this program
+    #[account(seeds = [b"fee_authority"], bump)]
+    pub fee_authority: UncheckedAccount<'info>,
+    /// CHECK: Validated by sol-mind-protocol in record_fees
+    #[account(mut)]
+    pub protocol_fee_stats: UncheckedAccount<'info>,
+    /// CHECK: Validated by sol-mind-protocol in record_fees
+    #[account(mut)]
+    pub project_fee_stats: UncheckedAccount<'info>,
+
+    pub fee_mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub fee_payer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub protocol_treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub sol_mind_protocol_program: Program<'info, SolMindProtocol>,
     pub system_program: Program<'info, System>,
     /// CHECK: Verified by address constraint to mpl_core::ID
     #[account(address = mpl_core::ID)]
@@ -75,16 +124,52 @@ impl<'info> CreateMinterConfig<'info> {
         uri: Option<String>,
         plugins: Option<Vec<PluginAuthorityPair>>,
         bump: &CreateMinterConfigBumps,
-    ) -> Result<()> {
-        pay_protocol_fee(
+    ) -> Result<MinterConfigCreated> {
+        let project_fee_override =
+            ProjectFeeOverride::load(&self.project_fee_override.to_account_info())?;
+
+        let protocol_fee = pay_protocol_fee(
             &self.payer,
             &self.protocol_config,
             &self.protocol_treasury.to_account_info(),
             &self.system_program,
+            FeeTokenAccounts::from_optional(
+                &self.fee_mint,
+                &self.fee_payer_token_account,
+                &self.protocol_treasury_token_account,
+                &self.token_program,
+            ),
+            project_fee_override.as_ref(),
+            None,
             Operation::CreateMinterConfig,
             None,
         )?;
 
+        record_charged_fees(
+            self.sol_mind_protocol_program.to_account_info(),
+            RecordFees {
+                fee_authority: self.fee_authority.to_account_info(),
+                protocol_fee_stats: self.protocol_fee_stats.to_account_info(),
+                project_config: self.project_config.to_account_info(),
+                project_fee_stats: self.project_fee_stats.to_account_info(),
+            },
+            bump.fee_authority,
+            protocol_fee,
+            0,
+        )?;
+
+        register_resource(
+            self.sol_mind_protocol_program.to_account_info(),
+            RegisterProjectResource {
+                fee_authority: self.fee_authority.to_account_info(),
+                authority: self.authority.to_account_info(),
+                authority_role: self.authority_role.to_account_info(),
+                project_config: self.project_config.to_account_info(),
+            },
+            bump.fee_authority,
+            ProjectResource::MinterConfig,
+        )?;
+
         self.minter_config.set_inner(MinterConfig {
             name: name.clone(),
             mint_price,
@@ -93,6 +178,9 @@ impl<'info> CreateMinterConfig<'info> {
             assets_config,
             collection: self.collection.as_ref().map(|c| c.key()),
             bump: bump.minter_config,
+            public_mint: false,
+            phases: Vec::new(),
+            wallet_mint_limit: 0,
         });
 
         if let Some(collection) = &self.collection {
@@ -121,6 +209,13 @@ impl<'info> CreateMinterConfig<'info> {
                 .invoke_signed(signer_seeds)?;
         }
 
-        Ok(())
+        Ok(MinterConfigCreated {
+            project_config: self.project_config.key(),
+            minter_config: self.minter_config.key(),
+            name,
+            mint_price,
+            max_supply,
+            collection: self.minter_config.collection,
+        })
     }
 }
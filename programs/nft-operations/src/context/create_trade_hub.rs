@@ -1,21 +1,35 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
-use sol_mind_protocol::helpers::pay_protocol_fee;
-use sol_mind_protocol::{Operation, ProjectConfig, ProtocolConfig};
+use sol_mind_protocol::cpi::accounts::{RecordFees, RegisterProjectResource};
+use sol_mind_protocol::helpers::{pay_protocol_fee, FeeTokenAccounts};
+use sol_mind_protocol::program::SolMindProtocol;
+use sol_mind_protocol::{
+    Operation, ProjectConfig, ProjectFeeOverride, ProjectResource, ProjectRole, ProtocolConfig,
+    Role, Subscription,
+};
 
 use crate::errors::ErrorCode;
+use crate::events::TradeHubCreated;
+use crate::helpers::{record_charged_fees, register_resource};
 use crate::state::TradeHub;
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(name: String)]
 pub struct CreateTradeHub<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: Role PDA of the authority, only read when it has been created
     #[account(
-        mut,
-        constraint = project_config.check_authorities(authority.key) @ ErrorCode::Unauthorized
+        seeds = [b"project_role", project_config.key().as_ref(), authority.key().as_ref()],
+        bump,
+        seeds::program = sol_mind_protocol::ID,
+        constraint = ProjectRole::grants(&authority_role, Role::HubManager)? @ ErrorCode::Unauthorized,
     )]
-    pub authority: Signer<'info>,
+    pub authority_role: UncheckedAccount<'info>,
     #[account(
         init,
         payer = payer,
@@ -29,9 +43,10 @@ pub struct CreateTradeHub<'info> {
     )]
     pub trade_hub: Account<'info, TradeHub>,
     #[account(
+        mut,
         seeds = [
             b"project",
-            project_config.owner.as_ref(),
+            project_config.creator.as_ref(),
             project_config.protocol_config.as_ref(),
             &project_config.project_id.to_le_bytes(),
         ],
@@ -41,9 +56,8 @@ pub struct CreateTradeHub<'info> {
     pub project_config: Account<'info, ProjectConfig>,
 
     #[account(
-        seeds = [b"sol-mind-protocol"],
-        bump = protocol_config.bump,
-        seeds::program = sol_mind_protocol::ID,
+        address = project_config.protocol_config @ ErrorCode::InvalidProtocolConfig,
+        constraint = !protocol_config.is_operation_paused(Operation::CreateTradeHub) @ ErrorCode::OperationPaused,
     )]
     pub protocol_config: Account<'info, ProtocolConfig>,
 
@@ -55,26 +69,109 @@ pub struct CreateTradeHub<'info> {
     )]
     pub protocol_treasury: SystemAccount<'info>,
 
+    /// CHECK: Fee override PDA of the project, only read when it has been created
+    #[account(
+        seeds = [b"fee_override", project_config.key().as_ref()],
+        bump,
+        seeds::program = sol_mind_protocol::ID,
+    )]
+    pub project_fee_override: UncheckedAccount<'info>,
+
+    /// CHECK: Subscription PDA of the project, only read when it has been created
+    #[account(
+        seeds = [b"subscription", project_config.key().as_ref()],
+        bump,
+        seeds::program = sol_mind_protocol::ID,
+        constraint = !Subscription::is_delinquent(&subscription, &protocol_config, &project_config)? @ ErrorCode::SubscriptionDelinquent,
+    )]
+    pub subscription: UncheckedAccount<'info>,
+
+    /// CHECK: PDA signing record_fees and register_project_resource on behalf of this program
+    #[account(seeds = [b"fee_authority"], bump)]
+    pub fee_authority: UncheckedAccount<'info>,
+    /// CHECK: Validated by sol-mind-protocol in record_fees
+    #[account(mut)]
+    pub protocol_fee_stats: UncheckedAccount<'info>,
+    /// CHECK: Validated by sol-mind-protocol in record_fees
+    #[account(mut)]
+    pub project_fee_stats: UncheckedAccount<'info>,
+
+    pub fee_mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub fee_payer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub protocol_treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub sol_mind_protocol_program: Program<'info, SolMindProtocol>,
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> CreateTradeHub<'info> {
-    pub fn create_trade_hub(&mut self, name: String, fee_bps: u64, bump: u8) -> Result<()> {
-        pay_protocol_fee(
+    pub fn create_trade_hub(
+        &mut self,
+        name: String,
+        fee_bps: u64,
+        bumps: &CreateTradeHubBumps,
+    ) -> Result<TradeHubCreated> {
+        let project_fee_override =
+            ProjectFeeOverride::load(&self.project_fee_override.to_account_info())?;
+
+        let protocol_fee = pay_protocol_fee(
             &self.payer,
             &self.protocol_config,
             &self.protocol_treasury.to_account_info(),
             &self.system_program,
+            FeeTokenAccounts::from_optional(
+                &self.fee_mint,
+                &self.fee_payer_token_account,
+                &self.protocol_treasury_token_account,
+                &self.token_program,
+            ),
+            project_fee_override.as_ref(),
+            None,
             Operation::CreateTradeHub,
             None,
         )?;
 
+        record_charged_fees(
+            self.sol_mind_protocol_program.to_account_info(),
+            RecordFees {
+                fee_authority: self.fee_authority.to_account_info(),
+                protocol_fee_stats: self.protocol_fee_stats.to_account_info(),
+                project_config: self.project_config.to_account_info(),
+                project_fee_stats: self.project_fee_stats.to_account_info(),
+            },
+            bumps.fee_authority,
+            protocol_fee,
+            0,
+        )?;
+
+        register_resource(
+            self.sol_mind_protocol_program.to_account_info(),
+            RegisterProjectResource {
+                fee_authority: self.fee_authority.to_account_info(),
+                authority: self.authority.to_account_info(),
+                authority_role: self.authority_role.to_account_info(),
+                project_config: self.project_config.to_account_info(),
+            },
+            bumps.fee_authority,
+            ProjectResource::TradeHub,
+        )?;
+
         self.trade_hub.set_inner(TradeHub {
             project: self.project_config.key(),
-            name,
+            name: name.clone(),
             fee_bps: fee_bps,
-            bump,
+            bump: bumps.trade_hub,
+            listings_count: 0,
         });
-        Ok(())
+
+        Ok(TradeHubCreated {
+            project_config: self.project_config.key(),
+            trade_hub: self.trade_hub.key(),
+            name,
+            fee_bps,
+        })
     }
 }
@@ -4,9 +4,11 @@ use mpl_core::{
     types::{FreezeDelegate, Plugin, PluginType},
 };
 
+use crate::events::AssetDelisted;
 use crate::state::TradeHub;
 use crate::Listing;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct DelistAsset<'info> {
     #[account(mut)]
@@ -32,6 +34,7 @@ pub struct DelistAsset<'info> {
     )]
     pub listing: Account<'info, Listing>,
     #[account(
+        mut,
         seeds = [
             b"trade_hub",
             trade_hub.name.as_bytes(),
@@ -48,7 +51,7 @@ pub struct DelistAsset<'info> {
 }
 
 impl<'info> DelistAsset<'info> {
-    pub fn delist_asset(&mut self) -> Result<()> {
+    pub fn delist_asset(&mut self) -> Result<AssetDelisted> {
         let project_key = self.trade_hub.project.key();
         let seeds = &[
             b"trade_hub",
@@ -85,6 +88,12 @@ impl<'info> DelistAsset<'info> {
             .plugin_type(PluginType::TransferDelegate)
             .invoke()?;
 
-        Ok(())
+        self.trade_hub.remove_listing();
+
+        Ok(AssetDelisted {
+            trade_hub: self.trade_hub.key(),
+            asset: self.asset.key(),
+            owner: self.owner.key(),
+        })
     }
 }
@@ -6,9 +6,11 @@ use mpl_core::{
     types::{FreezeDelegate, Plugin, PluginAuthority, PluginType, TransferDelegate},
 };
 
+use crate::events::AssetListed;
 use crate::state::TradeHub;
 use crate::{errors::ErrorCode, Listing};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct ListAsset<'info> {
     #[account(mut)]
@@ -33,6 +35,7 @@ pub struct ListAsset<'info> {
     )]
     pub listing: Account<'info, Listing>,
     #[account(
+        mut,
         seeds = [
             b"trade_hub",
             trade_hub.name.as_bytes(),
@@ -49,7 +52,7 @@ pub struct ListAsset<'info> {
 }
 
 impl<'info> ListAsset<'info> {
-    pub fn create_listing(&mut self, price: u64, bump: u8) -> Result<()> {
+    pub fn create_listing(&mut self, price: u64, bump: u8) -> Result<AssetListed> {
         let transfer_delegate_plugint = fetch_plugin::<BaseAssetV1, TransferDelegate>(
             &self.asset.to_account_info(),
             PluginType::TransferDelegate,
@@ -117,7 +120,14 @@ impl<'info> ListAsset<'info> {
             created_at: Clock::get()?.unix_timestamp,
             bump,
         });
+        self.trade_hub.add_listing()?;
 
-        Ok(())
+        Ok(AssetListed {
+            trade_hub: self.trade_hub.key(),
+            listing: self.listing.key(),
+            asset: self.asset.key(),
+            owner: self.owner.key(),
+            price,
+        })
     }
 }
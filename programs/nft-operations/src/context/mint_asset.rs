@@ -1,24 +1,39 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use mpl_core::types::PluginAuthorityPair;
 use mpl_core::{instructions::CreateV1CpiBuilder, types::DataState};
-use sol_mind_protocol::helpers::pay_protocol_fee;
-use sol_mind_protocol::{Operation, ProjectConfig, ProtocolConfig};
+use sol_mind_protocol::cpi::accounts::RecordFees;
+use sol_mind_protocol::helpers::{pay_protocol_fee, FeeTokenAccounts};
+use sol_mind_protocol::program::SolMindProtocol;
+use sol_mind_protocol::{
+    Operation, ProjectConfig, ProjectFeeOverride, ProjectRole, ProtocolConfig, Referrer, Role,
+};
 
 use crate::errors::ErrorCode;
-use crate::state::MinterConfig;
+use crate::events::AssetMinted;
+use crate::helpers::record_charged_fees;
+use crate::state::{AllowlistProof, MintRecord, MinterConfig};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct MintAsset<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     #[account(mut)]
     pub owner: Signer<'info>,
+    /// Any signer, such as the payer, while the minter config is in public mode
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: Role PDA of the authority, only read when it has been created
     #[account(
-        mut,
-        constraint = project_config.check_authorities(authority.key) @ ErrorCode::Unauthorized
+        seeds = [b"project_role", project_config.key().as_ref(), authority.key().as_ref()],
+        bump,
+        seeds::program = sol_mind_protocol::ID,
+        constraint = minter_config.public_mint || ProjectRole::grants(&authority_role, Role::Minter)? @ ErrorCode::Unauthorized,
     )]
-    pub authority: Signer<'info>,
+    pub authority_role: UncheckedAccount<'info>,
     #[account(mut)]
     pub mint: Signer<'info>,
     /// CHECK: Collection account validated by mpl_core program
@@ -34,10 +49,19 @@ pub struct MintAsset<'info> {
         bump = minter_config.bump,
     )]
     pub minter_config: Account<'info, MinterConfig>,
+    /// Mints counted for `owner`, required in allowlist phases and with a wallet mint limit
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + MintRecord::INIT_SPACE,
+        seeds = [b"mint_record", minter_config.key().as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub mint_record: Option<Account<'info, MintRecord>>,
     #[account(
         seeds = [
             b"project",
-            project_config.owner.as_ref(),
+            project_config.creator.as_ref(),
             project_config.protocol_config.as_ref(),
             &project_config.project_id.to_le_bytes(),
         ],
@@ -45,12 +69,18 @@ pub struct MintAsset<'info> {
         seeds::program = sol_mind_protocol::ID,
     )]
     pub project_config: Account<'info, ProjectConfig>,
-
     #[account(
-        seeds = [b"sol-mind-protocol"],
-        bump = protocol_config.bump,
+        mut,
+        seeds = [b"treasury", project_config.key().as_ref()],
+        bump = project_config.treasury_bump,
         seeds::program = sol_mind_protocol::ID,
     )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(
+        address = project_config.protocol_config @ ErrorCode::InvalidProtocolConfig,
+        constraint = !protocol_config.is_operation_paused(Operation::MintAsset) @ ErrorCode::OperationPaused,
+    )]
     pub protocol_config: Account<'info, ProtocolConfig>,
 
     #[account(
@@ -61,6 +91,40 @@ pub struct MintAsset<'info> {
     )]
     pub protocol_treasury: SystemAccount<'info>,
 
+    /// CHECK: Fee override PDA of the project, only read when it has been created
+    #[account(
+        seeds = [b"fee_override", project_config.key().as_ref()],
+        bump,
+        seeds::program = sol_mind_protocol::ID,
+    )]
+    pub project_fee_override: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"referrer", protocol_config.key().as_ref(), referrer.wallet.as_ref()],
+        bump = referrer.bump,
+        seeds::program = sol_mind_protocol::ID,
+    )]
+    pub referrer: Option<Account<'info, Referrer>>,
+
+    /// CHECK: PDA signing record_fees on behalf of this program
+    #[account(seeds = [b"fee_authority"], bump)]
+    pub fee_authority: UncheckedAccount<'info>,
+    /// CHECK: Validated by sol-mind-protocol in record_fees
+    #[account(mut)]
+    pub protocol_fee_stats: UncheckedAccount<'info>,
+    /// CHECK: Validated by sol-mind-protocol in record_fees
+    #[account(mut)]
+    pub project_fee_stats: UncheckedAccount<'info>,
+
+    pub fee_mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub fee_payer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub protocol_treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub sol_mind_protocol_program: Program<'info, SolMindProtocol>,
     pub system_program: Program<'info, System>,
     /// CHECK: Verified by address constraint to mpl_core::ID
     #[account(address = mpl_core::ID)]
@@ -73,20 +137,106 @@ impl<'info> MintAsset<'info> {
         name: Option<String>,
         uri: Option<String>,
         plugins: Option<Vec<PluginAuthorityPair>>,
-    ) -> Result<()> {
+        allowlist: Option<AllowlistProof>,
+        bumps: &MintAssetBumps,
+    ) -> Result<AssetMinted> {
         if self.minter_config.max_supply > 0 {
             require!(
                 self.minter_config.mints_counter < self.minter_config.max_supply,
                 ErrorCode::MaxSupplyReached
             );
         }
-        pay_protocol_fee(
+        // Minter role holders mint outside the phases and allowlists, e.g. for airdrops, at
+        // `mint_price`
+        let phase = if ProjectRole::grants(&self.authority_role, Role::Minter)? {
+            None
+        } else {
+            self.minter_config
+                .record_phase_mint(Clock::get()?.unix_timestamp)?
+        };
+        let mint_price = phase.map_or(self.minter_config.mint_price, |phase| phase.price);
+
+        // The allowlist grants its mints to the wallet receiving the asset, for each phase
+        if let Some((phase, merkle_root)) =
+            phase.and_then(|phase| phase.merkle_root.map(|root| (phase, root)))
+        {
+            let allowlist = allowlist.ok_or(ErrorCode::AllowlistProofRequired)?;
+            require!(
+                allowlist.verify(&merkle_root, &self.owner.key()),
+                ErrorCode::NotAllowlisted
+            );
+
+            let mint_record = self
+                .mint_record
+                .as_ref()
+                .ok_or(ErrorCode::MintRecordRequired)?;
+            require!(
+                mint_record.minted_in_phase(phase.start_at) < allowlist.allowed_count,
+                ErrorCode::AllowlistMintLimitReached
+            );
+        }
+
+        if self.minter_config.wallet_mint_limit > 0 {
+            let mint_record = self
+                .mint_record
+                .as_ref()
+                .ok_or(ErrorCode::MintRecordRequired)?;
+            require!(
+                mint_record.minted < self.minter_config.wallet_mint_limit,
+                ErrorCode::WalletMintLimitReached
+            );
+        }
+
+        if let (Some(mint_record), Some(bump)) = (&mut self.mint_record, bumps.mint_record) {
+            mint_record.record_mint(
+                self.minter_config.key(),
+                self.owner.key(),
+                phase.as_ref(),
+                bump,
+            )?;
+        }
+        let project_fee_override =
+            ProjectFeeOverride::load(&self.project_fee_override.to_account_info())?;
+
+        let protocol_fee = pay_protocol_fee(
             &self.payer,
             &self.protocol_config,
             &self.protocol_treasury.to_account_info(),
             &self.system_program,
+            FeeTokenAccounts::from_optional(
+                &self.fee_mint,
+                &self.fee_payer_token_account,
+                &self.protocol_treasury_token_account,
+                &self.token_program,
+            ),
+            project_fee_override.as_ref(),
+            self.referrer.as_ref(),
             Operation::MintAsset,
-            None,
+            Some(mint_price),
+        )?;
+
+        // The protocol fee comes on top of the mint price, which goes to the project in full
+        if mint_price > 0 {
+            let cpi_program = self.system_program.to_account_info();
+            let cpi_accounts = Transfer {
+                from: self.payer.to_account_info(),
+                to: self.treasury.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+            transfer(cpi_ctx, mint_price)?;
+        }
+
+        record_charged_fees(
+            self.sol_mind_protocol_program.to_account_info(),
+            RecordFees {
+                fee_authority: self.fee_authority.to_account_info(),
+                protocol_fee_stats: self.protocol_fee_stats.to_account_info(),
+                project_config: self.project_config.to_account_info(),
+                project_fee_stats: self.project_fee_stats.to_account_info(),
+            },
+            bumps.fee_authority,
+            protocol_fee,
+            0,
         )?;
 
         let (asset_name, asset_uri) = match &self.minter_config.assets_config {
@@ -156,12 +306,19 @@ impl<'info> MintAsset<'info> {
 
         builder.invoke_signed(signer_seeds)?;
 
+        let mint_number = self.minter_config.mints_counter;
         self.minter_config.mints_counter = self
             .minter_config
             .mints_counter
             .checked_add(1)
             .expect("Mints counter overflowed");
 
-        Ok(())
+        Ok(AssetMinted {
+            project_config: self.project_config.key(),
+            minter_config: self.minter_config.key(),
+            asset: self.mint.key(),
+            owner: self.owner.key(),
+            mint_number,
+        })
     }
 }
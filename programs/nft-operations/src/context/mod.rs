@@ -1,13 +1,21 @@
+pub mod close_mint_record;
+pub mod close_minter_config;
+pub mod close_trade_hub;
 pub mod create_minter_config;
 pub mod create_trade_hub;
 pub mod delist_asset;
 pub mod list_asset;
 pub mod mint_asset;
 pub mod purchase;
+pub mod update_minter_config;
 
+pub use close_mint_record::*;
+pub use close_minter_config::*;
+pub use close_trade_hub::*;
 pub use create_minter_config::*;
 pub use create_trade_hub::*;
 pub use delist_asset::*;
 pub use list_asset::*;
 pub use mint_asset::*;
 pub use purchase::*;
+pub use update_minter_config::*;
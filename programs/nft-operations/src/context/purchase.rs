@@ -2,15 +2,24 @@ use anchor_lang::{
     prelude::*,
     system_program::{transfer, Transfer},
 };
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use mpl_core::{
     instructions::{TransferV1CpiBuilder, UpdatePluginV1CpiBuilder},
     types::{FreezeDelegate, Plugin},
 };
-use sol_mind_protocol::{helpers::pay_protocol_fee, Operation, ProjectConfig, ProtocolConfig};
+use sol_mind_protocol::{
+    cpi::accounts::RecordFees,
+    helpers::{pay_protocol_fee, FeeTokenAccounts},
+    program::SolMindProtocol,
+    Operation, ProjectConfig, ProjectFeeOverride, ProtocolConfig, Referrer,
+};
 
 use crate::errors::ErrorCode;
+use crate::events::AssetPurchased;
+use crate::helpers::record_charged_fees;
 use crate::state::{Listing, TradeHub};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct Purchase<'info> {
     #[account(mut)]
@@ -36,6 +45,8 @@ pub struct Purchase<'info> {
     )]
     pub listing: Account<'info, Listing>,
     #[account(
+        mut,
+        constraint = trade_hub.project == project_config.key() @ ErrorCode::InvalidTradeHubProject,
         seeds = [
             b"trade_hub",
             trade_hub.name.as_bytes(),
@@ -54,7 +65,7 @@ pub struct Purchase<'info> {
     #[account(
         seeds = [
             b"project",
-            project_config.owner.as_ref(),
+            project_config.creator.as_ref(),
             project_config.protocol_config.as_ref(),
             &project_config.project_id.to_le_bytes(),
         ],
@@ -63,9 +74,8 @@ pub struct Purchase<'info> {
     )]
     pub project_config: Account<'info, ProjectConfig>,
     #[account(
-        seeds = [b"sol-mind-protocol"],
-        bump = protocol_config.bump,
-        seeds::program = sol_mind_protocol::ID,
+        address = project_config.protocol_config @ ErrorCode::InvalidProtocolConfig,
+        constraint = !protocol_config.is_operation_paused(Operation::TradeNFT) @ ErrorCode::OperationPaused,
     )]
     pub protocol_config: Account<'info, ProtocolConfig>,
     #[account(
@@ -76,6 +86,40 @@ pub struct Purchase<'info> {
     )]
     pub protocol_treasury: SystemAccount<'info>,
 
+    /// CHECK: Fee override PDA of the project, only read when it has been created
+    #[account(
+        seeds = [b"fee_override", project_config.key().as_ref()],
+        bump,
+        seeds::program = sol_mind_protocol::ID,
+    )]
+    pub project_fee_override: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"referrer", protocol_config.key().as_ref(), referrer.wallet.as_ref()],
+        bump = referrer.bump,
+        seeds::program = sol_mind_protocol::ID,
+    )]
+    pub referrer: Option<Account<'info, Referrer>>,
+
+    /// CHECK: PDA signing record_fees on behalf of this program
+    #[account(seeds = [b"fee_authority"], bump)]
+    pub fee_authority: UncheckedAccount<'info>,
+    /// CHECK: Validated by sol-mind-protocol in record_fees
+    #[account(mut)]
+    pub protocol_fee_stats: UncheckedAccount<'info>,
+    /// CHECK: Validated by sol-mind-protocol in record_fees
+    #[account(mut)]
+    pub project_fee_stats: UncheckedAccount<'info>,
+
+    pub fee_mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub fee_payer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub protocol_treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub sol_mind_protocol_program: Program<'info, SolMindProtocol>,
     pub system_program: Program<'info, System>,
     /// CHECK: Verified by address constraint to mpl_core::ID
     #[account(address = mpl_core::ID)]
@@ -83,16 +127,31 @@ pub struct Purchase<'info> {
 }
 
 impl<'info> Purchase<'info> {
-    pub fn purchase_asset(&mut self, max_price: u64) -> Result<()> {
+    pub fn purchase_asset(
+        &mut self,
+        max_price: u64,
+        bumps: &PurchaseBumps,
+    ) -> Result<AssetPurchased> {
         let asset_price = self.listing.price;
 
         require!(asset_price <= max_price, ErrorCode::MaxPriceExceeded);
 
+        let project_fee_override =
+            ProjectFeeOverride::load(&self.project_fee_override.to_account_info())?;
+
         let protocol_fee = pay_protocol_fee(
             &self.buyer,
             &self.protocol_config,
             &self.protocol_treasury.to_account_info(),
             &self.system_program,
+            FeeTokenAccounts::from_optional(
+                &self.fee_mint,
+                &self.fee_payer_token_account,
+                &self.protocol_treasury_token_account,
+                &self.token_program,
+            ),
+            project_fee_override.as_ref(),
+            self.referrer.as_ref(),
             Operation::TradeNFT,
             Some(asset_price),
         )?;
@@ -107,8 +166,14 @@ impl<'info> Purchase<'info> {
         let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
         transfer(cpi_ctx, trade_hub_fee)?;
 
+        // A protocol fee paid in tokens comes on top of the price instead of out of it
+        let protocol_fee_lamports = match protocol_fee.mint {
+            Some(_) => 0,
+            None => protocol_fee.amount,
+        };
+
         let seller_amount = asset_price
-            .checked_sub(protocol_fee)
+            .checked_sub(protocol_fee_lamports)
             .ok_or(ErrorCode::MathOverflow)?
             .checked_sub(trade_hub_fee)
             .ok_or(ErrorCode::MathOverflow)?;
@@ -121,6 +186,19 @@ impl<'info> Purchase<'info> {
         let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
         transfer(cpi_ctx, seller_amount)?;
 
+        record_charged_fees(
+            self.sol_mind_protocol_program.to_account_info(),
+            RecordFees {
+                fee_authority: self.fee_authority.to_account_info(),
+                protocol_fee_stats: self.protocol_fee_stats.to_account_info(),
+                project_config: self.project_config.to_account_info(),
+                project_fee_stats: self.project_fee_stats.to_account_info(),
+            },
+            bumps.fee_authority,
+            protocol_fee,
+            trade_hub_fee,
+        )?;
+
         let project_key = self.trade_hub.project.key();
         let seeds = &[
             b"trade_hub",
@@ -147,6 +225,18 @@ impl<'info> Purchase<'info> {
             .authority(Some(&self.trade_hub.to_account_info()))
             .new_owner(&self.buyer.to_account_info())
             .invoke_signed(signer_seeds)?;
-        Ok(())
+
+        self.trade_hub.remove_listing();
+
+        Ok(AssetPurchased {
+            trade_hub: self.trade_hub.key(),
+            asset: self.asset.key(),
+            seller: self.owner.key(),
+            buyer: self.buyer.key(),
+            price: asset_price,
+            protocol_fee: protocol_fee.amount,
+            protocol_fee_mint: protocol_fee.mint,
+            trade_hub_fee,
+        })
     }
 }
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+
+use sol_mind_protocol::{ProjectConfig, ProjectRole, Role};
+
+use crate::errors::ErrorCode;
+use crate::events::{MintPhasesSet, PublicMintSet, WalletMintLimitSet};
+use crate::state::{MintPhase, MinterConfig};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateMinterConfig<'info> {
+    pub authority: Signer<'info>,
+    /// CHECK: Role PDA of the authority, only read when it has been created
+    #[account(
+        seeds = [b"project_role", project_config.key().as_ref(), authority.key().as_ref()],
+        bump,
+        seeds::program = sol_mind_protocol::ID,
+        constraint = ProjectRole::grants(&authority_role, Role::Minter)? @ ErrorCode::Unauthorized,
+    )]
+    pub authority_role: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"minter_config",
+            project_config.key().as_ref(),
+            minter_config.name.as_bytes(),
+        ],
+        bump = minter_config.bump,
+    )]
+    pub minter_config: Account<'info, MinterConfig>,
+    #[account(
+        seeds = [
+            b"project",
+            project_config.creator.as_ref(),
+            project_config.protocol_config.as_ref(),
+            &project_config.project_id.to_le_bytes(),
+        ],
+        bump = project_config.bump,
+        seeds::program = sol_mind_protocol::ID,
+    )]
+    pub project_config: Account<'info, ProjectConfig>,
+}
+
+impl<'info> UpdateMinterConfig<'info> {
+    /// Lets any payer mint at the mint price; `Minter` role holders can still mint, e.g. for
+    /// airdrops, whether or not public minting is open.
+    pub fn set_public_mint(&mut self, public_mint: bool) -> Result<PublicMintSet> {
+        self.minter_config.public_mint = public_mint;
+
+        Ok(PublicMintSet {
+            project_config: self.project_config.key(),
+            minter_config: self.minter_config.key(),
+            authority: self.authority.key(),
+            public_mint,
+        })
+    }
+
+    /// Replaces the mint phases, restarting their mint counts. `max_supply` still caps the
+    /// total across phases, and `Minter` role holders mint outside them. Once minting has
+    /// ended, wallets can close their mint records, so reopening it would reset their counts.
+    pub fn set_mint_phases(&mut self, mut phases: Vec<MintPhase>) -> Result<MintPhasesSet> {
+        require!(
+            !self
+                .minter_config
+                .minting_ended(Clock::get()?.unix_timestamp),
+            ErrorCode::MintingEnded
+        );
+        MinterConfig::validate_phases(&phases)?;
+
+        for phase in phases.iter_mut() {
+            phase.minted = 0;
+        }
+        self.minter_config.phases = phases.clone();
+
+        Ok(MintPhasesSet {
+            project_config: self.project_config.key(),
+            minter_config: self.minter_config.key(),
+            authority: self.authority.key(),
+            phases,
+        })
+    }
+
+    /// Caps the mints of each wallet across all phases. Mints made without a mint record,
+    /// before the limit was set, are not counted.
+    pub fn set_wallet_mint_limit(&mut self, wallet_mint_limit: u64) -> Result<WalletMintLimitSet> {
+        self.minter_config.wallet_mint_limit = wallet_mint_limit;
+
+        Ok(WalletMintLimitSet {
+            project_config: self.project_config.key(),
+            minter_config: self.minter_config.key(),
+            authority: self.authority.key(),
+            wallet_mint_limit,
+        })
+    }
+}
@@ -26,4 +26,36 @@ pub enum ErrorCode {
     MaxPriceExceeded,
     #[msg("Math operation overflow")]
     MathOverflow,
+    #[msg("Operation is paused by the protocol")]
+    OperationPaused,
+    #[msg("Protocol config is not the one of the project")]
+    InvalidProtocolConfig,
+    #[msg("Project subscription is delinquent")]
+    SubscriptionDelinquent,
+    #[msg("Mint phases must be ordered, non-overlapping windows within the limit")]
+    InvalidMintPhases,
+    #[msg("No mint phase is active")]
+    MintPhaseNotActive,
+    #[msg("Mint phase supply cap reached")]
+    PhaseSupplyReached,
+    #[msg("Mint phase requires an allowlist proof")]
+    AllowlistProofRequired,
+    #[msg("Wallet is not on the allowlist")]
+    NotAllowlisted,
+    #[msg("Mint requires the mint record of the wallet")]
+    MintRecordRequired,
+    #[msg("Wallet minted its allowlist allowance")]
+    AllowlistMintLimitReached,
+    #[msg("Wallet reached the mint limit of the minter config")]
+    WalletMintLimitReached,
+    #[msg("Minting has not ended for the minter config")]
+    MintingNotEnded,
+    #[msg("Trade hub still has open listings")]
+    TradeHubHasListings,
+    #[msg("Trade hub does not belong to the project")]
+    InvalidTradeHubProject,
+    #[msg("Minting has ended for the minter config")]
+    MintingEnded,
+    #[msg("Trade hub was created before its listings were counted")]
+    TradeHubListingsUncounted,
 }
@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+
+use crate::state::MintPhase;
+
+#[event]
+pub struct MinterConfigCreated {
+    pub project_config: Pubkey,
+    pub minter_config: Pubkey,
+    pub name: String,
+    pub mint_price: u64,
+    pub max_supply: u64,
+    pub collection: Option<Pubkey>,
+}
+
+#[event]
+pub struct PublicMintSet {
+    pub project_config: Pubkey,
+    pub minter_config: Pubkey,
+    pub authority: Pubkey,
+    pub public_mint: bool,
+}
+
+#[event]
+pub struct MintPhasesSet {
+    pub project_config: Pubkey,
+    pub minter_config: Pubkey,
+    pub authority: Pubkey,
+    pub phases: Vec<MintPhase>,
+}
+
+#[event]
+pub struct WalletMintLimitSet {
+    pub project_config: Pubkey,
+    pub minter_config: Pubkey,
+    pub authority: Pubkey,
+    pub wallet_mint_limit: u64,
+}
+
+#[event]
+pub struct AssetMinted {
+    pub project_config: Pubkey,
+    pub minter_config: Pubkey,
+    pub asset: Pubkey,
+    pub owner: Pubkey,
+    pub mint_number: u64, // mints_counter of the minter config before this mint
+}
+
+#[event]
+pub struct MinterConfigClosed {
+    pub project_config: Pubkey,
+    pub minter_config: Pubkey,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct MintRecordClosed {
+    pub minter_config: Pubkey,
+    pub wallet: Pubkey,
+    pub minted: u64,
+}
+
+#[event]
+pub struct TradeHubCreated {
+    pub project_config: Pubkey,
+    pub trade_hub: Pubkey,
+    pub name: String,
+    pub fee_bps: u64,
+}
+
+#[event]
+pub struct TradeHubClosed {
+    pub project_config: Pubkey,
+    pub trade_hub: Pubkey,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct AssetListed {
+    pub trade_hub: Pubkey,
+    pub listing: Pubkey,
+    pub asset: Pubkey,
+    pub owner: Pubkey,
+    pub price: u64,
+}
+
+#[event]
+pub struct AssetDelisted {
+    pub trade_hub: Pubkey,
+    pub asset: Pubkey,
+    pub owner: Pubkey,
+}
+
+#[event]
+pub struct AssetPurchased {
+    pub trade_hub: Pubkey,
+    pub asset: Pubkey,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub price: u64,
+    pub protocol_fee: u64,
+    pub protocol_fee_mint: Option<Pubkey>, // None when the protocol fee was paid in lamports
+    pub trade_hub_fee: u64,
+}
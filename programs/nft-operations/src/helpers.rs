@@ -1,6 +1,11 @@
 use crate::errors::ErrorCode;
 use anchor_lang::prelude::*;
 use mpl_core::types::PluginAuthorityPair;
+use sol_mind_protocol::cpi::accounts::{
+    RecordFees, RegisterProjectResource, UnregisterProjectResource,
+};
+use sol_mind_protocol::cpi::{record_fees, register_project_resource, unregister_project_resource};
+use sol_mind_protocol::{FeePayment, ProjectResource};
 
 pub fn decoded_core_plugins(
     plugins: Option<Vec<Vec<u8>>>,
@@ -18,3 +23,51 @@ pub fn decoded_core_plugins(
         None => Ok(None),
     }
 }
+
+/// Records the fees charged by an instruction in the protocol and project fee stats, signing
+/// as this program's fee authority.
+pub fn record_charged_fees<'info>(
+    sol_mind_protocol_program: AccountInfo<'info>,
+    accounts: RecordFees<'info>,
+    fee_authority_bump: u8,
+    protocol_fee: FeePayment,
+    trade_hub_fee: u64,
+) -> Result<()> {
+    let seeds: &[&[u8]] = &[b"fee_authority", &[fee_authority_bump]];
+    let signer_seeds = &[seeds];
+
+    let cpi_ctx = CpiContext::new_with_signer(sol_mind_protocol_program, accounts, signer_seeds);
+
+    record_fees(cpi_ctx, protocol_fee, trade_hub_fee)
+}
+
+/// Counts a new minter config or trade hub on its project, signing as this program's fee
+/// authority so only nft-operations can register resources.
+pub fn register_resource<'info>(
+    sol_mind_protocol_program: AccountInfo<'info>,
+    accounts: RegisterProjectResource<'info>,
+    fee_authority_bump: u8,
+    resource: ProjectResource,
+) -> Result<()> {
+    let seeds: &[&[u8]] = &[b"fee_authority", &[fee_authority_bump]];
+    let signer_seeds = &[seeds];
+
+    let cpi_ctx = CpiContext::new_with_signer(sol_mind_protocol_program, accounts, signer_seeds);
+
+    register_project_resource(cpi_ctx, resource)
+}
+
+/// Releases a closed minter config or trade hub from its project.
+pub fn unregister_resource<'info>(
+    sol_mind_protocol_program: AccountInfo<'info>,
+    accounts: UnregisterProjectResource<'info>,
+    fee_authority_bump: u8,
+    resource: ProjectResource,
+) -> Result<()> {
+    let seeds: &[&[u8]] = &[b"fee_authority", &[fee_authority_bump]];
+    let signer_seeds = &[seeds];
+
+    let cpi_ctx = CpiContext::new_with_signer(sol_mind_protocol_program, accounts, signer_seeds);
+
+    unregister_project_resource(cpi_ctx, resource)
+}
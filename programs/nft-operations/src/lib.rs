@@ -4,6 +4,7 @@ declare_id!("Anv3tR3s1eQqQE1a5wnhtYdi6dkjv9rTn34V6pom9C9q");
 
 pub mod context;
 pub mod errors;
+pub mod events;
 pub mod helpers;
 pub mod state;
 
@@ -24,7 +25,7 @@ pub mod nft_operations {
         plugins: Option<Vec<Vec<u8>>>,
     ) -> Result<()> {
         let decoded_plugins = decoded_core_plugins(plugins)?;
-        ctx.accounts.create_minter_config(
+        let event = ctx.accounts.create_minter_config(
             name,
             mint_price,
             max_supply,
@@ -32,7 +33,24 @@ pub mod nft_operations {
             uri,
             decoded_plugins,
             &ctx.bumps,
-        )
+        )?;
+
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    pub fn set_public_mint(ctx: Context<UpdateMinterConfig>, public_mint: bool) -> Result<()> {
+        let event = ctx.accounts.set_public_mint(public_mint)?;
+
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    pub fn set_mint_phases(ctx: Context<UpdateMinterConfig>, phases: Vec<MintPhase>) -> Result<()> {
+        let event = ctx.accounts.set_mint_phases(phases)?;
+
+        emit_cpi!(event);
+        Ok(())
     }
 
     pub fn mint_asset(
@@ -40,10 +58,40 @@ pub mod nft_operations {
         name: Option<String>,
         uri: Option<String>,
         plugins: Option<Vec<Vec<u8>>>,
+        allowlist: Option<AllowlistProof>,
     ) -> Result<()> {
         let decoded_plugins = decoded_core_plugins(plugins)?;
 
-        ctx.accounts.mint_asset(name, uri, decoded_plugins)
+        let event = ctx
+            .accounts
+            .mint_asset(name, uri, decoded_plugins, allowlist, &ctx.bumps)?;
+
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    pub fn set_wallet_mint_limit(
+        ctx: Context<UpdateMinterConfig>,
+        wallet_mint_limit: u64,
+    ) -> Result<()> {
+        let event = ctx.accounts.set_wallet_mint_limit(wallet_mint_limit)?;
+
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    pub fn close_mint_record(ctx: Context<CloseMintRecord>) -> Result<()> {
+        let event = ctx.accounts.close_mint_record()?;
+
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    pub fn close_minter_config(ctx: Context<CloseMinterConfig>) -> Result<()> {
+        let event = ctx.accounts.close_minter_config(&ctx.bumps)?;
+
+        emit_cpi!(event);
+        Ok(())
     }
 
     pub fn create_trade_hub(
@@ -51,19 +99,37 @@ pub mod nft_operations {
         name: String,
         fee_bps: u64,
     ) -> Result<()> {
-        ctx.accounts
-            .create_trade_hub(name, fee_bps, ctx.bumps.trade_hub)
+        let event = ctx.accounts.create_trade_hub(name, fee_bps, &ctx.bumps)?;
+
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    pub fn close_trade_hub(ctx: Context<CloseTradeHub>) -> Result<()> {
+        let event = ctx.accounts.close_trade_hub(&ctx.bumps)?;
+
+        emit_cpi!(event);
+        Ok(())
     }
 
     pub fn list_asset(ctx: Context<ListAsset>, price: u64) -> Result<()> {
-        ctx.accounts.create_listing(price, ctx.bumps.listing)
+        let event = ctx.accounts.create_listing(price, ctx.bumps.listing)?;
+
+        emit_cpi!(event);
+        Ok(())
     }
 
     pub fn delist_asset(ctx: Context<DelistAsset>) -> Result<()> {
-        ctx.accounts.delist_asset()
+        let event = ctx.accounts.delist_asset()?;
+
+        emit_cpi!(event);
+        Ok(())
     }
 
     pub fn purchase_asset(ctx: Context<Purchase>, max_price: u64) -> Result<()> {
-        ctx.accounts.purchase_asset(max_price)
+        let event = ctx.accounts.purchase_asset(max_price, &ctx.bumps)?;
+
+        emit_cpi!(event);
+        Ok(())
     }
 }
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::MintPhase;
+
+/// Mints of a wallet from a minter config, counted when the wallet mints with a record.
+#[account]
+#[derive(InitSpace)]
+pub struct MintRecord {
+    pub minter_config: Pubkey,
+    pub wallet: Pubkey,
+    pub minted: u64,
+    pub bump: u8,
+    pub phase_start_at: i64, // start of the last mint phase the wallet minted in
+    pub phase_minted: u64,   // mints of the wallet in that phase
+}
+
+impl MintRecord {
+    /// Mints of the wallet in the phase starting at `start_at`. Phases never overlap, so their
+    /// start identifies them even after the schedule is replaced.
+    pub fn minted_in_phase(&self, start_at: i64) -> u64 {
+        if self.phase_start_at == start_at {
+            self.phase_minted
+        } else {
+            0
+        }
+    }
+
+    pub fn record_mint(
+        &mut self,
+        minter_config: Pubkey,
+        wallet: Pubkey,
+        phase: Option<&MintPhase>,
+        bump: u8,
+    ) -> Result<()> {
+        self.minter_config = minter_config;
+        self.wallet = wallet;
+        self.bump = bump;
+        self.minted = self.minted.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+
+        if let Some(phase) = phase {
+            self.phase_minted = self
+                .minted_in_phase(phase.start_at)
+                .checked_add(1)
+                .ok_or(ErrorCode::MathOverflow)?;
+            self.phase_start_at = phase.start_at;
+        }
+
+        Ok(())
+    }
+}
@@ -1,4 +1,9 @@
 use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
+
+use crate::errors::ErrorCode;
+
+pub const MAX_MINT_PHASES: usize = 5;
 
 #[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Default)]
 pub struct AssetsConfig {
@@ -8,6 +13,56 @@ pub struct AssetsConfig {
     pub asset_uri_prefix: String,
 }
 
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct MintPhase {
+    pub price: u64,
+    pub start_at: i64,   // unix timestamp
+    pub end_at: i64,     // unix timestamp, exclusive
+    pub supply_cap: u64, // if 0 unlimited
+    pub minted: u64,
+    pub merkle_root: Option<[u8; 32]>, // allowlist of the phase, None for anyone
+}
+
+impl MintPhase {
+    pub fn is_active(&self, now: i64) -> bool {
+        self.start_at <= now && now < self.end_at
+    }
+}
+
+/// Merkle proof that a wallet is on the allowlist of a mint phase, with the number of mints the
+/// allowlist grants it.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AllowlistProof {
+    pub allowed_count: u64,
+    pub proof: Vec<[u8; 32]>,
+}
+
+impl AllowlistProof {
+    /// Leaves hash the wallet and its allowed count; nodes hash their children in sorted
+    /// order, so proofs carry no left or right flags.
+    pub fn leaf(wallet: &Pubkey, allowed_count: u64) -> [u8; 32] {
+        hashv(&[&[0u8], wallet.as_ref(), &allowed_count.to_le_bytes()]).to_bytes()
+    }
+
+    pub fn node(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+        let (left, right) = if left <= right {
+            (left, right)
+        } else {
+            (right, left)
+        };
+        hashv(&[&[1u8], left, right]).to_bytes()
+    }
+
+    pub fn verify(&self, merkle_root: &[u8; 32], wallet: &Pubkey) -> bool {
+        let mut node = Self::leaf(wallet, self.allowed_count);
+        for sibling in &self.proof {
+            node = Self::node(&node, sibling);
+        }
+
+        node == *merkle_root
+    }
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct MinterConfig {
@@ -19,4 +74,62 @@ pub struct MinterConfig {
     pub assets_config: Option<AssetsConfig>,
     pub collection: Option<Pubkey>,
     pub bump: u8,
+    pub public_mint: bool, // any payer can mint, without an authority holding the Minter role
+    #[max_len(MAX_MINT_PHASES)]
+    pub phases: Vec<MintPhase>, // ordered by time, empty to mint at `mint_price` at any time
+    pub wallet_mint_limit: u64, // mints per wallet, counted in mint records; if 0 unlimited
+}
+
+impl MinterConfig {
+    pub fn validate_phases(phases: &[MintPhase]) -> Result<()> {
+        require!(
+            phases.len() <= MAX_MINT_PHASES,
+            ErrorCode::InvalidMintPhases
+        );
+
+        for (index, phase) in phases.iter().enumerate() {
+            require!(phase.start_at < phase.end_at, ErrorCode::InvalidMintPhases);
+            if index > 0 {
+                require!(
+                    phases[index - 1].end_at <= phase.start_at,
+                    ErrorCode::InvalidMintPhases
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Counts a mint in the phase active at `now` and returns the phase. Without phases,
+    /// minting is open at any time at `mint_price`.
+    pub fn record_phase_mint(&mut self, now: i64) -> Result<Option<MintPhase>> {
+        if self.phases.is_empty() {
+            return Ok(None);
+        }
+
+        let phase = self
+            .phases
+            .iter_mut()
+            .find(|phase| phase.is_active(now))
+            .ok_or(ErrorCode::MintPhaseNotActive)?;
+
+        if phase.supply_cap > 0 {
+            require!(
+                phase.minted < phase.supply_cap,
+                ErrorCode::PhaseSupplyReached
+            );
+        }
+
+        phase.minted = phase.minted.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+        Ok(Some(*phase))
+    }
+
+    /// Whether no more assets can be minted: the max supply is reached or the last mint phase
+    /// has ended. Without either, minting never ends.
+    pub fn minting_ended(&self, now: i64) -> bool {
+        let sold_out = self.max_supply > 0 && self.mints_counter >= self.max_supply;
+        let phases_ended = self.phases.last().is_some_and(|phase| phase.end_at <= now);
+
+        sold_out || phases_ended
+    }
 }
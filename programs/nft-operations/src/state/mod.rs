@@ -1,7 +1,9 @@
 pub mod listing;
+pub mod mint_record;
 pub mod minter_config;
 pub mod trade_hub;
 
 pub use listing::*;
+pub use mint_record::*;
 pub use minter_config::*;
 pub use trade_hub::*;
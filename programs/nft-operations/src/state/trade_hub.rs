@@ -10,6 +10,7 @@ pub struct TradeHub {
     pub name: String,
     pub fee_bps: u64,
     pub bump: u8,
+    pub listings_count: u64, // open listings, the hub can only be closed without any
 }
 
 impl TradeHub {
@@ -20,4 +21,23 @@ impl TradeHub {
             .checked_div(10_000)
             .ok_or(error!(ErrorCode::FeeCalculationOverflow))
     }
+
+    pub fn add_listing(&mut self) -> Result<()> {
+        self.listings_count = self
+            .listings_count
+            .checked_add(1)
+            .ok_or(ErrorCode::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Saturates, since listings of a hub created before they were counted are not.
+    pub fn remove_listing(&mut self) {
+        self.listings_count = self.listings_count.saturating_sub(1);
+    }
+
+    /// Whether the hub has counted its listings since it was created. Hubs of the first
+    /// release were allocated without `listings_count`, and their open listings are unknown.
+    pub fn counts_listings(account_info: &AccountInfo) -> bool {
+        account_info.data_len() >= 8 + Self::INIT_SPACE
+    }
 }
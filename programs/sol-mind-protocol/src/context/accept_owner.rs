@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ProtocolError;
+use crate::events::ProjectOwnershipTransferred;
+use crate::state::ProjectConfig;
+
+#[derive(Accounts)]
+pub struct AcceptOwner<'info> {
+    pub new_owner: Signer<'info>,
+    #[account(
+        mut,
+        constraint = project_config.pending_owner == Some(new_owner.key()) @ ProtocolError::NotPendingOwner,
+        seeds = [
+            b"project",
+            project_config.creator.as_ref(),
+            project_config.protocol_config.as_ref(),
+            project_config.project_id.to_le_bytes().as_ref(),
+        ],
+        bump = project_config.bump,
+    )]
+    pub project_config: Account<'info, ProjectConfig>,
+}
+
+impl<'info> AcceptOwner<'info> {
+    pub fn accept_owner(&mut self) -> Result<()> {
+        let previous_owner = self.project_config.owner;
+
+        self.project_config.owner = self.new_owner.key();
+        self.project_config.pending_owner = None;
+
+        emit!(ProjectOwnershipTransferred {
+            project_config: self.project_config.key(),
+            previous_owner,
+            new_owner: self.new_owner.key(),
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ProtocolError;
+use crate::events::ProposalApproved;
+use crate::state::{Proposal, ProtocolConfig};
+
+#[derive(Accounts)]
+pub struct ApproveProposal<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [
+            b"sol-mind-protocol",
+            ProtocolConfig::instance_seed(protocol_config.instance_id).as_slice(),
+        ],
+        bump = protocol_config.bump,
+        constraint = protocol_config.check_admins(&admin.key()) @ ProtocolError::Unauthorized,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(
+        mut,
+        seeds = [
+            b"proposal",
+            protocol_config.key().as_ref(),
+            proposal.proposal_id.to_le_bytes().as_ref(),
+        ],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+}
+
+impl<'info> ApproveProposal<'info> {
+    pub fn approve_proposal(&mut self) -> Result<()> {
+        let admin = self.admin.key();
+        require!(
+            !self.proposal.is_expired(Clock::get()?.unix_timestamp),
+            ProtocolError::ProposalExpired
+        );
+        require!(
+            !self.proposal.has_approved(&admin),
+            ProtocolError::ProposalAlreadyApproved
+        );
+
+        // Drop approvals of keys that are no longer admins to keep room within MAX_ADMINS.
+        let protocol_config = &self.protocol_config;
+        self.proposal
+            .approvals
+            .retain(|approval| protocol_config.check_admins(approval));
+        self.proposal.approvals.push(admin);
+
+        emit!(ProposalApproved {
+            protocol_config: self.protocol_config.key(),
+            proposal: self.proposal.key(),
+            admin,
+            approvals: self.proposal.approvals.len() as u8,
+        });
+
+        Ok(())
+    }
+}
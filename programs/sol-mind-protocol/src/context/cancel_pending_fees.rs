@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ProtocolError;
+use crate::events::FeesUpdateCancelled;
+use crate::state::ProtocolConfig;
+
+#[derive(Accounts)]
+pub struct CancelPendingFees<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"sol-mind-protocol",
+            ProtocolConfig::instance_seed(protocol_config.instance_id).as_slice(),
+        ],
+        bump = protocol_config.bump,
+        constraint = protocol_config.check_admins(&admin.key()) @ ProtocolError::Unauthorized,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+impl<'info> CancelPendingFees<'info> {
+    pub fn cancel_pending_fees(&mut self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let pending = self.protocol_config.cancel_pending_fees(now)?;
+
+        emit!(FeesUpdateCancelled {
+            protocol_config: self.protocol_config.key(),
+            admin: self.admin.key(),
+            fees: pending.fees,
+            effective_at: pending.effective_at,
+        });
+
+        Ok(())
+    }
+}
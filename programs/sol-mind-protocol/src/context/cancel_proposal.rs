@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ProtocolError;
+use crate::events::ProposalCancelled;
+use crate::state::{Proposal, ProtocolConfig};
+
+#[derive(Accounts)]
+pub struct CancelProposal<'info> {
+    #[account(
+        constraint = admin.key() == proposal.proposer
+            || protocol_config.check_admins(&admin.key()) @ ProtocolError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [
+            b"sol-mind-protocol",
+            ProtocolConfig::instance_seed(protocol_config.instance_id).as_slice(),
+        ],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(
+        mut,
+        has_one = proposer,
+        close = proposer,
+        seeds = [
+            b"proposal",
+            protocol_config.key().as_ref(),
+            proposal.proposal_id.to_le_bytes().as_ref(),
+        ],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+    #[account(mut)]
+    pub proposer: SystemAccount<'info>,
+}
+
+impl<'info> CancelProposal<'info> {
+    /// The proposer can withdraw a proposal at any time and any admin can clear an expired one.
+    /// Otherwise the cancellation needs as many admin signatures as an execution, with the
+    /// other admins passed as signing remaining accounts.
+    pub fn cancel_proposal(&mut self, co_signers: &[AccountInfo<'info>]) -> Result<()> {
+        let admin = self.admin.key();
+        let now = Clock::get()?.unix_timestamp;
+
+        if admin != self.proposal.proposer && !self.proposal.is_expired(now) {
+            self.protocol_config
+                .require_admin_signers(self.admin.to_account_info(), co_signers)?;
+        }
+
+        emit!(ProposalCancelled {
+            protocol_config: self.protocol_config.key(),
+            proposal: self.proposal.key(),
+            admin,
+        });
+
+        Ok(())
+    }
+}
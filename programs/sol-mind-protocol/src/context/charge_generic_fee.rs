@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked, ID as INSTRUCTIONS_SYSVAR_ID,
+};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::ProtocolError;
+use crate::events::GenericFeeCharged;
+use crate::helpers::{pay_protocol_fee, FeeTokenAccounts};
+use crate::state::{FeeStats, Operation, ProtocolConfig, RegisteredProgram};
+
+/// Called by registered external programs so their users pay the `Generic` protocol fee.
+#[derive(Accounts)]
+pub struct ChargeGenericFee<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [
+            b"sol-mind-protocol",
+            ProtocolConfig::instance_seed(protocol_config.instance_id).as_slice(),
+        ],
+        bump = protocol_config.bump,
+        constraint = !protocol_config.is_operation_paused(Operation::Generic) @ ProtocolError::OperationPaused,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(
+        mut,
+        seeds = [b"treasury", protocol_config.key().as_ref()],
+        bump,
+    )]
+    pub protocol_treasury: SystemAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"fee_stats", protocol_config.key().as_ref()],
+        bump = protocol_fee_stats.bump,
+    )]
+    pub protocol_fee_stats: Account<'info, FeeStats>,
+    #[account(
+        seeds = [
+            b"registered_program",
+            protocol_config.key().as_ref(),
+            registered_program.program_id.as_ref(),
+        ],
+        bump = registered_program.bump,
+    )]
+    pub registered_program: Account<'info, RegisteredProgram>,
+    /// CHECK: address is checked, read through the sysvar instruction helpers
+    #[account(address = INSTRUCTIONS_SYSVAR_ID)]
+    pub instructions: UncheckedAccount<'info>,
+
+    pub fee_mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub fee_payer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub protocol_treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ChargeGenericFee<'info> {
+    pub fn charge_generic_fee(&mut self, base_amount: Option<u64>) -> Result<()> {
+        self.verify_caller()?;
+
+        let payment = pay_protocol_fee(
+            &self.payer,
+            &self.protocol_config,
+            &self.protocol_treasury.to_account_info(),
+            &self.system_program,
+            FeeTokenAccounts::from_optional(
+                &self.fee_mint,
+                &self.fee_payer_token_account,
+                &self.protocol_treasury_token_account,
+                &self.token_program,
+            ),
+            None,
+            None,
+            Operation::Generic,
+            base_amount,
+        )?;
+
+        self.protocol_fee_stats
+            .record_protocol_fee(&payment, true)?;
+
+        emit!(GenericFeeCharged {
+            protocol_config: self.protocol_config.key(),
+            program_id: self.registered_program.program_id,
+            payer: self.payer.key(),
+            amount: payment.amount,
+            mint: payment.mint,
+        });
+
+        Ok(())
+    }
+
+    /// The caller is taken to be the program of the transaction instruction being executed,
+    /// which is this program when `charge_generic_fee` is called directly.
+    fn verify_caller(&self) -> Result<()> {
+        let instructions = self.instructions.to_account_info();
+        let current_index = load_current_index_checked(&instructions)?;
+        let current_instruction =
+            load_instruction_at_checked(usize::from(current_index), &instructions)?;
+
+        require_keys_eq!(
+            current_instruction.program_id,
+            self.registered_program.program_id,
+            ProtocolError::ProgramNotRegistered
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ProtocolError;
+use crate::events::ReferralFeesClaimed;
+use crate::state::Referrer;
+
+#[derive(Accounts)]
+pub struct ClaimReferralFees<'info> {
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+    #[account(
+        mut,
+        has_one = wallet @ ProtocolError::Unauthorized,
+        seeds = [b"referrer", referrer.protocol_config.as_ref(), wallet.key().as_ref()],
+        bump = referrer.bump,
+    )]
+    pub referrer: Account<'info, Referrer>,
+}
+
+impl<'info> ClaimReferralFees<'info> {
+    /// Pays the wallet every referral fee accrued in the referrer PDA.
+    pub fn claim_referral_fees(&mut self) -> Result<()> {
+        let referrer_info = self.referrer.to_account_info();
+        let amount = Referrer::claimable(&referrer_info)?;
+
+        require!(amount > 0, ProtocolError::NothingToClaim);
+
+        referrer_info.sub_lamports(amount)?;
+        self.wallet.to_account_info().add_lamports(amount)?;
+
+        emit!(ReferralFeesClaimed {
+            protocol_config: self.referrer.protocol_config,
+            referrer: self.referrer.key(),
+            wallet: self.wallet.key(),
+            amount,
+        });
+
+        Ok(())
+    }
+}
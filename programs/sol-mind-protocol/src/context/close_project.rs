@@ -0,0 +1,198 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+    Ids,
+};
+use anchor_spl::token_interface::{
+    close_account as close_token_account, CloseAccount, TokenAccount, TokenInterface,
+};
+
+use crate::errors::ProtocolError;
+use crate::events::ProjectClosed;
+use crate::helpers::close_account;
+use crate::state::{ProjectConfig, ProjectRegistryEntry, PROJECT_CONFIG_VERSION};
+
+// Besides the project config and treasury, `close_project` closes the project's accounts that
+// exist to the owner: its registry entry, fee stats, treasury policy and subscription. Projects
+// of the first release have no registry entry or fee stats, so these are passed as unchecked
+// PDAs. Role PDAs and the fee override must be closed first, since their rent belongs to
+// whoever created them. The treasury's token accounts are passed as remaining accounts along
+// with their token programs, and closed once emptied.
+
+#[derive(Accounts)]
+pub struct CloseProject<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        close = owner,
+        has_one = owner @ ProtocolError::Unauthorized,
+        constraint = project_config.version == PROJECT_CONFIG_VERSION
+            @ ProtocolError::ConfigNotMigrated,
+        constraint = !project_config.has_resources() @ ProtocolError::ProjectHasResources,
+        constraint = project_config.roles_count == 0 @ ProtocolError::ProjectHasRoles,
+        seeds = [
+            b"project",
+            project_config.creator.as_ref(),
+            project_config.protocol_config.as_ref(),
+            project_config.project_id.to_le_bytes().as_ref(),
+        ],
+        bump = project_config.bump,
+    )]
+    pub project_config: Account<'info, ProjectConfig>,
+    /// CHECK: Registry entry of the project name, closed when it maps to this project
+    #[account(
+        mut,
+        seeds = [
+            b"project_registry",
+            project_config.protocol_config.as_ref(),
+            ProjectRegistryEntry::name_seed(&project_config.name).as_ref(),
+        ],
+        bump,
+    )]
+    pub project_registry: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"treasury", project_config.key().as_ref()],
+        bump = project_config.treasury_bump
+    )]
+    pub treasury: SystemAccount<'info>,
+    /// CHECK: Fee stats PDA of the project, closed when it has been created
+    #[account(
+        mut,
+        seeds = [b"fee_stats", project_config.key().as_ref()],
+        bump,
+    )]
+    pub fee_stats: UncheckedAccount<'info>,
+    /// CHECK: Treasury policy PDA of the project, closed when it has been created
+    #[account(
+        mut,
+        seeds = [b"treasury_policy", project_config.key().as_ref()],
+        bump,
+    )]
+    pub treasury_policy: UncheckedAccount<'info>,
+    /// CHECK: Subscription PDA of the project, closed when it has been created
+    #[account(
+        mut,
+        seeds = [b"subscription", project_config.key().as_ref()],
+        bump,
+    )]
+    pub subscription: UncheckedAccount<'info>,
+    /// CHECK: Fee override PDA of the project, must not exist
+    #[account(
+        seeds = [b"fee_override", project_config.key().as_ref()],
+        bump,
+        constraint = fee_override.data_is_empty() @ ProtocolError::ProjectHasFeeOverride,
+    )]
+    pub fee_override: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CloseProject<'info> {
+    pub fn close_project(&mut self, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        let project_key = self.project_config.key();
+        let seeds = &[
+            b"treasury".as_ref(),
+            project_key.as_ref(),
+            &[self.project_config.treasury_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        self.close_treasury_token_accounts(remaining_accounts, signer_seeds)?;
+        self.close_project_accounts()?;
+
+        let amount = self.treasury.lamports();
+
+        let cpi_program = self.system_program.to_account_info();
+        let cpi_accounts = Transfer {
+            from: self.treasury.to_account_info(),
+            to: self.owner.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+
+        transfer(cpi_ctx, amount)?;
+
+        emit!(ProjectClosed {
+            project_config: project_key,
+            owner: self.owner.key(),
+            treasury_amount: amount,
+        });
+
+        Ok(())
+    }
+
+    fn close_project_accounts(&self) -> Result<()> {
+        let owner = self.owner.to_account_info();
+
+        if self.project_registry.owner == &crate::ID {
+            let entry = {
+                let data = self.project_registry.try_borrow_data()?;
+                ProjectRegistryEntry::try_deserialize(&mut &data[..])?
+            };
+            // A first-release project has no entry, and its name may since have been
+            // registered by another project.
+            if entry.project_config == self.project_config.key() {
+                close_account(&self.project_registry, &owner)?;
+            }
+        }
+
+        for account in [&self.fee_stats, &self.treasury_policy, &self.subscription] {
+            if account.owner == &crate::ID {
+                close_account(account, &owner)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Closes the treasury's token accounts among `remaining_accounts` to the owner, through
+    /// the token program passed alongside them. Closing fails while a balance is left.
+    fn close_treasury_token_accounts(
+        &self,
+        remaining_accounts: &[AccountInfo<'info>],
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        let token_programs: Vec<&AccountInfo<'info>> = remaining_accounts
+            .iter()
+            .filter(|account| account.executable)
+            .collect();
+
+        for account in remaining_accounts
+            .iter()
+            .filter(|account| !account.executable)
+        {
+            require!(
+                TokenInterface::ids().contains(account.owner),
+                ProtocolError::InvalidTreasuryTokenAccount
+            );
+            let token_account = {
+                let data = account.try_borrow_data()?;
+                TokenAccount::try_deserialize(&mut &data[..])
+                    .map_err(|_| ProtocolError::InvalidTreasuryTokenAccount)?
+            };
+            require_keys_eq!(
+                token_account.owner,
+                self.treasury.key(),
+                ProtocolError::InvalidTreasuryTokenAccount
+            );
+            let token_program = token_programs
+                .iter()
+                .find(|program| program.key == account.owner)
+                .ok_or(ProtocolError::InvalidTreasuryTokenAccount)?;
+
+            close_token_account(CpiContext::new_with_signer(
+                (*token_program).clone(),
+                CloseAccount {
+                    account: account.clone(),
+                    destination: self.owner.to_account_info(),
+                    authority: self.treasury.to_account_info(),
+                },
+                signer_seeds,
+            ))?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ProtocolError;
+use crate::events::ProjectFeeOverrideClosed;
+use crate::state::{ProjectConfig, ProjectFeeOverride, ProtocolConfig};
+
+#[derive(Accounts)]
+pub struct CloseProjectFeeOverride<'info> {
+    pub admin: Signer<'info>,
+    /// Receives the rent of the fee override account
+    #[account(mut)]
+    pub payer: SystemAccount<'info>,
+    #[account(
+        seeds = [
+            b"sol-mind-protocol",
+            ProtocolConfig::instance_seed(protocol_config.instance_id).as_slice(),
+        ],
+        bump = protocol_config.bump,
+        constraint = protocol_config.check_admins(&admin.key()) @ ProtocolError::Unauthorized,
+        constraint = !protocol_config.requires_approval() @ ProtocolError::ApprovalRequired,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(
+        has_one = protocol_config @ ProtocolError::InvalidProjectConfig,
+        seeds = [
+            b"project",
+            project_config.creator.as_ref(),
+            project_config.protocol_config.as_ref(),
+            project_config.project_id.to_le_bytes().as_ref(),
+        ],
+        bump = project_config.bump,
+    )]
+    pub project_config: Account<'info, ProjectConfig>,
+    #[account(
+        mut,
+        has_one = payer @ ProtocolError::InvalidRentPayer,
+        close = payer,
+        seeds = [b"fee_override", project_config.key().as_ref()],
+        bump = project_fee_override.bump,
+    )]
+    pub project_fee_override: Account<'info, ProjectFeeOverride>,
+}
+
+impl<'info> CloseProjectFeeOverride<'info> {
+    pub fn close_project_fee_override(&mut self) -> Result<()> {
+        emit!(ProjectFeeOverrideClosed {
+            protocol_config: self.protocol_config.key(),
+            project_config: self.project_config.key(),
+            admin: self.admin.key(),
+        });
+
+        Ok(())
+    }
+}
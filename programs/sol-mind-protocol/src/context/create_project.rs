@@ -1,11 +1,17 @@
 use crate::{
-    helpers::{cpi_transfer, pay_protocol_fee},
-    state::{Operation, ProjectConfig, ProtocolConfig},
+    errors::ProtocolError,
+    events::{ProjectCreated, ProtocolFeePaid},
+    helpers::{cpi_transfer, pay_protocol_fee, FeeTokenAccounts},
+    state::{
+        FeeStats, Operation, ProjectConfig, ProjectRegistryEntry, ProtocolConfig, Referrer,
+        PROJECT_CONFIG_VERSION,
+    },
 };
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 #[derive(Accounts)]
-#[instruction(project_id: u64)]
+#[instruction(name: String)]
 pub struct CreateProject<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
@@ -17,11 +23,23 @@ pub struct CreateProject<'info> {
             b"project",
             owner.key.as_ref(),
             protocol_config.key().as_ref(),
-            project_id.to_le_bytes().as_ref(),
+            protocol_config.project_count.to_le_bytes().as_ref(),
         ],
         bump,
     )]
     pub project_config: Account<'info, ProjectConfig>,
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + ProjectRegistryEntry::INIT_SPACE,
+        seeds = [
+            b"project_registry",
+            protocol_config.key().as_ref(),
+            ProjectRegistryEntry::name_seed(&name).as_ref(),
+        ],
+        bump,
+    )]
+    pub project_registry: Account<'info, ProjectRegistryEntry>,
     #[account(
         mut,
         seeds = [b"treasury", project_config.key().as_ref()],
@@ -30,8 +48,13 @@ pub struct CreateProject<'info> {
     pub treasury: SystemAccount<'info>,
 
     #[account(
-        seeds = [b"sol-mind-protocol"],
+        mut,
+        seeds = [
+            b"sol-mind-protocol",
+            ProtocolConfig::instance_seed(protocol_config.instance_id).as_slice(),
+        ],
         bump = protocol_config.bump,
+        constraint = !protocol_config.is_operation_paused(Operation::CreateProject) @ ProtocolError::OperationPaused,
     )]
     pub protocol_config: Account<'info, ProtocolConfig>,
 
@@ -41,6 +64,33 @@ pub struct CreateProject<'info> {
         bump,
     )]
     pub protocol_treasury: SystemAccount<'info>,
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + FeeStats::INIT_SPACE,
+        seeds = [b"fee_stats", project_config.key().as_ref()],
+        bump,
+    )]
+    pub project_fee_stats: Account<'info, FeeStats>,
+    #[account(
+        mut,
+        seeds = [b"fee_stats", protocol_config.key().as_ref()],
+        bump = protocol_fee_stats.bump,
+    )]
+    pub protocol_fee_stats: Account<'info, FeeStats>,
+    #[account(
+        mut,
+        seeds = [b"referrer", protocol_config.key().as_ref(), referrer.wallet.as_ref()],
+        bump = referrer.bump,
+    )]
+    pub referrer: Option<Account<'info, Referrer>>,
+
+    pub fee_mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub fee_payer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub protocol_treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
 
     pub system_program: Program<'info, System>,
 }
@@ -48,17 +98,25 @@ pub struct CreateProject<'info> {
 impl<'info> CreateProject<'info> {
     pub fn create_project(
         &mut self,
-        project_id: u64,
         name: String,
         description: String,
-        authorities: Vec<Pubkey>,
         bumps: &CreateProjectBumps,
     ) -> Result<()> {
-        pay_protocol_fee(
+        let project_id = self.protocol_config.project_count;
+
+        let payment = pay_protocol_fee(
             &self.owner,
             &self.protocol_config,
             &self.protocol_treasury.to_account_info(),
             &self.system_program,
+            FeeTokenAccounts::from_optional(
+                &self.fee_mint,
+                &self.fee_payer_token_account,
+                &self.protocol_treasury_token_account,
+                &self.token_program,
+            ),
+            None,
+            self.referrer.as_ref(),
             Operation::CreateProject,
             None,
         )?;
@@ -75,12 +133,57 @@ impl<'info> CreateProject<'info> {
         self.project_config.set_inner(ProjectConfig {
             protocol_config: self.protocol_config.key(),
             project_id,
+            creator: self.owner.key(),
             owner: self.owner.key(),
-            name,
+            pending_owner: None,
+            name: String::new(),
             description,
-            autthorities: authorities,
+            autthorities: Vec::new(),
+            minter_configs_count: 0,
+            trade_hubs_count: 0,
             treasury_bump: bumps.treasury,
             bump: bumps.project_config,
+            version: PROJECT_CONFIG_VERSION,
+            created_epoch: Clock::get()?.epoch,
+            roles_count: 0,
+        });
+        // The registry entry is keyed by the name hash, which takes names of any length
+        self.project_config.set_name(name.clone())?;
+
+        self.project_fee_stats.set_inner(FeeStats::new(
+            self.project_config.key(),
+            bumps.project_fee_stats,
+        ));
+        self.project_fee_stats
+            .record_protocol_fee(&payment, false)?;
+        self.protocol_fee_stats
+            .record_protocol_fee(&payment, true)?;
+
+        self.project_registry.set_inner(ProjectRegistryEntry {
+            protocol_config: self.protocol_config.key(),
+            project_config: self.project_config.key(),
+            bump: bumps.project_registry,
+        });
+
+        self.protocol_config.project_count = project_id
+            .checked_add(1)
+            .ok_or(ProtocolError::MathOverflow)?;
+
+        emit!(ProjectCreated {
+            protocol_config: self.protocol_config.key(),
+            project_config: self.project_config.key(),
+            project_id,
+            owner: self.owner.key(),
+            name,
+        });
+        emit!(ProtocolFeePaid {
+            protocol_config: self.protocol_config.key(),
+            project_config: self.project_config.key(),
+            operation: payment.operation,
+            amount: payment.amount,
+            mint: payment.mint,
+            referrer: payment.referrer,
+            referral_amount: payment.referral_amount,
         });
 
         Ok(())
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ProtocolError;
+use crate::events::ProposalCreated;
+use crate::state::{Proposal, ProposalAction, ProtocolConfig, PROPOSAL_LIFETIME};
+
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"sol-mind-protocol",
+            ProtocolConfig::instance_seed(protocol_config.instance_id).as_slice(),
+        ],
+        bump = protocol_config.bump,
+        constraint = protocol_config.check_admins(&admin.key()) @ ProtocolError::Unauthorized,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Proposal::INIT_SPACE,
+        seeds = [
+            b"proposal",
+            protocol_config.key().as_ref(),
+            protocol_config.proposal_count.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateProposal<'info> {
+    pub fn create_proposal(&mut self, action: ProposalAction, bump: u8) -> Result<()> {
+        action.validate()?;
+
+        let proposal_id = self.protocol_config.proposal_count;
+        let created_at = Clock::get()?.unix_timestamp;
+
+        self.proposal.set_inner(Proposal {
+            protocol_config: self.protocol_config.key(),
+            proposal_id,
+            proposer: self.admin.key(),
+            action: action.clone(),
+            approvals: vec![self.admin.key()],
+            created_at,
+            bump,
+            expires_at: created_at
+                .checked_add(PROPOSAL_LIFETIME)
+                .ok_or(ProtocolError::MathOverflow)?,
+        });
+
+        self.protocol_config.proposal_count = proposal_id
+            .checked_add(1)
+            .ok_or(ProtocolError::MathOverflow)?;
+
+        emit!(ProposalCreated {
+            protocol_config: self.protocol_config.key(),
+            proposal: self.proposal.key(),
+            proposal_id,
+            proposer: self.admin.key(),
+            action,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,111 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+
+use crate::errors::ProtocolError;
+use crate::events::{ProtocolFeesDistributed, ProtocolFeesTransferred};
+use crate::helpers::validate_transfer;
+use crate::state::{FeeStats, ProtocolConfig};
+
+#[derive(Accounts)]
+pub struct DistributeProtocolFees<'info> {
+    #[account(
+        seeds = [
+            b"sol-mind-protocol",
+            ProtocolConfig::instance_seed(protocol_config.instance_id).as_slice(),
+        ],
+        bump = protocol_config.bump,
+        constraint = !protocol_config.paused @ ProtocolError::OperationPaused,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(
+        mut,
+        seeds = [b"treasury", protocol_config.key().as_ref()],
+        bump
+    )]
+    pub treasury: SystemAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"fee_stats", protocol_config.key().as_ref()],
+        bump = fee_stats.bump,
+    )]
+    pub fee_stats: Account<'info, FeeStats>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> DistributeProtocolFees<'info> {
+    /// Pays every revenue share its cut of the treasury balance above the rent-exempt minimum.
+    /// `recipients` are the share recipients, in the order of `ProtocolConfig::revenue_shares`.
+    pub fn distribute_protocol_fees(
+        &mut self,
+        recipients: &[AccountInfo<'info>],
+        treasury_bump: u8,
+    ) -> Result<()> {
+        let shares = self.protocol_config.revenue_shares.clone();
+
+        require!(!shares.is_empty(), ProtocolError::NoRevenueShares);
+        require!(
+            recipients.len() == shares.len(),
+            ProtocolError::RevenueRecipientMismatch
+        );
+
+        let treasury_info = self.treasury.to_account_info();
+        let rent_exempt = Rent::get()?.minimum_balance(treasury_info.data_len());
+        let distributable = treasury_info.lamports().saturating_sub(rent_exempt);
+
+        require!(distributable > 0, ProtocolError::NothingToDistribute);
+
+        let protocol_key = self.protocol_config.key();
+        let seeds = &[b"treasury", protocol_key.as_ref(), &[treasury_bump]];
+        let signer_seeds = &[&seeds[..]];
+
+        let mut distributed: u64 = 0;
+        for (share, recipient) in shares.iter().zip(recipients) {
+            require_keys_eq!(
+                recipient.key(),
+                share.recipient,
+                ProtocolError::RevenueRecipientMismatch
+            );
+
+            let amount = share.payout(distributable)?;
+            if amount == 0 {
+                continue;
+            }
+
+            validate_transfer(&treasury_info, amount)?;
+
+            let cpi_accounts = Transfer {
+                from: treasury_info.clone(),
+                to: recipient.clone(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            );
+
+            transfer(cpi_ctx, amount)?;
+
+            distributed = distributed
+                .checked_add(amount)
+                .ok_or(ProtocolError::MathOverflow)?;
+
+            emit!(ProtocolFeesTransferred {
+                protocol_config: protocol_key,
+                to: share.recipient,
+                amount,
+            });
+        }
+
+        self.fee_stats.record_withdrawal(distributed)?;
+
+        emit!(ProtocolFeesDistributed {
+            protocol_config: protocol_key,
+            amount: distributed,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,684 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::ProtocolError;
+use crate::events::{
+    AdminAdded, AdminRemoved, AdminRotated, ApprovalThresholdUpdated, FeesUpdateScheduled,
+    OperationPauseUpdated, ProgramRegistered, ProgramUnregistered, ProjectFeeOverrideClosed,
+    ProjectFeeOverrideSet, ProposalExecuted, ProtocolFeesTransferred, ProtocolPauseUpdated,
+    ProtocolTokenFeesTransferred, ReferrerRegistered, ReferrerRemoved, ReferrerUpdated,
+    RevenueSharesUpdated, SubscriptionPlanRemoved, SubscriptionPlanSet, WhitelistTransferAddrAdded,
+    WhitelistTransferAddrRemoved,
+};
+use crate::helpers::{
+    close_account, cpi_transfer_token_signed, create_pda_account, validate_transfer,
+    validate_treasury_token_account,
+};
+use crate::state::{
+    FeeStats, OperationFeeOverride, PendingFees, ProjectConfig, ProjectFeeOverride, Proposal,
+    ProposalAction, ProtocolConfig, Referrer, RegisteredProgram, WhitelistEntry,
+};
+
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    #[account(
+        mut,
+        constraint = protocol_config.check_admins(&admin.key()) @ ProtocolError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"sol-mind-protocol",
+            ProtocolConfig::instance_seed(protocol_config.instance_id).as_slice(),
+        ],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(
+        mut,
+        has_one = proposer,
+        close = proposer,
+        seeds = [
+            b"proposal",
+            protocol_config.key().as_ref(),
+            proposal.proposal_id.to_le_bytes().as_ref(),
+        ],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+    #[account(mut)]
+    pub proposer: SystemAccount<'info>,
+    #[account(mut)]
+    pub to: Option<SystemAccount<'info>>,
+    #[account(
+        mut,
+        seeds = [b"treasury", protocol_config.key().as_ref()],
+        bump
+    )]
+    pub treasury: SystemAccount<'info>,
+    /// Only needed by `TransferProtocolFees`, to record the withdrawal
+    #[account(
+        mut,
+        seeds = [b"fee_stats", protocol_config.key().as_ref()],
+        bump = fee_stats.bump,
+    )]
+    pub fee_stats: Option<Account<'info, FeeStats>>,
+
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub to_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    #[account(
+        has_one = protocol_config @ ProtocolError::InvalidProjectConfig,
+        seeds = [
+            b"project",
+            project_config.creator.as_ref(),
+            project_config.protocol_config.as_ref(),
+            project_config.project_id.to_le_bytes().as_ref(),
+        ],
+        bump = project_config.bump,
+    )]
+    pub project_config: Option<Account<'info, ProjectConfig>>,
+    /// CHECK: the fee override PDA of `project_config`, created or closed by the proposal
+    #[account(mut)]
+    pub project_fee_override: Option<UncheckedAccount<'info>>,
+    /// CHECK: the registered program PDA, created or closed by the proposal
+    #[account(mut)]
+    pub registered_program: Option<UncheckedAccount<'info>>,
+    /// CHECK: the referrer PDA, created, updated or closed by the proposal
+    #[account(mut)]
+    pub referrer: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ExecuteProposal<'info> {
+    pub fn execute_proposal(&mut self, bumps: &ExecuteProposalBumps) -> Result<()> {
+        require!(
+            !self.proposal.is_expired(Clock::get()?.unix_timestamp),
+            ProtocolError::ProposalExpired
+        );
+        require!(
+            self.proposal.approvals_count(&self.protocol_config)
+                >= self.protocol_config.approval_threshold as usize,
+            ProtocolError::ProposalThresholdNotMet
+        );
+
+        match self.proposal.action.clone() {
+            ProposalAction::UpdateFees { fees } => {
+                let now = Clock::get()?.unix_timestamp;
+                let pending = self.protocol_config.schedule_fees(fees, now)?;
+                self.emit_fees_update_scheduled(pending);
+            }
+            ProposalAction::UpdateSingleFee { operation, fee } => {
+                let now = Clock::get()?.unix_timestamp;
+                let pending = self
+                    .protocol_config
+                    .schedule_single_fee(operation, fee, now)?;
+                self.emit_fees_update_scheduled(pending);
+            }
+            ProposalAction::SetApprovalThreshold { threshold } => {
+                self.protocol_config.set_approval_threshold(threshold)?;
+
+                emit!(ApprovalThresholdUpdated {
+                    protocol_config: self.protocol_config.key(),
+                    threshold,
+                });
+            }
+            ProposalAction::TransferProtocolFees { to, amount } => {
+                self.transfer_protocol_fees(to, amount, bumps.treasury)?;
+            }
+            ProposalAction::SetRevenueShares { shares } => {
+                self.protocol_config.set_revenue_shares(shares.clone())?;
+
+                emit!(RevenueSharesUpdated {
+                    protocol_config: self.protocol_config.key(),
+                    admin: self.admin.key(),
+                    shares,
+                });
+            }
+            ProposalAction::AddAdmin { new_admin } => {
+                self.protocol_config.add_admin(new_admin)?;
+
+                emit!(AdminAdded {
+                    protocol_config: self.protocol_config.key(),
+                    admin: self.admin.key(),
+                    new_admin,
+                });
+            }
+            ProposalAction::RemoveAdmin { old_admin } => {
+                self.protocol_config.remove_admin(&old_admin)?;
+
+                emit!(AdminRemoved {
+                    protocol_config: self.protocol_config.key(),
+                    admin: self.admin.key(),
+                    old_admin,
+                });
+            }
+            ProposalAction::RotateAdmin {
+                old_admin,
+                new_admin,
+            } => {
+                self.protocol_config.rotate_admin(&old_admin, new_admin)?;
+
+                emit!(AdminRotated {
+                    protocol_config: self.protocol_config.key(),
+                    admin: self.admin.key(),
+                    old_admin,
+                    new_admin,
+                });
+            }
+            ProposalAction::AddWhitelistTransferAddr {
+                address,
+                label,
+                transfer_cap,
+            } => {
+                self.protocol_config
+                    .add_whitelist_transfer_addr(WhitelistEntry {
+                        address,
+                        label: label.clone(),
+                        transfer_cap,
+                    })?;
+
+                emit!(WhitelistTransferAddrAdded {
+                    protocol_config: self.protocol_config.key(),
+                    admin: self.admin.key(),
+                    address,
+                    label,
+                    transfer_cap,
+                });
+            }
+            ProposalAction::RemoveWhitelistTransferAddr { address } => {
+                self.protocol_config
+                    .remove_whitelist_transfer_addr(&address)?;
+
+                emit!(WhitelistTransferAddrRemoved {
+                    protocol_config: self.protocol_config.key(),
+                    admin: self.admin.key(),
+                    address,
+                });
+            }
+            ProposalAction::SetProtocolPaused { paused } => {
+                self.protocol_config.paused = paused;
+
+                emit!(ProtocolPauseUpdated {
+                    protocol_config: self.protocol_config.key(),
+                    admin: self.admin.key(),
+                    paused,
+                });
+            }
+            ProposalAction::SetOperationPaused { operation, paused } => {
+                self.protocol_config.set_operation_paused(operation, paused);
+
+                emit!(OperationPauseUpdated {
+                    protocol_config: self.protocol_config.key(),
+                    admin: self.admin.key(),
+                    operation,
+                    paused,
+                });
+            }
+            ProposalAction::TransferProtocolTokenFees { mint, to, amount } => {
+                self.transfer_protocol_token_fees(mint, to, amount, bumps.treasury)?;
+            }
+            ProposalAction::SetProjectFeeOverride {
+                project_config,
+                overrides,
+                expires_at,
+            } => {
+                self.set_project_fee_override(project_config, overrides, expires_at)?;
+            }
+            ProposalAction::CloseProjectFeeOverride { project_config } => {
+                self.close_project_fee_override(project_config)?;
+            }
+            ProposalAction::RegisterProgram { program_id } => {
+                self.register_program(program_id)?;
+            }
+            ProposalAction::UnregisterProgram { program_id } => {
+                self.unregister_program(program_id)?;
+            }
+            ProposalAction::RegisterReferrer { wallet, share_bps } => {
+                self.register_referrer(wallet, share_bps)?;
+            }
+            ProposalAction::UpdateReferrer { wallet, share_bps } => {
+                self.update_referrer(wallet, share_bps)?;
+            }
+            ProposalAction::RemoveReferrer { wallet } => {
+                self.remove_referrer(wallet)?;
+            }
+            ProposalAction::SetSubscriptionPlan { plan } => {
+                self.protocol_config.set_subscription_plan(plan)?;
+
+                emit!(SubscriptionPlanSet {
+                    protocol_config: self.protocol_config.key(),
+                    admin: self.admin.key(),
+                    plan,
+                });
+            }
+            ProposalAction::RemoveSubscriptionPlan { plan_id } => {
+                self.protocol_config.remove_subscription_plan(plan_id)?;
+
+                emit!(SubscriptionPlanRemoved {
+                    protocol_config: self.protocol_config.key(),
+                    admin: self.admin.key(),
+                    plan_id,
+                });
+            }
+        }
+
+        emit!(ProposalExecuted {
+            protocol_config: self.protocol_config.key(),
+            proposal: self.proposal.key(),
+            admin: self.admin.key(),
+        });
+
+        Ok(())
+    }
+
+    fn emit_fees_update_scheduled(&self, pending: PendingFees) {
+        emit!(FeesUpdateScheduled {
+            protocol_config: self.protocol_config.key(),
+            fees: pending.fees,
+            effective_at: pending.effective_at,
+        });
+    }
+
+    fn transfer_protocol_fees(&mut self, to: Pubkey, amount: u64, treasury_bump: u8) -> Result<()> {
+        let destination = self
+            .to
+            .as_ref()
+            .ok_or(ProtocolError::ProposalDestinationMismatch)?;
+        require_keys_eq!(
+            destination.key(),
+            to,
+            ProtocolError::ProposalDestinationMismatch
+        );
+        self.protocol_config
+            .validate_whitelist_transfer(&to, amount)?;
+
+        validate_transfer(&self.treasury.to_account_info(), amount)?;
+
+        let cpi_program = self.system_program.to_account_info();
+        let cpi_accounts = Transfer {
+            from: self.treasury.to_account_info(),
+            to: destination.to_account_info(),
+        };
+
+        let protocol_key = self.protocol_config.key();
+        let seeds = &[b"treasury", protocol_key.as_ref(), &[treasury_bump]];
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+
+        transfer(cpi_ctx, amount)?;
+
+        self.fee_stats
+            .as_mut()
+            .ok_or(ProtocolError::ProposalAccountMissing)?
+            .record_withdrawal(amount)?;
+
+        emit!(ProtocolFeesTransferred {
+            protocol_config: protocol_key,
+            to,
+            amount,
+        });
+
+        Ok(())
+    }
+
+    fn transfer_protocol_token_fees(
+        &self,
+        mint: Pubkey,
+        to: Pubkey,
+        amount: u64,
+        treasury_bump: u8,
+    ) -> Result<()> {
+        let (Some(mint_account), Some(treasury_token_account), Some(token_program)) = (
+            self.mint.as_ref(),
+            self.treasury_token_account.as_ref(),
+            self.token_program.as_ref(),
+        ) else {
+            return err!(ProtocolError::ProposalAccountMissing);
+        };
+        let destination = self
+            .to_token_account
+            .as_ref()
+            .ok_or(ProtocolError::ProposalDestinationMismatch)?;
+        require_keys_eq!(
+            destination.key(),
+            to,
+            ProtocolError::ProposalDestinationMismatch
+        );
+        require_keys_eq!(mint_account.key(), mint, ProtocolError::InvalidFeeMint);
+        validate_treasury_token_account(
+            &self.treasury.key(),
+            &mint,
+            &treasury_token_account.key(),
+            &token_program.key(),
+        )?;
+        self.protocol_config
+            .validate_whitelist_transfer(&destination.owner, amount)?;
+
+        let protocol_key = self.protocol_config.key();
+        let seeds = &[b"treasury", protocol_key.as_ref(), &[treasury_bump]];
+        let signer_seeds = &[&seeds[..]];
+
+        cpi_transfer_token_signed(
+            self.treasury.to_account_info(),
+            treasury_token_account.to_account_info(),
+            destination.to_account_info(),
+            mint_account,
+            token_program,
+            amount,
+            signer_seeds,
+        )?;
+
+        emit!(ProtocolTokenFeesTransferred {
+            protocol_config: protocol_key,
+            mint,
+            to,
+            amount,
+        });
+
+        Ok(())
+    }
+
+    /// Fee override account of `project_config` and its bump, checked against the PDA.
+    fn project_fee_override_account(
+        &self,
+        project_config: Pubkey,
+    ) -> Result<(AccountInfo<'info>, u8)> {
+        let (Some(project), Some(fee_override)) = (
+            self.project_config.as_ref(),
+            self.project_fee_override.as_ref(),
+        ) else {
+            return err!(ProtocolError::ProposalAccountMissing);
+        };
+        require_keys_eq!(
+            project.key(),
+            project_config,
+            ProtocolError::InvalidProjectConfig
+        );
+
+        let (address, bump) =
+            Pubkey::find_program_address(&[b"fee_override", project_config.as_ref()], &crate::ID);
+        require_keys_eq!(
+            fee_override.key(),
+            address,
+            ProtocolError::InvalidFeeOverrideAccount
+        );
+
+        Ok((fee_override.to_account_info(), bump))
+    }
+
+    fn set_project_fee_override(
+        &self,
+        project_config: Pubkey,
+        overrides: Vec<OperationFeeOverride>,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        let (account, bump) = self.project_fee_override_account(project_config)?;
+        let fee_override = ProjectFeeOverride::new(
+            project_config,
+            overrides,
+            expires_at,
+            ProjectFeeOverride::load(&account)?.as_ref(),
+            self.admin.key(),
+            Clock::get()?.unix_timestamp,
+            bump,
+        )?;
+
+        if account.data_is_empty() {
+            create_pda_account(
+                &account,
+                8 + ProjectFeeOverride::INIT_SPACE,
+                self.admin.to_account_info(),
+                &self.system_program,
+                &[&[b"fee_override", project_config.as_ref(), &[bump]]],
+            )?;
+        }
+
+        let mut data = account.try_borrow_mut_data()?;
+        let mut writer: &mut [u8] = &mut data;
+        fee_override.try_serialize(&mut writer)?;
+
+        emit!(ProjectFeeOverrideSet {
+            protocol_config: self.protocol_config.key(),
+            project_config,
+            admin: self.admin.key(),
+            overrides: fee_override.overrides,
+            expires_at,
+        });
+
+        Ok(())
+    }
+
+    fn close_project_fee_override(&self, project_config: Pubkey) -> Result<()> {
+        let (account, _) = self.project_fee_override_account(project_config)?;
+        let fee_override =
+            ProjectFeeOverride::load(&account)?.ok_or(ErrorCode::AccountNotInitialized)?;
+
+        close_account(&account, &self.rent_payer(fee_override.payer)?)?;
+
+        emit!(ProjectFeeOverrideClosed {
+            protocol_config: self.protocol_config.key(),
+            project_config,
+            admin: self.admin.key(),
+        });
+
+        Ok(())
+    }
+
+    /// The `to` account, which must be `payer` to receive the rent of a closed account.
+    fn rent_payer(&self, payer: Pubkey) -> Result<AccountInfo<'info>> {
+        let to = self
+            .to
+            .as_ref()
+            .ok_or(ProtocolError::ProposalAccountMissing)?;
+        require_keys_eq!(to.key(), payer, ProtocolError::InvalidRentPayer);
+
+        Ok(to.to_account_info())
+    }
+
+    /// Registered program account of `program_id` and its bump, checked against the PDA.
+    fn registered_program_account(&self, program_id: Pubkey) -> Result<(AccountInfo<'info>, u8)> {
+        let registered_program = self
+            .registered_program
+            .as_ref()
+            .ok_or(ProtocolError::ProposalAccountMissing)?;
+
+        let protocol_key = self.protocol_config.key();
+        let (address, bump) = Pubkey::find_program_address(
+            &[
+                b"registered_program",
+                protocol_key.as_ref(),
+                program_id.as_ref(),
+            ],
+            &crate::ID,
+        );
+        require_keys_eq!(
+            registered_program.key(),
+            address,
+            ErrorCode::ConstraintSeeds
+        );
+
+        Ok((registered_program.to_account_info(), bump))
+    }
+
+    fn register_program(&self, program_id: Pubkey) -> Result<()> {
+        RegisteredProgram::validate_program_id(&program_id)?;
+
+        let (account, bump) = self.registered_program_account(program_id)?;
+        require!(
+            account.data_is_empty(),
+            ProtocolError::ProgramAlreadyRegistered
+        );
+
+        let protocol_key = self.protocol_config.key();
+        create_pda_account(
+            &account,
+            8 + RegisteredProgram::INIT_SPACE,
+            self.admin.to_account_info(),
+            &self.system_program,
+            &[&[
+                b"registered_program",
+                protocol_key.as_ref(),
+                program_id.as_ref(),
+                &[bump],
+            ]],
+        )?;
+
+        let registered_program = RegisteredProgram {
+            protocol_config: protocol_key,
+            program_id,
+            registered_by: self.admin.key(),
+            payer: self.admin.key(),
+            bump,
+        };
+        let mut data = account.try_borrow_mut_data()?;
+        let mut writer: &mut [u8] = &mut data;
+        registered_program.try_serialize(&mut writer)?;
+
+        emit!(ProgramRegistered {
+            protocol_config: protocol_key,
+            admin: self.admin.key(),
+            program_id,
+        });
+
+        Ok(())
+    }
+
+    fn unregister_program(&self, program_id: Pubkey) -> Result<()> {
+        let (account, _) = self.registered_program_account(program_id)?;
+        require!(
+            !account.data_is_empty() && account.owner == &crate::ID,
+            ProtocolError::ProgramNotRegistered
+        );
+        let registered_program = {
+            let data = account.try_borrow_data()?;
+            RegisteredProgram::try_deserialize(&mut &data[..])?
+        };
+
+        close_account(&account, &self.rent_payer(registered_program.payer)?)?;
+
+        emit!(ProgramUnregistered {
+            protocol_config: self.protocol_config.key(),
+            admin: self.admin.key(),
+            program_id,
+        });
+
+        Ok(())
+    }
+
+    /// Referrer account of `wallet` and its bump, checked against the PDA.
+    fn referrer_account(&self, wallet: Pubkey) -> Result<(AccountInfo<'info>, u8)> {
+        let referrer = self
+            .referrer
+            .as_ref()
+            .ok_or(ProtocolError::ProposalAccountMissing)?;
+
+        let protocol_key = self.protocol_config.key();
+        let (address, bump) = Pubkey::find_program_address(
+            &[b"referrer", protocol_key.as_ref(), wallet.as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(referrer.key(), address, ErrorCode::ConstraintSeeds);
+
+        Ok((referrer.to_account_info(), bump))
+    }
+
+    fn register_referrer(&self, wallet: Pubkey, share_bps: u16) -> Result<()> {
+        Referrer::validate_share_bps(share_bps)?;
+
+        let (account, bump) = self.referrer_account(wallet)?;
+        require!(
+            account.data_is_empty(),
+            ProtocolError::ReferrerAlreadyRegistered
+        );
+
+        let protocol_key = self.protocol_config.key();
+        create_pda_account(
+            &account,
+            8 + Referrer::INIT_SPACE,
+            self.admin.to_account_info(),
+            &self.system_program,
+            &[&[b"referrer", protocol_key.as_ref(), wallet.as_ref(), &[bump]]],
+        )?;
+
+        let referrer = Referrer {
+            protocol_config: protocol_key,
+            wallet,
+            share_bps,
+            bump,
+        };
+        let mut data = account.try_borrow_mut_data()?;
+        let mut writer: &mut [u8] = &mut data;
+        referrer.try_serialize(&mut writer)?;
+
+        emit!(ReferrerRegistered {
+            protocol_config: protocol_key,
+            admin: self.admin.key(),
+            referrer: account.key(),
+            wallet,
+            share_bps,
+        });
+
+        Ok(())
+    }
+
+    fn update_referrer(&self, wallet: Pubkey, share_bps: u16) -> Result<()> {
+        Referrer::validate_share_bps(share_bps)?;
+
+        let (account, _) = self.referrer_account(wallet)?;
+        let mut referrer = Referrer::load(&account)?.ok_or(ProtocolError::ReferrerNotRegistered)?;
+        referrer.share_bps = share_bps;
+
+        let mut data = account.try_borrow_mut_data()?;
+        let mut writer: &mut [u8] = &mut data;
+        referrer.try_serialize(&mut writer)?;
+
+        emit!(ReferrerUpdated {
+            protocol_config: self.protocol_config.key(),
+            admin: self.admin.key(),
+            referrer: account.key(),
+            share_bps,
+        });
+
+        Ok(())
+    }
+
+    fn remove_referrer(&self, wallet: Pubkey) -> Result<()> {
+        let (account, _) = self.referrer_account(wallet)?;
+        require!(
+            Referrer::load(&account)?.is_some(),
+            ProtocolError::ReferrerNotRegistered
+        );
+        let destination = self
+            .to
+            .as_ref()
+            .ok_or(ProtocolError::ProposalDestinationMismatch)?;
+        require_keys_eq!(
+            destination.key(),
+            wallet,
+            ProtocolError::ProposalDestinationMismatch
+        );
+
+        let unclaimed = Referrer::claimable(&account)?;
+        close_account(&account, &destination.to_account_info())?;
+
+        emit!(ReferrerRemoved {
+            protocol_config: self.protocol_config.key(),
+            admin: self.admin.key(),
+            referrer: account.key(),
+            wallet,
+            unclaimed,
+        });
+
+        Ok(())
+    }
+}
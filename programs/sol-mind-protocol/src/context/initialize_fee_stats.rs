@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+
+use crate::events::FeeStatsInitialized;
+use crate::state::{FeeStats, ProjectConfig, ProtocolConfig};
+
+/// Creates the fee stats of a protocol initialized before fee stats existed.
+#[derive(Accounts)]
+pub struct InitializeProtocolFeeStats<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [
+            b"sol-mind-protocol",
+            ProtocolConfig::instance_seed(protocol_config.instance_id).as_slice(),
+        ],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + FeeStats::INIT_SPACE,
+        seeds = [b"fee_stats", protocol_config.key().as_ref()],
+        bump,
+    )]
+    pub fee_stats: Account<'info, FeeStats>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeProtocolFeeStats<'info> {
+    pub fn initialize_protocol_fee_stats(&mut self, bump: u8) -> Result<()> {
+        self.fee_stats
+            .set_inner(FeeStats::new(self.protocol_config.key(), bump));
+
+        emit!(FeeStatsInitialized {
+            fee_stats: self.fee_stats.key(),
+            owner: self.protocol_config.key(),
+        });
+
+        Ok(())
+    }
+}
+
+/// Creates the fee stats of a project created before fee stats existed.
+#[derive(Accounts)]
+pub struct InitializeProjectFeeStats<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [
+            b"project",
+            project_config.creator.as_ref(),
+            project_config.protocol_config.as_ref(),
+            project_config.project_id.to_le_bytes().as_ref(),
+        ],
+        bump = project_config.bump,
+    )]
+    pub project_config: Account<'info, ProjectConfig>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + FeeStats::INIT_SPACE,
+        seeds = [b"fee_stats", project_config.key().as_ref()],
+        bump,
+    )]
+    pub fee_stats: Account<'info, FeeStats>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeProjectFeeStats<'info> {
+    pub fn initialize_project_fee_stats(&mut self, bump: u8) -> Result<()> {
+        self.fee_stats
+            .set_inner(FeeStats::new(self.project_config.key(), bump));
+
+        emit!(FeeStatsInitialized {
+            fee_stats: self.fee_stats.key(),
+            owner: self.project_config.key(),
+        });
+
+        Ok(())
+    }
+}
@@ -1,8 +1,13 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{FeesStructure, ProtocolConfig};
+use crate::errors::ProtocolError;
+use crate::events::{FeeStatsInitialized, ProtocolInitialized};
+use crate::state::{
+    FeeStats, FeesStructure, ProtocolConfig, WhitelistEntry, PROTOCOL_CONFIG_VERSION,
+};
 
 #[derive(Accounts)]
+#[instruction(instance_id: u64)]
 pub struct InitializeProtocol<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
@@ -12,10 +17,26 @@ pub struct InitializeProtocol<'info> {
         space = 8 + ProtocolConfig::INIT_SPACE,
         seeds = [
             b"sol-mind-protocol",
+            ProtocolConfig::instance_seed(instance_id).as_slice(),
         ],
         bump,
     )]
     pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + FeeStats::INIT_SPACE,
+        seeds = [b"fee_stats", protocol_config.key().as_ref()],
+        bump,
+    )]
+    pub fee_stats: Account<'info, FeeStats>,
+    /// Instance 0, whose admins approve every other instance. Not needed for instance 0.
+    #[account(
+        seeds = [b"sol-mind-protocol"],
+        bump = root_protocol_config.bump,
+    )]
+    pub root_protocol_config: Option<Account<'info, ProtocolConfig>>,
+    pub root_admin: Option<Signer<'info>>,
 
     pub system_program: Program<'info, System>,
 }
@@ -23,18 +44,74 @@ pub struct InitializeProtocol<'info> {
 impl<'info> InitializeProtocol<'info> {
     pub fn initialize_protocol(
         &mut self,
+        instance_id: u64,
         admins: Vec<Pubkey>,
         whitelist_transfer_addrs: Vec<Pubkey>,
         fees: FeesStructure,
-        bump: u8,
+        bumps: &InitializeProtocolBumps,
+        co_signers: &[AccountInfo<'info>],
     ) -> Result<()> {
+        if instance_id != 0 {
+            self.validate_root_admin(co_signers)?;
+        }
+        fees.validate()?;
+
+        let whitelist_transfer_addrs = whitelist_transfer_addrs
+            .into_iter()
+            .map(|address| WhitelistEntry {
+                address,
+                label: None,
+                transfer_cap: None,
+            })
+            .collect();
+
         self.protocol_config.set_inner(ProtocolConfig {
-            admins,
+            admins: admins.clone(),
             whitelist_transfer_addrs,
+            revenue_shares: Vec::new(),
+            fees: fees.clone(),
+            pending_fees: None,
+            paused: false,
+            paused_operations: 0,
+            approval_threshold: 1,
+            proposal_count: 0,
+            project_count: 0,
+            bump: bumps.protocol_config,
+            version: PROTOCOL_CONFIG_VERSION,
+            instance_id,
+            subscription_plans: Vec::new(),
+        });
+
+        self.fee_stats
+            .set_inner(FeeStats::new(self.protocol_config.key(), bumps.fee_stats));
+
+        emit!(ProtocolInitialized {
+            protocol_config: self.protocol_config.key(),
+            instance_id,
+            admins,
             fees,
-            bump,
+        });
+        emit!(FeeStatsInitialized {
+            fee_stats: self.fee_stats.key(),
+            owner: self.protocol_config.key(),
         });
 
         Ok(())
     }
+
+    /// Requires an admin of instance 0, joined by co-signing admins up to its approval
+    /// threshold, so that instances cannot be created by anyone.
+    fn validate_root_admin(&self, co_signers: &[AccountInfo<'info>]) -> Result<()> {
+        let (Some(root_protocol_config), Some(root_admin)) =
+            (self.root_protocol_config.as_ref(), self.root_admin.as_ref())
+        else {
+            return err!(ProtocolError::RootAdminRequired);
+        };
+        require!(
+            root_protocol_config.check_admins(&root_admin.key()),
+            ProtocolError::RootAdminRequired
+        );
+
+        root_protocol_config.require_admin_signers(root_admin.to_account_info(), co_signers)
+    }
 }
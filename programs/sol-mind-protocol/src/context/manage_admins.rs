@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ProtocolError;
+use crate::events::{AdminAdded, AdminRemoved, AdminRotated};
+use crate::state::ProtocolConfig;
+
+#[derive(Accounts)]
+pub struct ManageAdmins<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"sol-mind-protocol",
+            ProtocolConfig::instance_seed(protocol_config.instance_id).as_slice(),
+        ],
+        bump = protocol_config.bump,
+        constraint = protocol_config.check_admins(&admin.key()) @ ProtocolError::Unauthorized,
+        constraint = !protocol_config.requires_approval() @ ProtocolError::ApprovalRequired,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+impl<'info> ManageAdmins<'info> {
+    pub fn add_admin(&mut self, new_admin: Pubkey) -> Result<()> {
+        self.protocol_config.add_admin(new_admin)?;
+
+        emit!(AdminAdded {
+            protocol_config: self.protocol_config.key(),
+            admin: self.admin.key(),
+            new_admin,
+        });
+
+        Ok(())
+    }
+
+    pub fn remove_admin(&mut self, old_admin: Pubkey) -> Result<()> {
+        self.protocol_config.remove_admin(&old_admin)?;
+
+        emit!(AdminRemoved {
+            protocol_config: self.protocol_config.key(),
+            admin: self.admin.key(),
+            old_admin,
+        });
+
+        Ok(())
+    }
+
+    pub fn rotate_admin(&mut self, old_admin: Pubkey, new_admin: Pubkey) -> Result<()> {
+        self.protocol_config.rotate_admin(&old_admin, new_admin)?;
+
+        emit!(AdminRotated {
+            protocol_config: self.protocol_config.key(),
+            admin: self.admin.key(),
+            old_admin,
+            new_admin,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ProtocolError;
+use crate::events::{ProjectOwnerProposed, ProjectUpdated};
+use crate::state::ProjectConfig;
+
+#[derive(Accounts)]
+pub struct ManageProject<'info> {
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        has_one = owner @ ProtocolError::Unauthorized,
+        seeds = [
+            b"project",
+            project_config.creator.as_ref(),
+            project_config.protocol_config.as_ref(),
+            project_config.project_id.to_le_bytes().as_ref(),
+        ],
+        bump = project_config.bump,
+    )]
+    pub project_config: Account<'info, ProjectConfig>,
+}
+
+impl<'info> ManageProject<'info> {
+    pub fn update_project(&mut self, description: Option<String>) -> Result<()> {
+        if let Some(description) = description {
+            self.project_config.set_description(description)?;
+        }
+
+        emit!(ProjectUpdated {
+            project_config: self.project_config.key(),
+            name: self.project_config.name.clone(),
+            description: self.project_config.description.clone(),
+        });
+
+        Ok(())
+    }
+
+    pub fn propose_owner(&mut self, new_owner: Pubkey) -> Result<()> {
+        self.project_config.pending_owner = Some(new_owner);
+
+        emit!(ProjectOwnerProposed {
+            project_config: self.project_config.key(),
+            owner: self.owner.key(),
+            pending_owner: new_owner,
+        });
+
+        Ok(())
+    }
+}
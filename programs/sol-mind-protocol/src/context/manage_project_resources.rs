@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ProtocolError;
+use crate::events::{ProjectResourceRegistered, ProjectResourceUnregistered};
+use crate::state::{ProjectConfig, ProjectResource, ProjectRole, NFT_OPERATIONS_PROGRAM_ID};
+
+/// Called by nft-operations when a minter config or trade hub is created, so
+/// `close_project` knows the project is still referenced.
+#[derive(Accounts)]
+#[instruction(resource: ProjectResource)]
+pub struct RegisterProjectResource<'info> {
+    #[account(
+        seeds = [b"fee_authority"],
+        bump,
+        seeds::program = NFT_OPERATIONS_PROGRAM_ID,
+    )]
+    pub fee_authority: Signer<'info>,
+    pub authority: Signer<'info>,
+    /// CHECK: Role PDA of the authority, only read when it has been created
+    #[account(
+        seeds = [b"project_role", project_config.key().as_ref(), authority.key().as_ref()],
+        bump,
+        constraint = ProjectRole::grants(&authority_role, resource.required_role())?
+            @ ProtocolError::Unauthorized,
+    )]
+    pub authority_role: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"project",
+            project_config.creator.as_ref(),
+            project_config.protocol_config.as_ref(),
+            project_config.project_id.to_le_bytes().as_ref(),
+        ],
+        bump = project_config.bump,
+    )]
+    pub project_config: Account<'info, ProjectConfig>,
+}
+
+/// Called by nft-operations when a minter config or trade hub is closed.
+#[derive(Accounts)]
+#[instruction(resource: ProjectResource)]
+pub struct UnregisterProjectResource<'info> {
+    #[account(
+        seeds = [b"fee_authority"],
+        bump,
+        seeds::program = NFT_OPERATIONS_PROGRAM_ID,
+    )]
+    pub fee_authority: Signer<'info>,
+    pub authority: Signer<'info>,
+    /// CHECK: Role PDA of the authority, only read when it has been created
+    #[account(
+        seeds = [b"project_role", project_config.key().as_ref(), authority.key().as_ref()],
+        bump,
+        constraint = ProjectRole::grants(&authority_role, resource.required_role())?
+            @ ProtocolError::Unauthorized,
+    )]
+    pub authority_role: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"project",
+            project_config.creator.as_ref(),
+            project_config.protocol_config.as_ref(),
+            project_config.project_id.to_le_bytes().as_ref(),
+        ],
+        bump = project_config.bump,
+    )]
+    pub project_config: Account<'info, ProjectConfig>,
+}
+
+impl<'info> RegisterProjectResource<'info> {
+    pub fn register_project_resource(&mut self, resource: ProjectResource) -> Result<()> {
+        self.project_config.register_resource(resource)?;
+
+        emit!(ProjectResourceRegistered {
+            project_config: self.project_config.key(),
+            authority: self.authority.key(),
+            resource,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> UnregisterProjectResource<'info> {
+    pub fn unregister_project_resource(&mut self, resource: ProjectResource) -> Result<()> {
+        self.project_config.unregister_resource(resource);
+
+        emit!(ProjectResourceUnregistered {
+            project_config: self.project_config.key(),
+            authority: self.authority.key(),
+            resource,
+        });
+
+        Ok(())
+    }
+}
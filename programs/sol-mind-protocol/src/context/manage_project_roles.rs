@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+use anchor_lang::AccountsClose;
+
+use crate::errors::ProtocolError;
+use crate::events::{ProjectRoleGranted, ProjectRoleRevoked};
+use crate::state::{ProjectConfig, ProjectRole, Role};
+
+/// The project owner manages every role; `Admin` grantees manage every role but `Admin`.
+fn check_role_manager(
+    project_config: &ProjectConfig,
+    authority: &Pubkey,
+    authority_role: &AccountInfo,
+    role: Role,
+) -> Result<()> {
+    if project_config.owner == *authority {
+        return Ok(());
+    }
+
+    require!(
+        role != Role::Admin && ProjectRole::grants(authority_role, Role::Admin)?,
+        ProtocolError::Unauthorized
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(grantee: Pubkey)]
+pub struct GrantProjectRole<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    /// CHECK: Role PDA of the authority, only read when it has been created
+    #[account(
+        seeds = [b"project_role", project_config.key().as_ref(), authority.key().as_ref()],
+        bump,
+    )]
+    pub authority_role: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"project",
+            project_config.creator.as_ref(),
+            project_config.protocol_config.as_ref(),
+            project_config.project_id.to_le_bytes().as_ref(),
+        ],
+        bump = project_config.bump,
+    )]
+    pub project_config: Account<'info, ProjectConfig>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + ProjectRole::INIT_SPACE,
+        seeds = [b"project_role", project_config.key().as_ref(), grantee.as_ref()],
+        bump,
+    )]
+    pub project_role: Account<'info, ProjectRole>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> GrantProjectRole<'info> {
+    pub fn grant_project_role(&mut self, grantee: Pubkey, role: Role, bump: u8) -> Result<()> {
+        check_role_manager(
+            &self.project_config,
+            &self.authority.key(),
+            &self.authority_role,
+            role,
+        )?;
+
+        // A new role PDA is counted on the project, so the project can't be closed over it
+        if self.project_role.project_config == Pubkey::default() {
+            self.project_config.add_role()?;
+        }
+
+        self.project_role.project_config = self.project_config.key();
+        self.project_role.grantee = grantee;
+        self.project_role.bump = bump;
+        self.project_role.grant(role)?;
+
+        emit!(ProjectRoleGranted {
+            project_config: self.project_config.key(),
+            authority: self.authority.key(),
+            grantee,
+            role,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct RevokeProjectRole<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: Role PDA of the authority, only read when it has been created
+    #[account(
+        seeds = [b"project_role", project_config.key().as_ref(), authority.key().as_ref()],
+        bump,
+    )]
+    pub authority_role: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"project",
+            project_config.creator.as_ref(),
+            project_config.protocol_config.as_ref(),
+            project_config.project_id.to_le_bytes().as_ref(),
+        ],
+        bump = project_config.bump,
+    )]
+    pub project_config: Account<'info, ProjectConfig>,
+    #[account(
+        mut,
+        seeds = [
+            b"project_role",
+            project_config.key().as_ref(),
+            project_role.grantee.as_ref(),
+        ],
+        bump = project_role.bump,
+    )]
+    pub project_role: Account<'info, ProjectRole>,
+}
+
+impl<'info> RevokeProjectRole<'info> {
+    /// Closes the role PDA to the authority once the grantee holds no roles.
+    pub fn revoke_project_role(&mut self, role: Role) -> Result<()> {
+        check_role_manager(
+            &self.project_config,
+            &self.authority.key(),
+            &self.authority_role,
+            role,
+        )?;
+
+        self.project_role.revoke(role)?;
+
+        emit!(ProjectRoleRevoked {
+            project_config: self.project_config.key(),
+            authority: self.authority.key(),
+            grantee: self.project_role.grantee,
+            role,
+        });
+
+        if self.project_role.roles == 0 {
+            self.project_role.close(self.authority.to_account_info())?;
+            self.project_config.remove_role();
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,135 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ProtocolError;
+use crate::events::{ReferrerRegistered, ReferrerRemoved, ReferrerUpdated};
+use crate::state::{ProtocolConfig, Referrer};
+
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct RegisterReferrer<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [
+            b"sol-mind-protocol",
+            ProtocolConfig::instance_seed(protocol_config.instance_id).as_slice(),
+        ],
+        bump = protocol_config.bump,
+        constraint = protocol_config.check_admins(&admin.key()) @ ProtocolError::Unauthorized,
+        constraint = !protocol_config.requires_approval() @ ProtocolError::ApprovalRequired,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Referrer::INIT_SPACE,
+        seeds = [b"referrer", protocol_config.key().as_ref(), wallet.as_ref()],
+        bump,
+    )]
+    pub referrer: Account<'info, Referrer>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RegisterReferrer<'info> {
+    pub fn register_referrer(&mut self, wallet: Pubkey, share_bps: u16, bump: u8) -> Result<()> {
+        Referrer::validate_share_bps(share_bps)?;
+
+        self.referrer.set_inner(Referrer {
+            protocol_config: self.protocol_config.key(),
+            wallet,
+            share_bps,
+            bump,
+        });
+
+        emit!(ReferrerRegistered {
+            protocol_config: self.protocol_config.key(),
+            admin: self.admin.key(),
+            referrer: self.referrer.key(),
+            wallet,
+            share_bps,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct UpdateReferrer<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [
+            b"sol-mind-protocol",
+            ProtocolConfig::instance_seed(protocol_config.instance_id).as_slice(),
+        ],
+        bump = protocol_config.bump,
+        constraint = protocol_config.check_admins(&admin.key()) @ ProtocolError::Unauthorized,
+        constraint = !protocol_config.requires_approval() @ ProtocolError::ApprovalRequired,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(
+        mut,
+        seeds = [b"referrer", protocol_config.key().as_ref(), referrer.wallet.as_ref()],
+        bump = referrer.bump,
+    )]
+    pub referrer: Account<'info, Referrer>,
+}
+
+impl<'info> UpdateReferrer<'info> {
+    pub fn update_referrer(&mut self, share_bps: u16) -> Result<()> {
+        Referrer::validate_share_bps(share_bps)?;
+
+        self.referrer.share_bps = share_bps;
+
+        emit!(ReferrerUpdated {
+            protocol_config: self.protocol_config.key(),
+            admin: self.admin.key(),
+            referrer: self.referrer.key(),
+            share_bps,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct RemoveReferrer<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [
+            b"sol-mind-protocol",
+            ProtocolConfig::instance_seed(protocol_config.instance_id).as_slice(),
+        ],
+        bump = protocol_config.bump,
+        constraint = protocol_config.check_admins(&admin.key()) @ ProtocolError::Unauthorized,
+        constraint = !protocol_config.requires_approval() @ ProtocolError::ApprovalRequired,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    /// CHECK: receives the unclaimed referral fees and the rent, address checked against the
+    /// referrer
+    #[account(mut, address = referrer.wallet)]
+    pub wallet: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = wallet,
+        seeds = [b"referrer", protocol_config.key().as_ref(), referrer.wallet.as_ref()],
+        bump = referrer.bump,
+    )]
+    pub referrer: Account<'info, Referrer>,
+}
+
+impl<'info> RemoveReferrer<'info> {
+    /// Closes the referrer, paying out its unclaimed referral fees along with the rent.
+    pub fn remove_referrer(&mut self) -> Result<()> {
+        emit!(ReferrerRemoved {
+            protocol_config: self.protocol_config.key(),
+            admin: self.admin.key(),
+            referrer: self.referrer.key(),
+            wallet: self.referrer.wallet,
+            unclaimed: Referrer::claimable(&self.referrer.to_account_info())?,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ProtocolError;
+use crate::events::{ProgramRegistered, ProgramUnregistered};
+use crate::state::{ProtocolConfig, RegisteredProgram};
+
+#[derive(Accounts)]
+#[instruction(program_id: Pubkey)]
+pub struct RegisterProgram<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [
+            b"sol-mind-protocol",
+            ProtocolConfig::instance_seed(protocol_config.instance_id).as_slice(),
+        ],
+        bump = protocol_config.bump,
+        constraint = protocol_config.check_admins(&admin.key()) @ ProtocolError::Unauthorized,
+        constraint = !protocol_config.requires_approval() @ ProtocolError::ApprovalRequired,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + RegisteredProgram::INIT_SPACE,
+        seeds = [
+            b"registered_program",
+            protocol_config.key().as_ref(),
+            program_id.as_ref(),
+        ],
+        bump,
+    )]
+    pub registered_program: Account<'info, RegisteredProgram>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RegisterProgram<'info> {
+    pub fn register_program(&mut self, program_id: Pubkey, bump: u8) -> Result<()> {
+        RegisteredProgram::validate_program_id(&program_id)?;
+
+        self.registered_program.set_inner(RegisteredProgram {
+            protocol_config: self.protocol_config.key(),
+            program_id,
+            registered_by: self.admin.key(),
+            payer: self.payer.key(),
+            bump,
+        });
+
+        emit!(ProgramRegistered {
+            protocol_config: self.protocol_config.key(),
+            admin: self.admin.key(),
+            program_id,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct UnregisterProgram<'info> {
+    pub admin: Signer<'info>,
+    /// Receives the rent of the registered program account
+    #[account(mut)]
+    pub payer: SystemAccount<'info>,
+    #[account(
+        seeds = [
+            b"sol-mind-protocol",
+            ProtocolConfig::instance_seed(protocol_config.instance_id).as_slice(),
+        ],
+        bump = protocol_config.bump,
+        constraint = protocol_config.check_admins(&admin.key()) @ ProtocolError::Unauthorized,
+        constraint = !protocol_config.requires_approval() @ ProtocolError::ApprovalRequired,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(
+        mut,
+        has_one = payer @ ProtocolError::InvalidRentPayer,
+        close = payer,
+        seeds = [
+            b"registered_program",
+            protocol_config.key().as_ref(),
+            registered_program.program_id.as_ref(),
+        ],
+        bump = registered_program.bump,
+    )]
+    pub registered_program: Account<'info, RegisteredProgram>,
+}
+
+impl<'info> UnregisterProgram<'info> {
+    pub fn unregister_program(&mut self) -> Result<()> {
+        emit!(ProgramUnregistered {
+            protocol_config: self.protocol_config.key(),
+            admin: self.admin.key(),
+            program_id: self.registered_program.program_id,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ProtocolError;
+use crate::events::{SubscriptionPaid, SubscriptionPlanRemoved, SubscriptionPlanSet};
+use crate::helpers::cpi_transfer;
+use crate::state::{FeeStats, ProjectConfig, ProtocolConfig, Subscription, SubscriptionPlan};
+
+#[derive(Accounts)]
+pub struct ManageSubscriptionPlans<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"sol-mind-protocol",
+            ProtocolConfig::instance_seed(protocol_config.instance_id).as_slice(),
+        ],
+        bump = protocol_config.bump,
+        constraint = protocol_config.check_admins(&admin.key()) @ ProtocolError::Unauthorized,
+        constraint = !protocol_config.requires_approval() @ ProtocolError::ApprovalRequired,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+impl<'info> ManageSubscriptionPlans<'info> {
+    pub fn set_subscription_plan(&mut self, plan: SubscriptionPlan) -> Result<()> {
+        self.protocol_config.set_subscription_plan(plan)?;
+
+        emit!(SubscriptionPlanSet {
+            protocol_config: self.protocol_config.key(),
+            admin: self.admin.key(),
+            plan,
+        });
+
+        Ok(())
+    }
+
+    /// Existing subscriptions to the plan keep their paid epochs but lose the grace period.
+    pub fn remove_subscription_plan(&mut self, plan_id: u8) -> Result<()> {
+        self.protocol_config.remove_subscription_plan(plan_id)?;
+
+        emit!(SubscriptionPlanRemoved {
+            protocol_config: self.protocol_config.key(),
+            admin: self.admin.key(),
+            plan_id,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct PaySubscription<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub owner: Signer<'info>,
+    #[account(
+        has_one = owner @ ProtocolError::Unauthorized,
+        has_one = protocol_config @ ProtocolError::InvalidProjectConfig,
+        seeds = [
+            b"project",
+            project_config.creator.as_ref(),
+            project_config.protocol_config.as_ref(),
+            project_config.project_id.to_le_bytes().as_ref(),
+        ],
+        bump = project_config.bump,
+    )]
+    pub project_config: Account<'info, ProjectConfig>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + Subscription::INIT_SPACE,
+        seeds = [b"subscription", project_config.key().as_ref()],
+        bump,
+    )]
+    pub subscription: Account<'info, Subscription>,
+    #[account(
+        seeds = [
+            b"sol-mind-protocol",
+            ProtocolConfig::instance_seed(protocol_config.instance_id).as_slice(),
+        ],
+        bump = protocol_config.bump,
+        constraint = !protocol_config.paused @ ProtocolError::OperationPaused,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(
+        mut,
+        seeds = [b"treasury", protocol_config.key().as_ref()],
+        bump,
+    )]
+    pub protocol_treasury: SystemAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"fee_stats", protocol_config.key().as_ref()],
+        bump = protocol_fee_stats.bump,
+    )]
+    pub protocol_fee_stats: Account<'info, FeeStats>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> PaySubscription<'info> {
+    /// Prepays `epochs` of the plan into the protocol treasury.
+    pub fn pay_subscription(&mut self, plan_id: u8, epochs: u64, bump: u8) -> Result<()> {
+        let plan = *self
+            .protocol_config
+            .get_subscription_plan(plan_id)
+            .ok_or(ProtocolError::SubscriptionPlanNotFound)?;
+        let amount = plan
+            .price_per_epoch
+            .checked_mul(epochs)
+            .ok_or(ProtocolError::MathOverflow)?;
+
+        self.subscription.project_config = self.project_config.key();
+        self.subscription.bump = bump;
+        self.subscription
+            .extend(plan_id, epochs, Clock::get()?.epoch)?;
+
+        if amount > 0 {
+            cpi_transfer(
+                self.payer.to_account_info(),
+                self.protocol_treasury.to_account_info(),
+                amount,
+                &self.system_program,
+            )?;
+            self.protocol_fee_stats
+                .record_subscription_payment(amount)?;
+        }
+
+        emit!(SubscriptionPaid {
+            protocol_config: self.protocol_config.key(),
+            project_config: self.project_config.key(),
+            plan_id,
+            epochs,
+            amount,
+            paid_until_epoch: self.subscription.paid_until_epoch,
+        });
+
+        Ok(())
+    }
+}
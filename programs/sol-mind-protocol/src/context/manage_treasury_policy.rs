@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ProtocolError;
+use crate::events::{TreasuryPolicyClosed, TreasuryPolicySet};
+use crate::state::{ProjectConfig, TreasuryPolicy};
+
+#[derive(Accounts)]
+pub struct SetTreasuryPolicy<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub owner: Signer<'info>,
+    #[account(
+        has_one = owner @ ProtocolError::Unauthorized,
+        seeds = [
+            b"project",
+            project_config.creator.as_ref(),
+            project_config.protocol_config.as_ref(),
+            project_config.project_id.to_le_bytes().as_ref(),
+        ],
+        bump = project_config.bump,
+    )]
+    pub project_config: Account<'info, ProjectConfig>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + TreasuryPolicy::INIT_SPACE,
+        seeds = [b"treasury_policy", project_config.key().as_ref()],
+        bump,
+    )]
+    pub treasury_policy: Account<'info, TreasuryPolicy>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetTreasuryPolicy<'info> {
+    /// Replaces the destinations and cap; lamports already withdrawn this epoch still count.
+    pub fn set_treasury_policy(
+        &mut self,
+        destinations: Vec<Pubkey>,
+        epoch_cap: Option<u64>,
+        bump: u8,
+    ) -> Result<()> {
+        TreasuryPolicy::validate_destinations(&destinations)?;
+
+        self.treasury_policy.project_config = self.project_config.key();
+        self.treasury_policy.destinations = destinations.clone();
+        self.treasury_policy.epoch_cap = epoch_cap;
+        self.treasury_policy.bump = bump;
+
+        emit!(TreasuryPolicySet {
+            project_config: self.project_config.key(),
+            destinations,
+            epoch_cap,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct CloseTreasuryPolicy<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        has_one = owner @ ProtocolError::Unauthorized,
+        seeds = [
+            b"project",
+            project_config.creator.as_ref(),
+            project_config.protocol_config.as_ref(),
+            project_config.project_id.to_le_bytes().as_ref(),
+        ],
+        bump = project_config.bump,
+    )]
+    pub project_config: Account<'info, ProjectConfig>,
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"treasury_policy", project_config.key().as_ref()],
+        bump = treasury_policy.bump,
+    )]
+    pub treasury_policy: Account<'info, TreasuryPolicy>,
+}
+
+impl<'info> CloseTreasuryPolicy<'info> {
+    pub fn close_treasury_policy(&mut self) -> Result<()> {
+        emit!(TreasuryPolicyClosed {
+            project_config: self.project_config.key(),
+        });
+
+        Ok(())
+    }
+}
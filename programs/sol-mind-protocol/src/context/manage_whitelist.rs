@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ProtocolError;
+use crate::events::{WhitelistTransferAddrAdded, WhitelistTransferAddrRemoved};
+use crate::state::{ProtocolConfig, WhitelistEntry};
+
+#[derive(Accounts)]
+pub struct ManageWhitelist<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"sol-mind-protocol",
+            ProtocolConfig::instance_seed(protocol_config.instance_id).as_slice(),
+        ],
+        bump = protocol_config.bump,
+        constraint = protocol_config.check_admins(&admin.key()) @ ProtocolError::Unauthorized,
+        constraint = !protocol_config.requires_approval() @ ProtocolError::ApprovalRequired,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+impl<'info> ManageWhitelist<'info> {
+    pub fn add_whitelist_transfer_addr(
+        &mut self,
+        address: Pubkey,
+        label: Option<String>,
+        transfer_cap: Option<u64>,
+    ) -> Result<()> {
+        self.protocol_config
+            .add_whitelist_transfer_addr(WhitelistEntry {
+                address,
+                label: label.clone(),
+                transfer_cap,
+            })?;
+
+        emit!(WhitelistTransferAddrAdded {
+            protocol_config: self.protocol_config.key(),
+            admin: self.admin.key(),
+            address,
+            label,
+            transfer_cap,
+        });
+
+        Ok(())
+    }
+
+    pub fn remove_whitelist_transfer_addr(&mut self, address: Pubkey) -> Result<()> {
+        self.protocol_config
+            .remove_whitelist_transfer_addr(&address)?;
+
+        emit!(WhitelistTransferAddrRemoved {
+            protocol_config: self.protocol_config.key(),
+            admin: self.admin.key(),
+            address,
+        });
+
+        Ok(())
+    }
+}
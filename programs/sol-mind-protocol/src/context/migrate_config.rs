@@ -0,0 +1,204 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ProtocolError;
+use crate::events::{ProjectConfigMigrated, ProtocolConfigMigrated};
+use crate::helpers::{create_pda_account, grow_account};
+use crate::state::{
+    ProjectConfig, ProjectRegistryEntry, ProjectResource, ProjectRole, ProtocolConfig,
+};
+
+// Configs of an older layout decode through `deserialize_config`, but can only be written
+// once migrated. A proposal updates the protocol config, so instead of `ApprovalRequired` a
+// migration takes the other admins' signatures as remaining accounts, up to the approval
+// threshold. The account is grown before Anchor writes the upgraded config back on exit.
+//
+// A project migration also takes the project's accounts its older version did not count,
+// as non-signer remaining accounts: minter configs and trade hubs of first-release projects,
+// and role PDAs of projects before version 3. Each is checked to belong to the project and
+// counted, so `close_project` can rely on the counts. First-release projects also get the
+// registry entry of their name, unless another project has registered the name since.
+
+#[derive(Accounts)]
+#[instruction(instance_id: u64)]
+pub struct MigrateProtocolConfig<'info> {
+    #[account(
+        mut,
+        constraint = protocol_config.check_admins(&admin.key()) @ ProtocolError::Unauthorized,
+    )]
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"sol-mind-protocol",
+            ProtocolConfig::instance_seed(instance_id).as_slice(),
+        ],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateProtocolConfig<'info> {
+    pub fn migrate_protocol_config(&mut self, co_signers: &[AccountInfo<'info>]) -> Result<()> {
+        self.protocol_config
+            .require_admin_signers(self.admin.to_account_info(), co_signers)?;
+
+        let from_version = self.protocol_config.migrate()?;
+        grow_account(
+            &self.protocol_config.to_account_info(),
+            8 + ProtocolConfig::INIT_SPACE,
+            self.admin.to_account_info(),
+            &self.system_program,
+        )?;
+
+        emit!(ProtocolConfigMigrated {
+            protocol_config: self.protocol_config.key(),
+            admin: self.admin.key(),
+            from_version,
+            to_version: self.protocol_config.version,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct MigrateProjectConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [
+            b"sol-mind-protocol",
+            ProtocolConfig::instance_seed(protocol_config.instance_id).as_slice(),
+        ],
+        bump = protocol_config.bump,
+        constraint = protocol_config.check_admins(&admin.key()) @ ProtocolError::Unauthorized,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(
+        mut,
+        has_one = protocol_config @ ProtocolError::InvalidProjectConfig,
+        seeds = [
+            b"project",
+            project_config.creator.as_ref(),
+            protocol_config.key().as_ref(),
+            project_config.project_id.to_le_bytes().as_ref(),
+        ],
+        bump = project_config.bump,
+    )]
+    pub project_config: Account<'info, ProjectConfig>,
+    /// CHECK: Registry entry of the project name, created when no project has registered it
+    #[account(
+        mut,
+        seeds = [
+            b"project_registry",
+            protocol_config.key().as_ref(),
+            ProjectRegistryEntry::name_seed(&project_config.name).as_ref(),
+        ],
+        bump,
+    )]
+    pub project_registry: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateProjectConfig<'info> {
+    pub fn migrate_project_config(
+        &mut self,
+        remaining_accounts: &[AccountInfo<'info>],
+        registry_bump: u8,
+    ) -> Result<()> {
+        let (co_signers, project_accounts): (Vec<_>, Vec<_>) = remaining_accounts
+            .iter()
+            .cloned()
+            .partition(|account| account.is_signer);
+        self.protocol_config
+            .require_admin_signers(self.admin.to_account_info(), &co_signers)?;
+
+        let from_version = self.project_config.migrate()?;
+        self.count_project_accounts(from_version, &project_accounts)?;
+        self.register_project_name(registry_bump)?;
+        grow_account(
+            &self.project_config.to_account_info(),
+            8 + ProjectConfig::INIT_SPACE,
+            self.admin.to_account_info(),
+            &self.system_program,
+        )?;
+
+        emit!(ProjectConfigMigrated {
+            project_config: self.project_config.key(),
+            admin: self.admin.key(),
+            from_version,
+            to_version: self.project_config.version,
+        });
+
+        Ok(())
+    }
+
+    fn count_project_accounts(
+        &mut self,
+        from_version: u8,
+        accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        let project_key = self.project_config.key();
+
+        for (index, account) in accounts.iter().enumerate() {
+            require!(
+                accounts[..index]
+                    .iter()
+                    .all(|other| other.key != account.key),
+                ProtocolError::InvalidProjectAccount
+            );
+
+            if *account.owner == crate::ID {
+                require!(from_version < 3, ProtocolError::InvalidProjectAccount);
+                let data = account.try_borrow_data()?;
+                let role = ProjectRole::try_deserialize(&mut &data[..])
+                    .map_err(|_| ProtocolError::InvalidProjectAccount)?;
+                require_keys_eq!(
+                    role.project_config,
+                    project_key,
+                    ProtocolError::InvalidProjectAccount
+                );
+                self.project_config.add_role()?;
+            } else {
+                require!(from_version == 0, ProtocolError::InvalidProjectAccount);
+                let resource = ProjectResource::from_account(account, &project_key)?;
+                self.project_config.register_resource(resource)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn register_project_name(&self, bump: u8) -> Result<()> {
+        if !self.project_registry.data_is_empty() {
+            return Ok(());
+        }
+
+        let protocol_key = self.protocol_config.key();
+        let name_seed = ProjectRegistryEntry::name_seed(&self.project_config.name);
+        create_pda_account(
+            &self.project_registry,
+            8 + ProjectRegistryEntry::INIT_SPACE,
+            self.admin.to_account_info(),
+            &self.system_program,
+            &[&[
+                b"project_registry",
+                protocol_key.as_ref(),
+                name_seed.as_ref(),
+                &[bump],
+            ]],
+        )?;
+
+        let entry = ProjectRegistryEntry {
+            protocol_config: protocol_key,
+            project_config: self.project_config.key(),
+            bump,
+        };
+        let mut data = self.project_registry.try_borrow_mut_data()?;
+        let mut writer: &mut [u8] = &mut data;
+        entry.try_serialize(&mut writer)
+    }
+}
@@ -1,11 +1,69 @@
+pub mod accept_owner;
+pub mod approve_proposal;
+pub mod cancel_pending_fees;
+pub mod cancel_proposal;
+pub mod charge_generic_fee;
+pub mod claim_referral_fees;
+pub mod close_project;
+pub mod close_project_fee_override;
 pub mod create_project;
+pub mod create_proposal;
+pub mod distribute_protocol_fees;
+pub mod execute_proposal;
+pub mod initialize_fee_stats;
 pub mod initialize_protocol;
+pub mod manage_admins;
+pub mod manage_project;
+pub mod manage_project_resources;
+pub mod manage_project_roles;
+pub mod manage_referrers;
+pub mod manage_registered_programs;
+pub mod manage_subscriptions;
+pub mod manage_treasury_policy;
+pub mod manage_whitelist;
+pub mod migrate_config;
+pub mod record_fees;
+pub mod rename_project;
+pub mod set_pause;
+pub mod set_project_fee_override;
+pub mod set_revenue_shares;
 pub mod transfer_project_fees;
+pub mod transfer_project_token_fees;
 pub mod transfer_protocol_fees;
+pub mod transfer_protocol_token_fees;
 pub mod update_fees;
 
+pub use accept_owner::*;
+pub use approve_proposal::*;
+pub use cancel_pending_fees::*;
+pub use cancel_proposal::*;
+pub use charge_generic_fee::*;
+pub use claim_referral_fees::*;
+pub use close_project::*;
+pub use close_project_fee_override::*;
 pub use create_project::*;
+pub use create_proposal::*;
+pub use distribute_protocol_fees::*;
+pub use execute_proposal::*;
+pub use initialize_fee_stats::*;
 pub use initialize_protocol::*;
+pub use manage_admins::*;
+pub use manage_project::*;
+pub use manage_project_resources::*;
+pub use manage_project_roles::*;
+pub use manage_referrers::*;
+pub use manage_registered_programs::*;
+pub use manage_subscriptions::*;
+pub use manage_treasury_policy::*;
+pub use manage_whitelist::*;
+pub use migrate_config::*;
+pub use record_fees::*;
+pub use rename_project::*;
+pub use set_pause::*;
+pub use set_project_fee_override::*;
+pub use set_revenue_shares::*;
 pub use transfer_project_fees::*;
+pub use transfer_project_token_fees::*;
 pub use transfer_protocol_fees::*;
+pub use transfer_protocol_token_fees::*;
 pub use update_fees::*;
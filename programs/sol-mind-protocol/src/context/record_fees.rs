@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::events::ProtocolFeePaid;
+use crate::state::{FeePayment, FeeStats, ProjectConfig, NFT_OPERATIONS_PROGRAM_ID};
+
+/// Called by nft-operations after charging fees, so the fee stats of the protocol and the
+/// project include them.
+#[derive(Accounts)]
+pub struct RecordFees<'info> {
+    #[account(
+        seeds = [b"fee_authority"],
+        bump,
+        seeds::program = NFT_OPERATIONS_PROGRAM_ID,
+    )]
+    pub fee_authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"fee_stats", project_config.protocol_config.as_ref()],
+        bump = protocol_fee_stats.bump,
+    )]
+    pub protocol_fee_stats: Account<'info, FeeStats>,
+    #[account(
+        seeds = [
+            b"project",
+            project_config.creator.as_ref(),
+            project_config.protocol_config.as_ref(),
+            project_config.project_id.to_le_bytes().as_ref(),
+        ],
+        bump = project_config.bump,
+    )]
+    pub project_config: Account<'info, ProjectConfig>,
+    #[account(
+        mut,
+        seeds = [b"fee_stats", project_config.key().as_ref()],
+        bump = project_fee_stats.bump,
+    )]
+    pub project_fee_stats: Account<'info, FeeStats>,
+}
+
+impl<'info> RecordFees<'info> {
+    pub fn record_fees(&mut self, protocol_fee: FeePayment, trade_hub_fee: u64) -> Result<()> {
+        self.protocol_fee_stats
+            .record_protocol_fee(&protocol_fee, true)?;
+        self.project_fee_stats
+            .record_protocol_fee(&protocol_fee, false)?;
+
+        if trade_hub_fee > 0 {
+            self.project_fee_stats.record_trade_hub_fee(trade_hub_fee)?;
+        }
+
+        emit!(ProtocolFeePaid {
+            protocol_config: self.project_config.protocol_config,
+            project_config: self.project_config.key(),
+            operation: protocol_fee.operation,
+            amount: protocol_fee.amount,
+            mint: protocol_fee.mint,
+            referrer: protocol_fee.referrer,
+            referral_amount: protocol_fee.referral_amount,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ProtocolError;
+use crate::events::ProjectRenamed;
+use crate::helpers::close_account;
+use crate::state::{ProjectConfig, ProjectRegistryEntry};
+
+#[derive(Accounts)]
+#[instruction(new_name: String)]
+pub struct RenameProject<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        has_one = owner @ ProtocolError::Unauthorized,
+        seeds = [
+            b"project",
+            project_config.creator.as_ref(),
+            project_config.protocol_config.as_ref(),
+            project_config.project_id.to_le_bytes().as_ref(),
+        ],
+        bump = project_config.bump,
+    )]
+    pub project_config: Account<'info, ProjectConfig>,
+    /// CHECK: Registry entry of the current name, closed when it maps to this project
+    #[account(
+        mut,
+        seeds = [
+            b"project_registry",
+            project_config.protocol_config.as_ref(),
+            ProjectRegistryEntry::name_seed(&project_config.name).as_ref(),
+        ],
+        bump,
+    )]
+    pub project_registry: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + ProjectRegistryEntry::INIT_SPACE,
+        seeds = [
+            b"project_registry",
+            project_config.protocol_config.as_ref(),
+            ProjectRegistryEntry::name_seed(&new_name).as_ref(),
+        ],
+        bump,
+    )]
+    pub new_project_registry: Account<'info, ProjectRegistryEntry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RenameProject<'info> {
+    pub fn rename_project(&mut self, new_name: String, bump: u8) -> Result<()> {
+        let previous_name = self.project_config.name.clone();
+        self.close_project_registry()?;
+        self.project_config.set_name(new_name.clone())?;
+
+        self.new_project_registry.set_inner(ProjectRegistryEntry {
+            protocol_config: self.project_config.protocol_config,
+            project_config: self.project_config.key(),
+            bump,
+        });
+
+        emit!(ProjectRenamed {
+            project_config: self.project_config.key(),
+            previous_name,
+            new_name,
+        });
+
+        Ok(())
+    }
+
+    /// A first-release project whose name was registered by another project has no entry
+    /// to release.
+    fn close_project_registry(&self) -> Result<()> {
+        if self.project_registry.owner != &crate::ID {
+            return Ok(());
+        }
+
+        let entry = {
+            let data = self.project_registry.try_borrow_data()?;
+            ProjectRegistryEntry::try_deserialize(&mut &data[..])?
+        };
+        if entry.project_config == self.project_config.key() {
+            close_account(&self.project_registry, &self.owner.to_account_info())?;
+        }
+
+        Ok(())
+    }
+}
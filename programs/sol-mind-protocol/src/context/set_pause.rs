@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ProtocolError;
+use crate::events::{OperationPauseUpdated, ProtocolPauseUpdated};
+use crate::state::{Operation, ProtocolConfig};
+
+#[derive(Accounts)]
+pub struct SetPause<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"sol-mind-protocol",
+            ProtocolConfig::instance_seed(protocol_config.instance_id).as_slice(),
+        ],
+        bump = protocol_config.bump,
+        constraint = protocol_config.check_admins(&admin.key()) @ ProtocolError::Unauthorized,
+        constraint = !protocol_config.requires_approval() @ ProtocolError::ApprovalRequired,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+impl<'info> SetPause<'info> {
+    pub fn set_protocol_paused(&mut self, paused: bool) -> Result<()> {
+        self.protocol_config.paused = paused;
+
+        emit!(ProtocolPauseUpdated {
+            protocol_config: self.protocol_config.key(),
+            admin: self.admin.key(),
+            paused,
+        });
+
+        Ok(())
+    }
+
+    pub fn set_operation_paused(&mut self, operation: Operation, paused: bool) -> Result<()> {
+        self.protocol_config.set_operation_paused(operation, paused);
+
+        emit!(OperationPauseUpdated {
+            protocol_config: self.protocol_config.key(),
+            admin: self.admin.key(),
+            operation,
+            paused,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ProtocolError;
+use crate::events::ProjectFeeOverrideSet;
+use crate::state::{OperationFeeOverride, ProjectConfig, ProjectFeeOverride, ProtocolConfig};
+
+#[derive(Accounts)]
+pub struct SetProjectFeeOverride<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [
+            b"sol-mind-protocol",
+            ProtocolConfig::instance_seed(protocol_config.instance_id).as_slice(),
+        ],
+        bump = protocol_config.bump,
+        constraint = protocol_config.check_admins(&admin.key()) @ ProtocolError::Unauthorized,
+        constraint = !protocol_config.requires_approval() @ ProtocolError::ApprovalRequired,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(
+        has_one = protocol_config @ ProtocolError::InvalidProjectConfig,
+        seeds = [
+            b"project",
+            project_config.creator.as_ref(),
+            project_config.protocol_config.as_ref(),
+            project_config.project_id.to_le_bytes().as_ref(),
+        ],
+        bump = project_config.bump,
+    )]
+    pub project_config: Account<'info, ProjectConfig>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + ProjectFeeOverride::INIT_SPACE,
+        seeds = [b"fee_override", project_config.key().as_ref()],
+        bump,
+    )]
+    pub project_fee_override: Account<'info, ProjectFeeOverride>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetProjectFeeOverride<'info> {
+    pub fn set_project_fee_override(
+        &mut self,
+        overrides: Vec<OperationFeeOverride>,
+        expires_at: Option<i64>,
+        bump: u8,
+    ) -> Result<()> {
+        // A newly created override account still holds the default project config
+        let previous = (self.project_fee_override.project_config == self.project_config.key())
+            .then_some(&*self.project_fee_override);
+        let fee_override = ProjectFeeOverride::new(
+            self.project_config.key(),
+            overrides,
+            expires_at,
+            previous,
+            self.payer.key(),
+            Clock::get()?.unix_timestamp,
+            bump,
+        )?;
+        let overrides = fee_override.overrides.clone();
+
+        self.project_fee_override.set_inner(fee_override);
+
+        emit!(ProjectFeeOverrideSet {
+            protocol_config: self.protocol_config.key(),
+            project_config: self.project_config.key(),
+            admin: self.admin.key(),
+            overrides,
+            expires_at,
+        });
+
+        Ok(())
+    }
+}
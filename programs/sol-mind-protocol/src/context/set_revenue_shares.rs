@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ProtocolError;
+use crate::events::RevenueSharesUpdated;
+use crate::state::{ProtocolConfig, RevenueShare};
+
+#[derive(Accounts)]
+pub struct SetRevenueShares<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"sol-mind-protocol",
+            ProtocolConfig::instance_seed(protocol_config.instance_id).as_slice(),
+        ],
+        bump = protocol_config.bump,
+        constraint = protocol_config.check_admins(&admin.key()) @ ProtocolError::Unauthorized,
+        constraint = !protocol_config.requires_approval() @ ProtocolError::ApprovalRequired,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+impl<'info> SetRevenueShares<'info> {
+    pub fn set_revenue_shares(&mut self, shares: Vec<RevenueShare>) -> Result<()> {
+        self.protocol_config.set_revenue_shares(shares.clone())?;
+
+        emit!(RevenueSharesUpdated {
+            protocol_config: self.protocol_config.key(),
+            admin: self.admin.key(),
+            shares,
+        });
+
+        Ok(())
+    }
+}
@@ -3,19 +3,30 @@ use anchor_lang::{
     system_program::{transfer, Transfer},
 };
 
-use crate::{helpers::validate_transfer, ProjectConfig};
+use crate::{
+    errors::ProtocolError, events::ProjectFeesTransferred, helpers::validate_transfer, FeeStats,
+    ProjectConfig, ProjectRole, Role, TreasuryPolicy,
+};
 
+/// Withdraws lamports from the project treasury. The owner withdraws freely; `Treasurer` role
+/// holders are bound by the project's treasury policy.
 #[derive(Accounts)]
 pub struct TransferProjectFees<'info> {
     #[account(mut)]
-    pub owner: Signer<'info>,
+    pub authority: Signer<'info>,
+    /// CHECK: Role PDA of the authority, only read when it has been created
+    #[account(
+        seeds = [b"project_role", project_config.key().as_ref(), authority.key().as_ref()],
+        bump,
+    )]
+    pub authority_role: UncheckedAccount<'info>,
     #[account(mut)]
     pub to: SystemAccount<'info>,
     #[account(
         mut,
         seeds = [
             b"project",
-            owner.key.as_ref(),
+            project_config.creator.as_ref(),
             project_config.protocol_config.as_ref(),
             project_config.project_id.to_le_bytes().as_ref(),
         ],
@@ -28,6 +39,18 @@ pub struct TransferProjectFees<'info> {
         bump = project_config.treasury_bump
     )]
     pub treasury: SystemAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"fee_stats", project_config.key().as_ref()],
+        bump = fee_stats.bump,
+    )]
+    pub fee_stats: Account<'info, FeeStats>,
+    #[account(
+        mut,
+        seeds = [b"treasury_policy", project_config.key().as_ref()],
+        bump = treasury_policy.bump,
+    )]
+    pub treasury_policy: Option<Account<'info, TreasuryPolicy>>,
 
     pub system_program: Program<'info, System>,
 }
@@ -36,6 +59,19 @@ impl<'info> TransferProjectFees<'info> {
     pub fn transfer_project_fees(&mut self, amount: u64) -> Result<()> {
         validate_transfer(&self.treasury.to_account_info(), amount)?;
 
+        if self.authority.key() != self.project_config.owner {
+            require!(
+                ProjectRole::grants(&self.authority_role, Role::Treasurer)?,
+                ProtocolError::Unauthorized
+            );
+
+            let treasury_policy = self
+                .treasury_policy
+                .as_mut()
+                .ok_or(ProtocolError::TreasuryPolicyRequired)?;
+            treasury_policy.record_withdrawal(&self.to.key(), amount, Clock::get()?.epoch)?;
+        }
+
         let cpi_program = self.system_program.to_account_info();
         let cpi_accounts = Transfer {
             from: self.treasury.to_account_info(),
@@ -53,6 +89,15 @@ impl<'info> TransferProjectFees<'info> {
 
         transfer(cpi_ctx, amount)?;
 
+        self.fee_stats.record_withdrawal(amount)?;
+
+        emit!(ProjectFeesTransferred {
+            project_config: self.project_config.key(),
+            authority: self.authority.key(),
+            to: self.to.key(),
+            amount,
+        });
+
         Ok(())
     }
 }
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    errors::ProtocolError, events::ProjectTokenFeesTransferred, helpers::cpi_transfer_token_signed,
+    ProjectConfig,
+};
+
+#[derive(Accounts)]
+pub struct TransferProjectTokenFees<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub to_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        has_one = owner @ ProtocolError::Unauthorized,
+        seeds = [
+            b"project",
+            project_config.creator.as_ref(),
+            project_config.protocol_config.as_ref(),
+            project_config.project_id.to_le_bytes().as_ref(),
+        ],
+        bump = project_config.bump,
+    )]
+    pub project_config: Account<'info, ProjectConfig>,
+    #[account(
+        seeds = [b"treasury", project_config.key().as_ref()],
+        bump = project_config.treasury_bump
+    )]
+    pub treasury: SystemAccount<'info>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> TransferProjectTokenFees<'info> {
+    pub fn transfer_project_token_fees(&mut self, amount: u64) -> Result<()> {
+        let seeds = &[
+            b"treasury".as_ref(),
+            self.project_config.to_account_info().key.as_ref(),
+            &[self.project_config.treasury_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        cpi_transfer_token_signed(
+            self.treasury.to_account_info(),
+            self.treasury_token_account.to_account_info(),
+            self.to_token_account.to_account_info(),
+            &self.mint,
+            &self.token_program,
+            amount,
+            signer_seeds,
+        )?;
+
+        emit!(ProjectTokenFeesTransferred {
+            project_config: self.project_config.key(),
+            mint: self.mint.key(),
+            to: self.to_token_account.key(),
+            amount,
+        });
+
+        Ok(())
+    }
+}
@@ -3,7 +3,12 @@ use anchor_lang::{
     system_program::{transfer, Transfer},
 };
 
-use crate::{errors::ProtocolError, helpers::validate_transfer, state::ProtocolConfig};
+use crate::{
+    errors::ProtocolError,
+    events::ProtocolFeesTransferred,
+    helpers::validate_transfer,
+    state::{FeeStats, ProtocolConfig},
+};
 
 #[derive(Accounts)]
 pub struct ProtocolFeesTransfer<'info> {
@@ -18,8 +23,12 @@ pub struct ProtocolFeesTransfer<'info> {
     )]
     pub to: SystemAccount<'info>,
     #[account(
-        seeds = [b"sol-mind-protocol"],
+        seeds = [
+            b"sol-mind-protocol",
+            ProtocolConfig::instance_seed(protocol_config.instance_id).as_slice(),
+        ],
         bump = protocol_config.bump,
+        constraint = !protocol_config.requires_approval() @ ProtocolError::ApprovalRequired,
     )]
     pub protocol_config: Account<'info, ProtocolConfig>,
 
@@ -29,6 +38,12 @@ pub struct ProtocolFeesTransfer<'info> {
         bump
     )]
     pub treasury: SystemAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"fee_stats", protocol_config.key().as_ref()],
+        bump = fee_stats.bump,
+    )]
+    pub fee_stats: Account<'info, FeeStats>,
 
     pub system_program: Program<'info, System>,
 }
@@ -39,6 +54,8 @@ impl<'info> ProtocolFeesTransfer<'info> {
         amount: u64,
         bumps: &ProtocolFeesTransferBumps,
     ) -> Result<()> {
+        self.protocol_config
+            .validate_whitelist_transfer(&self.to.key(), amount)?;
         validate_transfer(&self.treasury.to_account_info(), amount)?;
 
         let cpi_program = self.system_program.to_account_info();
@@ -55,6 +72,14 @@ impl<'info> ProtocolFeesTransfer<'info> {
 
         transfer(cpi_ctx, amount)?;
 
+        self.fee_stats.record_withdrawal(amount)?;
+
+        emit!(ProtocolFeesTransferred {
+            protocol_config: protocol_key,
+            to: self.to.key(),
+            amount,
+        });
+
         Ok(())
     }
 }
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    errors::ProtocolError, events::ProtocolTokenFeesTransferred,
+    helpers::cpi_transfer_token_signed, state::ProtocolConfig,
+};
+
+#[derive(Accounts)]
+pub struct ProtocolTokenFeesTransfer<'info> {
+    #[account(
+        mut,
+        constraint = protocol_config.check_admins(&admin.key()) @ ProtocolError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+        constraint = protocol_config.check_whitelist_transfer_addrs(&to_token_account.owner) @ ProtocolError::AddressNotWhiteListed
+    )]
+    pub to_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        seeds = [
+            b"sol-mind-protocol",
+            ProtocolConfig::instance_seed(protocol_config.instance_id).as_slice(),
+        ],
+        bump = protocol_config.bump,
+        constraint = !protocol_config.requires_approval() @ ProtocolError::ApprovalRequired,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        seeds = [b"treasury", protocol_config.key().as_ref()],
+        bump
+    )]
+    pub treasury: SystemAccount<'info>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> ProtocolTokenFeesTransfer<'info> {
+    pub fn transfer_protocol_token_fees(
+        &mut self,
+        amount: u64,
+        bumps: &ProtocolTokenFeesTransferBumps,
+    ) -> Result<()> {
+        self.protocol_config
+            .validate_whitelist_transfer(&self.to_token_account.owner, amount)?;
+
+        let protocol_key = self.protocol_config.key();
+        let seeds = &[b"treasury", protocol_key.as_ref(), &[bumps.treasury]];
+        let signer_seeds = &[&seeds[..]];
+
+        cpi_transfer_token_signed(
+            self.treasury.to_account_info(),
+            self.treasury_token_account.to_account_info(),
+            self.to_token_account.to_account_info(),
+            &self.mint,
+            &self.token_program,
+            amount,
+            signer_seeds,
+        )?;
+
+        emit!(ProtocolTokenFeesTransferred {
+            protocol_config: protocol_key,
+            mint: self.mint.key(),
+            to: self.to_token_account.key(),
+            amount,
+        });
+
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::ProtocolError;
+use crate::events::FeesUpdateScheduled;
 use crate::state::{Fee, FeesStructure, Operation, ProtocolConfig};
 
 #[derive(Accounts)]
@@ -9,28 +10,43 @@ pub struct UpdateFees<'info> {
     pub admin: Signer<'info>,
     #[account(
         mut,
-        seeds = [b"sol-mind-protocol"],
+        seeds = [
+            b"sol-mind-protocol",
+            ProtocolConfig::instance_seed(protocol_config.instance_id).as_slice(),
+        ],
         bump = protocol_config.bump,
         constraint = protocol_config.check_admins(&admin.key()) @ ProtocolError::Unauthorized,
+        constraint = !protocol_config.requires_approval() @ ProtocolError::ApprovalRequired,
     )]
     pub protocol_config: Account<'info, ProtocolConfig>,
 }
 
 impl<'info> UpdateFees<'info> {
     pub fn update_fees(&mut self, fees: FeesStructure) -> Result<()> {
-        self.protocol_config.fees = fees;
+        let now = Clock::get()?.unix_timestamp;
+        let pending = self.protocol_config.schedule_fees(fees, now)?;
+
+        emit!(FeesUpdateScheduled {
+            protocol_config: self.protocol_config.key(),
+            fees: pending.fees,
+            effective_at: pending.effective_at,
+        });
+
         Ok(())
     }
 
     pub fn update_single_fee(&mut self, operation: Operation, fee: Fee) -> Result<()> {
-        match operation {
-            Operation::CreateProject => self.protocol_config.fees.create_project = fee,
-            Operation::CreateMinterConfig => self.protocol_config.fees.create_minter_config = fee,
-            Operation::CreateTradeHub => self.protocol_config.fees.create_trade_hub = fee,
-            Operation::MintAsset => self.protocol_config.fees.mint_asset = fee,
-            Operation::TradeNFT => self.protocol_config.fees.trade_nft = fee,
-            Operation::Generic => self.protocol_config.fees.generic_operation = fee,
-        }
+        let now = Clock::get()?.unix_timestamp;
+        let pending = self
+            .protocol_config
+            .schedule_single_fee(operation, fee, now)?;
+
+        emit!(FeesUpdateScheduled {
+            protocol_config: self.protocol_config.key(),
+            fees: pending.fees,
+            effective_at: pending.effective_at,
+        });
+
         Ok(())
     }
 }
@@ -12,4 +12,142 @@ pub enum ProtocolError {
     MinimumBalanceRequired,
     #[msg("Fee calculation overflow")]
     FeeCalculationOverflow,
+    #[msg("Admin already exists")]
+    AdminAlreadyExists,
+    #[msg("Admin not found")]
+    AdminNotFound,
+    #[msg("Maximum number of admins reached")]
+    MaxAdminsReached,
+    #[msg("Cannot remove the last admin")]
+    CannotRemoveLastAdmin,
+    #[msg("Operation requires admin approval through a proposal")]
+    ApprovalRequired,
+    #[msg("Approval threshold must be between 1 and the number of admins")]
+    InvalidApprovalThreshold,
+    #[msg("Admin already approved this proposal")]
+    ProposalAlreadyApproved,
+    #[msg("Proposal does not have enough approvals")]
+    ProposalThresholdNotMet,
+    #[msg("Destination account does not match the proposal")]
+    ProposalDestinationMismatch,
+    #[msg("Math operation overflow")]
+    MathOverflow,
+    #[msg("Address is already whitelisted")]
+    WhitelistAddressAlreadyExists,
+    #[msg("Address is not whitelisted")]
+    WhitelistAddressNotFound,
+    #[msg("Maximum number of whitelisted addresses reached")]
+    MaxWhitelistAddressesReached,
+    #[msg("Whitelist label is too long")]
+    WhitelistLabelTooLong,
+    #[msg("Transfer amount exceeds the destination cap")]
+    TransferCapExceeded,
+    #[msg("No pending fee update to cancel")]
+    NoPendingFees,
+    #[msg("Operation is paused")]
+    OperationPaused,
+    #[msg("Project name is too long")]
+    ProjectNameTooLong,
+    #[msg("Project description is too long")]
+    ProjectDescriptionTooLong,
+    #[msg("Maximum number of project authorities reached")]
+    MaxAuthoritiesReached,
+    #[msg("Signer is not the pending project owner")]
+    NotPendingOwner,
+    #[msg("Project still has minter configs or trade hubs")]
+    ProjectHasResources,
+    #[msg("Fee is paid in tokens but the token accounts were not provided")]
+    FeeTokenAccountsRequired,
+    #[msg("Mint does not match the fee mint")]
+    InvalidFeeMint,
+    #[msg("Token account is not the treasury associated token account")]
+    InvalidTreasuryTokenAccount,
+    #[msg("Fee basis points cannot exceed 10000")]
+    InvalidFeeBps,
+    #[msg("Fee minimum cannot exceed its maximum")]
+    InvalidFeeBounds,
+    #[msg("Maximum number of fee overrides reached")]
+    MaxFeeOverridesReached,
+    #[msg("Operation already has a fee override")]
+    DuplicateFeeOverride,
+    #[msg("Fee override expiry must be in the future")]
+    InvalidFeeOverrideExpiry,
+    #[msg("Fee override account is not owned by the protocol")]
+    InvalidFeeOverrideAccount,
+    #[msg("Maximum number of revenue shares reached")]
+    MaxRevenueSharesReached,
+    #[msg("Revenue recipient is listed more than once")]
+    DuplicateRevenueRecipient,
+    #[msg("Revenue shares must add up to 10000 bps")]
+    InvalidRevenueShares,
+    #[msg("No revenue shares are configured")]
+    NoRevenueShares,
+    #[msg("Recipient accounts do not match the revenue shares")]
+    RevenueRecipientMismatch,
+    #[msg("Treasury has nothing to distribute")]
+    NothingToDistribute,
+    #[msg("Account already uses the current layout version")]
+    AlreadyMigrated,
+    #[msg("Account is not a project config of this protocol")]
+    InvalidProjectConfig,
+    #[msg("Program cannot be registered to charge fees")]
+    InvalidRegisteredProgram,
+    #[msg("Calling program is not registered to charge fees")]
+    ProgramNotRegistered,
+    #[msg("Grantee already holds this role")]
+    RoleAlreadyGranted,
+    #[msg("Grantee does not hold this role")]
+    RoleNotGranted,
+    #[msg("Account is not a project role PDA")]
+    InvalidProjectRoleAccount,
+    #[msg("Treasury withdrawals by treasurers require a treasury policy")]
+    TreasuryPolicyRequired,
+    #[msg("Destination is not allowed by the treasury policy")]
+    TreasuryDestinationNotAllowed,
+    #[msg("Withdrawal exceeds the treasury policy epoch cap")]
+    TreasuryEpochCapExceeded,
+    #[msg("Maximum number of treasury destinations reached")]
+    MaxTreasuryDestinationsReached,
+    #[msg("Treasury destination is listed more than once")]
+    DuplicateTreasuryDestination,
+    #[msg("No referral fees to claim")]
+    NothingToClaim,
+    #[msg("Maximum number of subscription plans reached")]
+    MaxSubscriptionPlansReached,
+    #[msg("Subscription plan not found")]
+    SubscriptionPlanNotFound,
+    #[msg("Subscriptions must be paid for at least one epoch")]
+    InvalidSubscriptionEpochs,
+    #[msg("Subscription plan can only change once the paid period has ended")]
+    SubscriptionPlanChangeNotAllowed,
+    #[msg("Account is not a subscription PDA")]
+    InvalidSubscriptionAccount,
+    #[msg("Proposal has expired")]
+    ProposalExpired,
+    #[msg("Fees paid in tokens must be fixed amounts")]
+    TokenFeeMustBeFixed,
+    #[msg("Account required by the proposal action was not provided")]
+    ProposalAccountMissing,
+    #[msg("Program is already registered")]
+    ProgramAlreadyRegistered,
+    #[msg("Only an admin of instance 0 can initialize another protocol instance")]
+    RootAdminRequired,
+    #[msg("Referral share exceeds the maximum referral basis points")]
+    ReferralShareTooHigh,
+    #[msg("Referrer is already registered")]
+    ReferrerAlreadyRegistered,
+    #[msg("Referrer is not registered")]
+    ReferrerNotRegistered,
+    #[msg("Fee payer can't be its own referrer")]
+    SelfReferral,
+    #[msg("Account is not the payer of the closed account's rent")]
+    InvalidRentPayer,
+    #[msg("Config must be migrated before it can be updated")]
+    ConfigNotMigrated,
+    #[msg("Account is not an uncounted minter config, trade hub or role PDA of the project")]
+    InvalidProjectAccount,
+    #[msg("Project still has role PDAs")]
+    ProjectHasRoles,
+    #[msg("Project still has a fee override")]
+    ProjectHasFeeOverride,
 }
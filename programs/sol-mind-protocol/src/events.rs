@@ -0,0 +1,379 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{
+    FeesStructure, Operation, ProjectResource, ProposalAction, RevenueShare, Role,
+    ScheduledFeeOverride, SubscriptionPlan,
+};
+
+#[event]
+pub struct ProtocolInitialized {
+    pub protocol_config: Pubkey,
+    pub instance_id: u64,
+    pub admins: Vec<Pubkey>,
+    pub fees: FeesStructure,
+}
+
+#[event]
+pub struct FeeStatsInitialized {
+    pub fee_stats: Pubkey,
+    pub owner: Pubkey,
+}
+
+#[event]
+pub struct AdminAdded {
+    pub protocol_config: Pubkey,
+    pub admin: Pubkey,
+    pub new_admin: Pubkey,
+}
+
+#[event]
+pub struct AdminRemoved {
+    pub protocol_config: Pubkey,
+    pub admin: Pubkey,
+    pub old_admin: Pubkey,
+}
+
+#[event]
+pub struct AdminRotated {
+    pub protocol_config: Pubkey,
+    pub admin: Pubkey,
+    pub old_admin: Pubkey,
+    pub new_admin: Pubkey,
+}
+
+#[event]
+pub struct ProposalCreated {
+    pub protocol_config: Pubkey,
+    pub proposal: Pubkey,
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub action: ProposalAction,
+}
+
+#[event]
+pub struct ProposalApproved {
+    pub protocol_config: Pubkey,
+    pub proposal: Pubkey,
+    pub admin: Pubkey,
+    pub approvals: u8,
+}
+
+#[event]
+pub struct ProposalExecuted {
+    pub protocol_config: Pubkey,
+    pub proposal: Pubkey,
+    pub admin: Pubkey,
+}
+
+#[event]
+pub struct ProposalCancelled {
+    pub protocol_config: Pubkey,
+    pub proposal: Pubkey,
+    pub admin: Pubkey,
+}
+
+#[event]
+pub struct ApprovalThresholdUpdated {
+    pub protocol_config: Pubkey,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct WhitelistTransferAddrAdded {
+    pub protocol_config: Pubkey,
+    pub admin: Pubkey,
+    pub address: Pubkey,
+    pub label: Option<String>,
+    pub transfer_cap: Option<u64>,
+}
+
+#[event]
+pub struct WhitelistTransferAddrRemoved {
+    pub protocol_config: Pubkey,
+    pub admin: Pubkey,
+    pub address: Pubkey,
+}
+
+#[event]
+pub struct ProtocolFeesTransferred {
+    pub protocol_config: Pubkey,
+    pub to: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ProtocolFeesDistributed {
+    pub protocol_config: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct RevenueSharesUpdated {
+    pub protocol_config: Pubkey,
+    pub admin: Pubkey,
+    pub shares: Vec<RevenueShare>,
+}
+
+#[event]
+pub struct ProjectFeesTransferred {
+    pub project_config: Pubkey,
+    pub authority: Pubkey,
+    pub to: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct TreasuryPolicySet {
+    pub project_config: Pubkey,
+    pub destinations: Vec<Pubkey>,
+    pub epoch_cap: Option<u64>,
+}
+
+#[event]
+pub struct TreasuryPolicyClosed {
+    pub project_config: Pubkey,
+}
+
+#[event]
+pub struct ProjectTokenFeesTransferred {
+    pub project_config: Pubkey,
+    pub mint: Pubkey,
+    pub to: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ProtocolTokenFeesTransferred {
+    pub protocol_config: Pubkey,
+    pub mint: Pubkey,
+    pub to: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct FeesUpdateScheduled {
+    pub protocol_config: Pubkey,
+    pub fees: FeesStructure,
+    pub effective_at: i64,
+}
+
+#[event]
+pub struct FeesUpdateCancelled {
+    pub protocol_config: Pubkey,
+    pub admin: Pubkey,
+    pub fees: FeesStructure,
+    pub effective_at: i64,
+}
+
+#[event]
+pub struct ProtocolPauseUpdated {
+    pub protocol_config: Pubkey,
+    pub admin: Pubkey,
+    pub paused: bool,
+}
+
+#[event]
+pub struct OperationPauseUpdated {
+    pub protocol_config: Pubkey,
+    pub admin: Pubkey,
+    pub operation: Operation,
+    pub paused: bool,
+}
+
+#[event]
+pub struct ProtocolFeePaid {
+    pub protocol_config: Pubkey,
+    pub project_config: Pubkey,
+    pub operation: Operation,
+    pub amount: u64,
+    pub mint: Option<Pubkey>, // None for lamports
+    pub referrer: Option<Pubkey>,
+    pub referral_amount: u64, // part of `amount` paid to the referrer
+}
+
+#[event]
+pub struct ProjectCreated {
+    pub protocol_config: Pubkey,
+    pub project_config: Pubkey,
+    pub project_id: u64,
+    pub owner: Pubkey,
+    pub name: String,
+}
+
+#[event]
+pub struct ProjectUpdated {
+    pub project_config: Pubkey,
+    pub name: String,
+    pub description: String,
+}
+
+#[event]
+pub struct ProjectRenamed {
+    pub project_config: Pubkey,
+    pub previous_name: String,
+    pub new_name: String,
+}
+
+#[event]
+pub struct ProjectOwnerProposed {
+    pub project_config: Pubkey,
+    pub owner: Pubkey,
+    pub pending_owner: Pubkey,
+}
+
+#[event]
+pub struct ProjectOwnershipTransferred {
+    pub project_config: Pubkey,
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey,
+}
+
+#[event]
+pub struct ProjectClosed {
+    pub project_config: Pubkey,
+    pub owner: Pubkey,
+    pub treasury_amount: u64,
+}
+
+#[event]
+pub struct ProjectResourceRegistered {
+    pub project_config: Pubkey,
+    pub authority: Pubkey,
+    pub resource: ProjectResource,
+}
+
+#[event]
+pub struct ProjectResourceUnregistered {
+    pub project_config: Pubkey,
+    pub authority: Pubkey,
+    pub resource: ProjectResource,
+}
+
+#[event]
+pub struct ProjectFeeOverrideSet {
+    pub protocol_config: Pubkey,
+    pub project_config: Pubkey,
+    pub admin: Pubkey,
+    pub overrides: Vec<ScheduledFeeOverride>,
+    pub expires_at: Option<i64>,
+}
+
+#[event]
+pub struct ProjectFeeOverrideClosed {
+    pub protocol_config: Pubkey,
+    pub project_config: Pubkey,
+    pub admin: Pubkey,
+}
+
+#[event]
+pub struct ProtocolConfigMigrated {
+    pub protocol_config: Pubkey,
+    pub admin: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+}
+
+#[event]
+pub struct ProjectConfigMigrated {
+    pub project_config: Pubkey,
+    pub admin: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+}
+
+#[event]
+pub struct ProjectRoleGranted {
+    pub project_config: Pubkey,
+    pub authority: Pubkey,
+    pub grantee: Pubkey,
+    pub role: Role,
+}
+
+#[event]
+pub struct ProjectRoleRevoked {
+    pub project_config: Pubkey,
+    pub authority: Pubkey,
+    pub grantee: Pubkey,
+    pub role: Role,
+}
+
+#[event]
+pub struct ProgramRegistered {
+    pub protocol_config: Pubkey,
+    pub admin: Pubkey,
+    pub program_id: Pubkey,
+}
+
+#[event]
+pub struct ProgramUnregistered {
+    pub protocol_config: Pubkey,
+    pub admin: Pubkey,
+    pub program_id: Pubkey,
+}
+
+#[event]
+pub struct GenericFeeCharged {
+    pub protocol_config: Pubkey,
+    pub program_id: Pubkey,
+    pub payer: Pubkey,
+    pub amount: u64,
+    pub mint: Option<Pubkey>, // None for lamports
+}
+
+#[event]
+pub struct ReferrerRegistered {
+    pub protocol_config: Pubkey,
+    pub admin: Pubkey,
+    pub referrer: Pubkey,
+    pub wallet: Pubkey,
+    pub share_bps: u16,
+}
+
+#[event]
+pub struct ReferrerUpdated {
+    pub protocol_config: Pubkey,
+    pub admin: Pubkey,
+    pub referrer: Pubkey,
+    pub share_bps: u16,
+}
+
+#[event]
+pub struct ReferrerRemoved {
+    pub protocol_config: Pubkey,
+    pub admin: Pubkey,
+    pub referrer: Pubkey,
+    pub wallet: Pubkey,
+    pub unclaimed: u64, // referral fees paid out to the wallet on removal
+}
+
+#[event]
+pub struct ReferralFeesClaimed {
+    pub protocol_config: Pubkey,
+    pub referrer: Pubkey,
+    pub wallet: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct SubscriptionPlanSet {
+    pub protocol_config: Pubkey,
+    pub admin: Pubkey,
+    pub plan: SubscriptionPlan,
+}
+
+#[event]
+pub struct SubscriptionPlanRemoved {
+    pub protocol_config: Pubkey,
+    pub admin: Pubkey,
+    pub plan_id: u8,
+}
+
+#[event]
+pub struct SubscriptionPaid {
+    pub protocol_config: Pubkey,
+    pub project_config: Pubkey,
+    pub plan_id: u8,
+    pub epochs: u64,
+    pub amount: u64,
+    pub paid_until_epoch: u64,
+}
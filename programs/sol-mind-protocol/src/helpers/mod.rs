@@ -1,7 +1,15 @@
+pub mod token;
+
+pub use token::*;
+
 use anchor_lang::prelude::*;
-use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::system_program::{
+    allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer,
+};
 
-use crate::{errors::ProtocolError, Operation, ProtocolConfig};
+use crate::{
+    errors::ProtocolError, FeePayment, Operation, ProjectFeeOverride, ProtocolConfig, Referrer,
+};
 
 pub fn validate_transfer(account_info: &AccountInfo, amount: u64) -> Result<()> {
     let current_balance = account_info.lamports();
@@ -34,24 +42,178 @@ pub fn cpi_transfer<'info>(
     Ok(())
 }
 
+/// Grows `account` to `space` bytes, zero-extending its data and topping up its rent from
+/// `payer`. Accounts that are already large enough are left untouched.
+pub fn grow_account<'info>(
+    account: &AccountInfo<'info>,
+    space: usize,
+    payer: AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    if account.data_len() >= space {
+        return Ok(());
+    }
+
+    let rent_exempt = Rent::get()?.minimum_balance(space);
+    let top_up = rent_exempt.saturating_sub(account.lamports());
+    if top_up > 0 {
+        cpi_transfer(payer, account.clone(), top_up, system_program)?;
+    }
+
+    account.resize(space)?;
+    Ok(())
+}
+
+/// Creates the PDA `account` with `space` bytes owned by this program, paid by `payer`. Like
+/// Anchor's `init`, an address that was already funded is topped up, allocated and assigned.
+pub fn create_pda_account<'info>(
+    account: &AccountInfo<'info>,
+    space: usize,
+    payer: AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let rent_exempt = Rent::get()?.minimum_balance(space);
+    let cpi_program = system_program.to_account_info();
+
+    if account.lamports() == 0 {
+        let cpi_accounts = CreateAccount {
+            from: payer,
+            to: account.clone(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+
+        return create_account(cpi_ctx, rent_exempt, space as u64, &crate::ID);
+    }
+
+    let top_up = rent_exempt.saturating_sub(account.lamports());
+    if top_up > 0 {
+        cpi_transfer(payer, account.clone(), top_up, system_program)?;
+    }
+
+    let cpi_accounts = Allocate {
+        account_to_allocate: account.clone(),
+    };
+    allocate(
+        CpiContext::new_with_signer(cpi_program.clone(), cpi_accounts, signer_seeds),
+        space as u64,
+    )?;
+
+    let cpi_accounts = Assign {
+        account_to_assign: account.clone(),
+    };
+    assign(
+        CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds),
+        &crate::ID,
+    )
+}
+
+/// Closes `account`, sending its lamports to `destination`, as Anchor's `close` does.
+pub fn close_account<'info>(
+    account: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+) -> Result<()> {
+    let lamports = destination
+        .lamports()
+        .checked_add(account.lamports())
+        .ok_or(ProtocolError::MathOverflow)?;
+    **destination.try_borrow_mut_lamports()? = lamports;
+    **account.try_borrow_mut_lamports()? = 0;
+
+    account.assign(&System::id());
+    account.resize(0)?;
+    Ok(())
+}
+
+/// Charges the protocol fee for `operation` and returns what was paid, for the caller to
+/// record in the fee stats. A referrer receives its share of fees paid in lamports; fees paid
+/// in tokens go to the treasury in full. A referrer can't refer its own fees.
+#[allow(clippy::too_many_arguments)]
 pub fn pay_protocol_fee<'info>(
     fee_payer: &Signer<'info>,
     protocol_config: &Account<'info, ProtocolConfig>,
     protocol_treasury: &AccountInfo<'info>,
     system_program: &Program<'info, System>,
+    fee_token_accounts: Option<FeeTokenAccounts<'_, 'info>>,
+    project_fee_override: Option<&ProjectFeeOverride>,
+    referrer: Option<&Account<'info, Referrer>>,
     operation: Operation,
     base_amount: Option<u64>,
-) -> Result<u64> {
-    let fee_amount = protocol_config.calculate_fee_amount(operation, base_amount)?;
-
-    if fee_amount > 0 {
-        cpi_transfer(
-            fee_payer.to_account_info(),
-            protocol_treasury.to_account_info(),
-            fee_amount,
-            system_program,
-        )?;
+) -> Result<FeePayment> {
+    if let Some(referrer) = referrer {
+        require_keys_neq!(
+            referrer.wallet,
+            fee_payer.key(),
+            ProtocolError::SelfReferral
+        );
+    }
+
+    let fee_amount =
+        protocol_config.calculate_fee_amount(operation, base_amount, project_fee_override)?;
+    let fee = protocol_config.get_project_fee(
+        operation,
+        project_fee_override,
+        Clock::get()?.unix_timestamp,
+    );
+    let referral_amount = match (referrer, fee.mint) {
+        (Some(referrer), None) => referrer.referral_amount(fee_amount)?,
+        _ => 0,
+    };
+    let payment = FeePayment {
+        operation,
+        amount: fee_amount,
+        mint: fee.mint,
+        referrer: referrer.map(|referrer| referrer.key()),
+        referral_amount,
+    };
+
+    if fee_amount == 0 {
+        return Ok(payment);
+    }
+
+    match fee.mint {
+        None => {
+            let treasury_amount = fee_amount
+                .checked_sub(referral_amount)
+                .ok_or(ProtocolError::MathOverflow)?;
+            if treasury_amount > 0 {
+                cpi_transfer(
+                    fee_payer.to_account_info(),
+                    protocol_treasury.to_account_info(),
+                    treasury_amount,
+                    system_program,
+                )?;
+            }
+            if let Some(referrer) = referrer.filter(|_| referral_amount > 0) {
+                cpi_transfer(
+                    fee_payer.to_account_info(),
+                    referrer.to_account_info(),
+                    referral_amount,
+                    system_program,
+                )?;
+            }
+        }
+        Some(mint) => {
+            let accounts = fee_token_accounts.ok_or(ProtocolError::FeeTokenAccountsRequired)?;
+
+            require_keys_eq!(accounts.mint.key(), mint, ProtocolError::InvalidFeeMint);
+            validate_treasury_token_account(
+                protocol_treasury.key,
+                &mint,
+                &accounts.treasury_token_account.key(),
+                accounts.token_program.key,
+            )?;
+
+            cpi_transfer_token_fee(
+                fee_payer.to_account_info(),
+                accounts.payer_token_account.to_account_info(),
+                accounts.treasury_token_account.to_account_info(),
+                accounts.mint,
+                accounts.token_program,
+                fee_amount,
+            )?;
+        }
     }
 
-    Ok(fee_amount)
+    Ok(payment)
 }
@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::get_associated_token_address_with_program_id,
+    token_2022::spl_token_2022::{
+        self,
+        extension::{
+            transfer_fee::TransferFeeConfig, BaseStateWithExtensions, StateWithExtensions,
+        },
+    },
+    token_interface::{
+        transfer_checked, transfer_checked_with_fee, Mint, TokenAccount, TokenInterface,
+        TransferChecked, TransferCheckedWithFee,
+    },
+};
+
+use crate::errors::ProtocolError;
+
+/// Token accounts used to pay a fee whose `Fee::mint` is set.
+pub struct FeeTokenAccounts<'a, 'info> {
+    pub mint: &'a InterfaceAccount<'info, Mint>,
+    pub payer_token_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub treasury_token_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub token_program: &'a Interface<'info, TokenInterface>,
+}
+
+impl<'a, 'info> FeeTokenAccounts<'a, 'info> {
+    /// Groups the optional fee accounts of a context, `None` unless all of them were passed.
+    pub fn from_optional(
+        mint: &'a Option<InterfaceAccount<'info, Mint>>,
+        payer_token_account: &'a Option<InterfaceAccount<'info, TokenAccount>>,
+        treasury_token_account: &'a Option<InterfaceAccount<'info, TokenAccount>>,
+        token_program: &'a Option<Interface<'info, TokenInterface>>,
+    ) -> Option<Self> {
+        Some(Self {
+            mint: mint.as_ref()?,
+            payer_token_account: payer_token_account.as_ref()?,
+            treasury_token_account: treasury_token_account.as_ref()?,
+            token_program: token_program.as_ref()?,
+        })
+    }
+}
+
+pub fn validate_treasury_token_account(
+    treasury: &Pubkey,
+    mint: &Pubkey,
+    token_account: &Pubkey,
+    token_program: &Pubkey,
+) -> Result<()> {
+    require_keys_eq!(
+        *token_account,
+        get_associated_token_address_with_program_id(treasury, mint, token_program),
+        ProtocolError::InvalidTreasuryTokenAccount
+    );
+
+    Ok(())
+}
+
+/// Transfer fee withheld by a Token-2022 mint when `amount` has to reach the destination in full.
+pub fn token_transfer_fee(mint: &AccountInfo, amount: u64) -> Result<u64> {
+    if *mint.owner != spl_token_2022::ID {
+        return Ok(0);
+    }
+
+    let mint_data = mint.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+
+    match mint_state.get_extension::<TransferFeeConfig>() {
+        Ok(transfer_fee_config) => transfer_fee_config
+            .calculate_inverse_epoch_fee(Clock::get()?.epoch, amount)
+            .ok_or(error!(ProtocolError::FeeCalculationOverflow)),
+        Err(_) => Ok(0),
+    }
+}
+
+/// Moves `amount` tokens so that the destination receives it in full, grossing the transfer up
+/// by the mint's transfer fee when the Token-2022 transfer-fee extension is enabled.
+pub fn cpi_transfer_token_fee<'info>(
+    authority: AccountInfo<'info>,
+    from: AccountInfo<'info>,
+    to: AccountInfo<'info>,
+    mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    amount: u64,
+) -> Result<u64> {
+    let mint_info = mint.to_account_info();
+    let transfer_fee = token_transfer_fee(&mint_info, amount)?;
+
+    if transfer_fee == 0 {
+        let cpi_accounts = TransferChecked {
+            from,
+            mint: mint_info,
+            to,
+            authority,
+        };
+        let cpi_ctx = CpiContext::new(token_program.to_account_info(), cpi_accounts);
+
+        transfer_checked(cpi_ctx, amount, mint.decimals)?;
+
+        return Ok(amount);
+    }
+
+    let gross_amount = amount
+        .checked_add(transfer_fee)
+        .ok_or(ProtocolError::FeeCalculationOverflow)?;
+
+    let cpi_accounts = TransferCheckedWithFee {
+        token_program_id: token_program.to_account_info(),
+        source: from,
+        mint: mint_info,
+        destination: to,
+        authority,
+    };
+    let cpi_ctx = CpiContext::new(token_program.to_account_info(), cpi_accounts);
+
+    transfer_checked_with_fee(cpi_ctx, gross_amount, mint.decimals, transfer_fee)?;
+
+    Ok(gross_amount)
+}
+
+/// Moves `amount` tokens out of a PDA-owned token account. Any transfer fee is withheld from
+/// the amount the destination receives.
+pub fn cpi_transfer_token_signed<'info>(
+    authority: AccountInfo<'info>,
+    from: AccountInfo<'info>,
+    to: AccountInfo<'info>,
+    mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let cpi_accounts = TransferChecked {
+        from,
+        mint: mint.to_account_info(),
+        to,
+        authority,
+    };
+    let cpi_ctx =
+        CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer_seeds);
+
+    transfer_checked(cpi_ctx, amount, mint.decimals)
+}
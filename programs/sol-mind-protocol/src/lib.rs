@@ -4,31 +4,46 @@ declare_id!("7GoSe63kVLi3Bt2mkPHUFVbmTcsZvp7QEUHjDB6GvLgV");
 
 pub mod context;
 pub mod errors;
+pub mod events;
 pub mod helpers;
 pub mod state;
 
 pub use context::*;
 pub use errors::*;
+pub use events::*;
 pub use helpers::*;
 pub use state::*;
 
 #[program]
 pub mod sol_mind_protocol {
     use super::*;
-    pub fn initialize_protocol(
-        ctx: Context<InitializeProtocol>,
+    pub fn initialize_protocol<'info>(
+        ctx: Context<'_, '_, 'info, 'info, InitializeProtocol<'info>>,
+        instance_id: u64,
         admins: Vec<Pubkey>,
         whitelist_transfer_addrs: Vec<Pubkey>,
         fees: FeesStructure,
     ) -> Result<()> {
         ctx.accounts.initialize_protocol(
+            instance_id,
             admins,
             whitelist_transfer_addrs,
             fees,
-            ctx.bumps.protocol_config,
+            &ctx.bumps,
+            ctx.remaining_accounts,
         )
     }
 
+    pub fn initialize_protocol_fee_stats(ctx: Context<InitializeProtocolFeeStats>) -> Result<()> {
+        ctx.accounts
+            .initialize_protocol_fee_stats(ctx.bumps.fee_stats)
+    }
+
+    pub fn initialize_project_fee_stats(ctx: Context<InitializeProjectFeeStats>) -> Result<()> {
+        ctx.accounts
+            .initialize_project_fee_stats(ctx.bumps.fee_stats)
+    }
+
     pub fn update_fees(ctx: Context<UpdateFees>, fees: FeesStructure) -> Result<()> {
         ctx.accounts.update_fees(fees)
     }
@@ -41,22 +56,269 @@ pub mod sol_mind_protocol {
         ctx.accounts.update_single_fee(operation, fee)
     }
 
+    pub fn cancel_pending_fees(ctx: Context<CancelPendingFees>) -> Result<()> {
+        ctx.accounts.cancel_pending_fees()
+    }
+
+    pub fn set_protocol_paused(ctx: Context<SetPause>, paused: bool) -> Result<()> {
+        ctx.accounts.set_protocol_paused(paused)
+    }
+
+    pub fn set_operation_paused(
+        ctx: Context<SetPause>,
+        operation: Operation,
+        paused: bool,
+    ) -> Result<()> {
+        ctx.accounts.set_operation_paused(operation, paused)
+    }
+
+    pub fn add_admin(ctx: Context<ManageAdmins>, new_admin: Pubkey) -> Result<()> {
+        ctx.accounts.add_admin(new_admin)
+    }
+
+    pub fn remove_admin(ctx: Context<ManageAdmins>, old_admin: Pubkey) -> Result<()> {
+        ctx.accounts.remove_admin(old_admin)
+    }
+
+    pub fn rotate_admin(
+        ctx: Context<ManageAdmins>,
+        old_admin: Pubkey,
+        new_admin: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.rotate_admin(old_admin, new_admin)
+    }
+
+    pub fn add_whitelist_transfer_addr(
+        ctx: Context<ManageWhitelist>,
+        address: Pubkey,
+        label: Option<String>,
+        transfer_cap: Option<u64>,
+    ) -> Result<()> {
+        ctx.accounts
+            .add_whitelist_transfer_addr(address, label, transfer_cap)
+    }
+
+    pub fn remove_whitelist_transfer_addr(
+        ctx: Context<ManageWhitelist>,
+        address: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.remove_whitelist_transfer_addr(address)
+    }
+
+    pub fn create_proposal(ctx: Context<CreateProposal>, action: ProposalAction) -> Result<()> {
+        ctx.accounts.create_proposal(action, ctx.bumps.proposal)
+    }
+
+    pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+        ctx.accounts.approve_proposal()
+    }
+
+    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+        ctx.accounts.execute_proposal(&ctx.bumps)
+    }
+
+    pub fn cancel_proposal<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CancelProposal<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.cancel_proposal(ctx.remaining_accounts)
+    }
+
     pub fn create_project(
         ctx: Context<CreateProject>,
-        project_id: u64,
         name: String,
         description: String,
-        authorities: Vec<Pubkey>,
     ) -> Result<()> {
+        ctx.accounts.create_project(name, description, &ctx.bumps)
+    }
+
+    pub fn update_project(ctx: Context<ManageProject>, description: Option<String>) -> Result<()> {
+        ctx.accounts.update_project(description)
+    }
+
+    pub fn rename_project(ctx: Context<RenameProject>, new_name: String) -> Result<()> {
         ctx.accounts
-            .create_project(project_id, name, description, authorities, &ctx.bumps)
+            .rename_project(new_name, ctx.bumps.new_project_registry)
+    }
+
+    pub fn propose_owner(ctx: Context<ManageProject>, new_owner: Pubkey) -> Result<()> {
+        ctx.accounts.propose_owner(new_owner)
+    }
+
+    pub fn accept_owner(ctx: Context<AcceptOwner>) -> Result<()> {
+        ctx.accounts.accept_owner()
+    }
+
+    pub fn migrate_protocol_config<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MigrateProtocolConfig<'info>>,
+        _instance_id: u64,
+    ) -> Result<()> {
+        ctx.accounts.migrate_protocol_config(ctx.remaining_accounts)
+    }
+
+    pub fn migrate_project_config<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MigrateProjectConfig<'info>>,
+    ) -> Result<()> {
+        ctx.accounts
+            .migrate_project_config(ctx.remaining_accounts, ctx.bumps.project_registry)
+    }
+
+    pub fn close_project<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseProject<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.close_project(ctx.remaining_accounts)
+    }
+
+    pub fn register_project_resource(
+        ctx: Context<RegisterProjectResource>,
+        resource: ProjectResource,
+    ) -> Result<()> {
+        ctx.accounts.register_project_resource(resource)
+    }
+
+    pub fn unregister_project_resource(
+        ctx: Context<UnregisterProjectResource>,
+        resource: ProjectResource,
+    ) -> Result<()> {
+        ctx.accounts.unregister_project_resource(resource)
+    }
+
+    pub fn grant_project_role(
+        ctx: Context<GrantProjectRole>,
+        grantee: Pubkey,
+        role: Role,
+    ) -> Result<()> {
+        ctx.accounts
+            .grant_project_role(grantee, role, ctx.bumps.project_role)
+    }
+
+    pub fn revoke_project_role(ctx: Context<RevokeProjectRole>, role: Role) -> Result<()> {
+        ctx.accounts.revoke_project_role(role)
+    }
+
+    pub fn set_project_fee_override(
+        ctx: Context<SetProjectFeeOverride>,
+        overrides: Vec<OperationFeeOverride>,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        ctx.accounts
+            .set_project_fee_override(overrides, expires_at, ctx.bumps.project_fee_override)
+    }
+
+    pub fn close_project_fee_override(ctx: Context<CloseProjectFeeOverride>) -> Result<()> {
+        ctx.accounts.close_project_fee_override()
+    }
+
+    pub fn register_program(ctx: Context<RegisterProgram>, program_id: Pubkey) -> Result<()> {
+        ctx.accounts
+            .register_program(program_id, ctx.bumps.registered_program)
+    }
+
+    pub fn unregister_program(ctx: Context<UnregisterProgram>) -> Result<()> {
+        ctx.accounts.unregister_program()
+    }
+
+    pub fn register_referrer(
+        ctx: Context<RegisterReferrer>,
+        wallet: Pubkey,
+        share_bps: u16,
+    ) -> Result<()> {
+        ctx.accounts
+            .register_referrer(wallet, share_bps, ctx.bumps.referrer)
+    }
+
+    pub fn update_referrer(ctx: Context<UpdateReferrer>, share_bps: u16) -> Result<()> {
+        ctx.accounts.update_referrer(share_bps)
+    }
+
+    pub fn remove_referrer(ctx: Context<RemoveReferrer>) -> Result<()> {
+        ctx.accounts.remove_referrer()
+    }
+
+    pub fn claim_referral_fees(ctx: Context<ClaimReferralFees>) -> Result<()> {
+        ctx.accounts.claim_referral_fees()
+    }
+
+    pub fn charge_generic_fee(
+        ctx: Context<ChargeGenericFee>,
+        base_amount: Option<u64>,
+    ) -> Result<()> {
+        ctx.accounts.charge_generic_fee(base_amount)
+    }
+
+    pub fn record_fees(
+        ctx: Context<RecordFees>,
+        protocol_fee: FeePayment,
+        trade_hub_fee: u64,
+    ) -> Result<()> {
+        ctx.accounts.record_fees(protocol_fee, trade_hub_fee)
     }
 
     pub fn transfer_project_fees(ctx: Context<TransferProjectFees>, amount: u64) -> Result<()> {
         ctx.accounts.transfer_project_fees(amount)
     }
 
+    pub fn set_treasury_policy(
+        ctx: Context<SetTreasuryPolicy>,
+        destinations: Vec<Pubkey>,
+        epoch_cap: Option<u64>,
+    ) -> Result<()> {
+        ctx.accounts
+            .set_treasury_policy(destinations, epoch_cap, ctx.bumps.treasury_policy)
+    }
+
+    pub fn close_treasury_policy(ctx: Context<CloseTreasuryPolicy>) -> Result<()> {
+        ctx.accounts.close_treasury_policy()
+    }
+
+    pub fn set_subscription_plan(
+        ctx: Context<ManageSubscriptionPlans>,
+        plan: SubscriptionPlan,
+    ) -> Result<()> {
+        ctx.accounts.set_subscription_plan(plan)
+    }
+
+    pub fn remove_subscription_plan(
+        ctx: Context<ManageSubscriptionPlans>,
+        plan_id: u8,
+    ) -> Result<()> {
+        ctx.accounts.remove_subscription_plan(plan_id)
+    }
+
+    pub fn pay_subscription(ctx: Context<PaySubscription>, plan_id: u8, epochs: u64) -> Result<()> {
+        ctx.accounts
+            .pay_subscription(plan_id, epochs, ctx.bumps.subscription)
+    }
+
     pub fn transfer_protocol_fees(ctx: Context<ProtocolFeesTransfer>, amount: u64) -> Result<()> {
         ctx.accounts.transfer_protocol_fees(amount, &ctx.bumps)
     }
+
+    pub fn set_revenue_shares(
+        ctx: Context<SetRevenueShares>,
+        shares: Vec<RevenueShare>,
+    ) -> Result<()> {
+        ctx.accounts.set_revenue_shares(shares)
+    }
+
+    pub fn distribute_protocol_fees<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DistributeProtocolFees<'info>>,
+    ) -> Result<()> {
+        ctx.accounts
+            .distribute_protocol_fees(ctx.remaining_accounts, ctx.bumps.treasury)
+    }
+
+    pub fn transfer_project_token_fees(
+        ctx: Context<TransferProjectTokenFees>,
+        amount: u64,
+    ) -> Result<()> {
+        ctx.accounts.transfer_project_token_fees(amount)
+    }
+
+    pub fn transfer_protocol_token_fees(
+        ctx: Context<ProtocolTokenFeesTransfer>,
+        amount: u64,
+    ) -> Result<()> {
+        ctx.accounts
+            .transfer_protocol_token_fees(amount, &ctx.bumps)
+    }
 }
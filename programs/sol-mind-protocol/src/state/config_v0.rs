@@ -0,0 +1,225 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{
+    Fee, FeeType, FeesStructure, ProjectConfig, ProtocolConfig, WhitelistEntry, MAX_ADMINS,
+    MAX_PROJECT_AUTHORITIES, MAX_PROJECT_DESCRIPTION_LEN, MAX_PROJECT_NAME_LEN,
+    MAX_WHITELIST_TRANSFER_ADDRS, PROJECT_CONFIG_VERSION, PROTOCOL_CONFIG_VERSION,
+};
+
+// Protocol and project configs as the first release stored them, before the `version` field.
+// Later layouts changed some of these fields in place, so such accounts cannot be decoded as a
+// newer layout and are converted field by field instead. The first release always allocated
+// `8 + INIT_SPACE` bytes, which is how these accounts are recognized.
+
+#[derive(InitSpace, AnchorDeserialize, Clone, Copy)]
+pub enum FeeTypeV0 {
+    Fixed,
+    Percentage,
+}
+
+#[derive(InitSpace, AnchorDeserialize, Clone, Copy)]
+pub struct FeeV0 {
+    pub amount: u64,
+    pub fee_type: FeeTypeV0,
+}
+
+#[derive(InitSpace, AnchorDeserialize, Clone)]
+pub struct FeesStructureV0 {
+    pub create_project: FeeV0,
+    pub create_minter_config: FeeV0,
+    pub create_trade_hub: FeeV0,
+    pub trade_nft: FeeV0,
+    pub mint_asset: FeeV0,
+    pub generic_operation: FeeV0,
+}
+
+#[derive(InitSpace, AnchorDeserialize, Clone)]
+pub struct ProtocolConfigV0 {
+    #[max_len(MAX_ADMINS)]
+    pub admins: Vec<Pubkey>,
+    #[max_len(MAX_WHITELIST_TRANSFER_ADDRS)]
+    pub whitelist_transfer_addrs: Vec<Pubkey>,
+    pub fees: FeesStructureV0,
+    pub bump: u8,
+}
+
+#[derive(InitSpace, AnchorDeserialize, Clone)]
+pub struct ProjectConfigV0 {
+    pub protocol_config: Pubkey,
+    pub project_id: u64,
+    pub owner: Pubkey,
+    #[max_len(MAX_PROJECT_NAME_LEN)]
+    pub name: String,
+    #[max_len(MAX_PROJECT_DESCRIPTION_LEN)]
+    pub description: String,
+    #[max_len(MAX_PROJECT_AUTHORITIES)]
+    pub autthorities: Vec<Pubkey>,
+    pub treasury_bump: u8,
+    pub bump: u8,
+}
+
+impl From<FeeV0> for Fee {
+    fn from(fee: FeeV0) -> Self {
+        Fee {
+            amount: fee.amount,
+            fee_type: match fee.fee_type {
+                FeeTypeV0::Fixed => FeeType::Fixed,
+                FeeTypeV0::Percentage => FeeType::Percentage,
+            },
+            mint: None,
+        }
+    }
+}
+
+impl From<FeesStructureV0> for FeesStructure {
+    fn from(fees: FeesStructureV0) -> Self {
+        FeesStructure {
+            create_project: fees.create_project.into(),
+            create_minter_config: fees.create_minter_config.into(),
+            create_trade_hub: fees.create_trade_hub.into(),
+            trade_nft: fees.trade_nft.into(),
+            mint_asset: fees.mint_asset.into(),
+            generic_operation: fees.generic_operation.into(),
+        }
+    }
+}
+
+impl From<ProtocolConfigV0> for ProtocolConfig {
+    fn from(config: ProtocolConfigV0) -> Self {
+        ProtocolConfig {
+            admins: config.admins,
+            whitelist_transfer_addrs: config
+                .whitelist_transfer_addrs
+                .into_iter()
+                .map(|address| WhitelistEntry {
+                    address,
+                    label: None,
+                    transfer_cap: None,
+                })
+                .collect(),
+            revenue_shares: Vec::new(),
+            fees: config.fees.into(),
+            pending_fees: None,
+            paused: false,
+            paused_operations: 0,
+            approval_threshold: 1,
+            proposal_count: 0,
+            project_count: 0,
+            bump: config.bump,
+            version: 0,
+            instance_id: 0,
+            subscription_plans: Vec::new(),
+        }
+    }
+}
+
+impl From<ProjectConfigV0> for ProjectConfig {
+    fn from(config: ProjectConfigV0) -> Self {
+        ProjectConfig {
+            protocol_config: config.protocol_config,
+            project_id: config.project_id,
+            creator: config.owner, // the project PDA was always seeded by its owner
+            owner: config.owner,
+            pending_owner: None,
+            name: config.name,
+            description: config.description,
+            autthorities: config.autthorities,
+            minter_configs_count: 0,
+            trade_hubs_count: 0,
+            treasury_bump: config.treasury_bump,
+            bump: config.bump,
+            version: 0,
+            created_epoch: 0,
+            roles_count: 0, // the first release had no roles
+        }
+    }
+}
+
+/// Decodes the data of a config account after its discriminator: as `V0` when the account has
+/// the size of the first release's layout, otherwise as `T` zero-extended to its full size, so
+/// fields appended since the account was written decode as zeroes.
+pub(crate) fn deserialize_config<T, V0>(data: &[u8]) -> Result<T>
+where
+    T: AnchorDeserialize + Space,
+    V0: AnchorDeserialize + Space + Into<T>,
+{
+    let config = if data.len() == V0::INIT_SPACE {
+        V0::deserialize(&mut &data[..]).map(Into::into)
+    } else if data.len() < T::INIT_SPACE {
+        let mut extended = data.to_vec();
+        extended.resize(T::INIT_SPACE, 0);
+        T::deserialize(&mut &extended[..])
+    } else {
+        T::deserialize(&mut &data[..])
+    };
+
+    config.map_err(|_| ErrorCode::AccountDidNotDeserialize.into())
+}
+
+/// Implements the account traits `#[account]` would derive, decoding through
+/// `deserialize_config` and refusing to write a config of an older version: its account holds
+/// an older layout and has no room for the current one until it is migrated.
+macro_rules! impl_config_account {
+    ($config:ident, $config_v0:ident, $version:expr, $discriminator:expr) => {
+        impl anchor_lang::Discriminator for $config {
+            const DISCRIMINATOR: &'static [u8] = &$discriminator;
+        }
+
+        impl anchor_lang::Owner for $config {
+            fn owner() -> Pubkey {
+                crate::ID
+            }
+        }
+
+        impl anchor_lang::AccountSerialize for $config {
+            fn try_serialize<W: std::io::Write>(&self, writer: &mut W) -> Result<()> {
+                require!(
+                    self.version == $version,
+                    crate::errors::ProtocolError::ConfigNotMigrated
+                );
+
+                if writer
+                    .write_all(<Self as anchor_lang::Discriminator>::DISCRIMINATOR)
+                    .is_err()
+                    || AnchorSerialize::serialize(self, writer).is_err()
+                {
+                    return Err(ErrorCode::AccountDidNotSerialize.into());
+                }
+                Ok(())
+            }
+        }
+
+        impl anchor_lang::AccountDeserialize for $config {
+            fn try_deserialize(buf: &mut &[u8]) -> Result<Self> {
+                let discriminator = <Self as anchor_lang::Discriminator>::DISCRIMINATOR;
+                if buf.len() < discriminator.len() {
+                    return Err(ErrorCode::AccountDiscriminatorNotFound.into());
+                }
+                if &buf[..discriminator.len()] != discriminator {
+                    return Err(error!(ErrorCode::AccountDiscriminatorMismatch)
+                        .with_account_name(stringify!($config)));
+                }
+                Self::try_deserialize_unchecked(buf)
+            }
+
+            fn try_deserialize_unchecked(buf: &mut &[u8]) -> Result<Self> {
+                let discriminator = <Self as anchor_lang::Discriminator>::DISCRIMINATOR;
+                $crate::state::deserialize_config::<Self, $config_v0>(&buf[discriminator.len()..])
+            }
+        }
+    };
+}
+
+// sha256("account:<name>")[..8], as `#[account]` derives them
+impl_config_account!(
+    ProtocolConfig,
+    ProtocolConfigV0,
+    PROTOCOL_CONFIG_VERSION,
+    [207, 91, 250, 28, 152, 179, 215, 209]
+);
+impl_config_account!(
+    ProjectConfig,
+    ProjectConfigV0,
+    PROJECT_CONFIG_VERSION,
+    [187, 239, 0, 110, 5, 15, 245, 65]
+);
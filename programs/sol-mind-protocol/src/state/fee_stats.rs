@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ProtocolError;
+use crate::state::Operation;
+
+/// Program allowed to record the fees it charges through `record_fees`, signing with its
+/// `[b"fee_authority"]` PDA.
+pub const NFT_OPERATIONS_PROGRAM_ID: Pubkey =
+    pubkey!("Anv3tR3s1eQqQE1a5wnhtYdi6dkjv9rTn34V6pom9C9q");
+
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct FeeTotals {
+    pub count: u64,
+    pub lamports: u64, // fees paid in tokens are counted but not summed
+}
+
+impl FeeTotals {
+    fn record(&mut self, amount: u64, in_lamports: bool) -> Result<()> {
+        self.count = self
+            .count
+            .checked_add(1)
+            .ok_or(ProtocolError::MathOverflow)?;
+
+        if in_lamports {
+            self.lamports = self
+                .lamports
+                .checked_add(amount)
+                .ok_or(ProtocolError::MathOverflow)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct OperationFeeTotals {
+    pub create_project: FeeTotals,
+    pub create_minter_config: FeeTotals,
+    pub create_trade_hub: FeeTotals,
+    pub trade_nft: FeeTotals,
+    pub mint_asset: FeeTotals,
+    pub generic_operation: FeeTotals,
+}
+
+impl OperationFeeTotals {
+    pub fn get_mut(&mut self, operation: Operation) -> &mut FeeTotals {
+        match operation {
+            Operation::CreateProject => &mut self.create_project,
+            Operation::CreateMinterConfig => &mut self.create_minter_config,
+            Operation::CreateTradeHub => &mut self.create_trade_hub,
+            Operation::MintAsset => &mut self.mint_asset,
+            Operation::TradeNFT => &mut self.trade_nft,
+            Operation::Generic => &mut self.generic_operation,
+        }
+    }
+}
+
+/// A protocol fee charged by `pay_protocol_fee`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct FeePayment {
+    pub operation: Operation,
+    pub amount: u64,
+    pub mint: Option<Pubkey>,
+    pub referrer: Option<Pubkey>,
+    pub referral_amount: u64, // part of `amount` paid to the referrer instead of the treasury
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct FeeStats {
+    pub owner: Pubkey, // protocol config or project config the stats are kept for
+    pub protocol_fees: OperationFeeTotals,
+    pub trade_hub_fees: FeeTotals, // trade hub fees paid into a project treasury
+    pub available: u64,            // fee lamports in the owner's treasury not withdrawn yet
+    pub withdrawn: u64,
+    pub bump: u8,
+}
+
+impl FeeStats {
+    pub fn new(owner: Pubkey, bump: u8) -> Self {
+        Self {
+            owner,
+            protocol_fees: OperationFeeTotals::default(),
+            trade_hub_fees: FeeTotals::default(),
+            available: 0,
+            withdrawn: 0,
+            bump,
+        }
+    }
+
+    /// Records a protocol fee, adding it to `available` when it was paid into the treasury
+    /// these stats are kept for.
+    pub fn record_protocol_fee(&mut self, payment: &FeePayment, collected: bool) -> Result<()> {
+        let in_lamports = payment.mint.is_none();
+
+        self.protocol_fees
+            .get_mut(payment.operation)
+            .record(payment.amount, in_lamports)?;
+
+        if collected && in_lamports {
+            let treasury_amount = payment
+                .amount
+                .checked_sub(payment.referral_amount)
+                .ok_or(ProtocolError::MathOverflow)?;
+            self.credit(treasury_amount)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn record_trade_hub_fee(&mut self, amount: u64) -> Result<()> {
+        self.trade_hub_fees.record(amount, true)?;
+        self.credit(amount)
+    }
+
+    pub fn record_subscription_payment(&mut self, amount: u64) -> Result<()> {
+        self.credit(amount)
+    }
+
+    /// Lamports leaving the treasury. `available` stops at zero, as the treasury can also hold
+    /// lamports that did not come from fees.
+    pub fn record_withdrawal(&mut self, amount: u64) -> Result<()> {
+        self.available = self.available.saturating_sub(amount);
+        self.withdrawn = self
+            .withdrawn
+            .checked_add(amount)
+            .ok_or(ProtocolError::MathOverflow)?;
+
+        Ok(())
+    }
+
+    fn credit(&mut self, amount: u64) -> Result<()> {
+        self.available = self
+            .available
+            .checked_add(amount)
+            .ok_or(ProtocolError::MathOverflow)?;
+
+        Ok(())
+    }
+}
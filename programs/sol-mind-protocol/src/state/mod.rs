@@ -1,5 +1,25 @@
+pub mod config_v0;
+pub mod fee_stats;
 pub mod project_config;
+pub mod project_fee_override;
+pub mod project_registry;
+pub mod project_role;
+pub mod proposal;
 pub mod protocol_config;
+pub mod referrer;
+pub mod registered_program;
+pub mod subscription;
+pub mod treasury_policy;
 
+pub use config_v0::*;
+pub use fee_stats::*;
 pub use project_config::*;
+pub use project_fee_override::*;
+pub use project_registry::*;
+pub use project_role::*;
+pub use proposal::*;
 pub use protocol_config::*;
+pub use referrer::*;
+pub use registered_program::*;
+pub use subscription::*;
+pub use treasury_policy::*;
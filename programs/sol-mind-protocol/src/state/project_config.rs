@@ -1,23 +1,175 @@
 use anchor_lang::prelude::*;
 
-#[account]
-#[derive(InitSpace)]
+use crate::errors::ProtocolError;
+use crate::state::{Role, NFT_OPERATIONS_PROGRAM_ID};
+
+pub const MAX_PROJECT_NAME_LEN: usize = 64;
+pub const MAX_PROJECT_DESCRIPTION_LEN: usize = 200;
+pub const MAX_PROJECT_AUTHORITIES: usize = 3;
+pub const PROJECT_CONFIG_VERSION: u8 = 3;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub enum ProjectResource {
+    MinterConfig,
+    TradeHub,
+}
+
+impl ProjectResource {
+    /// Role the authority registering the resource must hold.
+    pub fn required_role(self) -> Role {
+        match self {
+            ProjectResource::MinterConfig => Role::Minter,
+            ProjectResource::TradeHub => Role::HubManager,
+        }
+    }
+
+    /// Identifies a minter config or trade hub of nft-operations from its discriminator, and
+    /// checks it is the PDA of that resource under `project_config`.
+    pub fn from_account(account_info: &AccountInfo, project_config: &Pubkey) -> Result<Self> {
+        require_keys_eq!(
+            *account_info.owner,
+            NFT_OPERATIONS_PROGRAM_ID,
+            ProtocolError::InvalidProjectAccount
+        );
+
+        let data = account_info.try_borrow_data()?;
+        require!(data.len() >= 8, ProtocolError::InvalidProjectAccount);
+        let (discriminator, mut fields) = data.split_at(8);
+
+        // sha256("account:<name>")[..8] of the nft-operations accounts
+        let (resource, address) = match discriminator {
+            [78, 211, 23, 6, 233, 19, 19, 236] => {
+                let name = String::deserialize(&mut fields)
+                    .map_err(|_| ProtocolError::InvalidProjectAccount)?;
+                let seeds: &[&[u8]] = &[b"minter_config", project_config.as_ref(), name.as_bytes()];
+                (
+                    ProjectResource::MinterConfig,
+                    Pubkey::find_program_address(seeds, &NFT_OPERATIONS_PROGRAM_ID).0,
+                )
+            }
+            [2, 183, 109, 61, 76, 192, 54, 252] => {
+                let (_project, name) = <(Pubkey, String)>::deserialize(&mut fields)
+                    .map_err(|_| ProtocolError::InvalidProjectAccount)?;
+                let seeds: &[&[u8]] = &[b"trade_hub", name.as_bytes(), project_config.as_ref()];
+                (
+                    ProjectResource::TradeHub,
+                    Pubkey::find_program_address(seeds, &NFT_OPERATIONS_PROGRAM_ID).0,
+                )
+            }
+            _ => return err!(ProtocolError::InvalidProjectAccount),
+        };
+
+        require_keys_eq!(
+            account_info.key(),
+            address,
+            ProtocolError::InvalidProjectAccount
+        );
+        Ok(resource)
+    }
+}
+
+/// Account traits are implemented in `config_v0`, like those of `ProtocolConfig`.
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct ProjectConfig {
     pub protocol_config: Pubkey,
     pub project_id: u64,
+    pub creator: Pubkey, // seeds the project PDA, never changes
     pub owner: Pubkey,
-    #[max_len(64)]
+    pub pending_owner: Option<Pubkey>,
+    #[max_len(MAX_PROJECT_NAME_LEN)]
     pub name: String,
-    #[max_len(200)]
+    #[max_len(MAX_PROJECT_DESCRIPTION_LEN)]
     pub description: String,
-    #[max_len(3)]
+    /// Legacy authority list, no longer read or written. Project roles replaced it; the field
+    /// stays so the account layout is unchanged.
+    #[max_len(MAX_PROJECT_AUTHORITIES)]
     pub autthorities: Vec<Pubkey>,
+    pub minter_configs_count: u64,
+    pub trade_hubs_count: u64,
     pub treasury_bump: u8,
     pub bump: u8,
+    /// Layout version, 0 for accounts of the first release's layout (`ProjectConfigV0`).
+    /// Follows the same rules as `ProtocolConfig::version`.
+    pub version: u8,
+    pub created_epoch: u64, // epoch of creation, or of the migration to version 2
+    pub roles_count: u64,   // role PDAs, the project can only be closed without any
 }
 
 impl ProjectConfig {
-    pub fn check_authorities(&self, key: &Pubkey) -> bool {
-        self.autthorities.contains(key)
+    /// Upgrades the layout to `PROJECT_CONFIG_VERSION`, returning the version it started from.
+    pub fn migrate(&mut self) -> Result<u8> {
+        let from_version = self.version;
+        require!(
+            from_version < PROJECT_CONFIG_VERSION,
+            ProtocolError::AlreadyMigrated
+        );
+
+        // Version 1 only introduces the version byte. Version 2 adds the creation epoch, which
+        // is unknown for older projects, so their subscription grace period starts now.
+        // Version 3 adds the role count, which the migration backfills along with the resource
+        // counts of first-release projects.
+        if from_version < 2 {
+            self.created_epoch = Clock::get()?.epoch;
+        }
+        self.version = PROJECT_CONFIG_VERSION;
+        Ok(from_version)
+    }
+
+    pub fn set_name(&mut self, name: String) -> Result<()> {
+        require!(
+            name.len() <= MAX_PROJECT_NAME_LEN,
+            ProtocolError::ProjectNameTooLong
+        );
+
+        self.name = name;
+        Ok(())
+    }
+
+    pub fn set_description(&mut self, description: String) -> Result<()> {
+        require!(
+            description.len() <= MAX_PROJECT_DESCRIPTION_LEN,
+            ProtocolError::ProjectDescriptionTooLong
+        );
+
+        self.description = description;
+        Ok(())
+    }
+
+    pub fn has_resources(&self) -> bool {
+        self.minter_configs_count > 0 || self.trade_hubs_count > 0
+    }
+
+    pub fn register_resource(&mut self, resource: ProjectResource) -> Result<()> {
+        let counter = self.resource_counter(resource);
+        *counter = counter.checked_add(1).ok_or(ProtocolError::MathOverflow)?;
+
+        Ok(())
+    }
+
+    /// Saturates, so a resource created before the project counted them can still be closed
+    /// when its migration did not count it.
+    pub fn unregister_resource(&mut self, resource: ProjectResource) {
+        let counter = self.resource_counter(resource);
+        *counter = counter.saturating_sub(1);
+    }
+
+    pub fn add_role(&mut self) -> Result<()> {
+        self.roles_count = self
+            .roles_count
+            .checked_add(1)
+            .ok_or(ProtocolError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Saturates like `unregister_resource`.
+    pub fn remove_role(&mut self) {
+        self.roles_count = self.roles_count.saturating_sub(1);
+    }
+
+    fn resource_counter(&mut self, resource: ProjectResource) -> &mut u64 {
+        match resource {
+            ProjectResource::MinterConfig => &mut self.minter_configs_count,
+            ProjectResource::TradeHub => &mut self.trade_hubs_count,
+        }
     }
 }
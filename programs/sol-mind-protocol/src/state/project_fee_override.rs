@@ -0,0 +1,167 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ProtocolError;
+use crate::state::{Fee, Operation, FEE_UPDATE_DELAY, MAX_FEE_BPS};
+
+pub const MAX_FEE_OVERRIDES: usize = 6; // one per Operation
+
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum FeeAdjustment {
+    /// Charges `fee` instead of the protocol fee.
+    Override { fee: Fee },
+    /// Charges the protocol fee reduced by `bps`.
+    Discount { bps: u16 },
+}
+
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct OperationFeeOverride {
+    pub operation: Operation,
+    pub adjustment: FeeAdjustment,
+}
+
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct ScheduledFeeOverride {
+    pub operation: Operation,
+    pub adjustment: FeeAdjustment,
+    pub effective_at: i64, // when the adjustment starts to apply
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ProjectFeeOverride {
+    pub project_config: Pubkey,
+    #[max_len(MAX_FEE_OVERRIDES)]
+    pub overrides: Vec<ScheduledFeeOverride>,
+    pub expires_at: Option<i64>, // None for no expiry
+    pub payer: Pubkey,           // paid the rent, and receives it back on close
+    pub bump: u8,
+}
+
+impl ProjectFeeOverride {
+    /// Schedules `overrides`, replacing `previous`, the override currently stored for the
+    /// project if any. `payer` only pays the rent of a new override account.
+    pub fn new(
+        project_config: Pubkey,
+        overrides: Vec<OperationFeeOverride>,
+        expires_at: Option<i64>,
+        previous: Option<&Self>,
+        payer: Pubkey,
+        now: i64,
+        bump: u8,
+    ) -> Result<Self> {
+        Self::validate(&overrides, expires_at, now)?;
+
+        let payer = previous.map_or(payer, |previous| previous.payer);
+        let previous = previous.filter(|previous| !previous.is_expired(now));
+        let overrides = overrides
+            .into_iter()
+            .map(|entry| {
+                Ok(ScheduledFeeOverride {
+                    operation: entry.operation,
+                    adjustment: entry.adjustment,
+                    effective_at: Self::effective_at(&entry, previous, now)?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            project_config,
+            overrides,
+            expires_at,
+            payer,
+            bump,
+        })
+    }
+
+    /// An `Override` can raise or replace the protocol fee, so it waits out `FEE_UPDATE_DELAY`
+    /// like a fee update, unless `previous` already schedules the same one. Discounts only
+    /// lower the fee and apply at once.
+    pub fn effective_at(
+        entry: &OperationFeeOverride,
+        previous: Option<&Self>,
+        now: i64,
+    ) -> Result<i64> {
+        if matches!(entry.adjustment, FeeAdjustment::Discount { .. }) {
+            return Ok(now);
+        }
+
+        let unchanged = previous.and_then(|previous| {
+            previous.overrides.iter().find(|scheduled| {
+                scheduled.operation == entry.operation && scheduled.adjustment == entry.adjustment
+            })
+        });
+        match unchanged {
+            Some(scheduled) => Ok(scheduled.effective_at),
+            None => now
+                .checked_add(FEE_UPDATE_DELAY)
+                .ok_or(error!(ProtocolError::MathOverflow)),
+        }
+    }
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.expires_at.is_some_and(|expires_at| now >= expires_at)
+    }
+
+    pub fn validate(
+        overrides: &[OperationFeeOverride],
+        expires_at: Option<i64>,
+        now: i64,
+    ) -> Result<()> {
+        require!(
+            overrides.len() <= MAX_FEE_OVERRIDES,
+            ProtocolError::MaxFeeOverridesReached
+        );
+
+        for (index, entry) in overrides.iter().enumerate() {
+            require!(
+                !overrides[..index]
+                    .iter()
+                    .any(|other| other.operation == entry.operation),
+                ProtocolError::DuplicateFeeOverride
+            );
+
+            match entry.adjustment {
+                FeeAdjustment::Override { fee } => fee.validate()?,
+                FeeAdjustment::Discount { bps } => {
+                    require!(u64::from(bps) <= MAX_FEE_BPS, ProtocolError::InvalidFeeBps)
+                }
+            }
+        }
+
+        if let Some(expires_at) = expires_at {
+            require!(expires_at > now, ProtocolError::InvalidFeeOverrideExpiry);
+        }
+
+        Ok(())
+    }
+
+    /// Adjustment for `operation`, `None` when there is none, the override has expired or an
+    /// `Override` is not yet effective.
+    pub fn get_adjustment(&self, operation: Operation, now: i64) -> Option<FeeAdjustment> {
+        if self.is_expired(now) {
+            return None;
+        }
+
+        self.overrides
+            .iter()
+            .find(|entry| entry.operation == operation)
+            .filter(|entry| now >= entry.effective_at)
+            .map(|entry| entry.adjustment)
+    }
+
+    /// Reads the override stored at the project's override PDA, if one was created.
+    pub fn load(account_info: &AccountInfo) -> Result<Option<Self>> {
+        if account_info.data_is_empty() {
+            return Ok(None);
+        }
+
+        require_keys_eq!(
+            *account_info.owner,
+            crate::ID,
+            ProtocolError::InvalidFeeOverrideAccount
+        );
+
+        let data = account_info.try_borrow_data()?;
+        Ok(Some(Self::try_deserialize(&mut &data[..])?))
+    }
+}
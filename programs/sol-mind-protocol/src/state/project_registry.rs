@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hash;
+
+/// Maps a project name to its `ProjectConfig`, keeping names unique per protocol.
+#[account]
+#[derive(InitSpace)]
+pub struct ProjectRegistryEntry {
+    pub protocol_config: Pubkey,
+    pub project_config: Pubkey,
+    pub bump: u8,
+}
+
+impl ProjectRegistryEntry {
+    /// Project names can be longer than a PDA seed, so entries are keyed by the name hash.
+    pub fn name_seed(name: &str) -> [u8; 32] {
+        hash(name.as_bytes()).to_bytes()
+    }
+}
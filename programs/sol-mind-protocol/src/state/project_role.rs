@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ProtocolError;
+
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    /// Creates minter configs and mints assets.
+    Minter,
+    /// Creates trade hubs.
+    HubManager,
+    /// Withdraws project treasury fees.
+    Treasurer,
+    /// Holds every other role and grants or revokes them.
+    Admin,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ProjectRole {
+    pub project_config: Pubkey,
+    pub grantee: Pubkey,
+    pub roles: u8, // bitmap indexed by Role
+    pub bump: u8,
+}
+
+impl ProjectRole {
+    fn role_flag(role: Role) -> u8 {
+        1 << role as u8
+    }
+
+    pub fn has_role(&self, role: Role) -> bool {
+        self.roles & (Self::role_flag(role) | Self::role_flag(Role::Admin)) != 0
+    }
+
+    pub fn grant(&mut self, role: Role) -> Result<()> {
+        require!(
+            self.roles & Self::role_flag(role) == 0,
+            ProtocolError::RoleAlreadyGranted
+        );
+
+        self.roles |= Self::role_flag(role);
+        Ok(())
+    }
+
+    pub fn revoke(&mut self, role: Role) -> Result<()> {
+        require!(
+            self.roles & Self::role_flag(role) != 0,
+            ProtocolError::RoleNotGranted
+        );
+
+        self.roles &= !Self::role_flag(role);
+        Ok(())
+    }
+
+    /// Whether the role PDA of a grantee grants `role`. A PDA that was never created grants
+    /// nothing.
+    pub fn grants(account_info: &AccountInfo, role: Role) -> Result<bool> {
+        if account_info.data_is_empty() {
+            return Ok(false);
+        }
+
+        require_keys_eq!(
+            *account_info.owner,
+            crate::ID,
+            ProtocolError::InvalidProjectRoleAccount
+        );
+
+        let data = account_info.try_borrow_data()?;
+        Ok(Self::try_deserialize(&mut &data[..])?.has_role(role))
+    }
+}
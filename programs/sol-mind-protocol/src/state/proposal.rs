@@ -0,0 +1,164 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ProtocolError;
+use crate::state::{
+    Fee, FeesStructure, Operation, OperationFeeOverride, ProjectFeeOverride, ProtocolConfig,
+    Referrer, RegisteredProgram, RevenueShare, SubscriptionPlan, MAX_ADMINS, MAX_FEE_OVERRIDES,
+    MAX_REVENUE_SHARES, MAX_WHITELIST_LABEL_LEN,
+};
+
+pub const PROPOSAL_LIFETIME: i64 = 7 * 24 * 60 * 60; // seconds a proposal stays open for approval
+
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone)]
+#[allow(clippy::large_enum_variant)] // stored on-chain, boxing would not shrink the account
+pub enum ProposalAction {
+    UpdateFees {
+        fees: FeesStructure,
+    },
+    UpdateSingleFee {
+        operation: Operation,
+        fee: Fee,
+    },
+    TransferProtocolFees {
+        to: Pubkey,
+        amount: u64,
+    },
+    SetApprovalThreshold {
+        threshold: u8,
+    },
+    SetRevenueShares {
+        #[max_len(MAX_REVENUE_SHARES)]
+        shares: Vec<RevenueShare>,
+    },
+    AddAdmin {
+        new_admin: Pubkey,
+    },
+    RemoveAdmin {
+        old_admin: Pubkey,
+    },
+    RotateAdmin {
+        old_admin: Pubkey,
+        new_admin: Pubkey,
+    },
+    AddWhitelistTransferAddr {
+        address: Pubkey,
+        #[max_len(MAX_WHITELIST_LABEL_LEN)]
+        label: Option<String>,
+        transfer_cap: Option<u64>,
+    },
+    RemoveWhitelistTransferAddr {
+        address: Pubkey,
+    },
+    SetProtocolPaused {
+        paused: bool,
+    },
+    SetOperationPaused {
+        operation: Operation,
+        paused: bool,
+    },
+    /// `to` is the destination token account, whose owner must be whitelisted.
+    TransferProtocolTokenFees {
+        mint: Pubkey,
+        to: Pubkey,
+        amount: u64,
+    },
+    SetProjectFeeOverride {
+        project_config: Pubkey,
+        #[max_len(MAX_FEE_OVERRIDES)]
+        overrides: Vec<OperationFeeOverride>,
+        expires_at: Option<i64>,
+    },
+    CloseProjectFeeOverride {
+        project_config: Pubkey,
+    },
+    RegisterProgram {
+        program_id: Pubkey,
+    },
+    UnregisterProgram {
+        program_id: Pubkey,
+    },
+    RegisterReferrer {
+        wallet: Pubkey,
+        share_bps: u16,
+    },
+    UpdateReferrer {
+        wallet: Pubkey,
+        share_bps: u16,
+    },
+    /// Pays the unclaimed referral fees and the rent out to `wallet`.
+    RemoveReferrer {
+        wallet: Pubkey,
+    },
+    SetSubscriptionPlan {
+        plan: SubscriptionPlan,
+    },
+    RemoveSubscriptionPlan {
+        plan_id: u8,
+    },
+}
+
+impl ProposalAction {
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            ProposalAction::UpdateFees { fees } => fees.validate(),
+            ProposalAction::UpdateSingleFee { fee, .. } => fee.validate(),
+            ProposalAction::SetRevenueShares { shares } => {
+                ProtocolConfig::validate_revenue_shares(shares)
+            }
+            ProposalAction::AddWhitelistTransferAddr {
+                label: Some(label), ..
+            } => {
+                require!(
+                    label.len() <= MAX_WHITELIST_LABEL_LEN,
+                    ProtocolError::WhitelistLabelTooLong
+                );
+                Ok(())
+            }
+            ProposalAction::SetProjectFeeOverride {
+                overrides,
+                expires_at,
+                ..
+            } => ProjectFeeOverride::validate(overrides, *expires_at, Clock::get()?.unix_timestamp),
+            ProposalAction::RegisterProgram { program_id } => {
+                RegisteredProgram::validate_program_id(program_id)
+            }
+            ProposalAction::RegisterReferrer { share_bps, .. }
+            | ProposalAction::UpdateReferrer { share_bps, .. } => {
+                Referrer::validate_share_bps(*share_bps)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Proposal {
+    pub protocol_config: Pubkey,
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub action: ProposalAction,
+    #[max_len(MAX_ADMINS)]
+    pub approvals: Vec<Pubkey>,
+    pub created_at: i64,
+    pub bump: u8,
+    pub expires_at: i64,
+}
+
+impl Proposal {
+    pub fn has_approved(&self, key: &Pubkey) -> bool {
+        self.approvals.contains(key)
+    }
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        now >= self.expires_at
+    }
+
+    /// Approvals are only counted for keys that are still admins.
+    pub fn approvals_count(&self, protocol_config: &ProtocolConfig) -> usize {
+        self.approvals
+            .iter()
+            .filter(|approval| protocol_config.check_admins(approval))
+            .count()
+    }
+}
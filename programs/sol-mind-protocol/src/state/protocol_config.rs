@@ -1,18 +1,113 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::ProtocolError;
+use crate::state::{FeeAdjustment, ProjectFeeOverride, SubscriptionPlan, MAX_SUBSCRIPTION_PLANS};
 
-#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub const MAX_ADMINS: usize = 3;
+pub const MAX_WHITELIST_TRANSFER_ADDRS: usize = 3;
+pub const MAX_WHITELIST_LABEL_LEN: usize = 32;
+pub const FEE_UPDATE_DELAY: i64 = 24 * 60 * 60; // seconds before a fee update takes effect
+pub const MAX_FEE_BPS: u64 = 10_000;
+pub const MAX_REVENUE_SHARES: usize = 5;
+pub const PROTOCOL_CONFIG_VERSION: u8 = 3;
+
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct WhitelistEntry {
+    pub address: Pubkey,
+    #[max_len(MAX_WHITELIST_LABEL_LEN)]
+    pub label: Option<String>,
+    pub transfer_cap: Option<u64>, // max lamports per transfer, None for unlimited
+}
+
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct RevenueShare {
+    pub recipient: Pubkey,
+    pub bps: u16, // share of each distribution, all shares add up to MAX_FEE_BPS
+}
+
+impl RevenueShare {
+    pub fn payout(&self, amount: u64) -> Result<u64> {
+        bps_of(amount, u64::from(self.bps))
+    }
+}
+
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq)]
 pub struct Fee {
     pub amount: u64,
     pub fee_type: FeeType,
+    pub mint: Option<Pubkey>, // SPL or Token-2022 mint the fee is paid in, None for lamports
 }
 
-#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq)]
 pub enum FeeType {
     #[default]
     Fixed,
     Percentage,
+    /// `Fee::amount` plus `bps` of the base amount, clamped to `min` and `max` when set.
+    Hybrid {
+        bps: u16,
+        min: Option<u64>,
+        max: Option<u64>,
+    },
+}
+
+impl Fee {
+    pub fn validate(&self) -> Result<()> {
+        // Base amounts are lamport prices, so only a fixed amount can be charged in tokens
+        require!(
+            self.mint.is_none() || matches!(self.fee_type, FeeType::Fixed),
+            ProtocolError::TokenFeeMustBeFixed
+        );
+
+        match self.fee_type {
+            FeeType::Fixed => {}
+            FeeType::Percentage => {
+                require!(self.amount <= MAX_FEE_BPS, ProtocolError::InvalidFeeBps);
+            }
+            FeeType::Hybrid { bps, min, max } => {
+                require!(u64::from(bps) <= MAX_FEE_BPS, ProtocolError::InvalidFeeBps);
+                if let (Some(min), Some(max)) = (min, max) {
+                    require!(min <= max, ProtocolError::InvalidFeeBounds);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn calculate_amount(&self, base_amount: Option<u64>) -> Result<u64> {
+        match self.fee_type {
+            FeeType::Fixed => Ok(self.amount),
+            FeeType::Percentage => {
+                let amount = base_amount.ok_or(error!(ProtocolError::FeeCalculationOverflow))?;
+                bps_of(amount, self.amount)
+            }
+            FeeType::Hybrid { bps, min, max } => {
+                let variable = bps_of(base_amount.unwrap_or(0), u64::from(bps))?;
+                let mut amount = self
+                    .amount
+                    .checked_add(variable)
+                    .ok_or(error!(ProtocolError::FeeCalculationOverflow))?;
+
+                if let Some(min) = min {
+                    amount = amount.max(min);
+                }
+                if let Some(max) = max {
+                    amount = amount.min(max);
+                }
+
+                Ok(amount)
+            }
+        }
+    }
+}
+
+pub(crate) fn bps_of(amount: u64, bps: u64) -> Result<u64> {
+    amount
+        .checked_mul(bps)
+        .ok_or(error!(ProtocolError::FeeCalculationOverflow))?
+        .checked_div(MAX_FEE_BPS)
+        .ok_or(error!(ProtocolError::FeeCalculationOverflow))
 }
 
 #[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Default)]
@@ -25,7 +120,46 @@ pub struct FeesStructure {
     pub generic_operation: Fee,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+impl FeesStructure {
+    pub fn get_fee(&self, operation: Operation) -> Fee {
+        match operation {
+            Operation::CreateProject => self.create_project,
+            Operation::CreateMinterConfig => self.create_minter_config,
+            Operation::CreateTradeHub => self.create_trade_hub,
+            Operation::MintAsset => self.mint_asset,
+            Operation::TradeNFT => self.trade_nft,
+            Operation::Generic => self.generic_operation,
+        }
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        self.create_project.validate()?;
+        self.create_minter_config.validate()?;
+        self.create_trade_hub.validate()?;
+        self.trade_nft.validate()?;
+        self.mint_asset.validate()?;
+        self.generic_operation.validate()
+    }
+
+    pub fn set_fee(&mut self, operation: Operation, fee: Fee) {
+        match operation {
+            Operation::CreateProject => self.create_project = fee,
+            Operation::CreateMinterConfig => self.create_minter_config = fee,
+            Operation::CreateTradeHub => self.create_trade_hub = fee,
+            Operation::MintAsset => self.mint_asset = fee,
+            Operation::TradeNFT => self.trade_nft = fee,
+            Operation::Generic => self.generic_operation = fee,
+        }
+    }
+}
+
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PendingFees {
+    pub fees: FeesStructure,
+    pub effective_at: i64,
+}
+
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
 pub enum Operation {
     CreateProject,
     CreateMinterConfig,
@@ -35,34 +169,368 @@ pub enum Operation {
     Generic,
 }
 
-#[account]
-#[derive(InitSpace)]
+/// Account traits are implemented in `config_v0`, which also decodes configs of the first
+/// release's layout.
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct ProtocolConfig {
-    #[max_len(3)]
+    #[max_len(MAX_ADMINS)]
     pub admins: Vec<Pubkey>,
-    #[max_len(3)]
-    pub whitelist_transfer_addrs: Vec<Pubkey>,
+    #[max_len(MAX_WHITELIST_TRANSFER_ADDRS)]
+    pub whitelist_transfer_addrs: Vec<WhitelistEntry>,
+    #[max_len(MAX_REVENUE_SHARES)]
+    pub revenue_shares: Vec<RevenueShare>,
     pub fees: FeesStructure,
+    pub pending_fees: Option<PendingFees>,
+    pub paused: bool,
+    pub paused_operations: u16, // bitmap indexed by Operation
+    pub approval_threshold: u8,
+    pub proposal_count: u64,
+    pub project_count: u64,
     pub bump: u8,
+    /// Layout version, 0 for accounts of the first release's layout, which are converted from
+    /// `ProtocolConfigV0` when read. From version 1 on new fields are only appended, and reads
+    /// zero-extend a shorter account, so an older layout decodes with the newer fields zeroed.
+    /// Only the current version is written; older ones must be migrated first.
+    pub version: u8,
+    /// Identifies one of several protocol deployments hosted by the program, each with its
+    /// own admins and fees. Instance 0 is the one created before instances existed.
+    pub instance_id: u64,
+    #[max_len(MAX_SUBSCRIPTION_PLANS)]
+    pub subscription_plans: Vec<SubscriptionPlan>,
 }
 
 impl ProtocolConfig {
+    /// PDA seed of an instance. Instance 0 adds no seed, so it keeps the address of the
+    /// original `[b"sol-mind-protocol"]` config.
+    pub fn instance_seed(instance_id: u64) -> Vec<u8> {
+        if instance_id == 0 {
+            Vec::new()
+        } else {
+            instance_id.to_le_bytes().to_vec()
+        }
+    }
+
+    /// Upgrades the layout to `PROTOCOL_CONFIG_VERSION`, returning the version it started from.
+    pub fn migrate(&mut self) -> Result<u8> {
+        let from_version = self.version;
+        require!(
+            from_version < PROTOCOL_CONFIG_VERSION,
+            ProtocolError::AlreadyMigrated
+        );
+
+        // Version 0 configs are converted from `ProtocolConfigV0` when read. Version 1 only
+        // introduces the version byte, version 2 the instance id and version 3 the subscription
+        // plans, all read as zeroes from a shorter account: configs older than instances are
+        // instance 0 and start without plans.
+        self.version = PROTOCOL_CONFIG_VERSION;
+        Ok(from_version)
+    }
+
+    pub fn get_subscription_plan(&self, plan_id: u8) -> Option<&SubscriptionPlan> {
+        self.subscription_plans
+            .iter()
+            .find(|plan| plan.plan_id == plan_id)
+    }
+
+    /// Adds the plan, or replaces the one with the same id. A new price applies to epochs paid
+    /// from then on.
+    pub fn set_subscription_plan(&mut self, plan: SubscriptionPlan) -> Result<()> {
+        if let Some(existing) = self
+            .subscription_plans
+            .iter_mut()
+            .find(|existing| existing.plan_id == plan.plan_id)
+        {
+            *existing = plan;
+            return Ok(());
+        }
+
+        require!(
+            self.subscription_plans.len() < MAX_SUBSCRIPTION_PLANS,
+            ProtocolError::MaxSubscriptionPlansReached
+        );
+
+        self.subscription_plans.push(plan);
+        Ok(())
+    }
+
+    pub fn remove_subscription_plan(&mut self, plan_id: u8) -> Result<()> {
+        let index = self
+            .subscription_plans
+            .iter()
+            .position(|plan| plan.plan_id == plan_id)
+            .ok_or(ProtocolError::SubscriptionPlanNotFound)?;
+
+        self.subscription_plans.remove(index);
+        Ok(())
+    }
+
     pub fn check_admins(&self, key: &Pubkey) -> bool {
         self.admins.contains(key)
     }
 
+    pub fn add_admin(&mut self, new_admin: Pubkey) -> Result<()> {
+        require!(
+            !self.check_admins(&new_admin),
+            ProtocolError::AdminAlreadyExists
+        );
+        require!(
+            self.admins.len() < MAX_ADMINS,
+            ProtocolError::MaxAdminsReached
+        );
+
+        self.admins.push(new_admin);
+        Ok(())
+    }
+
+    pub fn remove_admin(&mut self, old_admin: &Pubkey) -> Result<()> {
+        let index = self
+            .admins
+            .iter()
+            .position(|admin| admin == old_admin)
+            .ok_or(ProtocolError::AdminNotFound)?;
+        require!(self.admins.len() > 1, ProtocolError::CannotRemoveLastAdmin);
+        require!(
+            self.admins.len() > self.approval_threshold as usize,
+            ProtocolError::InvalidApprovalThreshold
+        );
+
+        self.admins.remove(index);
+        Ok(())
+    }
+
+    pub fn rotate_admin(&mut self, old_admin: &Pubkey, new_admin: Pubkey) -> Result<()> {
+        require!(
+            !self.check_admins(&new_admin),
+            ProtocolError::AdminAlreadyExists
+        );
+        let admin = self
+            .admins
+            .iter_mut()
+            .find(|admin| *admin == old_admin)
+            .ok_or(ProtocolError::AdminNotFound)?;
+
+        *admin = new_admin;
+        Ok(())
+    }
+
+    pub fn is_operation_paused(&self, operation: Operation) -> bool {
+        self.paused || self.paused_operations & Self::operation_flag(operation) != 0
+    }
+
+    pub fn set_operation_paused(&mut self, operation: Operation, paused: bool) {
+        if paused {
+            self.paused_operations |= Self::operation_flag(operation);
+        } else {
+            self.paused_operations &= !Self::operation_flag(operation);
+        }
+    }
+
+    fn operation_flag(operation: Operation) -> u16 {
+        1 << operation as u16
+    }
+
+    pub fn requires_approval(&self) -> bool {
+        self.approval_threshold > 1
+    }
+
+    /// Distinct admins that signed the transaction among `accounts`.
+    pub fn count_admin_signers(&self, accounts: &[AccountInfo]) -> usize {
+        let mut signers: Vec<&Pubkey> = Vec::with_capacity(MAX_ADMINS);
+        for account in accounts {
+            if account.is_signer
+                && self.check_admins(account.key)
+                && !signers.contains(&account.key)
+            {
+                signers.push(account.key);
+            }
+        }
+        signers.len()
+    }
+
+    /// Requires `approval_threshold` distinct admin signatures among `admin` and `co_signers`,
+    /// for operations that act at once instead of going through a proposal.
+    pub fn require_admin_signers<'info>(
+        &self,
+        admin: AccountInfo<'info>,
+        co_signers: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        let mut signers = vec![admin];
+        signers.extend_from_slice(co_signers);
+        require!(
+            self.count_admin_signers(&signers) >= self.approval_threshold as usize,
+            ProtocolError::ProposalThresholdNotMet
+        );
+
+        Ok(())
+    }
+
+    pub fn set_approval_threshold(&mut self, threshold: u8) -> Result<()> {
+        require!(
+            threshold > 0 && threshold as usize <= self.admins.len(),
+            ProtocolError::InvalidApprovalThreshold
+        );
+
+        self.approval_threshold = threshold;
+        Ok(())
+    }
+
     pub fn check_whitelist_transfer_addrs(&self, key: &Pubkey) -> bool {
-        self.whitelist_transfer_addrs.contains(key)
+        self.get_whitelist_entry(key).is_some()
     }
 
-    pub fn get_fee(&self, operation: Operation) -> Fee {
-        match operation {
-            Operation::CreateProject => self.fees.create_project,
-            Operation::CreateMinterConfig => self.fees.create_minter_config,
-            Operation::CreateTradeHub => self.fees.create_trade_hub,
-            Operation::MintAsset => self.fees.mint_asset,
-            Operation::TradeNFT => self.fees.trade_nft,
-            Operation::Generic => self.fees.generic_operation,
+    pub fn get_whitelist_entry(&self, key: &Pubkey) -> Option<&WhitelistEntry> {
+        self.whitelist_transfer_addrs
+            .iter()
+            .find(|entry| entry.address == *key)
+    }
+
+    pub fn validate_whitelist_transfer(&self, to: &Pubkey, amount: u64) -> Result<()> {
+        let entry = self
+            .get_whitelist_entry(to)
+            .ok_or(ProtocolError::AddressNotWhiteListed)?;
+
+        if let Some(transfer_cap) = entry.transfer_cap {
+            require!(amount <= transfer_cap, ProtocolError::TransferCapExceeded);
+        }
+
+        Ok(())
+    }
+
+    pub fn add_whitelist_transfer_addr(&mut self, entry: WhitelistEntry) -> Result<()> {
+        require!(
+            !self.check_whitelist_transfer_addrs(&entry.address),
+            ProtocolError::WhitelistAddressAlreadyExists
+        );
+        require!(
+            self.whitelist_transfer_addrs.len() < MAX_WHITELIST_TRANSFER_ADDRS,
+            ProtocolError::MaxWhitelistAddressesReached
+        );
+        if let Some(label) = &entry.label {
+            require!(
+                label.len() <= MAX_WHITELIST_LABEL_LEN,
+                ProtocolError::WhitelistLabelTooLong
+            );
+        }
+
+        self.whitelist_transfer_addrs.push(entry);
+        Ok(())
+    }
+
+    pub fn remove_whitelist_transfer_addr(&mut self, address: &Pubkey) -> Result<WhitelistEntry> {
+        let index = self
+            .whitelist_transfer_addrs
+            .iter()
+            .position(|entry| entry.address == *address)
+            .ok_or(ProtocolError::WhitelistAddressNotFound)?;
+
+        Ok(self.whitelist_transfer_addrs.remove(index))
+    }
+
+    /// Shares must add up to `MAX_FEE_BPS` with distinct recipients.
+    /// An empty table disables `distribute_protocol_fees`.
+    pub fn validate_revenue_shares(shares: &[RevenueShare]) -> Result<()> {
+        require!(
+            shares.len() <= MAX_REVENUE_SHARES,
+            ProtocolError::MaxRevenueSharesReached
+        );
+
+        let mut total_bps: u64 = 0;
+        for (index, share) in shares.iter().enumerate() {
+            require!(
+                !shares[..index]
+                    .iter()
+                    .any(|other| other.recipient == share.recipient),
+                ProtocolError::DuplicateRevenueRecipient
+            );
+            total_bps += u64::from(share.bps);
+        }
+
+        require!(
+            shares.is_empty() || total_bps == MAX_FEE_BPS,
+            ProtocolError::InvalidRevenueShares
+        );
+
+        Ok(())
+    }
+
+    pub fn set_revenue_shares(&mut self, shares: Vec<RevenueShare>) -> Result<()> {
+        Self::validate_revenue_shares(&shares)?;
+
+        self.revenue_shares = shares;
+        Ok(())
+    }
+
+    /// Fees in effect at `now`, switching to the pending schedule once it is due.
+    pub fn current_fees(&self, now: i64) -> &FeesStructure {
+        match &self.pending_fees {
+            Some(pending) if now >= pending.effective_at => &pending.fees,
+            _ => &self.fees,
+        }
+    }
+
+    pub fn get_fee(&self, operation: Operation, now: i64) -> Fee {
+        self.current_fees(now).get_fee(operation)
+    }
+
+    /// Promotes the pending schedule to `fees` once it is due.
+    pub fn apply_pending_fees(&mut self, now: i64) {
+        if let Some(pending) = &self.pending_fees {
+            if now >= pending.effective_at {
+                self.fees = pending.fees.clone();
+                self.pending_fees = None;
+            }
+        }
+    }
+
+    pub fn schedule_fees(&mut self, fees: FeesStructure, now: i64) -> Result<PendingFees> {
+        fees.validate()?;
+        self.apply_pending_fees(now);
+
+        let effective_at = now
+            .checked_add(FEE_UPDATE_DELAY)
+            .ok_or(ProtocolError::MathOverflow)?;
+        let pending = PendingFees { fees, effective_at };
+        self.pending_fees = Some(pending.clone());
+
+        Ok(pending)
+    }
+
+    /// Schedules a single fee change on top of any change already pending.
+    pub fn schedule_single_fee(
+        &mut self,
+        operation: Operation,
+        fee: Fee,
+        now: i64,
+    ) -> Result<PendingFees> {
+        self.apply_pending_fees(now);
+
+        let mut fees = match &self.pending_fees {
+            Some(pending) => pending.fees.clone(),
+            None => self.fees.clone(),
+        };
+        fees.set_fee(operation, fee);
+
+        self.schedule_fees(fees, now)
+    }
+
+    pub fn cancel_pending_fees(&mut self, now: i64) -> Result<PendingFees> {
+        self.apply_pending_fees(now);
+
+        self.pending_fees
+            .take()
+            .ok_or(error!(ProtocolError::NoPendingFees))
+    }
+
+    /// Fee charged to a project for `operation`, after any active override replacing it.
+    pub fn get_project_fee(
+        &self,
+        operation: Operation,
+        fee_override: Option<&ProjectFeeOverride>,
+        now: i64,
+    ) -> Fee {
+        match fee_override.and_then(|fee_override| fee_override.get_adjustment(operation, now)) {
+            Some(FeeAdjustment::Override { fee }) => fee,
+            _ => self.get_fee(operation, now),
         }
     }
 
@@ -70,18 +538,18 @@ impl ProtocolConfig {
         &self,
         operation: Operation,
         base_amount: Option<u64>,
+        fee_override: Option<&ProjectFeeOverride>,
     ) -> Result<u64> {
-        let fee = self.get_fee(operation);
-        match fee.fee_type {
-            FeeType::Fixed => Ok(fee.amount),
-            FeeType::Percentage => {
-                let amount = base_amount.ok_or(error!(ProtocolError::FeeCalculationOverflow))?;
-                amount
-                    .checked_mul(fee.amount)
-                    .ok_or(error!(ProtocolError::FeeCalculationOverflow))?
-                    .checked_div(10_000)
-                    .ok_or(error!(ProtocolError::FeeCalculationOverflow))
-            }
+        let now = Clock::get()?.unix_timestamp;
+        let amount = self
+            .get_project_fee(operation, fee_override, now)
+            .calculate_amount(base_amount)?;
+
+        match fee_override.and_then(|fee_override| fee_override.get_adjustment(operation, now)) {
+            Some(FeeAdjustment::Discount { bps }) => amount
+                .checked_sub(bps_of(amount, u64::from(bps))?)
+                .ok_or(error!(ProtocolError::FeeCalculationOverflow)),
+            _ => Ok(amount),
         }
     }
 }
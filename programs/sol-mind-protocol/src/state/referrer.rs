@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ProtocolError;
+use crate::state::bps_of;
+
+pub const MAX_REFERRAL_BPS: u64 = 5_000; // highest share of a protocol fee a referrer can take
+
+/// A referrer registered by the protocol admins. Its share of the lamport protocol fees of
+/// the operations it refers is paid into this PDA, and `wallet` claims it from there.
+#[account]
+#[derive(InitSpace)]
+pub struct Referrer {
+    pub protocol_config: Pubkey,
+    pub wallet: Pubkey,
+    pub share_bps: u16, // share of each referred protocol fee
+    pub bump: u8,
+}
+
+impl Referrer {
+    pub fn validate_share_bps(share_bps: u16) -> Result<()> {
+        require!(
+            u64::from(share_bps) <= MAX_REFERRAL_BPS,
+            ProtocolError::ReferralShareTooHigh
+        );
+        Ok(())
+    }
+
+    pub fn referral_amount(&self, fee_amount: u64) -> Result<u64> {
+        bps_of(fee_amount, u64::from(self.share_bps))
+    }
+
+    /// Reads the referrer stored at a referrer PDA, if one was registered.
+    pub fn load(account_info: &AccountInfo) -> Result<Option<Self>> {
+        if account_info.data_is_empty() {
+            return Ok(None);
+        }
+
+        require_keys_eq!(
+            *account_info.owner,
+            crate::ID,
+            ProtocolError::ReferrerNotRegistered
+        );
+
+        let data = account_info.try_borrow_data()?;
+        Ok(Some(Self::try_deserialize(&mut &data[..])?))
+    }
+
+    /// Referral fees held by the PDA, i.e. its balance above the rent-exempt minimum.
+    pub fn claimable(account_info: &AccountInfo) -> Result<u64> {
+        let rent_exempt = Rent::get()?.minimum_balance(account_info.data_len());
+        Ok(account_info.lamports().saturating_sub(rent_exempt))
+    }
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ProtocolError;
+
+/// An external program allowed to charge the generic protocol fee through
+/// `charge_generic_fee`.
+#[account]
+#[derive(InitSpace)]
+pub struct RegisteredProgram {
+    pub protocol_config: Pubkey,
+    pub program_id: Pubkey,
+    pub registered_by: Pubkey, // admin that registered the program
+    pub payer: Pubkey,         // paid the rent, and receives it back on unregistration
+    pub bump: u8,
+}
+
+impl RegisteredProgram {
+    pub fn validate_program_id(program_id: &Pubkey) -> Result<()> {
+        // A direct call to `charge_generic_fee` would pass as a call from this program.
+        require_keys_neq!(
+            *program_id,
+            crate::ID,
+            ProtocolError::InvalidRegisteredProgram
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ProtocolError;
+use crate::state::{ProjectConfig, ProtocolConfig};
+
+pub const MAX_SUBSCRIPTION_PLANS: usize = 5;
+
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct SubscriptionPlan {
+    pub plan_id: u8,
+    pub price_per_epoch: u64, // lamports
+    pub grace_epochs: u64,    // epochs a lapsed subscription stays in good standing
+    pub trial_epochs: u64,    // epochs a new project can run unsubscribed while the plan is offered
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SubscriptionStatus {
+    Active,
+    /// Lapsed, but still within the grace period of its plan.
+    GracePeriod,
+    /// Lapsed past the grace period; the project cannot create minter configs or trade hubs.
+    Delinquent,
+}
+
+/// Epochs a project has prepaid under a subscription plan. Projects that never subscribed
+/// have no subscription, and are only billed once the protocol offers a plan.
+#[account]
+#[derive(InitSpace)]
+pub struct Subscription {
+    pub project_config: Pubkey,
+    pub plan_id: u8,
+    pub paid_until_epoch: u64, // first epoch not paid for
+    pub bump: u8,
+}
+
+impl Subscription {
+    pub fn status(&self, epoch: u64, grace_epochs: u64) -> SubscriptionStatus {
+        if epoch < self.paid_until_epoch {
+            SubscriptionStatus::Active
+        } else if epoch < self.paid_until_epoch.saturating_add(grace_epochs) {
+            SubscriptionStatus::GracePeriod
+        } else {
+            SubscriptionStatus::Delinquent
+        }
+    }
+
+    /// Adds `epochs` after the paid period, or after `epoch` once the period has lapsed.
+    /// The plan can only change once the paid period has ended.
+    pub fn extend(&mut self, plan_id: u8, epochs: u64, epoch: u64) -> Result<()> {
+        require!(epochs > 0, ProtocolError::InvalidSubscriptionEpochs);
+        require!(
+            plan_id == self.plan_id || epoch >= self.paid_until_epoch,
+            ProtocolError::SubscriptionPlanChangeNotAllowed
+        );
+
+        self.plan_id = plan_id;
+        self.paid_until_epoch = self
+            .paid_until_epoch
+            .max(epoch)
+            .checked_add(epochs)
+            .ok_or(ProtocolError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Whether the subscription PDA of a project is delinquent. A PDA that was never created
+    /// is delinquent once the protocol offers a plan and the longest `trial_epochs` of the
+    /// offered plans have passed since the project was created. Projects older than version 2
+    /// have no creation epoch and must be migrated first. A subscription whose plan was removed
+    /// gets no grace period.
+    pub fn is_delinquent(
+        account_info: &AccountInfo,
+        protocol_config: &ProtocolConfig,
+        project_config: &ProjectConfig,
+    ) -> Result<bool> {
+        if account_info.data_is_empty() {
+            let trial_epochs = match protocol_config
+                .subscription_plans
+                .iter()
+                .map(|plan| plan.trial_epochs)
+                .max()
+            {
+                Some(trial_epochs) => trial_epochs,
+                None => return Ok(false),
+            };
+            require!(
+                project_config.version >= 2,
+                ProtocolError::ConfigNotMigrated
+            );
+
+            let trial_end = project_config.created_epoch.saturating_add(trial_epochs);
+            return Ok(Clock::get()?.epoch >= trial_end);
+        }
+
+        require_keys_eq!(
+            *account_info.owner,
+            crate::ID,
+            ProtocolError::InvalidSubscriptionAccount
+        );
+
+        let data = account_info.try_borrow_data()?;
+        let subscription = Self::try_deserialize(&mut &data[..])?;
+        let grace_epochs = protocol_config
+            .get_subscription_plan(subscription.plan_id)
+            .map_or(0, |plan| plan.grace_epochs);
+
+        let status = subscription.status(Clock::get()?.epoch, grace_epochs);
+        Ok(status == SubscriptionStatus::Delinquent)
+    }
+}
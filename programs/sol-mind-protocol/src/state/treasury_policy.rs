@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ProtocolError;
+
+pub const MAX_TREASURY_DESTINATIONS: usize = 10;
+
+/// Limits on withdrawals from a project treasury by `Treasurer` role holders. The project owner
+/// is not bound by it.
+#[account]
+#[derive(InitSpace)]
+pub struct TreasuryPolicy {
+    pub project_config: Pubkey,
+    #[max_len(MAX_TREASURY_DESTINATIONS)]
+    pub destinations: Vec<Pubkey>,
+    pub epoch_cap: Option<u64>, // max lamports withdrawn per epoch, None for unlimited
+    pub epoch: u64,             // epoch `epoch_spent` was counted in
+    pub epoch_spent: u64,
+    pub bump: u8,
+}
+
+impl TreasuryPolicy {
+    pub fn validate_destinations(destinations: &[Pubkey]) -> Result<()> {
+        require!(
+            destinations.len() <= MAX_TREASURY_DESTINATIONS,
+            ProtocolError::MaxTreasuryDestinationsReached
+        );
+
+        for (index, destination) in destinations.iter().enumerate() {
+            require!(
+                !destinations[..index].contains(destination),
+                ProtocolError::DuplicateTreasuryDestination
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Lamports withdrawn in `epoch`; the count starts over when a new epoch begins.
+    pub fn spent_in_epoch(&self, epoch: u64) -> u64 {
+        if epoch == self.epoch {
+            self.epoch_spent
+        } else {
+            0
+        }
+    }
+
+    pub fn record_withdrawal(&mut self, to: &Pubkey, amount: u64, epoch: u64) -> Result<()> {
+        require!(
+            self.destinations.contains(to),
+            ProtocolError::TreasuryDestinationNotAllowed
+        );
+
+        let spent = self
+            .spent_in_epoch(epoch)
+            .checked_add(amount)
+            .ok_or(ProtocolError::MathOverflow)?;
+        if let Some(epoch_cap) = self.epoch_cap {
+            require!(spent <= epoch_cap, ProtocolError::TreasuryEpochCapExceeded);
+        }
+
+        self.epoch = epoch;
+        self.epoch_spent = spent;
+        Ok(())
+    }
+}
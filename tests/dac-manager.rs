@@ -1,6 +1,7 @@
 mod setup;
 
 use sol_mind_protocol_client::dac_manager::types::{AgentStatus, ComputeNodeStatus, TaskStatus};
+use sol_mind_protocol_client::events::{decode_dac_manager_event, DacManagerEvent};
 use solana_sdk::signature::Signer;
 
 use crate::setup::test_data::*;
@@ -8,7 +9,7 @@ use setup::{AccountHelper, Instructions, TestFixture};
 
 #[test]
 fn test_register_compute_node() {
-    let mut fixture = TestFixture::new();
+    let mut fixture = TestFixture::new().with_initialize_protocol();
     let node_pubkey = fixture.compute_node.pubkey();
 
     let result = Instructions::register_compute_node(
@@ -28,6 +29,10 @@ fn test_register_compute_node() {
             let compute_node_info =
                 AccountHelper::get_compute_node_info(&fixture.svm, &node_pubkey);
 
+            assert_eq!(
+                compute_node_info.protocol_config,
+                AccountHelper::find_protocol_config_pda().0
+            );
             assert_eq!(compute_node_info.owner, fixture.project_owner.pubkey());
             assert_eq!(compute_node_info.node_pubkey, node_pubkey);
             assert_eq!(compute_node_info.status, ComputeNodeStatus::Pending);
@@ -41,7 +46,7 @@ fn test_register_compute_node() {
 
 #[test]
 fn test_claim_compute_node() {
-    let mut fixture = TestFixture::new();
+    let mut fixture = TestFixture::new().with_initialize_protocol();
     let node_pubkey = fixture.compute_node.pubkey();
     let node_info_cid = "QmExample123".to_string();
 
@@ -88,6 +93,7 @@ fn test_claim_compute_node() {
 #[test]
 fn test_create_agent() {
     let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
         .with_register_compute_node()
         .with_claim_compute_node(None);
 
@@ -133,6 +139,7 @@ fn test_create_agent() {
 #[test]
 fn test_submit_task() {
     let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
         .with_register_compute_node()
         .with_claim_compute_node(None)
         .with_create_agent();
@@ -165,6 +172,18 @@ fn test_submit_task() {
             );
             assert_eq!(task_data_account.status, TaskStatus::Pending);
             assert_eq!(task_data_account.result, Vec::<u8>::new());
+
+            let event = utils::inner_instructions_data(&result)
+                .iter()
+                .find_map(|data| decode_dac_manager_event(data))
+                .expect("TaskStatusChanged event should be emitted");
+            match event {
+                DacManagerEvent::TaskStatusChanged(event) => {
+                    assert_eq!(event.agent, agent_pda);
+                    assert_eq!(event.status, TaskStatus::Pending);
+                }
+                event => panic!("Unexpected event: {:?}", event),
+            }
         }
         Err(e) => {
             panic!("Transaction failed: {:?}", e);
@@ -175,11 +194,13 @@ fn test_submit_task() {
 #[test]
 fn test_activate_agent() {
     let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
         .with_register_compute_node()
         .with_claim_compute_node(None)
         .with_create_agent();
 
-    let agent_before = AccountHelper::get_agent(&fixture.svm, &fixture.project_owner.pubkey(), AGENT_ID);
+    let agent_before =
+        AccountHelper::get_agent(&fixture.svm, &fixture.project_owner.pubkey(), AGENT_ID);
 
     assert_eq!(agent_before.status, AgentStatus::Pending);
 
@@ -198,8 +219,13 @@ fn test_activate_agent() {
     match result {
         Ok(result) => {
             utils::print_transaction_logs(&result);
-            let agent_after = AccountHelper::get_agent(&fixture.svm, &fixture.project_owner.pubkey(), AGENT_ID);
+            let agent_after =
+                AccountHelper::get_agent(&fixture.svm, &fixture.project_owner.pubkey(), AGENT_ID);
 
+            assert_eq!(
+                agent_after.protocol_config,
+                AccountHelper::find_protocol_config_pda().0
+            );
             assert_eq!(agent_after.owner, fixture.project_owner.pubkey());
             assert_eq!(agent_after.agent_id, AGENT_ID);
             assert_eq!(agent_after.compute_node, fixture.compute_node.pubkey());
@@ -210,3 +236,92 @@ fn test_activate_agent() {
         }
     }
 }
+
+#[test]
+fn test_register_compute_node_while_protocol_paused() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_protocol_paused();
+    let node_pubkey = fixture.compute_node.pubkey();
+
+    let result = Instructions::register_compute_node(
+        &mut fixture.svm,
+        node_pubkey,
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.payer.insecure_clone(),
+            &fixture.project_owner.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, protocol is paused");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("OperationPaused"),
+                "Expected OperationPaused error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_submit_task_with_other_protocol_instance() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_register_compute_node()
+        .with_claim_compute_node(None)
+        .with_create_agent();
+
+    Instructions::initialize_protocol_instance(
+        &mut fixture.svm,
+        PROTOCOL_INSTANCE_ID,
+        vec![fixture.admin_2.pubkey()],
+        vec![],
+        default_fees_structure(),
+        Some(fixture.admin_1.pubkey()),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.payer.insecure_clone(),
+            &fixture.admin_1.insecure_clone(),
+        ],
+    )
+    .expect("Failed to initialize protocol instance");
+
+    // The agent's own instance is paused, so an unpaused instance must not stand in for it
+    fixture = fixture.with_protocol_paused();
+
+    let agent_pda = AccountHelper::find_agent_pda(&fixture.project_owner.pubkey(), AGENT_ID).0;
+
+    let result = Instructions::submit_instance_task(
+        &mut fixture.svm,
+        PROTOCOL_INSTANCE_ID,
+        vec![1, 2, 3, 4],
+        agent_pda,
+        fixture.compute_node.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.payer.insecure_clone(),
+            &fixture.compute_node.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, agent belongs to another protocol instance");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("ProtocolConfigMismatch"),
+                "Expected ProtocolConfigMismatch error, got: {:?}",
+                e
+            );
+        }
+    }
+}
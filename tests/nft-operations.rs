@@ -2,6 +2,11 @@ mod setup;
 
 use anchor_lang::AnchorSerialize;
 use mpl_core::types::{Creator, Plugin, PluginAuthority, PluginAuthorityPair, Royalties};
+use sol_mind_protocol_client::allowlist::AllowlistTree;
+use sol_mind_protocol_client::events::{decode_nft_operations_event, NftOperationsEvent};
+use sol_mind_protocol_client::types::{
+    AllowlistProof, Fee, FeeAdjustment, FeeType, Operation, OperationFeeOverride, Role,
+};
 use solana_program::pubkey::Pubkey as ProgramPubkey;
 use solana_sdk::{
     clock::Clock,
@@ -10,7 +15,10 @@ use solana_sdk::{
 };
 
 use setup::test_data::*;
-use setup::{AccountHelper, Instructions, TestFixture};
+use setup::{
+    AccountHelper, Instructions, ProjectConfigV0, ProtocolConfigV0, TestFixture,
+    PROJECT_CONFIG_V0_SPACE, PROTOCOL_CONFIG_V0_SPACE,
+};
 use utils::MplUtils;
 
 #[test]
@@ -290,6 +298,8 @@ fn test_mint_asset_without_assets_config_and_collection() {
         mint.pubkey(),
         fixture.project_authority_1.pubkey(),
         None,
+        None,
+        None,
         &[
             &fixture.payer.insecure_clone(),
             &asset_owner.insecure_clone(),
@@ -357,6 +367,8 @@ fn test_mint_asset_with_collection() {
         mint.pubkey(),
         fixture.project_authority_1.pubkey(),
         Some(collection.pubkey()),
+        None,
+        None,
         &[
             &fixture.payer.insecure_clone(),
             &asset_owner.insecure_clone(),
@@ -424,6 +436,8 @@ fn test_mint_asset_exceeds_max_supply() {
             mint.pubkey(),
             fixture.project_authority_1.pubkey(),
             None,
+            None,
+            None,
             &[
                 &fixture.payer.insecure_clone(),
                 &asset_owner.insecure_clone(),
@@ -451,6 +465,8 @@ fn test_mint_asset_exceeds_max_supply() {
         mint.pubkey(),
         fixture.project_authority_1.pubkey(),
         None,
+        None,
+        None,
         &[
             &fixture.payer.insecure_clone(),
             &asset_owner.insecure_clone(),
@@ -508,6 +524,8 @@ fn test_mint_asset_with_unauthorized_authority() {
         mint.pubkey(),
         unauthorized_authority.pubkey(),
         None,
+        None,
+        None,
         &[
             &fixture.payer.insecure_clone(),
             &asset_owner.insecure_clone(),
@@ -671,6 +689,219 @@ fn test_create_trade_hub_with_unauthorized_authority() {
     }
 }
 
+#[test]
+fn test_create_trade_hub_without_hub_manager_role() {
+    let minter = Keypair::new();
+
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_project_role(PROJECT_1_ID, minter.pubkey(), Role::Minter);
+
+    fixture
+        .svm
+        .airdrop(&minter.pubkey(), 1 * LAMPORTS_PER_SOL)
+        .expect("Failed to fund minter");
+
+    let result = Instructions::create_trade_hub(
+        &mut fixture.svm,
+        TRADE_HUB_NAME.to_string(),
+        TRADE_HUB_FEE_BPS,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        minter.pubkey(),
+        &[&fixture.payer.insecure_clone(), &minter.insecure_clone()],
+    );
+
+    assert!(
+        result.is_err(),
+        "Transaction should have failed for an authority without the HubManager role"
+    );
+
+    if let Err(e) = result {
+        let error_string = format!("{:?}", e);
+        assert!(
+            error_string.contains("Unauthorized"),
+            "Error should indicate unauthorized access, got: {:?}",
+            e
+        );
+    }
+}
+
+#[test]
+fn test_mint_asset_after_minter_role_revoked() {
+    let mut fixture = TestFixture::new()
+        .with_metaplex_core_program()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_create_minter_config(PROJECT_1_ID, None);
+
+    Instructions::revoke_project_role(
+        &mut fixture.svm,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        fixture.project_authority_1.pubkey(),
+        Role::Minter,
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.project_owner.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to revoke project role");
+
+    let asset_owner = Keypair::new();
+    let mint = Keypair::new();
+
+    let result = Instructions::mint_asset(
+        &mut fixture.svm,
+        MINTER_NAME,
+        Some(ASSET_NAME.to_string()),
+        Some(ASSET_URI.to_string()),
+        None,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        asset_owner.pubkey(),
+        mint.pubkey(),
+        fixture.project_authority_1.pubkey(),
+        None,
+        None,
+        None,
+        &[
+            &fixture.payer.insecure_clone(),
+            &asset_owner.insecure_clone(),
+            &fixture.project_authority_1.insecure_clone(),
+            &mint.insecure_clone(),
+        ],
+    );
+
+    assert!(
+        result.is_err(),
+        "Transaction should have failed after the Minter role was revoked"
+    );
+
+    if let Err(e) = result {
+        let error_string = format!("{:?}", e);
+        assert!(
+            error_string.contains("Unauthorized"),
+            "Error should indicate unauthorized access, got: {:?}",
+            e
+        );
+    }
+}
+
+#[test]
+fn test_mint_asset_with_v0_configs() {
+    let mut fixture = TestFixture::new()
+        .with_metaplex_core_program()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_create_minter_config(PROJECT_1_ID, None);
+
+    let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+    let project_config_pda =
+        AccountHelper::find_project_pda(&fixture.project_owner.pubkey(), PROJECT_1_ID).0;
+    let protocol_config = AccountHelper::get_protocol_config(&fixture.svm);
+    let project_config = AccountHelper::get_project_config(
+        &fixture.svm,
+        &fixture.project_owner.pubkey(),
+        PROJECT_1_ID,
+    );
+    AccountHelper::write_v0_config(
+        &mut fixture.svm,
+        &protocol_config_pda,
+        &ProtocolConfigV0::from_config(&protocol_config),
+        PROTOCOL_CONFIG_V0_SPACE,
+    );
+    AccountHelper::write_v0_config(
+        &mut fixture.svm,
+        &project_config_pda,
+        &ProjectConfigV0::from_config(&project_config),
+        PROJECT_CONFIG_V0_SPACE,
+    );
+
+    let (protocol_treasury_pda, _) = AccountHelper::find_treasury_pda(&protocol_config_pda);
+    let protocol_treasury_initial_balance =
+        utils::get_lamports(&fixture.svm, &protocol_treasury_pda);
+
+    let asset_owner = Keypair::new();
+    let mint = Keypair::new();
+
+    // Minting only reads the configs, which are converted from the first release's layout
+    let result = Instructions::mint_asset(
+        &mut fixture.svm,
+        MINTER_NAME,
+        Some(ASSET_NAME.to_string()),
+        Some(ASSET_URI.to_string()),
+        None,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        asset_owner.pubkey(),
+        mint.pubkey(),
+        fixture.project_authority_1.pubkey(),
+        None,
+        None,
+        None,
+        &[
+            &fixture.payer.insecure_clone(),
+            &asset_owner.insecure_clone(),
+            &fixture.project_authority_1.insecure_clone(),
+            &mint.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let minter_config =
+                AccountHelper::get_minter_config(&fixture.svm, &project_config_pda, &MINTER_NAME);
+
+            assert_eq!(minter_config.mints_counter, 1);
+            assert_eq!(
+                utils::get_lamports(&fixture.svm, &protocol_treasury_pda),
+                protocol_treasury_initial_balance + protocol_config.fees.mint_asset.amount
+            );
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+
+    // Creating a trade hub counts it in the project config, which must be migrated first
+    let result = Instructions::create_trade_hub(
+        &mut fixture.svm,
+        TRADE_HUB_NAME.to_string(),
+        TRADE_HUB_FEE_BPS,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        fixture.project_authority_1.pubkey(),
+        &[
+            &fixture.payer.insecure_clone(),
+            &fixture.project_authority_1.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, the project config is not migrated");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("ConfigNotMigrated"),
+                "Expected ConfigNotMigrated error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
 #[test]
 fn test_list_asset() {
     let asset_owner = Keypair::new();
@@ -733,6 +964,20 @@ fn test_list_asset() {
                 .and_then(|plugin| plugin.base.authority.address)
                 .expect("Transfer delegate plugin should exist with trade hub authority");
             assert_eq!(transfer_authority.to_string(), trade_hub_pda.to_string());
+
+            let event = utils::inner_instructions_data(&result)
+                .iter()
+                .find_map(|data| decode_nft_operations_event(data))
+                .expect("AssetListed event should be emitted");
+            match event {
+                NftOperationsEvent::AssetListed(event) => {
+                    assert_eq!(event.trade_hub, trade_hub_pda);
+                    assert_eq!(event.asset, mint.pubkey());
+                    assert_eq!(event.owner, asset_owner.pubkey());
+                    assert_eq!(event.price, LISTING_PRICE);
+                }
+                event => panic!("Unexpected event: {:?}", event),
+            }
         }
         Err(e) => {
             panic!("Transaction failed: {:?}", e);
@@ -897,6 +1142,7 @@ fn test_purchase_asset() {
         TRADE_HUB_NAME,
         &project_config_pda,
         None,
+        None,
         LISTING_PRICE,
         &[&buyer.insecure_clone()],
     );
@@ -977,6 +1223,62 @@ fn test_purchase_asset() {
     }
 }
 
+#[test]
+fn test_purchase_asset_with_other_project_config() {
+    let asset_owner = Keypair::new();
+    let buyer = Keypair::new();
+    let mint = Keypair::new();
+
+    let mut fixture = TestFixture::new()
+        .with_metaplex_core_program()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_initialize_project(PROJECT_2_ID)
+        .with_create_minter_config(PROJECT_1_ID, None)
+        .with_create_trade_hub(PROJECT_1_ID)
+        .with_minted_asset(PROJECT_1_ID, &asset_owner, &mint, None)
+        .with_list_asset(PROJECT_1_ID, mint.pubkey(), &asset_owner, None);
+
+    fixture
+        .svm
+        .airdrop(&buyer.pubkey(), 10 * LAMPORTS_PER_SOL)
+        .expect("Failed to fund buyer");
+
+    let project_1_config_pda =
+        AccountHelper::find_project_pda(&fixture.project_owner.pubkey(), PROJECT_1_ID).0;
+    let project_2_config_pda =
+        AccountHelper::find_project_pda(&fixture.project_owner.pubkey(), PROJECT_2_ID).0;
+    let trade_hub_pda = AccountHelper::find_trade_hub_pda(TRADE_HUB_NAME, &project_1_config_pda).0;
+
+    // Project 2's treasury and fee accounts stand in for project 1's.
+    let result = Instructions::purchase_asset_from_trade_hub(
+        &mut fixture.svm,
+        buyer.pubkey(),
+        &asset_owner.pubkey(),
+        &mint.pubkey(),
+        &trade_hub_pda,
+        &project_2_config_pda,
+        None,
+        None,
+        LISTING_PRICE,
+        &[&buyer.insecure_clone()],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Expected transaction to fail, but it succeeded");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("InvalidTradeHubProject"),
+                "Expected InvalidTradeHubProject error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
 #[test]
 fn test_purchase_asset_with_collection() {
     let collection = Keypair::new();
@@ -1014,6 +1316,7 @@ fn test_purchase_asset_with_collection() {
         TRADE_HUB_NAME,
         &project_config_pda,
         Some(collection.pubkey()),
+        None,
         LISTING_PRICE,
         &[&buyer.insecure_clone()],
     );
@@ -1114,3 +1417,2531 @@ fn test_deslist_asset_and_than_list_again() {
     assert!(listing.is_some());
     assert_eq!(listing.unwrap().price, LISTING_PRICE);
 }
+
+#[test]
+fn test_mint_asset_while_operation_paused() {
+    let mut fixture = TestFixture::new()
+        .with_metaplex_core_program()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_create_minter_config(PROJECT_1_ID, None)
+        .with_operation_paused(Operation::MintAsset);
+
+    let asset_owner = Keypair::new();
+    let mint = Keypair::new();
+
+    let result = Instructions::mint_asset(
+        &mut fixture.svm,
+        MINTER_NAME,
+        Some(ASSET_NAME.to_string()),
+        Some(ASSET_URI.to_string()),
+        None,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        asset_owner.pubkey(),
+        mint.pubkey(),
+        fixture.project_authority_1.pubkey(),
+        None,
+        None,
+        None,
+        &[
+            &fixture.payer.insecure_clone(),
+            &asset_owner.insecure_clone(),
+            &fixture.project_authority_1.insecure_clone(),
+            &mint.insecure_clone(),
+        ],
+    );
+
+    assert!(result.is_err(), "Minting should fail while paused");
+
+    if let Err(e) = result {
+        let error_string = format!("{:?}", e);
+        assert!(
+            error_string.contains("OperationPaused"),
+            "Error should indicate the operation is paused, got: {:?}",
+            e
+        );
+    }
+}
+
+#[test]
+fn test_close_project_with_minter_config() {
+    let mut fixture = TestFixture::new()
+        .with_metaplex_core_program()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_create_minter_config(PROJECT_1_ID, None);
+
+    let owner = fixture.project_owner.pubkey();
+    let project_config = AccountHelper::get_project_config(&fixture.svm, &owner, PROJECT_1_ID);
+    assert_eq!(project_config.minter_configs_count, 1);
+
+    let result = Instructions::close_project(
+        &mut fixture.svm,
+        PROJECT_1_ID,
+        owner,
+        owner,
+        &[],
+        fixture.payer.pubkey(),
+        &[
+            &fixture.project_owner.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    assert!(
+        result.is_err(),
+        "Closing a project with minter configs should fail"
+    );
+
+    if let Err(e) = result {
+        let error_string = format!("{:?}", e);
+        assert!(
+            error_string.contains("ProjectHasResources"),
+            "Error should indicate the project still has resources, got: {:?}",
+            e
+        );
+    }
+}
+
+#[test]
+fn test_close_minter_config() {
+    let mut fixture = TestFixture::new()
+        .with_metaplex_core_program()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_create_minter_config(PROJECT_1_ID, None);
+
+    let owner = fixture.project_owner.pubkey();
+
+    let result = Instructions::close_minter_config(
+        &mut fixture.svm,
+        MINTER_NAME,
+        PROJECT_1_ID,
+        owner,
+        fixture.project_authority_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.payer.insecure_clone(),
+            &fixture.project_authority_1.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let project_config_pda = AccountHelper::find_project_pda(&owner, PROJECT_1_ID).0;
+            let minter_config_pda =
+                AccountHelper::find_minter_config_pda(&project_config_pda, MINTER_NAME).0;
+            assert!(fixture.svm.get_account(&minter_config_pda).is_none());
+
+            let project_config =
+                AccountHelper::get_project_config(&fixture.svm, &owner, PROJECT_1_ID);
+            assert_eq!(project_config.minter_configs_count, 0);
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_close_trade_hub_with_open_listings() {
+    let asset_owner = Keypair::new();
+    let mint = Keypair::new();
+
+    let mut fixture = TestFixture::new()
+        .with_metaplex_core_program()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_create_minter_config(PROJECT_1_ID, None)
+        .with_create_trade_hub(PROJECT_1_ID)
+        .with_minted_asset(PROJECT_1_ID, &asset_owner, &mint, None)
+        .with_list_asset(PROJECT_1_ID, mint.pubkey(), &asset_owner, None);
+
+    let owner = fixture.project_owner.pubkey();
+    let project_config_pda = AccountHelper::find_project_pda(&owner, PROJECT_1_ID).0;
+    let trade_hub = AccountHelper::get_trade_hub(&fixture.svm, TRADE_HUB_NAME, &project_config_pda);
+    assert_eq!(trade_hub.listings_count, 1);
+
+    let result = Instructions::close_trade_hub(
+        &mut fixture.svm,
+        TRADE_HUB_NAME,
+        PROJECT_1_ID,
+        owner,
+        fixture.project_authority_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.payer.insecure_clone(),
+            &fixture.project_authority_1.insecure_clone(),
+        ],
+    );
+
+    assert!(
+        result.is_err(),
+        "Closing a trade hub with open listings should fail"
+    );
+
+    if let Err(e) = result {
+        let error_string = format!("{:?}", e);
+        assert!(
+            error_string.contains("TradeHubHasListings"),
+            "Error should indicate the trade hub has open listings, got: {:?}",
+            e
+        );
+    }
+}
+
+#[test]
+fn test_close_project_after_closing_resources() {
+    let asset_owner = Keypair::new();
+    let mint = Keypair::new();
+
+    let mut fixture = TestFixture::new()
+        .with_metaplex_core_program()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_create_minter_config(PROJECT_1_ID, None)
+        .with_create_trade_hub(PROJECT_1_ID)
+        .with_minted_asset(PROJECT_1_ID, &asset_owner, &mint, None)
+        .with_list_asset(PROJECT_1_ID, mint.pubkey(), &asset_owner, None)
+        .with_delist_asset(PROJECT_1_ID, mint.pubkey(), &asset_owner, None);
+
+    let owner = fixture.project_owner.pubkey();
+    let project_config_pda = AccountHelper::find_project_pda(&owner, PROJECT_1_ID).0;
+    let trade_hub = AccountHelper::get_trade_hub(&fixture.svm, TRADE_HUB_NAME, &project_config_pda);
+    assert_eq!(trade_hub.listings_count, 0);
+
+    Instructions::close_minter_config(
+        &mut fixture.svm,
+        MINTER_NAME,
+        PROJECT_1_ID,
+        owner,
+        fixture.project_authority_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.payer.insecure_clone(),
+            &fixture.project_authority_1.insecure_clone(),
+        ],
+    )
+    .expect("Failed to close minter config");
+
+    Instructions::close_trade_hub(
+        &mut fixture.svm,
+        TRADE_HUB_NAME,
+        PROJECT_1_ID,
+        owner,
+        fixture.project_authority_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.payer.insecure_clone(),
+            &fixture.project_authority_1.insecure_clone(),
+        ],
+    )
+    .expect("Failed to close trade hub");
+
+    let mut fixture = fixture.with_default_project_roles_revoked(PROJECT_1_ID);
+
+    let result = Instructions::close_project(
+        &mut fixture.svm,
+        PROJECT_1_ID,
+        owner,
+        owner,
+        &[],
+        fixture.payer.pubkey(),
+        &[
+            &fixture.project_owner.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            assert!(fixture.svm.get_account(&project_config_pda).is_none());
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_close_resources_of_migrated_project() {
+    let mut fixture = TestFixture::new()
+        .with_metaplex_core_program()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_create_minter_config(PROJECT_1_ID, None)
+        .with_create_trade_hub(PROJECT_1_ID);
+
+    let owner = fixture.project_owner.pubkey();
+    let project_config_pda = AccountHelper::find_project_pda(&owner, PROJECT_1_ID).0;
+    let project_config = AccountHelper::get_project_config(&fixture.svm, &owner, PROJECT_1_ID);
+    AccountHelper::write_v0_config(
+        &mut fixture.svm,
+        &project_config_pda,
+        &ProjectConfigV0::from_config(&project_config),
+        PROJECT_CONFIG_V0_SPACE,
+    );
+
+    // The migration is given the trade hub and role PDAs, but misses the minter config
+    let trade_hub_pda = AccountHelper::find_trade_hub_pda(TRADE_HUB_NAME, &project_config_pda).0;
+    let project_accounts = [
+        trade_hub_pda,
+        AccountHelper::find_project_role_pda(
+            &project_config_pda,
+            &fixture.project_authority_1.pubkey(),
+        )
+        .0,
+        AccountHelper::find_project_role_pda(
+            &project_config_pda,
+            &fixture.project_authority_2.pubkey(),
+        )
+        .0,
+    ];
+
+    Instructions::migrate_project_config(
+        &mut fixture.svm,
+        project_config_pda,
+        DEFAULT_PROJECT_NAME,
+        fixture.admin_1.pubkey(),
+        &[],
+        &project_accounts,
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to migrate project config");
+
+    let migrated = AccountHelper::get_project_config(&fixture.svm, &owner, PROJECT_1_ID);
+    assert_eq!(migrated.minter_configs_count, 0);
+    assert_eq!(migrated.trade_hubs_count, 1);
+    assert_eq!(migrated.roles_count, 2);
+
+    // An uncounted minter config can still be closed
+    let result = Instructions::close_minter_config(
+        &mut fixture.svm,
+        MINTER_NAME,
+        PROJECT_1_ID,
+        owner,
+        fixture.project_authority_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.payer.insecure_clone(),
+            &fixture.project_authority_1.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let project_config =
+                AccountHelper::get_project_config(&fixture.svm, &owner, PROJECT_1_ID);
+            assert_eq!(project_config.minter_configs_count, 0);
+            assert_eq!(project_config.trade_hubs_count, 1);
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+
+    // The counted trade hub still keeps the project open
+    let result = Instructions::close_project(
+        &mut fixture.svm,
+        PROJECT_1_ID,
+        owner,
+        owner,
+        &[],
+        fixture.payer.pubkey(),
+        &[
+            &fixture.project_owner.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, the project still has a trade hub");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("ProjectHasResources"),
+                "Expected ProjectHasResources error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_delist_asset_from_uncounted_trade_hub() {
+    let asset_owner = Keypair::new();
+    let mint = Keypair::new();
+
+    let mut fixture = TestFixture::new()
+        .with_metaplex_core_program()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_create_minter_config(PROJECT_1_ID, None)
+        .with_create_trade_hub(PROJECT_1_ID)
+        .with_minted_asset(PROJECT_1_ID, &asset_owner, &mint, None)
+        .with_list_asset(PROJECT_1_ID, mint.pubkey(), &asset_owner, None);
+
+    let owner = fixture.project_owner.pubkey();
+    let project_config_pda = AccountHelper::find_project_pda(&owner, PROJECT_1_ID).0;
+    let trade_hub_pda = AccountHelper::find_trade_hub_pda(TRADE_HUB_NAME, &project_config_pda).0;
+
+    // Trade hubs of the first release end before `listings_count`, so the listing is uncounted
+    let mut trade_hub =
+        AccountHelper::get_trade_hub(&fixture.svm, TRADE_HUB_NAME, &project_config_pda);
+    trade_hub.listings_count = 0;
+    AccountHelper::write_legacy_config(&mut fixture.svm, &trade_hub_pda, &trade_hub, 8);
+
+    let result = Instructions::delist_asset(
+        &mut fixture.svm,
+        &fixture.payer.pubkey(),
+        &asset_owner.pubkey(),
+        &mint.pubkey(),
+        TRADE_HUB_NAME,
+        &project_config_pda,
+        None,
+        &[
+            &fixture.payer.insecure_clone(),
+            &asset_owner.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let trade_hub =
+                AccountHelper::get_trade_hub(&fixture.svm, TRADE_HUB_NAME, &project_config_pda);
+            assert_eq!(trade_hub.listings_count, 0);
+            assert!(
+                AccountHelper::get_listing(&fixture.svm, &mint.pubkey(), &trade_hub_pda).is_none()
+            );
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+
+    // Other listings of the hub may still be open, so it can't be closed
+    let result = Instructions::close_trade_hub(
+        &mut fixture.svm,
+        TRADE_HUB_NAME,
+        PROJECT_1_ID,
+        owner,
+        fixture.project_authority_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.payer.insecure_clone(),
+            &fixture.project_authority_1.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, the trade hub's listings are not counted");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("TradeHubListingsUncounted"),
+                "Expected TradeHubListingsUncounted error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_mint_asset_with_project_fee_discount() {
+    let mut fixture = TestFixture::new()
+        .with_metaplex_core_program()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_create_minter_config(PROJECT_1_ID, None)
+        .with_project_fee_override(
+            PROJECT_1_ID,
+            vec![OperationFeeOverride {
+                operation: Operation::MintAsset,
+                adjustment: FeeAdjustment::Discount { bps: 2_500 },
+            }],
+            None,
+        );
+
+    let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+    let (protocol_treasury_pda, _) = AccountHelper::find_treasury_pda(&protocol_config_pda);
+    let protocol_treasury_initial_balance =
+        utils::get_lamports(&fixture.svm, &protocol_treasury_pda);
+
+    let asset_owner = Keypair::new();
+    let mint = Keypair::new();
+
+    let result = Instructions::mint_asset(
+        &mut fixture.svm,
+        MINTER_NAME,
+        Some(ASSET_NAME.to_string()),
+        Some(ASSET_URI.to_string()),
+        None,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        asset_owner.pubkey(),
+        mint.pubkey(),
+        fixture.project_authority_1.pubkey(),
+        None,
+        None,
+        None,
+        &[
+            &fixture.payer.insecure_clone(),
+            &asset_owner.insecure_clone(),
+            &fixture.project_authority_1.insecure_clone(),
+            &mint.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let protocol_treasury_final_balance =
+                utils::get_lamports(&fixture.svm, &protocol_treasury_pda);
+
+            assert_eq!(
+                protocol_treasury_final_balance,
+                protocol_treasury_initial_balance + FEE_MINT_ASSET_AMOUNT * 3 / 4,
+                "Protocol should receive the discounted fee"
+            );
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_mint_asset_with_expired_project_fee_override() {
+    let mut fixture = TestFixture::new()
+        .with_metaplex_core_program()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_create_minter_config(PROJECT_1_ID, None)
+        .with_project_fee_override(
+            PROJECT_1_ID,
+            vec![OperationFeeOverride {
+                operation: Operation::MintAsset,
+                adjustment: FeeAdjustment::Discount { bps: 10_000 },
+            }],
+            Some(FEE_OVERRIDE_EXPIRES_AT),
+        );
+
+    let mut clock: Clock = fixture.svm.get_sysvar();
+    clock.unix_timestamp = FEE_OVERRIDE_EXPIRES_AT;
+    fixture.svm.set_sysvar(&clock);
+
+    let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+    let (protocol_treasury_pda, _) = AccountHelper::find_treasury_pda(&protocol_config_pda);
+    let protocol_treasury_initial_balance =
+        utils::get_lamports(&fixture.svm, &protocol_treasury_pda);
+
+    let asset_owner = Keypair::new();
+    let mint = Keypair::new();
+
+    let result = Instructions::mint_asset(
+        &mut fixture.svm,
+        MINTER_NAME,
+        Some(ASSET_NAME.to_string()),
+        Some(ASSET_URI.to_string()),
+        None,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        asset_owner.pubkey(),
+        mint.pubkey(),
+        fixture.project_authority_1.pubkey(),
+        None,
+        None,
+        None,
+        &[
+            &fixture.payer.insecure_clone(),
+            &asset_owner.insecure_clone(),
+            &fixture.project_authority_1.insecure_clone(),
+            &mint.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let protocol_treasury_final_balance =
+                utils::get_lamports(&fixture.svm, &protocol_treasury_pda);
+
+            assert_eq!(
+                protocol_treasury_final_balance,
+                protocol_treasury_initial_balance + FEE_MINT_ASSET_AMOUNT,
+                "An expired override should no longer discount the fee"
+            );
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_create_trade_hub_with_project_fee_override() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_project_fee_override(
+            PROJECT_1_ID,
+            vec![OperationFeeOverride {
+                operation: Operation::CreateTradeHub,
+                adjustment: FeeAdjustment::Override {
+                    fee: Fee {
+                        amount: FEE_CREATE_TRADE_HUB_AMOUNT / 2,
+                        fee_type: FeeType::Fixed,
+                        mint: None,
+                    },
+                },
+            }],
+            None,
+        );
+
+    utils::warp_clock(&mut fixture.svm, FEE_UPDATE_DELAY);
+
+    let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+    let (protocol_treasury_pda, _) = AccountHelper::find_treasury_pda(&protocol_config_pda);
+    let protocol_treasury_initial_balance =
+        utils::get_lamports(&fixture.svm, &protocol_treasury_pda);
+
+    let result = Instructions::create_trade_hub(
+        &mut fixture.svm,
+        TRADE_HUB_NAME.to_string(),
+        TRADE_HUB_FEE_BPS,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        fixture.project_authority_1.pubkey(),
+        &[
+            &fixture.payer.insecure_clone(),
+            &fixture.project_authority_1.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let protocol_treasury_final_balance =
+                utils::get_lamports(&fixture.svm, &protocol_treasury_pda);
+
+            assert_eq!(
+                protocol_treasury_final_balance,
+                protocol_treasury_initial_balance + FEE_CREATE_TRADE_HUB_AMOUNT / 2,
+                "Protocol should receive the overridden fee"
+            );
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_create_trade_hub_with_pending_project_fee_override() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_project_fee_override(
+            PROJECT_1_ID,
+            vec![OperationFeeOverride {
+                operation: Operation::CreateTradeHub,
+                adjustment: FeeAdjustment::Override {
+                    fee: Fee {
+                        amount: FEE_CREATE_TRADE_HUB_AMOUNT / 2,
+                        fee_type: FeeType::Fixed,
+                        mint: None,
+                    },
+                },
+            }],
+            None,
+        );
+
+    let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+    let (protocol_treasury_pda, _) = AccountHelper::find_treasury_pda(&protocol_config_pda);
+    let protocol_treasury_initial_balance =
+        utils::get_lamports(&fixture.svm, &protocol_treasury_pda);
+
+    let result = Instructions::create_trade_hub(
+        &mut fixture.svm,
+        TRADE_HUB_NAME.to_string(),
+        TRADE_HUB_FEE_BPS,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        fixture.project_authority_1.pubkey(),
+        &[
+            &fixture.payer.insecure_clone(),
+            &fixture.project_authority_1.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let protocol_treasury_final_balance =
+                utils::get_lamports(&fixture.svm, &protocol_treasury_pda);
+
+            assert_eq!(
+                protocol_treasury_final_balance,
+                protocol_treasury_initial_balance + FEE_CREATE_TRADE_HUB_AMOUNT,
+                "An Override should not apply before the fee update delay"
+            );
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_purchase_asset_records_fee_stats() {
+    let asset_owner = Keypair::new();
+    let buyer = Keypair::new();
+    let mint = Keypair::new();
+
+    let mut fixture = TestFixture::new()
+        .with_metaplex_core_program()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_create_minter_config(PROJECT_1_ID, None)
+        .with_create_trade_hub(PROJECT_1_ID)
+        .with_minted_asset(PROJECT_1_ID, &asset_owner, &mint, None)
+        .with_list_asset(PROJECT_1_ID, mint.pubkey(), &asset_owner, None);
+
+    fixture
+        .svm
+        .airdrop(&buyer.pubkey(), 10 * LAMPORTS_PER_SOL)
+        .expect("Failed to fund buyer");
+
+    let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+    let project_config_pda =
+        AccountHelper::find_project_pda(&fixture.project_owner.pubkey(), PROJECT_1_ID).0;
+
+    let protocol_fee_stats_before =
+        AccountHelper::get_fee_stats(&fixture.svm, &protocol_config_pda);
+
+    let result = Instructions::purchase_asset(
+        &mut fixture.svm,
+        buyer.pubkey(),
+        &asset_owner.pubkey(),
+        &mint.pubkey(),
+        TRADE_HUB_NAME,
+        &project_config_pda,
+        None,
+        None,
+        LISTING_PRICE,
+        &[&buyer.insecure_clone()],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let protocol_fee_stats =
+                AccountHelper::get_fee_stats(&fixture.svm, &protocol_config_pda);
+            let project_fee_stats = AccountHelper::get_fee_stats(&fixture.svm, &project_config_pda);
+
+            let protocol_fee = LISTING_PRICE * FEE_TRADE_NFT_AMOUNT / 10_000;
+            let trade_hub_fee = LISTING_PRICE * TRADE_HUB_FEE_BPS / 10_000;
+
+            assert_eq!(protocol_fee_stats.protocol_fees.mint_asset.count, 1);
+            assert_eq!(protocol_fee_stats.protocol_fees.trade_nft.count, 1);
+            assert_eq!(
+                protocol_fee_stats.protocol_fees.trade_nft.lamports,
+                protocol_fee
+            );
+            assert_eq!(
+                protocol_fee_stats.available,
+                protocol_fee_stats_before.available + protocol_fee
+            );
+
+            assert_eq!(
+                project_fee_stats.protocol_fees.create_minter_config.count,
+                1
+            );
+            assert_eq!(project_fee_stats.protocol_fees.create_trade_hub.count, 1);
+            assert_eq!(project_fee_stats.protocol_fees.mint_asset.count, 1);
+            assert_eq!(project_fee_stats.protocol_fees.trade_nft.count, 1);
+            assert_eq!(project_fee_stats.trade_hub_fees.count, 1);
+            assert_eq!(project_fee_stats.trade_hub_fees.lamports, trade_hub_fee);
+            assert_eq!(project_fee_stats.available, trade_hub_fee);
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_mint_asset_with_referrer() {
+    let referrer_wallet = Keypair::new().pubkey();
+    let mut fixture = TestFixture::new()
+        .with_metaplex_core_program()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_create_minter_config(PROJECT_1_ID, None)
+        .with_referrer(referrer_wallet);
+
+    let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+    let (protocol_treasury_pda, _) = AccountHelper::find_treasury_pda(&protocol_config_pda);
+    let referrer_pda = AccountHelper::find_referrer_pda(&referrer_wallet).0;
+    let protocol_treasury_initial_balance =
+        utils::get_lamports(&fixture.svm, &protocol_treasury_pda);
+    let referrer_initial_balance = utils::get_lamports(&fixture.svm, &referrer_pda);
+
+    let asset_owner = Keypair::new();
+    let mint = Keypair::new();
+
+    let result = Instructions::mint_asset(
+        &mut fixture.svm,
+        MINTER_NAME,
+        Some(ASSET_NAME.to_string()),
+        Some(ASSET_URI.to_string()),
+        None,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        asset_owner.pubkey(),
+        mint.pubkey(),
+        fixture.project_authority_1.pubkey(),
+        None,
+        Some(referrer_wallet),
+        None,
+        &[
+            &fixture.payer.insecure_clone(),
+            &asset_owner.insecure_clone(),
+            &fixture.project_authority_1.insecure_clone(),
+            &mint.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let referral_amount = FEE_MINT_ASSET_AMOUNT * REFERRER_SHARE_BPS as u64 / 10_000;
+
+            assert_eq!(
+                utils::get_lamports(&fixture.svm, &referrer_pda),
+                referrer_initial_balance + referral_amount,
+                "Referrer should receive its share of the fee"
+            );
+            assert_eq!(
+                utils::get_lamports(&fixture.svm, &protocol_treasury_pda),
+                protocol_treasury_initial_balance + FEE_MINT_ASSET_AMOUNT - referral_amount,
+                "Protocol should receive the rest of the fee"
+            );
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_purchase_asset_with_referrer() {
+    let asset_owner = Keypair::new();
+    let buyer = Keypair::new();
+    let mint = Keypair::new();
+    let referrer_wallet = Keypair::new().pubkey();
+
+    let mut fixture = TestFixture::new()
+        .with_metaplex_core_program()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_create_minter_config(PROJECT_1_ID, None)
+        .with_create_trade_hub(PROJECT_1_ID)
+        .with_minted_asset(PROJECT_1_ID, &asset_owner, &mint, None)
+        .with_list_asset(PROJECT_1_ID, mint.pubkey(), &asset_owner, None)
+        .with_referrer(referrer_wallet);
+
+    fixture
+        .svm
+        .airdrop(&buyer.pubkey(), 10 * LAMPORTS_PER_SOL)
+        .expect("Failed to fund buyer");
+
+    let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+    let project_config_pda =
+        AccountHelper::find_project_pda(&fixture.project_owner.pubkey(), PROJECT_1_ID).0;
+    let referrer_pda = AccountHelper::find_referrer_pda(&referrer_wallet).0;
+    let referrer_initial_balance = utils::get_lamports(&fixture.svm, &referrer_pda);
+    let protocol_fee_stats_before =
+        AccountHelper::get_fee_stats(&fixture.svm, &protocol_config_pda);
+
+    let result = Instructions::purchase_asset(
+        &mut fixture.svm,
+        buyer.pubkey(),
+        &asset_owner.pubkey(),
+        &mint.pubkey(),
+        TRADE_HUB_NAME,
+        &project_config_pda,
+        None,
+        Some(referrer_wallet),
+        LISTING_PRICE,
+        &[&buyer.insecure_clone()],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let protocol_fee_stats =
+                AccountHelper::get_fee_stats(&fixture.svm, &protocol_config_pda);
+
+            let protocol_fee = LISTING_PRICE * FEE_TRADE_NFT_AMOUNT / 10_000;
+            let referral_amount = protocol_fee * REFERRER_SHARE_BPS as u64 / 10_000;
+
+            assert_eq!(
+                utils::get_lamports(&fixture.svm, &referrer_pda),
+                referrer_initial_balance + referral_amount
+            );
+            assert_eq!(
+                protocol_fee_stats.protocol_fees.trade_nft.lamports,
+                protocol_fee
+            );
+            assert_eq!(
+                protocol_fee_stats.available,
+                protocol_fee_stats_before.available + protocol_fee - referral_amount
+            );
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_create_trade_hub_with_delinquent_subscription() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_subscription_plan()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_subscription(PROJECT_1_ID, 1);
+
+    utils::warp_epoch(&mut fixture.svm, 1 + SUBSCRIPTION_GRACE_EPOCHS);
+
+    let result = Instructions::create_trade_hub(
+        &mut fixture.svm,
+        TRADE_HUB_NAME.to_string(),
+        TRADE_HUB_FEE_BPS,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        fixture.project_authority_1.pubkey(),
+        &[
+            &fixture.payer.insecure_clone(),
+            &fixture.project_authority_1.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, the project subscription is delinquent");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("SubscriptionDelinquent"),
+                "Expected SubscriptionDelinquent error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_create_minter_config_with_lapsed_subscription_in_grace_period() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_subscription_plan()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_subscription(PROJECT_1_ID, 1);
+
+    utils::warp_epoch(&mut fixture.svm, SUBSCRIPTION_GRACE_EPOCHS);
+
+    let result = Instructions::create_minter_config(
+        &mut fixture.svm,
+        MINTER_NAME.to_string(),
+        MINT_PRICE,
+        MAX_SUPPLY,
+        None,
+        None,
+        None,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        fixture.project_authority_1.pubkey(),
+        None,
+        &[
+            &fixture.payer.insecure_clone(),
+            &fixture.project_authority_1.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let (project_config_pda, _) =
+                AccountHelper::find_project_pda(&fixture.project_owner.pubkey(), PROJECT_1_ID);
+            let minter_config =
+                AccountHelper::get_minter_config(&fixture.svm, &project_config_pda, &MINTER_NAME);
+            assert_eq!(minter_config.name, MINTER_NAME);
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_create_trade_hub_without_subscription_after_grace_period() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_subscription_plan()
+        .with_initialize_project(PROJECT_1_ID);
+
+    utils::warp_epoch(&mut fixture.svm, SUBSCRIPTION_TRIAL_EPOCHS);
+
+    let result = Instructions::create_trade_hub(
+        &mut fixture.svm,
+        TRADE_HUB_NAME.to_string(),
+        TRADE_HUB_FEE_BPS,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        fixture.project_authority_1.pubkey(),
+        &[
+            &fixture.payer.insecure_clone(),
+            &fixture.project_authority_1.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, the project never subscribed");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("SubscriptionDelinquent"),
+                "Expected SubscriptionDelinquent error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_create_trade_hub_without_subscription_in_grace_period() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_subscription_plan()
+        .with_initialize_project(PROJECT_1_ID);
+
+    utils::warp_epoch(&mut fixture.svm, SUBSCRIPTION_TRIAL_EPOCHS - 1);
+
+    let result = Instructions::create_trade_hub(
+        &mut fixture.svm,
+        TRADE_HUB_NAME.to_string(),
+        TRADE_HUB_FEE_BPS,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        fixture.project_authority_1.pubkey(),
+        &[
+            &fixture.payer.insecure_clone(),
+            &fixture.project_authority_1.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let project_config_pda =
+                AccountHelper::find_project_pda(&fixture.project_owner.pubkey(), PROJECT_1_ID).0;
+            let trade_hub =
+                AccountHelper::get_trade_hub(&fixture.svm, TRADE_HUB_NAME, &project_config_pda);
+            assert_eq!(trade_hub.project, project_config_pda);
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_create_trade_hub_without_subscription_before_migration() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_subscription_plan()
+        .with_initialize_project(PROJECT_1_ID);
+
+    // A first-release project has no creation epoch to start its trial period from
+    let project_config_pda =
+        AccountHelper::find_project_pda(&fixture.project_owner.pubkey(), PROJECT_1_ID).0;
+    let project_config = AccountHelper::get_project_config(
+        &fixture.svm,
+        &fixture.project_owner.pubkey(),
+        PROJECT_1_ID,
+    );
+    AccountHelper::write_v0_config(
+        &mut fixture.svm,
+        &project_config_pda,
+        &ProjectConfigV0::from_config(&project_config),
+        PROJECT_CONFIG_V0_SPACE,
+    );
+
+    let result = Instructions::create_trade_hub(
+        &mut fixture.svm,
+        TRADE_HUB_NAME.to_string(),
+        TRADE_HUB_FEE_BPS,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        fixture.project_authority_1.pubkey(),
+        &[
+            &fixture.payer.insecure_clone(),
+            &fixture.project_authority_1.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, the project config is not migrated");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("ConfigNotMigrated"),
+                "Expected ConfigNotMigrated error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_mint_asset_pays_mint_price_with_fixed_fee() {
+    let mut fixture = TestFixture::new()
+        .with_metaplex_core_program()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_create_minter_config(PROJECT_1_ID, None);
+
+    let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+    let (project_config_pda, _) =
+        AccountHelper::find_project_pda(&fixture.project_owner.pubkey(), PROJECT_1_ID);
+    let (protocol_treasury_pda, _) = AccountHelper::find_treasury_pda(&protocol_config_pda);
+    let (project_treasury_pda, _) = AccountHelper::find_treasury_pda(&project_config_pda);
+    let protocol_treasury_initial_balance =
+        utils::get_lamports(&fixture.svm, &protocol_treasury_pda);
+    let project_treasury_initial_balance = utils::get_lamports(&fixture.svm, &project_treasury_pda);
+
+    let asset_owner = Keypair::new();
+    let mint = Keypair::new();
+
+    let result = Instructions::mint_asset(
+        &mut fixture.svm,
+        MINTER_NAME,
+        Some(ASSET_NAME.to_string()),
+        Some(ASSET_URI.to_string()),
+        None,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        asset_owner.pubkey(),
+        mint.pubkey(),
+        fixture.project_authority_1.pubkey(),
+        None,
+        None,
+        None,
+        &[
+            &fixture.payer.insecure_clone(),
+            &asset_owner.insecure_clone(),
+            &fixture.project_authority_1.insecure_clone(),
+            &mint.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let project_fee_stats = AccountHelper::get_fee_stats(&fixture.svm, &project_config_pda);
+
+            assert_eq!(
+                utils::get_lamports(&fixture.svm, &project_treasury_pda),
+                project_treasury_initial_balance + MINT_PRICE,
+                "Project treasury should receive the mint price"
+            );
+            assert_eq!(
+                utils::get_lamports(&fixture.svm, &protocol_treasury_pda),
+                protocol_treasury_initial_balance + FEE_MINT_ASSET_AMOUNT,
+                "Protocol should receive the fixed fee on top of the price"
+            );
+            assert_eq!(
+                project_fee_stats.protocol_fees.mint_asset.lamports,
+                FEE_MINT_ASSET_AMOUNT
+            );
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_mint_asset_with_percentage_fee() {
+    let mut fixture = TestFixture::new()
+        .with_metaplex_core_program()
+        .with_initialize_protocol()
+        .with_update_single_fee(
+            Operation::MintAsset,
+            Fee {
+                amount: MINT_FEE_BPS,
+                fee_type: FeeType::Percentage,
+                mint: None,
+            },
+        )
+        .with_initialize_project(PROJECT_1_ID)
+        .with_create_minter_config(PROJECT_1_ID, None);
+
+    let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+    let (project_config_pda, _) =
+        AccountHelper::find_project_pda(&fixture.project_owner.pubkey(), PROJECT_1_ID);
+    let (protocol_treasury_pda, _) = AccountHelper::find_treasury_pda(&protocol_config_pda);
+    let (project_treasury_pda, _) = AccountHelper::find_treasury_pda(&project_config_pda);
+    let protocol_treasury_initial_balance =
+        utils::get_lamports(&fixture.svm, &protocol_treasury_pda);
+    let project_treasury_initial_balance = utils::get_lamports(&fixture.svm, &project_treasury_pda);
+
+    let asset_owner = Keypair::new();
+    let mint = Keypair::new();
+
+    let result = Instructions::mint_asset(
+        &mut fixture.svm,
+        MINTER_NAME,
+        Some(ASSET_NAME.to_string()),
+        Some(ASSET_URI.to_string()),
+        None,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        asset_owner.pubkey(),
+        mint.pubkey(),
+        fixture.project_authority_1.pubkey(),
+        None,
+        None,
+        None,
+        &[
+            &fixture.payer.insecure_clone(),
+            &asset_owner.insecure_clone(),
+            &fixture.project_authority_1.insecure_clone(),
+            &mint.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            assert_eq!(
+                utils::get_lamports(&fixture.svm, &project_treasury_pda),
+                project_treasury_initial_balance + MINT_PRICE,
+                "Project treasury should receive the mint price"
+            );
+            assert_eq!(
+                utils::get_lamports(&fixture.svm, &protocol_treasury_pda),
+                protocol_treasury_initial_balance + MINT_PRICE * MINT_FEE_BPS / 10_000,
+                "Protocol fee should be a percentage of the mint price"
+            );
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_mint_asset_free_mint() {
+    let mut fixture = TestFixture::new()
+        .with_metaplex_core_program()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID);
+
+    Instructions::create_minter_config(
+        &mut fixture.svm,
+        FREE_MINTER_NAME.to_string(),
+        0,
+        MAX_SUPPLY,
+        None,
+        None,
+        None,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        fixture.project_authority_1.pubkey(),
+        None,
+        &[
+            &fixture.payer.insecure_clone(),
+            &fixture.project_authority_1.insecure_clone(),
+        ],
+    )
+    .expect("Failed to create minter config");
+
+    let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+    let (project_config_pda, _) =
+        AccountHelper::find_project_pda(&fixture.project_owner.pubkey(), PROJECT_1_ID);
+    let (protocol_treasury_pda, _) = AccountHelper::find_treasury_pda(&protocol_config_pda);
+    let (project_treasury_pda, _) = AccountHelper::find_treasury_pda(&project_config_pda);
+    let protocol_treasury_initial_balance =
+        utils::get_lamports(&fixture.svm, &protocol_treasury_pda);
+    let project_treasury_initial_balance = utils::get_lamports(&fixture.svm, &project_treasury_pda);
+
+    let asset_owner = Keypair::new();
+    let mint = Keypair::new();
+
+    let result = Instructions::mint_asset(
+        &mut fixture.svm,
+        FREE_MINTER_NAME,
+        Some(ASSET_NAME.to_string()),
+        Some(ASSET_URI.to_string()),
+        None,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        asset_owner.pubkey(),
+        mint.pubkey(),
+        fixture.project_authority_1.pubkey(),
+        None,
+        None,
+        None,
+        &[
+            &fixture.payer.insecure_clone(),
+            &asset_owner.insecure_clone(),
+            &fixture.project_authority_1.insecure_clone(),
+            &mint.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            assert_eq!(
+                utils::get_lamports(&fixture.svm, &project_treasury_pda),
+                project_treasury_initial_balance,
+                "Project treasury should not change on a free mint"
+            );
+            assert_eq!(
+                utils::get_lamports(&fixture.svm, &protocol_treasury_pda),
+                protocol_treasury_initial_balance + FEE_MINT_ASSET_AMOUNT,
+                "Fixed protocol fee should still be charged on a free mint"
+            );
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_set_public_mint() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_create_minter_config(PROJECT_1_ID, None);
+
+    let result = Instructions::set_public_mint(
+        &mut fixture.svm,
+        MINTER_NAME,
+        true,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        fixture.project_authority_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.payer.insecure_clone(),
+            &fixture.project_authority_1.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let (project_config_pda, _) =
+                AccountHelper::find_project_pda(&fixture.project_owner.pubkey(), PROJECT_1_ID);
+            let minter_config =
+                AccountHelper::get_minter_config(&fixture.svm, &project_config_pda, &MINTER_NAME);
+            assert!(minter_config.public_mint);
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_set_public_mint_with_unauthorized_authority() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_create_minter_config(PROJECT_1_ID, None);
+
+    let unauthorized_authority = Keypair::new();
+
+    let result = Instructions::set_public_mint(
+        &mut fixture.svm,
+        MINTER_NAME,
+        true,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        unauthorized_authority.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.payer.insecure_clone(),
+            &unauthorized_authority.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed with unauthorized authority");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("Unauthorized"),
+                "Expected Unauthorized error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_public_mint_asset_without_authority() {
+    let mut fixture = TestFixture::new()
+        .with_metaplex_core_program()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_create_minter_config(PROJECT_1_ID, None)
+        .with_public_mint(PROJECT_1_ID);
+
+    let collector = Keypair::new();
+    let mint = Keypair::new();
+
+    fixture
+        .svm
+        .airdrop(&collector.pubkey(), 10 * LAMPORTS_PER_SOL)
+        .expect("Failed to fund collector");
+
+    let (project_config_pda, _) =
+        AccountHelper::find_project_pda(&fixture.project_owner.pubkey(), PROJECT_1_ID);
+    let (project_treasury_pda, _) = AccountHelper::find_treasury_pda(&project_config_pda);
+    let project_treasury_initial_balance = utils::get_lamports(&fixture.svm, &project_treasury_pda);
+
+    let result = Instructions::mint_asset(
+        &mut fixture.svm,
+        MINTER_NAME,
+        Some(ASSET_NAME.to_string()),
+        Some(ASSET_URI.to_string()),
+        None,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        collector.pubkey(),
+        collector.pubkey(),
+        mint.pubkey(),
+        collector.pubkey(),
+        None,
+        None,
+        None,
+        &[&collector.insecure_clone(), &mint.insecure_clone()],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let minter_config =
+                AccountHelper::get_minter_config(&fixture.svm, &project_config_pda, &MINTER_NAME);
+            let asset = MplUtils::get_asset(&fixture.svm, &mint.pubkey());
+
+            assert_eq!(minter_config.mints_counter, 1);
+            assert_eq!(asset.base.owner.to_string(), collector.pubkey().to_string());
+            assert_eq!(
+                utils::get_lamports(&fixture.svm, &project_treasury_pda),
+                project_treasury_initial_balance + MINT_PRICE,
+                "Collector should pay the mint price to the project treasury"
+            );
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_mint_asset_airdrop_while_public_mint() {
+    let asset_owner = Keypair::new();
+    let mint = Keypair::new();
+
+    let fixture = TestFixture::new()
+        .with_metaplex_core_program()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_create_minter_config(PROJECT_1_ID, None)
+        .with_public_mint(PROJECT_1_ID)
+        .with_minted_asset(PROJECT_1_ID, &asset_owner, &mint, None);
+
+    let asset = MplUtils::get_asset(&fixture.svm, &mint.pubkey());
+    assert_eq!(
+        asset.base.owner.to_string(),
+        asset_owner.pubkey().to_string()
+    );
+}
+
+#[test]
+fn test_set_mint_phases() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_create_minter_config(PROJECT_1_ID, None);
+
+    let result = Instructions::set_mint_phases(
+        &mut fixture.svm,
+        MINTER_NAME,
+        default_mint_phases(),
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        fixture.project_authority_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.payer.insecure_clone(),
+            &fixture.project_authority_1.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let (project_config_pda, _) =
+                AccountHelper::find_project_pda(&fixture.project_owner.pubkey(), PROJECT_1_ID);
+            let minter_config =
+                AccountHelper::get_minter_config(&fixture.svm, &project_config_pda, &MINTER_NAME);
+            assert_eq!(minter_config.phases, default_mint_phases());
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_set_mint_phases_overlapping() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_create_minter_config(PROJECT_1_ID, None);
+
+    let mut phases = default_mint_phases();
+    phases[1].start_at = phases[0].end_at - 1;
+
+    let result = Instructions::set_mint_phases(
+        &mut fixture.svm,
+        MINTER_NAME,
+        phases,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        fixture.project_authority_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.payer.insecure_clone(),
+            &fixture.project_authority_1.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed with overlapping phases");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("InvalidMintPhases"),
+                "Expected InvalidMintPhases error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_set_mint_phases_after_minting_ended() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_create_minter_config(PROJECT_1_ID, None)
+        .with_mint_phases(PROJECT_1_ID);
+
+    let mut clock: Clock = fixture.svm.get_sysvar();
+    clock.unix_timestamp = MINT_PHASE_START + 2 * MINT_PHASE_DURATION;
+    fixture.svm.set_sysvar(&clock);
+
+    // Reopening minting would let wallets that closed their mint records mint again
+    let mut phases = default_mint_phases();
+    for phase in phases.iter_mut() {
+        phase.start_at += 2 * MINT_PHASE_DURATION;
+        phase.end_at += 2 * MINT_PHASE_DURATION;
+    }
+
+    let result = Instructions::set_mint_phases(
+        &mut fixture.svm,
+        MINTER_NAME,
+        phases,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        fixture.project_authority_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.payer.insecure_clone(),
+            &fixture.project_authority_1.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, minting has ended");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("MintingEnded"),
+                "Expected MintingEnded error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_mint_asset_in_mint_phases() {
+    let mut fixture = TestFixture::new()
+        .with_metaplex_core_program()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_create_minter_config(PROJECT_1_ID, None)
+        .with_mint_phases(PROJECT_1_ID)
+        .with_public_mint(PROJECT_1_ID);
+
+    let mut clock: Clock = fixture.svm.get_sysvar();
+    clock.unix_timestamp = MINT_PHASE_START;
+    fixture.svm.set_sysvar(&clock);
+
+    let (project_config_pda, _) =
+        AccountHelper::find_project_pda(&fixture.project_owner.pubkey(), PROJECT_1_ID);
+    let (project_treasury_pda, _) = AccountHelper::find_treasury_pda(&project_config_pda);
+
+    for expected_price in [ALLOWLIST_PHASE_PRICE, MINT_PRICE] {
+        let project_treasury_initial_balance =
+            utils::get_lamports(&fixture.svm, &project_treasury_pda);
+        let asset_owner = Keypair::new();
+        let mint = Keypair::new();
+
+        let result = Instructions::mint_asset(
+            &mut fixture.svm,
+            MINTER_NAME,
+            Some(ASSET_NAME.to_string()),
+            Some(ASSET_URI.to_string()),
+            None,
+            PROJECT_1_ID,
+            fixture.project_owner.pubkey(),
+            fixture.payer.pubkey(),
+            asset_owner.pubkey(),
+            mint.pubkey(),
+            fixture.payer.pubkey(),
+            None,
+            None,
+            None,
+            &[
+                &fixture.payer.insecure_clone(),
+                &asset_owner.insecure_clone(),
+                &mint.insecure_clone(),
+            ],
+        );
+
+        match result {
+            Ok(result) => {
+                utils::print_transaction_logs(&result);
+
+                assert_eq!(
+                    utils::get_lamports(&fixture.svm, &project_treasury_pda),
+                    project_treasury_initial_balance + expected_price,
+                    "Mint should be charged the price of the active phase"
+                );
+            }
+            Err(e) => {
+                panic!("Transaction failed: {:?}", e);
+            }
+        }
+
+        utils::warp_clock(&mut fixture.svm, MINT_PHASE_DURATION);
+    }
+
+    let minter_config =
+        AccountHelper::get_minter_config(&fixture.svm, &project_config_pda, &MINTER_NAME);
+    assert_eq!(minter_config.mints_counter, 2);
+    assert_eq!(minter_config.phases[0].minted, 1);
+    assert_eq!(minter_config.phases[1].minted, 1);
+}
+
+#[test]
+fn test_mint_asset_outside_mint_phases() {
+    let mut fixture = TestFixture::new()
+        .with_metaplex_core_program()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_create_minter_config(PROJECT_1_ID, None)
+        .with_mint_phases(PROJECT_1_ID)
+        .with_public_mint(PROJECT_1_ID);
+
+    let mut clock: Clock = fixture.svm.get_sysvar();
+    clock.unix_timestamp = MINT_PHASE_START + 2 * MINT_PHASE_DURATION;
+    fixture.svm.set_sysvar(&clock);
+
+    let asset_owner = Keypair::new();
+    let mint = Keypair::new();
+
+    let result = Instructions::mint_asset(
+        &mut fixture.svm,
+        MINTER_NAME,
+        Some(ASSET_NAME.to_string()),
+        Some(ASSET_URI.to_string()),
+        None,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        asset_owner.pubkey(),
+        mint.pubkey(),
+        fixture.payer.pubkey(),
+        None,
+        None,
+        None,
+        &[
+            &fixture.payer.insecure_clone(),
+            &asset_owner.insecure_clone(),
+            &mint.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed after the last mint phase ended");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("MintPhaseNotActive"),
+                "Expected MintPhaseNotActive error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_mint_asset_exceeds_phase_supply_cap() {
+    let mut fixture = TestFixture::new()
+        .with_metaplex_core_program()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_create_minter_config(PROJECT_1_ID, None)
+        .with_mint_phases(PROJECT_1_ID)
+        .with_public_mint(PROJECT_1_ID);
+
+    let mut clock: Clock = fixture.svm.get_sysvar();
+    clock.unix_timestamp = MINT_PHASE_START;
+    fixture.svm.set_sysvar(&clock);
+
+    let asset_owner = Keypair::new();
+    for _ in 0..ALLOWLIST_PHASE_SUPPLY_CAP {
+        fixture = fixture.with_public_minted_asset(PROJECT_1_ID, &asset_owner, &Keypair::new());
+    }
+
+    let mint = Keypair::new();
+
+    let result = Instructions::mint_asset(
+        &mut fixture.svm,
+        MINTER_NAME,
+        Some(ASSET_NAME.to_string()),
+        Some(ASSET_URI.to_string()),
+        None,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        asset_owner.pubkey(),
+        mint.pubkey(),
+        fixture.payer.pubkey(),
+        None,
+        None,
+        None,
+        &[
+            &fixture.payer.insecure_clone(),
+            &asset_owner.insecure_clone(),
+            &mint.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed with the phase supply cap reached");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("PhaseSupplyReached"),
+                "Expected PhaseSupplyReached error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_mint_asset_with_allowlist_proof() {
+    let asset_owner = Keypair::new();
+    let tree = AllowlistTree::from_csv(&format!(
+        "wallet,allowed_count\n{},{}\n{},1\n",
+        asset_owner.pubkey(),
+        ALLOWLIST_ALLOWED_COUNT,
+        Keypair::new().pubkey(),
+    ))
+    .unwrap();
+
+    let mut fixture = TestFixture::new()
+        .with_metaplex_core_program()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_create_minter_config(PROJECT_1_ID, None)
+        .with_allowlist_mint_phases(PROJECT_1_ID, tree.root())
+        .with_public_mint(PROJECT_1_ID);
+
+    let (project_config_pda, _) =
+        AccountHelper::find_project_pda(&fixture.project_owner.pubkey(), PROJECT_1_ID);
+    let (minter_config_pda, _) =
+        AccountHelper::find_minter_config_pda(&project_config_pda, MINTER_NAME);
+
+    let mut clock: Clock = fixture.svm.get_sysvar();
+    clock.unix_timestamp = MINT_PHASE_START;
+    fixture.svm.set_sysvar(&clock);
+
+    let mint = Keypair::new();
+
+    let result = Instructions::mint_asset(
+        &mut fixture.svm,
+        MINTER_NAME,
+        Some(ASSET_NAME.to_string()),
+        Some(ASSET_URI.to_string()),
+        None,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        asset_owner.pubkey(),
+        mint.pubkey(),
+        fixture.payer.pubkey(),
+        None,
+        None,
+        tree.proof(&asset_owner.pubkey()),
+        &[
+            &fixture.payer.insecure_clone(),
+            &asset_owner.insecure_clone(),
+            &mint.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let mint_record = AccountHelper::get_mint_record(
+                &fixture.svm,
+                &minter_config_pda,
+                &asset_owner.pubkey(),
+            )
+            .expect("Mint record should exist");
+            assert_eq!(mint_record.minter_config, minter_config_pda);
+            assert_eq!(mint_record.wallet, asset_owner.pubkey());
+            assert_eq!(mint_record.minted, 1);
+
+            let minter_config =
+                AccountHelper::get_minter_config(&fixture.svm, &project_config_pda, &MINTER_NAME);
+            assert_eq!(minter_config.phases[0].minted, 1);
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_mint_asset_not_allowlisted() {
+    let tree = AllowlistTree::from_csv(&format!("{},1", Keypair::new().pubkey())).unwrap();
+
+    let mut fixture = TestFixture::new()
+        .with_metaplex_core_program()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_create_minter_config(PROJECT_1_ID, None)
+        .with_allowlist_mint_phases(PROJECT_1_ID, tree.root())
+        .with_public_mint(PROJECT_1_ID);
+
+    let mut clock: Clock = fixture.svm.get_sysvar();
+    clock.unix_timestamp = MINT_PHASE_START;
+    fixture.svm.set_sysvar(&clock);
+
+    let asset_owner = Keypair::new();
+    let mint = Keypair::new();
+    let forged_proof = AllowlistProof {
+        allowed_count: 1,
+        proof: vec![],
+    };
+
+    let result = Instructions::mint_asset(
+        &mut fixture.svm,
+        MINTER_NAME,
+        Some(ASSET_NAME.to_string()),
+        Some(ASSET_URI.to_string()),
+        None,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        asset_owner.pubkey(),
+        mint.pubkey(),
+        fixture.payer.pubkey(),
+        None,
+        None,
+        Some(forged_proof),
+        &[
+            &fixture.payer.insecure_clone(),
+            &asset_owner.insecure_clone(),
+            &mint.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed for a wallet outside the allowlist");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("NotAllowlisted"),
+                "Expected NotAllowlisted error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_mint_asset_exceeds_allowlist_count() {
+    let asset_owner = Keypair::new();
+    let tree = AllowlistTree::from_csv(&format!(
+        "{},{}",
+        asset_owner.pubkey(),
+        ALLOWLIST_ALLOWED_COUNT
+    ))
+    .unwrap();
+
+    let mut fixture = TestFixture::new()
+        .with_metaplex_core_program()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_create_minter_config(PROJECT_1_ID, None)
+        .with_allowlist_mint_phases(PROJECT_1_ID, tree.root())
+        .with_public_mint(PROJECT_1_ID);
+
+    let mut clock: Clock = fixture.svm.get_sysvar();
+    clock.unix_timestamp = MINT_PHASE_START;
+    fixture.svm.set_sysvar(&clock);
+
+    for _ in 0..ALLOWLIST_ALLOWED_COUNT {
+        let mint = Keypair::new();
+        Instructions::mint_asset(
+            &mut fixture.svm,
+            MINTER_NAME,
+            Some(ASSET_NAME.to_string()),
+            Some(ASSET_URI.to_string()),
+            None,
+            PROJECT_1_ID,
+            fixture.project_owner.pubkey(),
+            fixture.payer.pubkey(),
+            asset_owner.pubkey(),
+            mint.pubkey(),
+            fixture.payer.pubkey(),
+            None,
+            None,
+            tree.proof(&asset_owner.pubkey()),
+            &[
+                &fixture.payer.insecure_clone(),
+                &asset_owner.insecure_clone(),
+                &mint.insecure_clone(),
+            ],
+        )
+        .expect("Failed to mint allowlisted asset");
+    }
+
+    let mint = Keypair::new();
+
+    let result = Instructions::mint_asset(
+        &mut fixture.svm,
+        MINTER_NAME,
+        Some(ASSET_NAME.to_string()),
+        Some(ASSET_URI.to_string()),
+        None,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        asset_owner.pubkey(),
+        mint.pubkey(),
+        fixture.payer.pubkey(),
+        None,
+        None,
+        tree.proof(&asset_owner.pubkey()),
+        &[
+            &fixture.payer.insecure_clone(),
+            &asset_owner.insecure_clone(),
+            &mint.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed with the allowed count reached");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("AllowlistMintLimitReached"),
+                "Expected AllowlistMintLimitReached error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_mint_asset_allowlist_count_per_phase() {
+    let asset_owner = Keypair::new();
+    let tree = AllowlistTree::from_csv(&format!(
+        "{},{}",
+        asset_owner.pubkey(),
+        ALLOWLIST_ALLOWED_COUNT
+    ))
+    .unwrap();
+
+    let mut fixture = TestFixture::new()
+        .with_metaplex_core_program()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_create_minter_config(PROJECT_1_ID, None)
+        .with_public_mint(PROJECT_1_ID);
+
+    // Both phases are limited to the same allowlist
+    let mut phases = default_mint_phases();
+    for phase in phases.iter_mut() {
+        phase.merkle_root = Some(tree.root());
+    }
+    Instructions::set_mint_phases(
+        &mut fixture.svm,
+        MINTER_NAME,
+        phases,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        fixture.project_authority_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.payer.insecure_clone(),
+            &fixture.project_authority_1.insecure_clone(),
+        ],
+    )
+    .expect("Failed to set allowlist mint phases");
+
+    let (project_config_pda, _) =
+        AccountHelper::find_project_pda(&fixture.project_owner.pubkey(), PROJECT_1_ID);
+    let (minter_config_pda, _) =
+        AccountHelper::find_minter_config_pda(&project_config_pda, MINTER_NAME);
+
+    let mut clock: Clock = fixture.svm.get_sysvar();
+    clock.unix_timestamp = MINT_PHASE_START;
+    fixture.svm.set_sysvar(&clock);
+
+    for _ in 0..ALLOWLIST_ALLOWED_COUNT {
+        let mint = Keypair::new();
+        Instructions::mint_asset(
+            &mut fixture.svm,
+            MINTER_NAME,
+            Some(ASSET_NAME.to_string()),
+            Some(ASSET_URI.to_string()),
+            None,
+            PROJECT_1_ID,
+            fixture.project_owner.pubkey(),
+            fixture.payer.pubkey(),
+            asset_owner.pubkey(),
+            mint.pubkey(),
+            fixture.payer.pubkey(),
+            None,
+            None,
+            tree.proof(&asset_owner.pubkey()),
+            &[
+                &fixture.payer.insecure_clone(),
+                &asset_owner.insecure_clone(),
+                &mint.insecure_clone(),
+            ],
+        )
+        .expect("Failed to mint allowlisted asset");
+    }
+
+    utils::warp_clock(&mut fixture.svm, MINT_PHASE_DURATION);
+
+    let mint = Keypair::new();
+
+    let result = Instructions::mint_asset(
+        &mut fixture.svm,
+        MINTER_NAME,
+        Some(ASSET_NAME.to_string()),
+        Some(ASSET_URI.to_string()),
+        None,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        asset_owner.pubkey(),
+        mint.pubkey(),
+        fixture.payer.pubkey(),
+        None,
+        None,
+        tree.proof(&asset_owner.pubkey()),
+        &[
+            &fixture.payer.insecure_clone(),
+            &asset_owner.insecure_clone(),
+            &mint.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let mint_record = AccountHelper::get_mint_record(
+                &fixture.svm,
+                &minter_config_pda,
+                &asset_owner.pubkey(),
+            )
+            .expect("Mint record should exist");
+            assert_eq!(mint_record.minted, ALLOWLIST_ALLOWED_COUNT + 1);
+            assert_eq!(
+                mint_record.phase_start_at,
+                MINT_PHASE_START + MINT_PHASE_DURATION
+            );
+            assert_eq!(mint_record.phase_minted, 1);
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_mint_asset_airdrop_outside_mint_phases() {
+    let tree = AllowlistTree::from_csv(&format!("{},1", Keypair::new().pubkey())).unwrap();
+
+    let mut fixture = TestFixture::new()
+        .with_metaplex_core_program()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_create_minter_config(PROJECT_1_ID, None)
+        .with_allowlist_mint_phases(PROJECT_1_ID, tree.root());
+
+    let (project_config_pda, _) =
+        AccountHelper::find_project_pda(&fixture.project_owner.pubkey(), PROJECT_1_ID);
+    let (project_treasury_pda, _) = AccountHelper::find_treasury_pda(&project_config_pda);
+
+    // A wallet outside the allowlist during the allowlist phase, then after the last phase
+    for unix_timestamp in [MINT_PHASE_START, MINT_PHASE_START + 2 * MINT_PHASE_DURATION] {
+        let mut clock: Clock = fixture.svm.get_sysvar();
+        clock.unix_timestamp = unix_timestamp;
+        fixture.svm.set_sysvar(&clock);
+
+        let project_treasury_initial_balance =
+            utils::get_lamports(&fixture.svm, &project_treasury_pda);
+        let asset_owner = Keypair::new();
+        let mint = Keypair::new();
+
+        let result = Instructions::mint_asset(
+            &mut fixture.svm,
+            MINTER_NAME,
+            Some(ASSET_NAME.to_string()),
+            Some(ASSET_URI.to_string()),
+            None,
+            PROJECT_1_ID,
+            fixture.project_owner.pubkey(),
+            fixture.payer.pubkey(),
+            asset_owner.pubkey(),
+            mint.pubkey(),
+            fixture.project_authority_1.pubkey(),
+            None,
+            None,
+            None,
+            &[
+                &fixture.payer.insecure_clone(),
+                &asset_owner.insecure_clone(),
+                &fixture.project_authority_1.insecure_clone(),
+                &mint.insecure_clone(),
+            ],
+        );
+
+        match result {
+            Ok(result) => {
+                utils::print_transaction_logs(&result);
+
+                assert_eq!(
+                    utils::get_lamports(&fixture.svm, &project_treasury_pda),
+                    project_treasury_initial_balance + MINT_PRICE,
+                    "Airdrops should be charged the minter config mint price"
+                );
+            }
+            Err(e) => {
+                panic!("Transaction failed: {:?}", e);
+            }
+        }
+    }
+
+    let minter_config =
+        AccountHelper::get_minter_config(&fixture.svm, &project_config_pda, &MINTER_NAME);
+    assert_eq!(minter_config.mints_counter, 2);
+    assert_eq!(minter_config.phases[0].minted, 0);
+    assert_eq!(minter_config.phases[1].minted, 0);
+}
+
+#[test]
+fn test_set_wallet_mint_limit() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_create_minter_config(PROJECT_1_ID, None);
+
+    let result = Instructions::set_wallet_mint_limit(
+        &mut fixture.svm,
+        MINTER_NAME,
+        WALLET_MINT_LIMIT,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        fixture.project_authority_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.payer.insecure_clone(),
+            &fixture.project_authority_1.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let (project_config_pda, _) =
+                AccountHelper::find_project_pda(&fixture.project_owner.pubkey(), PROJECT_1_ID);
+            let minter_config =
+                AccountHelper::get_minter_config(&fixture.svm, &project_config_pda, &MINTER_NAME);
+            assert_eq!(minter_config.wallet_mint_limit, WALLET_MINT_LIMIT);
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_mint_asset_exceeds_wallet_mint_limit() {
+    let mut fixture = TestFixture::new()
+        .with_metaplex_core_program()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_create_minter_config(PROJECT_1_ID, None)
+        .with_wallet_mint_limit(PROJECT_1_ID);
+
+    let asset_owner = Keypair::new();
+    for _ in 0..WALLET_MINT_LIMIT {
+        fixture = fixture.with_minted_asset(PROJECT_1_ID, &asset_owner, &Keypair::new(), None);
+    }
+
+    let (project_config_pda, _) =
+        AccountHelper::find_project_pda(&fixture.project_owner.pubkey(), PROJECT_1_ID);
+    let (minter_config_pda, _) =
+        AccountHelper::find_minter_config_pda(&project_config_pda, MINTER_NAME);
+    let mint_record =
+        AccountHelper::get_mint_record(&fixture.svm, &minter_config_pda, &asset_owner.pubkey())
+            .expect("Mint record should exist");
+    assert_eq!(mint_record.minted, WALLET_MINT_LIMIT);
+
+    let mint = Keypair::new();
+
+    let result = Instructions::mint_asset(
+        &mut fixture.svm,
+        MINTER_NAME,
+        Some(ASSET_NAME.to_string()),
+        Some(ASSET_URI.to_string()),
+        None,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        asset_owner.pubkey(),
+        mint.pubkey(),
+        fixture.project_authority_1.pubkey(),
+        None,
+        None,
+        None,
+        &[
+            &fixture.payer.insecure_clone(),
+            &asset_owner.insecure_clone(),
+            &fixture.project_authority_1.insecure_clone(),
+            &mint.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed with the wallet mint limit reached");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("WalletMintLimitReached"),
+                "Expected WalletMintLimitReached error, got: {:?}",
+                e
+            );
+        }
+    }
+
+    // Other wallets keep their own count
+    let other_owner = Keypair::new();
+    fixture.with_minted_asset(PROJECT_1_ID, &other_owner, &Keypair::new(), None);
+}
+
+#[test]
+fn test_close_mint_record() {
+    let mut fixture = TestFixture::new()
+        .with_metaplex_core_program()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_create_minter_config(PROJECT_1_ID, None)
+        .with_mint_phases(PROJECT_1_ID);
+
+    let mut clock: Clock = fixture.svm.get_sysvar();
+    clock.unix_timestamp = MINT_PHASE_START;
+    fixture.svm.set_sysvar(&clock);
+
+    let asset_owner = Keypair::new();
+    let mut fixture = fixture.with_minted_asset(PROJECT_1_ID, &asset_owner, &Keypair::new(), None);
+
+    utils::warp_clock(&mut fixture.svm, 2 * MINT_PHASE_DURATION);
+
+    let (project_config_pda, _) =
+        AccountHelper::find_project_pda(&fixture.project_owner.pubkey(), PROJECT_1_ID);
+    let (minter_config_pda, _) =
+        AccountHelper::find_minter_config_pda(&project_config_pda, MINTER_NAME);
+    let (mint_record_pda, _) =
+        AccountHelper::find_mint_record_pda(&minter_config_pda, &asset_owner.pubkey());
+    let mint_record_rent = utils::get_lamports(&fixture.svm, &mint_record_pda);
+    let owner_initial_balance = utils::get_lamports(&fixture.svm, &asset_owner.pubkey());
+
+    let result = Instructions::close_mint_record(
+        &mut fixture.svm,
+        MINTER_NAME,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        asset_owner.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.payer.insecure_clone(),
+            &asset_owner.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            assert!(
+                AccountHelper::get_mint_record(
+                    &fixture.svm,
+                    &minter_config_pda,
+                    &asset_owner.pubkey()
+                )
+                .is_none(),
+                "Mint record should be closed"
+            );
+            assert_eq!(
+                utils::get_lamports(&fixture.svm, &asset_owner.pubkey()),
+                owner_initial_balance + mint_record_rent,
+                "Owner should reclaim the mint record rent"
+            );
+
+            let event = utils::inner_instructions_data(&result)
+                .iter()
+                .find_map(|data| decode_nft_operations_event(data))
+                .expect("MintRecordClosed event should be emitted");
+            match event {
+                NftOperationsEvent::MintRecordClosed(event) => {
+                    assert_eq!(event.minter_config, minter_config_pda);
+                    assert_eq!(event.wallet, asset_owner.pubkey());
+                    assert_eq!(event.minted, 1);
+                }
+                event => panic!("Unexpected event: {:?}", event),
+            }
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_close_mint_record_before_minting_ends() {
+    let mut fixture = TestFixture::new()
+        .with_metaplex_core_program()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_create_minter_config(PROJECT_1_ID, None)
+        .with_mint_phases(PROJECT_1_ID);
+
+    let mut clock: Clock = fixture.svm.get_sysvar();
+    clock.unix_timestamp = MINT_PHASE_START;
+    fixture.svm.set_sysvar(&clock);
+
+    let asset_owner = Keypair::new();
+    let mut fixture = fixture.with_minted_asset(PROJECT_1_ID, &asset_owner, &Keypair::new(), None);
+
+    let result = Instructions::close_mint_record(
+        &mut fixture.svm,
+        MINTER_NAME,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        asset_owner.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.payer.insecure_clone(),
+            &asset_owner.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed while minting is still open");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("MintingNotEnded"),
+                "Expected MintingNotEnded error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_close_mint_record_of_closed_minter_config() {
+    let mut fixture = TestFixture::new()
+        .with_metaplex_core_program()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_create_minter_config(PROJECT_1_ID, None)
+        .with_mint_phases(PROJECT_1_ID);
+
+    let mut clock: Clock = fixture.svm.get_sysvar();
+    clock.unix_timestamp = MINT_PHASE_START;
+    fixture.svm.set_sysvar(&clock);
+
+    let asset_owner = Keypair::new();
+    let mut fixture = fixture.with_minted_asset(PROJECT_1_ID, &asset_owner, &Keypair::new(), None);
+
+    Instructions::close_minter_config(
+        &mut fixture.svm,
+        MINTER_NAME,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        fixture.project_authority_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.payer.insecure_clone(),
+            &fixture.project_authority_1.insecure_clone(),
+        ],
+    )
+    .expect("Minter config should be closed while minting is open");
+
+    let (project_config_pda, _) =
+        AccountHelper::find_project_pda(&fixture.project_owner.pubkey(), PROJECT_1_ID);
+    let (minter_config_pda, _) =
+        AccountHelper::find_minter_config_pda(&project_config_pda, MINTER_NAME);
+
+    let result = Instructions::close_mint_record(
+        &mut fixture.svm,
+        MINTER_NAME,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        asset_owner.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.payer.insecure_clone(),
+            &asset_owner.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            assert!(
+                AccountHelper::get_mint_record(
+                    &fixture.svm,
+                    &minter_config_pda,
+                    &asset_owner.pubkey()
+                )
+                .is_none(),
+                "Mint record should be closed"
+            );
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
@@ -1,22 +1,47 @@
+use anchor_lang::AnchorSerialize;
 use litesvm::LiteSVM;
 use sol_mind_protocol_client::{
-    accounts::{ProjectConfig, ProtocolConfig},
+    accounts::{
+        FeeStats, ProjectConfig, ProjectFeeOverride, ProjectRegistryEntry, ProjectRole, Proposal,
+        ProtocolConfig, Referrer, RegisteredProgram, Subscription, TreasuryPolicy,
+    },
     dac_manager::accounts::{Agent, ComputeNodeInfo, TaskData},
     nft_operations::accounts::MinterConfig,
-    nft_operations::accounts::{Listing, TradeHub},
+    nft_operations::accounts::{Listing, MintRecord, TradeHub},
     DAC_MANAGER_ID, NFT_OPERATIONS_ID, SOL_MIND_PROTOCOL_ID,
 };
+use solana_program::hash::hash;
 use solana_pubkey::Pubkey;
+use solana_sdk::account::Account;
 
 pub struct AccountHelper;
 
 impl AccountHelper {
     pub fn find_protocol_config_pda() -> (Pubkey, u8) {
-        Pubkey::try_find_program_address(&[b"sol-mind-protocol"], &SOL_MIND_PROTOCOL_ID).unwrap()
+        Self::find_protocol_instance_pda(0)
+    }
+
+    /// Instance 0 adds no seed, so it is the config created before instances existed.
+    pub fn find_protocol_instance_pda(instance_id: u64) -> (Pubkey, u8) {
+        let instance_seed = if instance_id == 0 {
+            Vec::new()
+        } else {
+            instance_id.to_le_bytes().to_vec()
+        };
+
+        Pubkey::try_find_program_address(
+            &[b"sol-mind-protocol", &instance_seed],
+            &SOL_MIND_PROTOCOL_ID,
+        )
+        .unwrap()
     }
 
     pub fn get_protocol_config(svm: &LiteSVM) -> ProtocolConfig {
-        let addr = Self::find_protocol_config_pda().0;
+        Self::get_protocol_instance(svm, 0)
+    }
+
+    pub fn get_protocol_instance(svm: &LiteSVM, instance_id: u64) -> ProtocolConfig {
+        let addr = Self::find_protocol_instance_pda(instance_id).0;
 
         let account = svm
             .get_account(&addr)
@@ -26,8 +51,87 @@ impl AccountHelper {
             .expect("Failed to deserialize protocol config account")
     }
 
-    pub fn find_project_pda(owner: &Pubkey, project_id: u64) -> (Pubkey, u8) {
+    /// Rewrites an account the way an older layout stored it: without its last
+    /// `appended_len` bytes of fields, in an account that much shorter.
+    pub fn write_legacy_config<T: AnchorSerialize>(
+        svm: &mut LiteSVM,
+        address: &Pubkey,
+        config: &T,
+        appended_len: usize,
+    ) {
+        let mut account = svm.get_account(address).expect("Config account not found");
+
+        let mut data = Vec::new();
+        config
+            .serialize(&mut data)
+            .expect("Failed to serialize config");
+        data.truncate(data.len() - appended_len);
+        data.resize(account.data.len() - appended_len, 0);
+        account.data = data;
+
+        svm.set_account(*address, account)
+            .expect("Failed to write legacy config");
+    }
+
+    /// Rewrites a protocol or project config as the first release stored it, before versioning:
+    /// `config` in an account of that release's `space`, holding only its rent.
+    pub fn write_v0_config<T: AnchorSerialize>(
+        svm: &mut LiteSVM,
+        address: &Pubkey,
+        config: &T,
+        space: usize,
+    ) {
+        let mut account = svm.get_account(address).expect("Config account not found");
+
+        let mut data = account.data[..8].to_vec();
+        config
+            .serialize(&mut data)
+            .expect("Failed to serialize config");
+        data.resize(space, 0);
+        account.data = data;
+        account.lamports = svm.minimum_balance_for_rent_exemption(space);
+
+        svm.set_account(*address, account)
+            .expect("Failed to write v0 config");
+    }
+
+    /// Removes an account, as if it had never been created.
+    pub fn remove_account(svm: &mut LiteSVM, address: &Pubkey) {
+        svm.set_account(*address, Account::default())
+            .expect("Failed to remove account");
+    }
+
+    pub fn find_proposal_pda(proposal_id: u64) -> (Pubkey, u8) {
         let protocol_config_pda = Self::find_protocol_config_pda().0;
+        Pubkey::try_find_program_address(
+            &[
+                b"proposal",
+                protocol_config_pda.as_ref(),
+                &proposal_id.to_le_bytes(),
+            ],
+            &SOL_MIND_PROTOCOL_ID,
+        )
+        .unwrap()
+    }
+
+    pub fn get_proposal(svm: &LiteSVM, proposal_id: u64) -> Option<Proposal> {
+        let addr = Self::find_proposal_pda(proposal_id).0;
+
+        let account = svm.get_account(&addr)?;
+
+        Proposal::from_bytes(&account.data).ok()
+    }
+
+    pub fn find_project_pda(owner: &Pubkey, project_id: u64) -> (Pubkey, u8) {
+        Self::find_instance_project_pda(0, owner, project_id)
+    }
+
+    pub fn find_instance_project_pda(
+        instance_id: u64,
+        owner: &Pubkey,
+        project_id: u64,
+    ) -> (Pubkey, u8) {
+        let protocol_config_pda = Self::find_protocol_instance_pda(instance_id).0;
         Pubkey::try_find_program_address(
             &[
                 b"project",
@@ -41,7 +145,16 @@ impl AccountHelper {
     }
 
     pub fn get_project_config(svm: &LiteSVM, owner: &Pubkey, project_id: u64) -> ProjectConfig {
-        let addr = Self::find_project_pda(owner, project_id).0;
+        Self::get_instance_project_config(svm, 0, owner, project_id)
+    }
+
+    pub fn get_instance_project_config(
+        svm: &LiteSVM,
+        instance_id: u64,
+        owner: &Pubkey,
+        project_id: u64,
+    ) -> ProjectConfig {
+        let addr = Self::find_instance_project_pda(instance_id, owner, project_id).0;
 
         let account = svm
             .get_account(&addr)
@@ -51,6 +164,161 @@ impl AccountHelper {
             .expect("Failed to deserialize project config account")
     }
 
+    pub fn find_project_registry_pda(name: &str) -> (Pubkey, u8) {
+        Self::find_instance_project_registry_pda(0, name)
+    }
+
+    pub fn find_instance_project_registry_pda(instance_id: u64, name: &str) -> (Pubkey, u8) {
+        let protocol_config_pda = Self::find_protocol_instance_pda(instance_id).0;
+        Pubkey::try_find_program_address(
+            &[
+                b"project_registry",
+                protocol_config_pda.as_ref(),
+                hash(name.as_bytes()).as_ref(),
+            ],
+            &SOL_MIND_PROTOCOL_ID,
+        )
+        .unwrap()
+    }
+
+    pub fn get_project_registry(svm: &LiteSVM, name: &str) -> Option<ProjectRegistryEntry> {
+        let addr = Self::find_project_registry_pda(name).0;
+
+        let account = svm.get_account(&addr)?;
+
+        ProjectRegistryEntry::from_bytes(&account.data).ok()
+    }
+
+    pub fn find_project_fee_override_pda(project_config_pda: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::try_find_program_address(
+            &[b"fee_override", project_config_pda.as_ref()],
+            &SOL_MIND_PROTOCOL_ID,
+        )
+        .unwrap()
+    }
+
+    pub fn get_project_fee_override(
+        svm: &LiteSVM,
+        project_config_pda: &Pubkey,
+    ) -> Option<ProjectFeeOverride> {
+        let addr = Self::find_project_fee_override_pda(project_config_pda).0;
+
+        let account = svm.get_account(&addr)?;
+
+        ProjectFeeOverride::from_bytes(&account.data).ok()
+    }
+
+    pub fn find_project_role_pda(project_config: &Pubkey, grantee: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::try_find_program_address(
+            &[b"project_role", project_config.as_ref(), grantee.as_ref()],
+            &SOL_MIND_PROTOCOL_ID,
+        )
+        .unwrap()
+    }
+
+    pub fn get_project_role(
+        svm: &LiteSVM,
+        project_config: &Pubkey,
+        grantee: &Pubkey,
+    ) -> Option<ProjectRole> {
+        let addr = Self::find_project_role_pda(project_config, grantee).0;
+
+        let account = svm.get_account(&addr)?;
+
+        ProjectRole::from_bytes(&account.data).ok()
+    }
+
+    pub fn find_treasury_policy_pda(project_config: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::try_find_program_address(
+            &[b"treasury_policy", project_config.as_ref()],
+            &SOL_MIND_PROTOCOL_ID,
+        )
+        .unwrap()
+    }
+
+    pub fn get_treasury_policy(svm: &LiteSVM, project_config: &Pubkey) -> Option<TreasuryPolicy> {
+        let addr = Self::find_treasury_policy_pda(project_config).0;
+
+        let account = svm.get_account(&addr)?;
+
+        TreasuryPolicy::from_bytes(&account.data).ok()
+    }
+
+    pub fn find_registered_program_pda(program_id: &Pubkey) -> (Pubkey, u8) {
+        let protocol_config_pda = Self::find_protocol_config_pda().0;
+        Pubkey::try_find_program_address(
+            &[
+                b"registered_program",
+                protocol_config_pda.as_ref(),
+                program_id.as_ref(),
+            ],
+            &SOL_MIND_PROTOCOL_ID,
+        )
+        .unwrap()
+    }
+
+    pub fn get_registered_program(svm: &LiteSVM, program_id: &Pubkey) -> Option<RegisteredProgram> {
+        let addr = Self::find_registered_program_pda(program_id).0;
+
+        let account = svm.get_account(&addr)?;
+
+        RegisteredProgram::from_bytes(&account.data).ok()
+    }
+
+    pub fn find_referrer_pda(wallet: &Pubkey) -> (Pubkey, u8) {
+        Self::find_instance_referrer_pda(0, wallet)
+    }
+
+    pub fn find_instance_referrer_pda(instance_id: u64, wallet: &Pubkey) -> (Pubkey, u8) {
+        let protocol_config_pda = Self::find_protocol_instance_pda(instance_id).0;
+        Pubkey::try_find_program_address(
+            &[b"referrer", protocol_config_pda.as_ref(), wallet.as_ref()],
+            &SOL_MIND_PROTOCOL_ID,
+        )
+        .unwrap()
+    }
+
+    pub fn get_referrer(svm: &LiteSVM, wallet: &Pubkey) -> Option<Referrer> {
+        let addr = Self::find_referrer_pda(wallet).0;
+
+        let account = svm.get_account(&addr)?;
+
+        Referrer::from_bytes(&account.data).ok()
+    }
+
+    pub fn find_subscription_pda(project_config_pda: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::try_find_program_address(
+            &[b"subscription", project_config_pda.as_ref()],
+            &SOL_MIND_PROTOCOL_ID,
+        )
+        .unwrap()
+    }
+
+    pub fn get_subscription(svm: &LiteSVM, project_config_pda: &Pubkey) -> Option<Subscription> {
+        let addr = Self::find_subscription_pda(project_config_pda).0;
+
+        let account = svm.get_account(&addr)?;
+
+        Subscription::from_bytes(&account.data).ok()
+    }
+
+    pub fn find_fee_stats_pda(owner: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::try_find_program_address(&[b"fee_stats", owner.as_ref()], &SOL_MIND_PROTOCOL_ID)
+            .unwrap()
+    }
+
+    pub fn get_fee_stats(svm: &LiteSVM, owner: &Pubkey) -> FeeStats {
+        let addr = Self::find_fee_stats_pda(owner).0;
+
+        let account = svm.get_account(&addr).expect("Fee stats account not found");
+
+        FeeStats::from_bytes(&account.data).expect("Failed to deserialize fee stats account")
+    }
+
+    pub fn find_fee_authority_pda() -> (Pubkey, u8) {
+        Pubkey::try_find_program_address(&[b"fee_authority"], &NFT_OPERATIONS_ID).unwrap()
+    }
+
     pub fn find_treasury_pda(project_config_pda: &Pubkey) -> (Pubkey, u8) {
         Pubkey::try_find_program_address(
             &[b"treasury", project_config_pda.as_ref()],
@@ -78,6 +346,15 @@ impl AccountHelper {
         .expect("Failed to create treasury PDA")
     }
 
+    pub fn get_token_balance(svm: &LiteSVM, token_account: &Pubkey) -> u64 {
+        let account = svm
+            .get_account(token_account)
+            .expect("Token account not found");
+
+        // SPL token account layout: mint (32) | owner (32) | amount (8) | ...
+        u64::from_le_bytes(account.data[64..72].try_into().unwrap())
+    }
+
     pub fn find_minter_config_pda(project_config_pda: &Pubkey, name: &str) -> (Pubkey, u8) {
         Pubkey::try_find_program_address(
             &[
@@ -105,6 +382,26 @@ impl AccountHelper {
             .expect("Failed to deserialize minter config account")
     }
 
+    pub fn find_mint_record_pda(minter_config_pda: &Pubkey, wallet: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::try_find_program_address(
+            &[b"mint_record", minter_config_pda.as_ref(), wallet.as_ref()],
+            &NFT_OPERATIONS_ID,
+        )
+        .unwrap()
+    }
+
+    pub fn get_mint_record(
+        svm: &LiteSVM,
+        minter_config_pda: &Pubkey,
+        wallet: &Pubkey,
+    ) -> Option<MintRecord> {
+        let addr = Self::find_mint_record_pda(minter_config_pda, wallet).0;
+
+        let account = svm.get_account(&addr)?;
+
+        MintRecord::from_bytes(&account.data).ok()
+    }
+
     pub fn find_trade_hub_pda(name: &str, project_config_pda: &Pubkey) -> (Pubkey, u8) {
         Pubkey::try_find_program_address(
             &[b"trade_hub", name.as_bytes(), project_config_pda.as_ref()],
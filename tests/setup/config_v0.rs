@@ -0,0 +1,112 @@
+use anchor_lang::prelude::borsh;
+use anchor_lang::AnchorSerialize;
+use sol_mind_protocol_client::{
+    accounts::{ProjectConfig, ProtocolConfig},
+    types::{Fee, FeeType, FeesStructure},
+};
+use solana_pubkey::Pubkey;
+
+// Protocol and project configs as the first release of sol-mind-protocol stored them, before
+// versioning. That release allocated them at these sizes, discriminator included.
+pub const PROTOCOL_CONFIG_V0_SPACE: usize = 8 + 255;
+pub const PROJECT_CONFIG_V0_SPACE: usize = 8 + 446;
+
+#[derive(AnchorSerialize)]
+pub enum FeeTypeV0 {
+    Fixed,
+    Percentage,
+}
+
+#[derive(AnchorSerialize)]
+pub struct FeeV0 {
+    pub amount: u64,
+    pub fee_type: FeeTypeV0,
+}
+
+#[derive(AnchorSerialize)]
+pub struct FeesStructureV0 {
+    pub create_project: FeeV0,
+    pub create_minter_config: FeeV0,
+    pub create_trade_hub: FeeV0,
+    pub trade_nft: FeeV0,
+    pub mint_asset: FeeV0,
+    pub generic_operation: FeeV0,
+}
+
+#[derive(AnchorSerialize)]
+pub struct ProtocolConfigV0 {
+    pub admins: Vec<Pubkey>,
+    pub whitelist_transfer_addrs: Vec<Pubkey>,
+    pub fees: FeesStructureV0,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize)]
+pub struct ProjectConfigV0 {
+    pub protocol_config: Pubkey,
+    pub project_id: u64,
+    pub owner: Pubkey,
+    pub name: String,
+    pub description: String,
+    pub autthorities: Vec<Pubkey>,
+    pub treasury_bump: u8,
+    pub bump: u8,
+}
+
+impl FeeV0 {
+    /// The first release only had lamport fees, fixed or in basis points.
+    pub fn from_fee(fee: &Fee) -> Self {
+        FeeV0 {
+            amount: fee.amount,
+            fee_type: match fee.fee_type {
+                FeeType::Percentage => FeeTypeV0::Percentage,
+                _ => FeeTypeV0::Fixed,
+            },
+        }
+    }
+}
+
+impl FeesStructureV0 {
+    pub fn from_fees(fees: &FeesStructure) -> Self {
+        FeesStructureV0 {
+            create_project: FeeV0::from_fee(&fees.create_project),
+            create_minter_config: FeeV0::from_fee(&fees.create_minter_config),
+            create_trade_hub: FeeV0::from_fee(&fees.create_trade_hub),
+            trade_nft: FeeV0::from_fee(&fees.trade_nft),
+            mint_asset: FeeV0::from_fee(&fees.mint_asset),
+            generic_operation: FeeV0::from_fee(&fees.generic_operation),
+        }
+    }
+}
+
+impl ProtocolConfigV0 {
+    /// The fields of `config` that the first release already stored.
+    pub fn from_config(config: &ProtocolConfig) -> Self {
+        ProtocolConfigV0 {
+            admins: config.admins.clone(),
+            whitelist_transfer_addrs: config
+                .whitelist_transfer_addrs
+                .iter()
+                .map(|entry| entry.address)
+                .collect(),
+            fees: FeesStructureV0::from_fees(&config.fees),
+            bump: config.bump,
+        }
+    }
+}
+
+impl ProjectConfigV0 {
+    /// The fields of `config` that the first release already stored.
+    pub fn from_config(config: &ProjectConfig) -> Self {
+        ProjectConfigV0 {
+            protocol_config: config.protocol_config,
+            project_id: config.project_id,
+            owner: config.owner,
+            name: config.name.clone(),
+            description: config.description.clone(),
+            autthorities: config.autthorities.clone(),
+            treasury_bump: config.treasury_bump,
+            bump: config.bump,
+        }
+    }
+}
@@ -1,5 +1,8 @@
 use litesvm::LiteSVM;
-use sol_mind_protocol_client::types::{Fee, FeesStructure, Operation};
+use litesvm_token::{CreateAssociatedTokenAccount, CreateMint, MintTo};
+use sol_mind_protocol_client::types::{
+    Fee, FeesStructure, Operation, OperationFeeOverride, ProposalAction, RevenueShare, Role,
+};
 use solana_sdk::{
     native_token::LAMPORTS_PER_SOL,
     pubkey::Pubkey,
@@ -92,6 +95,26 @@ impl TestFixture {
         self
     }
 
+    pub fn create_fee_mint(&mut self) -> Pubkey {
+        CreateMint::new(&mut self.svm, &self.payer)
+            .decimals(FEE_TOKEN_DECIMALS)
+            .send()
+            .expect("Failed to create fee mint")
+    }
+
+    pub fn create_token_account(&mut self, mint: &Pubkey, owner: &Pubkey) -> Pubkey {
+        CreateAssociatedTokenAccount::new(&mut self.svm, &self.payer, mint)
+            .owner(owner)
+            .send()
+            .expect("Failed to create token account")
+    }
+
+    pub fn mint_tokens(&mut self, mint: &Pubkey, destination: &Pubkey, amount: u64) {
+        MintTo::new(&mut self.svm, &self.payer, mint, destination, amount)
+            .send()
+            .expect("Failed to mint tokens");
+    }
+
     pub fn with_initialize_protocol(mut self) -> Self {
         let fees = default_fees_structure();
 
@@ -109,16 +132,17 @@ impl TestFixture {
     }
 
     pub fn with_project_created(mut self, project_id: u64) -> Self {
+        assert_eq!(
+            AccountHelper::get_protocol_config(&self.svm).project_count,
+            project_id,
+            "Project IDs are assigned sequentially"
+        );
+
         Instructions::create_project(
             &mut self.svm,
-            project_id,
             DEFAULT_PROJECT_NAME.to_string(),
             DEFAULT_PROJECT_DESCRIPTION.to_string(),
             self.project_owner.pubkey(),
-            vec![
-                self.project_authority_1.pubkey(),
-                self.project_authority_2.pubkey(),
-            ],
             self.payer.pubkey(),
             &[
                 &self.project_owner.insecure_clone(),
@@ -127,20 +151,21 @@ impl TestFixture {
         )
         .expect("Failed to create project");
 
-        self
+        self.with_default_project_roles(project_id)
     }
 
     pub fn with_initialize_project(mut self, project_id: u64) -> Self {
+        assert_eq!(
+            AccountHelper::get_protocol_config(&self.svm).project_count,
+            project_id,
+            "Project IDs are assigned sequentially"
+        );
+
         Instructions::create_project(
             &mut self.svm,
-            project_id,
             DEFAULT_PROJECT_NAME.to_string(),
             DEFAULT_PROJECT_DESCRIPTION.to_string(),
             self.project_owner.pubkey(),
-            vec![
-                self.project_authority_1.pubkey(),
-                self.project_authority_2.pubkey(),
-            ],
             self.payer.pubkey(),
             &[
                 &self.project_owner.insecure_clone(),
@@ -149,6 +174,125 @@ impl TestFixture {
         )
         .expect("Failed to initialize project");
 
+        self.with_default_project_roles(project_id)
+    }
+
+    /// Grants both project authorities the roles the nft-operations instructions check.
+    fn with_default_project_roles(self, project_id: u64) -> Self {
+        let authority_1 = self.project_authority_1.pubkey();
+        let authority_2 = self.project_authority_2.pubkey();
+
+        self.with_project_role(project_id, authority_1, Role::Minter)
+            .with_project_role(project_id, authority_1, Role::HubManager)
+            .with_project_role(project_id, authority_2, Role::Minter)
+            .with_project_role(project_id, authority_2, Role::HubManager)
+    }
+
+    pub fn with_project_role(mut self, project_id: u64, grantee: Pubkey, role: Role) -> Self {
+        Instructions::grant_project_role(
+            &mut self.svm,
+            project_id,
+            self.project_owner.pubkey(),
+            grantee,
+            role,
+            self.project_owner.pubkey(),
+            self.payer.pubkey(),
+            &[
+                &self.project_owner.insecure_clone(),
+                &self.payer.insecure_clone(),
+            ],
+        )
+        .expect("Failed to grant project role");
+
+        self
+    }
+
+    pub fn with_project_role_revoked(
+        mut self,
+        project_id: u64,
+        grantee: Pubkey,
+        role: Role,
+    ) -> Self {
+        Instructions::revoke_project_role(
+            &mut self.svm,
+            project_id,
+            self.project_owner.pubkey(),
+            grantee,
+            role,
+            self.project_owner.pubkey(),
+            self.payer.pubkey(),
+            &[
+                &self.project_owner.insecure_clone(),
+                &self.payer.insecure_clone(),
+            ],
+        )
+        .expect("Failed to revoke project role");
+
+        self
+    }
+
+    /// Revokes the default roles, closing the authorities' role PDAs so the project can close.
+    pub fn with_default_project_roles_revoked(self, project_id: u64) -> Self {
+        let authority_1 = self.project_authority_1.pubkey();
+        let authority_2 = self.project_authority_2.pubkey();
+
+        self.with_project_role_revoked(project_id, authority_1, Role::Minter)
+            .with_project_role_revoked(project_id, authority_1, Role::HubManager)
+            .with_project_role_revoked(project_id, authority_2, Role::Minter)
+            .with_project_role_revoked(project_id, authority_2, Role::HubManager)
+    }
+
+    pub fn with_treasury_policy(
+        mut self,
+        project_id: u64,
+        destinations: Vec<Pubkey>,
+        epoch_cap: Option<u64>,
+    ) -> Self {
+        Instructions::set_treasury_policy(
+            &mut self.svm,
+            project_id,
+            destinations,
+            epoch_cap,
+            self.project_owner.pubkey(),
+            self.payer.pubkey(),
+            &[
+                &self.project_owner.insecure_clone(),
+                &self.payer.insecure_clone(),
+            ],
+        )
+        .expect("Failed to set treasury policy");
+
+        self
+    }
+
+    pub fn with_subscription_plan(mut self) -> Self {
+        Instructions::set_subscription_plan(
+            &mut self.svm,
+            default_subscription_plan(),
+            self.admin_1.pubkey(),
+            self.payer.pubkey(),
+            &[&self.admin_1.insecure_clone(), &self.payer.insecure_clone()],
+        )
+        .expect("Failed to set subscription plan");
+
+        self
+    }
+
+    pub fn with_subscription(mut self, project_id: u64, epochs: u64) -> Self {
+        Instructions::pay_subscription(
+            &mut self.svm,
+            project_id,
+            SUBSCRIPTION_PLAN_ID,
+            epochs,
+            self.project_owner.pubkey(),
+            self.payer.pubkey(),
+            &[
+                &self.project_owner.insecure_clone(),
+                &self.payer.insecure_clone(),
+            ],
+        )
+        .expect("Failed to pay subscription");
+
         self
     }
 
@@ -158,10 +302,12 @@ impl TestFixture {
             fees,
             self.admin_1.pubkey(),
             self.payer.pubkey(),
-            &[&self.admin_1.insecure_clone()],
+            &[&self.admin_1.insecure_clone(), &self.payer.insecure_clone()],
         )
         .expect("Failed to update fees");
 
+        utils::warp_clock(&mut self.svm, FEE_UPDATE_DELAY);
+
         self
     }
 
@@ -172,10 +318,128 @@ impl TestFixture {
             fee,
             self.admin_1.pubkey(),
             self.payer.pubkey(),
-            &[&self.admin_1.insecure_clone()],
+            &[&self.admin_1.insecure_clone(), &self.payer.insecure_clone()],
         )
         .expect("Failed to update single fee");
 
+        utils::warp_clock(&mut self.svm, FEE_UPDATE_DELAY);
+
+        self
+    }
+
+    pub fn with_protocol_paused(mut self) -> Self {
+        Instructions::set_protocol_paused(
+            &mut self.svm,
+            true,
+            self.admin_1.pubkey(),
+            self.payer.pubkey(),
+            &[&self.admin_1.insecure_clone(), &self.payer.insecure_clone()],
+        )
+        .expect("Failed to pause protocol");
+
+        self
+    }
+
+    pub fn with_operation_paused(mut self, operation: Operation) -> Self {
+        Instructions::set_operation_paused(
+            &mut self.svm,
+            operation,
+            true,
+            self.admin_1.pubkey(),
+            self.payer.pubkey(),
+            &[&self.admin_1.insecure_clone(), &self.payer.insecure_clone()],
+        )
+        .expect("Failed to pause operation");
+
+        self
+    }
+
+    pub fn with_project_fee_override(
+        mut self,
+        project_id: u64,
+        overrides: Vec<OperationFeeOverride>,
+        expires_at: Option<i64>,
+    ) -> Self {
+        let project_config_pda =
+            AccountHelper::find_project_pda(&self.project_owner.pubkey(), project_id).0;
+
+        Instructions::set_project_fee_override(
+            &mut self.svm,
+            overrides,
+            expires_at,
+            project_config_pda,
+            self.admin_1.pubkey(),
+            self.payer.pubkey(),
+            &[&self.admin_1.insecure_clone(), &self.payer.insecure_clone()],
+        )
+        .expect("Failed to set project fee override");
+
+        self
+    }
+
+    pub fn with_registered_program(mut self, program_id: Pubkey) -> Self {
+        Instructions::register_program(
+            &mut self.svm,
+            program_id,
+            self.admin_1.pubkey(),
+            self.payer.pubkey(),
+            &[&self.admin_1.insecure_clone(), &self.payer.insecure_clone()],
+        )
+        .expect("Failed to register program");
+
+        self
+    }
+
+    pub fn with_referrer(mut self, wallet: Pubkey) -> Self {
+        Instructions::register_referrer(
+            &mut self.svm,
+            wallet,
+            REFERRER_SHARE_BPS,
+            self.admin_1.pubkey(),
+            self.payer.pubkey(),
+            &[&self.admin_1.insecure_clone(), &self.payer.insecure_clone()],
+        )
+        .expect("Failed to register referrer");
+
+        self
+    }
+
+    pub fn with_revenue_shares(mut self, shares: Vec<RevenueShare>) -> Self {
+        Instructions::set_revenue_shares(
+            &mut self.svm,
+            shares,
+            self.admin_1.pubkey(),
+            self.payer.pubkey(),
+            &[&self.admin_1.insecure_clone(), &self.payer.insecure_clone()],
+        )
+        .expect("Failed to set revenue shares");
+
+        self
+    }
+
+    pub fn with_approval_threshold(mut self, threshold: u8) -> Self {
+        let proposal_id = AccountHelper::get_protocol_config(&self.svm).proposal_count;
+
+        Instructions::create_proposal(
+            &mut self.svm,
+            ProposalAction::SetApprovalThreshold { threshold },
+            self.admin_1.pubkey(),
+            self.payer.pubkey(),
+            &[&self.admin_1.insecure_clone(), &self.payer.insecure_clone()],
+        )
+        .expect("Failed to create approval threshold proposal");
+
+        Instructions::execute_proposal(
+            &mut self.svm,
+            proposal_id,
+            self.admin_1.pubkey(),
+            None,
+            self.admin_1.pubkey(),
+            self.payer.pubkey(),
+            &[&self.admin_1.insecure_clone(), &self.payer.insecure_clone()],
+        )
+        .expect("Failed to execute approval threshold proposal");
+
         self
     }
 
@@ -235,6 +499,8 @@ impl TestFixture {
             mint.pubkey(),
             self.project_authority_1.pubkey(),
             collection,
+            None,
+            None,
             &[
                 &self.payer.insecure_clone(),
                 &asset_owner.insecure_clone(),
@@ -247,6 +513,119 @@ impl TestFixture {
         self
     }
 
+    /// Mints as a collector in public mode, with the payer as `authority`.
+    pub fn with_public_minted_asset(
+        mut self,
+        project_id: u64,
+        asset_owner: &Keypair,
+        mint: &Keypair,
+    ) -> Self {
+        Instructions::mint_asset(
+            &mut self.svm,
+            MINTER_NAME,
+            Some(ASSET_NAME.to_string()),
+            Some(ASSET_URI.to_string()),
+            None,
+            project_id,
+            self.project_owner.pubkey(),
+            self.payer.pubkey(),
+            asset_owner.pubkey(),
+            mint.pubkey(),
+            self.payer.pubkey(),
+            None,
+            None,
+            None,
+            &[
+                &self.payer.insecure_clone(),
+                &asset_owner.insecure_clone(),
+                &mint.insecure_clone(),
+            ],
+        )
+        .expect("Failed to mint asset");
+
+        self
+    }
+
+    pub fn with_public_mint(mut self, project_id: u64) -> Self {
+        Instructions::set_public_mint(
+            &mut self.svm,
+            MINTER_NAME,
+            true,
+            project_id,
+            self.project_owner.pubkey(),
+            self.project_authority_1.pubkey(),
+            self.payer.pubkey(),
+            &[
+                &self.payer.insecure_clone(),
+                &self.project_authority_1.insecure_clone(),
+            ],
+        )
+        .expect("Failed to set public mint");
+
+        self
+    }
+
+    pub fn with_mint_phases(mut self, project_id: u64) -> Self {
+        Instructions::set_mint_phases(
+            &mut self.svm,
+            MINTER_NAME,
+            default_mint_phases(),
+            project_id,
+            self.project_owner.pubkey(),
+            self.project_authority_1.pubkey(),
+            self.payer.pubkey(),
+            &[
+                &self.payer.insecure_clone(),
+                &self.project_authority_1.insecure_clone(),
+            ],
+        )
+        .expect("Failed to set mint phases");
+
+        self
+    }
+
+    /// Same phases as `with_mint_phases`, with the first one limited to `merkle_root`.
+    pub fn with_allowlist_mint_phases(mut self, project_id: u64, merkle_root: [u8; 32]) -> Self {
+        let mut phases = default_mint_phases();
+        phases[0].merkle_root = Some(merkle_root);
+
+        Instructions::set_mint_phases(
+            &mut self.svm,
+            MINTER_NAME,
+            phases,
+            project_id,
+            self.project_owner.pubkey(),
+            self.project_authority_1.pubkey(),
+            self.payer.pubkey(),
+            &[
+                &self.payer.insecure_clone(),
+                &self.project_authority_1.insecure_clone(),
+            ],
+        )
+        .expect("Failed to set allowlist mint phases");
+
+        self
+    }
+
+    pub fn with_wallet_mint_limit(mut self, project_id: u64) -> Self {
+        Instructions::set_wallet_mint_limit(
+            &mut self.svm,
+            MINTER_NAME,
+            WALLET_MINT_LIMIT,
+            project_id,
+            self.project_owner.pubkey(),
+            self.project_authority_1.pubkey(),
+            self.payer.pubkey(),
+            &[
+                &self.payer.insecure_clone(),
+                &self.project_authority_1.insecure_clone(),
+            ],
+        )
+        .expect("Failed to set wallet mint limit");
+
+        self
+    }
+
     pub fn with_create_trade_hub(mut self, project_id: u64) -> Self {
         Instructions::create_trade_hub(
             &mut self.svm,
@@ -336,6 +715,7 @@ impl TestFixture {
             TRADE_HUB_NAME,
             &project_config_pda,
             collection,
+            None,
             LISTING_PRICE,
             &[&buyer.insecure_clone()],
         )
@@ -1,126 +1,1174 @@
 use litesvm::{types::TransactionResult, LiteSVM};
 use sol_mind_protocol_client::nft_operations::{
     instructions::{
+        CloseMintRecordBuilder, CloseMinterConfigBuilder, CloseTradeHubBuilder,
         CreateMinterConfigBuilder, CreateTradeHubBuilder, DelistAssetBuilder, ListAssetBuilder,
-        MintAssetBuilder, PurchaseAssetBuilder,
+        MintAssetBuilder, PurchaseAssetBuilder, SetMintPhasesBuilder, SetPublicMintBuilder,
+        SetWalletMintLimitBuilder,
     },
-    types::AssetsConfig,
+    types::{AllowlistProof, AssetsConfig, MintPhase},
 };
 use sol_mind_protocol_client::{
     dac_manager::instructions::{
-        ActivateAgentBuilder, ClaimComputeNodeBuilder, CreateAgentBuilder, RegisterComputeNodeBuilder, SubmitTaskBuilder,
+        ActivateAgentBuilder, ClaimComputeNodeBuilder, CreateAgentBuilder,
+        RegisterComputeNodeBuilder, SubmitTaskBuilder,
     },
     instructions::{
-        CreateProjectBuilder, InitializeProtocolBuilder, TransferProjectFeesBuilder,
-        TransferProtocolFeesBuilder, UpdateFeesBuilder, UpdateSingleFeeBuilder,
+        AcceptOwnerBuilder, AddAdminBuilder, AddWhitelistTransferAddrBuilder,
+        ApproveProposalBuilder, CancelPendingFeesBuilder, CancelProposalBuilder,
+        ChargeGenericFeeBuilder, ClaimReferralFeesBuilder, CloseProjectBuilder,
+        CloseProjectFeeOverrideBuilder, CloseTreasuryPolicyBuilder, CreateProjectBuilder,
+        CreateProposalBuilder, DistributeProtocolFeesBuilder, ExecuteProposalBuilder,
+        GrantProjectRoleBuilder, InitializeProtocolBuilder, MigrateProjectConfigBuilder,
+        MigrateProtocolConfigBuilder, PaySubscriptionBuilder, ProposeOwnerBuilder,
+        RegisterProgramBuilder, RegisterReferrerBuilder, RemoveAdminBuilder, RemoveReferrerBuilder,
+        RemoveSubscriptionPlanBuilder, RemoveWhitelistTransferAddrBuilder, RenameProjectBuilder,
+        RevokeProjectRoleBuilder, RotateAdminBuilder, SetOperationPausedBuilder,
+        SetProjectFeeOverrideBuilder, SetProtocolPausedBuilder, SetRevenueSharesBuilder,
+        SetSubscriptionPlanBuilder, SetTreasuryPolicyBuilder, TransferProjectFeesBuilder,
+        TransferProjectTokenFeesBuilder, TransferProtocolFeesBuilder,
+        TransferProtocolTokenFeesBuilder, UnregisterProgramBuilder, UpdateFeesBuilder,
+        UpdateProjectBuilder, UpdateReferrerBuilder, UpdateSingleFeeBuilder,
+    },
+    types::{
+        Fee, FeesStructure, Operation, OperationFeeOverride, ProposalAction, RevenueShare, Role,
+        SubscriptionPlan,
     },
-    types::{Fee, FeesStructure, Operation},
 };
 use solana_pubkey::Pubkey;
-use solana_sdk::signature::Keypair;
+use solana_sdk::{instruction::AccountMeta, signature::Keypair};
 use solana_sdk_ids::system_program::ID as SYSTEM_PROGRAM_ID;
+use std::str::FromStr;
+
+use super::accounts::AccountHelper;
+use super::test_data::TOKEN_PROGRAM_ID;
+
+pub struct Instructions;
+
+impl Instructions {
+    pub fn initialize_protocol(
+        svm: &mut LiteSVM,
+        admins: Vec<Pubkey>,
+        whitelist_transfer_addrs: Vec<Pubkey>,
+        fees: FeesStructure,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        Self::initialize_protocol_instance(
+            svm,
+            0,
+            admins,
+            whitelist_transfer_addrs,
+            fees,
+            None,
+            payer,
+            signing_keypairs,
+        )
+    }
+
+    /// `root_admin` must be an admin of instance 0 whenever `instance_id` is not 0.
+    pub fn initialize_protocol_instance(
+        svm: &mut LiteSVM,
+        instance_id: u64,
+        admins: Vec<Pubkey>,
+        whitelist_transfer_addrs: Vec<Pubkey>,
+        fees: FeesStructure,
+        root_admin: Option<Pubkey>,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let protocol_config_pda = AccountHelper::find_protocol_instance_pda(instance_id).0;
+
+        let fee_stats_pda = AccountHelper::find_fee_stats_pda(&protocol_config_pda).0;
+
+        let instruction = InitializeProtocolBuilder::new()
+            .payer(payer)
+            .protocol_config(protocol_config_pda)
+            .fee_stats(fee_stats_pda)
+            .root_protocol_config(root_admin.map(|_| AccountHelper::find_protocol_config_pda().0))
+            .root_admin(root_admin)
+            .system_program(SYSTEM_PROGRAM_ID)
+            .instance_id(instance_id)
+            .admins(admins)
+            .whitelist_transfer_addrs(whitelist_transfer_addrs)
+            .fees(fees)
+            .instruction();
+
+        utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
+    }
+
+    pub fn create_project(
+        svm: &mut LiteSVM,
+        name: String,
+        description: String,
+        owner: Pubkey,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        Self::create_instance_project(
+            svm,
+            0,
+            name,
+            description,
+            owner,
+            None,
+            payer,
+            signing_keypairs,
+        )
+    }
+
+    pub fn create_instance_project(
+        svm: &mut LiteSVM,
+        instance_id: u64,
+        name: String,
+        description: String,
+        owner: Pubkey,
+        referrer: Option<Pubkey>,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let protocol_config_pda = AccountHelper::find_protocol_instance_pda(instance_id).0;
+        let project_id = AccountHelper::get_protocol_instance(svm, instance_id).project_count;
+        let project_config_pda =
+            AccountHelper::find_instance_project_pda(instance_id, &owner, project_id).0;
+        let project_registry_pda =
+            AccountHelper::find_instance_project_registry_pda(instance_id, &name).0;
+        let treasury_pda = AccountHelper::find_treasury_pda(&project_config_pda).0;
+        let protocol_treasury_pda = AccountHelper::find_treasury_pda(&protocol_config_pda).0;
+        let project_fee_stats_pda = AccountHelper::find_fee_stats_pda(&project_config_pda).0;
+        let protocol_fee_stats_pda = AccountHelper::find_fee_stats_pda(&protocol_config_pda).0;
+        let referrer_pda = referrer
+            .map(|wallet| AccountHelper::find_instance_referrer_pda(instance_id, &wallet).0);
+
+        let instruction = CreateProjectBuilder::new()
+            .owner(owner)
+            .project_config(project_config_pda)
+            .project_registry(project_registry_pda)
+            .protocol_config(protocol_config_pda)
+            .treasury(treasury_pda)
+            .protocol_treasury(protocol_treasury_pda)
+            .project_fee_stats(project_fee_stats_pda)
+            .protocol_fee_stats(protocol_fee_stats_pda)
+            .referrer(referrer_pda)
+            .system_program(SYSTEM_PROGRAM_ID)
+            .name(name)
+            .description(description)
+            .instruction();
+
+        utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
+    }
+
+    pub fn create_project_with_token_fee(
+        svm: &mut LiteSVM,
+        name: String,
+        description: String,
+        owner: Pubkey,
+        fee_mint: Pubkey,
+        fee_payer_token_account: Pubkey,
+        protocol_treasury_token_account: Pubkey,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+        let project_id = AccountHelper::get_protocol_config(svm).project_count;
+        let project_config_pda = AccountHelper::find_project_pda(&owner, project_id).0;
+        let project_registry_pda = AccountHelper::find_project_registry_pda(&name).0;
+        let treasury_pda = AccountHelper::find_treasury_pda(&project_config_pda).0;
+        let protocol_treasury_pda = AccountHelper::find_treasury_pda(&protocol_config_pda).0;
+        let project_fee_stats_pda = AccountHelper::find_fee_stats_pda(&project_config_pda).0;
+        let protocol_fee_stats_pda = AccountHelper::find_fee_stats_pda(&protocol_config_pda).0;
+        let token_program_id =
+            Pubkey::from_str(TOKEN_PROGRAM_ID).expect("Invalid token program ID");
+
+        let instruction = CreateProjectBuilder::new()
+            .owner(owner)
+            .project_config(project_config_pda)
+            .project_registry(project_registry_pda)
+            .protocol_config(protocol_config_pda)
+            .treasury(treasury_pda)
+            .protocol_treasury(protocol_treasury_pda)
+            .project_fee_stats(project_fee_stats_pda)
+            .protocol_fee_stats(protocol_fee_stats_pda)
+            .fee_mint(Some(fee_mint))
+            .fee_payer_token_account(Some(fee_payer_token_account))
+            .protocol_treasury_token_account(Some(protocol_treasury_token_account))
+            .token_program(Some(token_program_id))
+            .system_program(SYSTEM_PROGRAM_ID)
+            .name(name)
+            .description(description)
+            .instruction();
+
+        utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
+    }
+
+    pub fn update_project(
+        svm: &mut LiteSVM,
+        project_id: u64,
+        creator: Pubkey,
+        description: Option<String>,
+        owner: Pubkey,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let project_config_pda = AccountHelper::find_project_pda(&creator, project_id).0;
+
+        let mut builder = UpdateProjectBuilder::new();
+        builder.owner(owner).project_config(project_config_pda);
+
+        if let Some(description) = description {
+            builder.description(description);
+        }
+
+        utils::send_transaction(svm, &[builder.instruction()], &payer, signing_keypairs)
+    }
+
+    pub fn rename_project(
+        svm: &mut LiteSVM,
+        project_id: u64,
+        creator: Pubkey,
+        new_name: String,
+        owner: Pubkey,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let project_config_pda = AccountHelper::find_project_pda(&creator, project_id).0;
+        let project_config = AccountHelper::get_project_config(svm, &creator, project_id);
+        let project_registry_pda = AccountHelper::find_project_registry_pda(&project_config.name).0;
+        let new_project_registry_pda = AccountHelper::find_project_registry_pda(&new_name).0;
+
+        let instruction = RenameProjectBuilder::new()
+            .owner(owner)
+            .project_config(project_config_pda)
+            .project_registry(project_registry_pda)
+            .new_project_registry(new_project_registry_pda)
+            .system_program(SYSTEM_PROGRAM_ID)
+            .new_name(new_name)
+            .instruction();
+
+        utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
+    }
+
+    pub fn propose_owner(
+        svm: &mut LiteSVM,
+        project_id: u64,
+        creator: Pubkey,
+        new_owner: Pubkey,
+        owner: Pubkey,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let project_config_pda = AccountHelper::find_project_pda(&creator, project_id).0;
+
+        let instruction = ProposeOwnerBuilder::new()
+            .owner(owner)
+            .project_config(project_config_pda)
+            .new_owner(new_owner)
+            .instruction();
+
+        utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
+    }
+
+    pub fn accept_owner(
+        svm: &mut LiteSVM,
+        project_id: u64,
+        creator: Pubkey,
+        new_owner: Pubkey,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let project_config_pda = AccountHelper::find_project_pda(&creator, project_id).0;
+
+        let instruction = AcceptOwnerBuilder::new()
+            .new_owner(new_owner)
+            .project_config(project_config_pda)
+            .instruction();
+
+        utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
+    }
+
+    /// `treasury_token_accounts` are the treasury's token accounts to close, along with
+    /// their token programs.
+    pub fn close_project(
+        svm: &mut LiteSVM,
+        project_id: u64,
+        creator: Pubkey,
+        owner: Pubkey,
+        treasury_token_accounts: &[AccountMeta],
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let project_config_pda = AccountHelper::find_project_pda(&creator, project_id).0;
+        let project_config = AccountHelper::get_project_config(svm, &creator, project_id);
+        let project_registry_pda = AccountHelper::find_project_registry_pda(&project_config.name).0;
+        let treasury_pda = AccountHelper::find_treasury_pda(&project_config_pda).0;
+        let fee_stats_pda = AccountHelper::find_fee_stats_pda(&project_config_pda).0;
+        let treasury_policy_pda = AccountHelper::find_treasury_policy_pda(&project_config_pda).0;
+        let subscription_pda = AccountHelper::find_subscription_pda(&project_config_pda).0;
+        let fee_override_pda = AccountHelper::find_project_fee_override_pda(&project_config_pda).0;
+
+        let instruction = CloseProjectBuilder::new()
+            .owner(owner)
+            .project_config(project_config_pda)
+            .project_registry(project_registry_pda)
+            .treasury(treasury_pda)
+            .fee_stats(fee_stats_pda)
+            .treasury_policy(treasury_policy_pda)
+            .subscription(subscription_pda)
+            .fee_override(fee_override_pda)
+            .system_program(SYSTEM_PROGRAM_ID)
+            .add_remaining_accounts(treasury_token_accounts)
+            .instruction();
+
+        utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
+    }
+
+    pub fn grant_project_role(
+        svm: &mut LiteSVM,
+        project_id: u64,
+        creator: Pubkey,
+        grantee: Pubkey,
+        role: Role,
+        authority: Pubkey,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let project_config_pda = AccountHelper::find_project_pda(&creator, project_id).0;
+        let authority_role_pda =
+            AccountHelper::find_project_role_pda(&project_config_pda, &authority).0;
+        let project_role_pda =
+            AccountHelper::find_project_role_pda(&project_config_pda, &grantee).0;
+
+        let instruction = GrantProjectRoleBuilder::new()
+            .payer(payer)
+            .authority(authority)
+            .authority_role(authority_role_pda)
+            .project_config(project_config_pda)
+            .project_role(project_role_pda)
+            .grantee(grantee)
+            .role(role)
+            .instruction();
+
+        utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
+    }
+
+    pub fn revoke_project_role(
+        svm: &mut LiteSVM,
+        project_id: u64,
+        creator: Pubkey,
+        grantee: Pubkey,
+        role: Role,
+        authority: Pubkey,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let project_config_pda = AccountHelper::find_project_pda(&creator, project_id).0;
+        let authority_role_pda =
+            AccountHelper::find_project_role_pda(&project_config_pda, &authority).0;
+        let project_role_pda =
+            AccountHelper::find_project_role_pda(&project_config_pda, &grantee).0;
+
+        let instruction = RevokeProjectRoleBuilder::new()
+            .authority(authority)
+            .authority_role(authority_role_pda)
+            .project_config(project_config_pda)
+            .project_role(project_role_pda)
+            .role(role)
+            .instruction();
+
+        utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
+    }
+
+    pub fn set_treasury_policy(
+        svm: &mut LiteSVM,
+        project_id: u64,
+        destinations: Vec<Pubkey>,
+        epoch_cap: Option<u64>,
+        owner: Pubkey,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let project_config_pda = AccountHelper::find_project_pda(&owner, project_id).0;
+        let treasury_policy_pda = AccountHelper::find_treasury_policy_pda(&project_config_pda).0;
+
+        let instruction = SetTreasuryPolicyBuilder::new()
+            .payer(payer)
+            .owner(owner)
+            .project_config(project_config_pda)
+            .treasury_policy(treasury_policy_pda)
+            .destinations(destinations)
+            .epoch_cap(epoch_cap)
+            .instruction();
+
+        utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
+    }
+
+    pub fn close_treasury_policy(
+        svm: &mut LiteSVM,
+        project_id: u64,
+        owner: Pubkey,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let project_config_pda = AccountHelper::find_project_pda(&owner, project_id).0;
+        let treasury_policy_pda = AccountHelper::find_treasury_policy_pda(&project_config_pda).0;
+
+        let instruction = CloseTreasuryPolicyBuilder::new()
+            .owner(owner)
+            .project_config(project_config_pda)
+            .treasury_policy(treasury_policy_pda)
+            .instruction();
+
+        utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
+    }
+
+    pub fn update_fees(
+        svm: &mut LiteSVM,
+        fees: FeesStructure,
+        admin: Pubkey,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+
+        let instruction = UpdateFeesBuilder::new()
+            .admin(admin)
+            .protocol_config(protocol_config_pda)
+            .fees(fees)
+            .instruction();
+
+        utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
+    }
+
+    pub fn update_single_fee(
+        svm: &mut LiteSVM,
+        operation: Operation,
+        fee: Fee,
+        admin: Pubkey,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+
+        let instruction = UpdateSingleFeeBuilder::new()
+            .admin(admin)
+            .protocol_config(protocol_config_pda)
+            .operation(operation)
+            .fee(fee)
+            .instruction();
+
+        utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
+    }
+
+    pub fn cancel_pending_fees(
+        svm: &mut LiteSVM,
+        admin: Pubkey,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+
+        let instruction = CancelPendingFeesBuilder::new()
+            .admin(admin)
+            .protocol_config(protocol_config_pda)
+            .instruction();
+
+        utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
+    }
+
+    pub fn set_protocol_paused(
+        svm: &mut LiteSVM,
+        paused: bool,
+        admin: Pubkey,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+
+        let instruction = SetProtocolPausedBuilder::new()
+            .admin(admin)
+            .protocol_config(protocol_config_pda)
+            .paused(paused)
+            .instruction();
+
+        utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
+    }
+
+    pub fn set_operation_paused(
+        svm: &mut LiteSVM,
+        operation: Operation,
+        paused: bool,
+        admin: Pubkey,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+
+        let instruction = SetOperationPausedBuilder::new()
+            .admin(admin)
+            .protocol_config(protocol_config_pda)
+            .operation(operation)
+            .paused(paused)
+            .instruction();
+
+        utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
+    }
+
+    pub fn set_project_fee_override(
+        svm: &mut LiteSVM,
+        overrides: Vec<OperationFeeOverride>,
+        expires_at: Option<i64>,
+        project_config_pda: Pubkey,
+        admin: Pubkey,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+        let project_fee_override_pda =
+            AccountHelper::find_project_fee_override_pda(&project_config_pda).0;
+
+        let mut builder = SetProjectFeeOverrideBuilder::new();
+
+        builder
+            .payer(payer)
+            .admin(admin)
+            .protocol_config(protocol_config_pda)
+            .project_config(project_config_pda)
+            .project_fee_override(project_fee_override_pda)
+            .overrides(overrides);
+
+        if let Some(expires_at) = expires_at {
+            builder.expires_at(expires_at);
+        }
+
+        utils::send_transaction(svm, &[builder.instruction()], &payer, signing_keypairs)
+    }
+
+    pub fn close_project_fee_override(
+        svm: &mut LiteSVM,
+        project_config_pda: Pubkey,
+        admin: Pubkey,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+        let project_fee_override_pda =
+            AccountHelper::find_project_fee_override_pda(&project_config_pda).0;
+
+        let instruction = CloseProjectFeeOverrideBuilder::new()
+            .admin(admin)
+            .payer(payer)
+            .protocol_config(protocol_config_pda)
+            .project_config(project_config_pda)
+            .project_fee_override(project_fee_override_pda)
+            .instruction();
+
+        utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
+    }
+
+    pub fn register_program(
+        svm: &mut LiteSVM,
+        program_id: Pubkey,
+        admin: Pubkey,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+        let registered_program_pda = AccountHelper::find_registered_program_pda(&program_id).0;
+
+        let instruction = RegisterProgramBuilder::new()
+            .payer(payer)
+            .admin(admin)
+            .protocol_config(protocol_config_pda)
+            .registered_program(registered_program_pda)
+            .program_id(program_id)
+            .instruction();
+
+        utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
+    }
+
+    pub fn unregister_program(
+        svm: &mut LiteSVM,
+        program_id: Pubkey,
+        admin: Pubkey,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+        let registered_program_pda = AccountHelper::find_registered_program_pda(&program_id).0;
+
+        let instruction = UnregisterProgramBuilder::new()
+            .admin(admin)
+            .payer(payer)
+            .protocol_config(protocol_config_pda)
+            .registered_program(registered_program_pda)
+            .instruction();
+
+        utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
+    }
+
+    pub fn register_referrer(
+        svm: &mut LiteSVM,
+        wallet: Pubkey,
+        share_bps: u16,
+        admin: Pubkey,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+        let referrer_pda = AccountHelper::find_referrer_pda(&wallet).0;
+
+        let instruction = RegisterReferrerBuilder::new()
+            .payer(payer)
+            .admin(admin)
+            .protocol_config(protocol_config_pda)
+            .referrer(referrer_pda)
+            .wallet(wallet)
+            .share_bps(share_bps)
+            .instruction();
+
+        utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
+    }
+
+    pub fn update_referrer(
+        svm: &mut LiteSVM,
+        wallet: Pubkey,
+        share_bps: u16,
+        admin: Pubkey,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+        let referrer_pda = AccountHelper::find_referrer_pda(&wallet).0;
+
+        let instruction = UpdateReferrerBuilder::new()
+            .admin(admin)
+            .protocol_config(protocol_config_pda)
+            .referrer(referrer_pda)
+            .share_bps(share_bps)
+            .instruction();
+
+        utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
+    }
+
+    pub fn remove_referrer(
+        svm: &mut LiteSVM,
+        wallet: Pubkey,
+        admin: Pubkey,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+        let referrer_pda = AccountHelper::find_referrer_pda(&wallet).0;
+
+        let instruction = RemoveReferrerBuilder::new()
+            .admin(admin)
+            .protocol_config(protocol_config_pda)
+            .wallet(wallet)
+            .referrer(referrer_pda)
+            .instruction();
+
+        utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
+    }
+
+    pub fn claim_referral_fees(
+        svm: &mut LiteSVM,
+        wallet: Pubkey,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let referrer_pda = AccountHelper::find_referrer_pda(&wallet).0;
+
+        let instruction = ClaimReferralFeesBuilder::new()
+            .wallet(wallet)
+            .referrer(referrer_pda)
+            .instruction();
+
+        utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
+    }
+
+    pub fn set_subscription_plan(
+        svm: &mut LiteSVM,
+        plan: SubscriptionPlan,
+        admin: Pubkey,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+
+        let instruction = SetSubscriptionPlanBuilder::new()
+            .admin(admin)
+            .protocol_config(protocol_config_pda)
+            .plan(plan)
+            .instruction();
+
+        utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
+    }
+
+    pub fn remove_subscription_plan(
+        svm: &mut LiteSVM,
+        plan_id: u8,
+        admin: Pubkey,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+
+        let instruction = RemoveSubscriptionPlanBuilder::new()
+            .admin(admin)
+            .protocol_config(protocol_config_pda)
+            .plan_id(plan_id)
+            .instruction();
+
+        utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
+    }
+
+    pub fn pay_subscription(
+        svm: &mut LiteSVM,
+        project_id: u64,
+        plan_id: u8,
+        epochs: u64,
+        owner: Pubkey,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+        let project_config_pda = AccountHelper::find_project_pda(&owner, project_id).0;
+        let subscription_pda = AccountHelper::find_subscription_pda(&project_config_pda).0;
+        let protocol_treasury_pda = AccountHelper::find_treasury_pda(&protocol_config_pda).0;
+        let protocol_fee_stats_pda = AccountHelper::find_fee_stats_pda(&protocol_config_pda).0;
+
+        let instruction = PaySubscriptionBuilder::new()
+            .payer(payer)
+            .owner(owner)
+            .project_config(project_config_pda)
+            .subscription(subscription_pda)
+            .protocol_config(protocol_config_pda)
+            .protocol_treasury(protocol_treasury_pda)
+            .protocol_fee_stats(protocol_fee_stats_pda)
+            .plan_id(plan_id)
+            .epochs(epochs)
+            .instruction();
+
+        utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
+    }
+
+    pub fn charge_generic_fee(
+        svm: &mut LiteSVM,
+        program_id: Pubkey,
+        base_amount: Option<u64>,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+        let protocol_treasury_pda = AccountHelper::find_treasury_pda(&protocol_config_pda).0;
+        let protocol_fee_stats_pda = AccountHelper::find_fee_stats_pda(&protocol_config_pda).0;
+        let registered_program_pda = AccountHelper::find_registered_program_pda(&program_id).0;
+
+        let mut builder = ChargeGenericFeeBuilder::new();
+        builder
+            .payer(payer)
+            .protocol_config(protocol_config_pda)
+            .protocol_treasury(protocol_treasury_pda)
+            .protocol_fee_stats(protocol_fee_stats_pda)
+            .registered_program(registered_program_pda);
+        if let Some(base_amount) = base_amount {
+            builder.base_amount(base_amount);
+        }
+
+        utils::send_transaction(svm, &[builder.instruction()], &payer, signing_keypairs)
+    }
+
+    pub fn migrate_protocol_config(
+        svm: &mut LiteSVM,
+        admin: Pubkey,
+        co_signers: &[Pubkey],
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+        let co_signers: Vec<AccountMeta> = co_signers
+            .iter()
+            .map(|co_signer| AccountMeta::new_readonly(*co_signer, true))
+            .collect();
+
+        let instruction = MigrateProtocolConfigBuilder::new()
+            .admin(admin)
+            .protocol_config(protocol_config_pda)
+            .instance_id(0)
+            .add_remaining_accounts(&co_signers)
+            .instruction();
+
+        utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
+    }
+
+    /// `project_accounts` are the minter configs, trade hubs and role PDAs of the project for
+    /// the migration to count.
+    pub fn migrate_project_config(
+        svm: &mut LiteSVM,
+        project_config: Pubkey,
+        project_name: &str,
+        admin: Pubkey,
+        co_signers: &[Pubkey],
+        project_accounts: &[Pubkey],
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+        let project_registry_pda = AccountHelper::find_project_registry_pda(project_name).0;
+        let remaining_accounts: Vec<AccountMeta> = co_signers
+            .iter()
+            .map(|co_signer| AccountMeta::new_readonly(*co_signer, true))
+            .chain(
+                project_accounts
+                    .iter()
+                    .map(|account| AccountMeta::new_readonly(*account, false)),
+            )
+            .collect();
+
+        let instruction = MigrateProjectConfigBuilder::new()
+            .admin(admin)
+            .protocol_config(protocol_config_pda)
+            .project_config(project_config)
+            .project_registry(project_registry_pda)
+            .add_remaining_accounts(&remaining_accounts)
+            .instruction();
+
+        utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
+    }
+
+    pub fn add_admin(
+        svm: &mut LiteSVM,
+        new_admin: Pubkey,
+        admin: Pubkey,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+
+        let instruction = AddAdminBuilder::new()
+            .admin(admin)
+            .protocol_config(protocol_config_pda)
+            .new_admin(new_admin)
+            .instruction();
+
+        utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
+    }
+
+    pub fn remove_admin(
+        svm: &mut LiteSVM,
+        old_admin: Pubkey,
+        admin: Pubkey,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+
+        let instruction = RemoveAdminBuilder::new()
+            .admin(admin)
+            .protocol_config(protocol_config_pda)
+            .old_admin(old_admin)
+            .instruction();
+
+        utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
+    }
+
+    pub fn rotate_admin(
+        svm: &mut LiteSVM,
+        old_admin: Pubkey,
+        new_admin: Pubkey,
+        admin: Pubkey,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+
+        let instruction = RotateAdminBuilder::new()
+            .admin(admin)
+            .protocol_config(protocol_config_pda)
+            .old_admin(old_admin)
+            .new_admin(new_admin)
+            .instruction();
+
+        utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
+    }
+
+    pub fn add_whitelist_transfer_addr(
+        svm: &mut LiteSVM,
+        address: Pubkey,
+        label: Option<String>,
+        transfer_cap: Option<u64>,
+        admin: Pubkey,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+
+        let mut builder = AddWhitelistTransferAddrBuilder::new();
+        builder
+            .admin(admin)
+            .protocol_config(protocol_config_pda)
+            .address(address);
+
+        if let Some(label) = label {
+            builder.label(label);
+        }
+        if let Some(transfer_cap) = transfer_cap {
+            builder.transfer_cap(transfer_cap);
+        }
+
+        let instruction = builder.instruction();
+
+        utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
+    }
+
+    pub fn remove_whitelist_transfer_addr(
+        svm: &mut LiteSVM,
+        address: Pubkey,
+        admin: Pubkey,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+
+        let instruction = RemoveWhitelistTransferAddrBuilder::new()
+            .admin(admin)
+            .protocol_config(protocol_config_pda)
+            .address(address)
+            .instruction();
+
+        utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
+    }
+
+    pub fn create_proposal(
+        svm: &mut LiteSVM,
+        action: ProposalAction,
+        admin: Pubkey,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+        let proposal_count = AccountHelper::get_protocol_config(svm).proposal_count;
+        let proposal_pda = AccountHelper::find_proposal_pda(proposal_count).0;
+
+        let instruction = CreateProposalBuilder::new()
+            .payer(payer)
+            .admin(admin)
+            .protocol_config(protocol_config_pda)
+            .proposal(proposal_pda)
+            .system_program(SYSTEM_PROGRAM_ID)
+            .action(action)
+            .instruction();
+
+        utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
+    }
+
+    pub fn approve_proposal(
+        svm: &mut LiteSVM,
+        proposal_id: u64,
+        admin: Pubkey,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+        let proposal_pda = AccountHelper::find_proposal_pda(proposal_id).0;
+
+        let instruction = ApproveProposalBuilder::new()
+            .admin(admin)
+            .protocol_config(protocol_config_pda)
+            .proposal(proposal_pda)
+            .instruction();
+
+        utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
+    }
+
+    pub fn execute_proposal(
+        svm: &mut LiteSVM,
+        proposal_id: u64,
+        proposer: Pubkey,
+        to: Option<Pubkey>,
+        admin: Pubkey,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        Self::execute_proposal_with(
+            svm,
+            proposal_id,
+            proposer,
+            admin,
+            payer,
+            signing_keypairs,
+            |builder| {
+                builder.to(to);
+            },
+        )
+    }
 
-use super::accounts::AccountHelper;
+    /// Executes a proposal whose action needs accounts beyond the common ones, which
+    /// `accounts` sets on the builder.
+    pub fn execute_proposal_with(
+        svm: &mut LiteSVM,
+        proposal_id: u64,
+        proposer: Pubkey,
+        admin: Pubkey,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+        accounts: impl FnOnce(&mut ExecuteProposalBuilder),
+    ) -> TransactionResult {
+        let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+        let proposal_pda = AccountHelper::find_proposal_pda(proposal_id).0;
+        let treasury_pda = AccountHelper::find_treasury_pda(&protocol_config_pda).0;
+        let fee_stats_pda = AccountHelper::find_fee_stats_pda(&protocol_config_pda).0;
 
-pub struct Instructions;
+        let mut builder = ExecuteProposalBuilder::new();
+        builder
+            .admin(admin)
+            .protocol_config(protocol_config_pda)
+            .proposal(proposal_pda)
+            .proposer(proposer)
+            .treasury(treasury_pda)
+            .fee_stats(Some(fee_stats_pda))
+            .system_program(SYSTEM_PROGRAM_ID);
+        accounts(&mut builder);
 
-impl Instructions {
-    pub fn initialize_protocol(
+        utils::send_transaction(svm, &[builder.instruction()], &payer, signing_keypairs)
+    }
+
+    pub fn cancel_proposal(
         svm: &mut LiteSVM,
-        admins: Vec<Pubkey>,
-        whitelist_transfer_addrs: Vec<Pubkey>,
-        fees: FeesStructure,
+        proposal_id: u64,
+        proposer: Pubkey,
+        admin: Pubkey,
+        co_signers: &[Pubkey],
         payer: Pubkey,
         signing_keypairs: &[&Keypair],
     ) -> TransactionResult {
         let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+        let proposal_pda = AccountHelper::find_proposal_pda(proposal_id).0;
+        let co_signers: Vec<AccountMeta> = co_signers
+            .iter()
+            .map(|co_signer| AccountMeta::new_readonly(*co_signer, true))
+            .collect();
 
-        let instruction = InitializeProtocolBuilder::new()
-            .payer(payer)
+        let instruction = CancelProposalBuilder::new()
+            .admin(admin)
             .protocol_config(protocol_config_pda)
-            .system_program(SYSTEM_PROGRAM_ID)
-            .admins(admins)
-            .whitelist_transfer_addrs(whitelist_transfer_addrs)
-            .fees(fees)
+            .proposal(proposal_pda)
+            .proposer(proposer)
+            .add_remaining_accounts(&co_signers)
             .instruction();
 
         utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
     }
 
-    pub fn create_project(
+    pub fn transfer_project_fees(
         svm: &mut LiteSVM,
+        amount: u64,
+        creator: Pubkey,
+        authority: Pubkey,
+        to: Pubkey,
         project_id: u64,
-        name: String,
-        description: String,
-        owner: Pubkey,
-        authorities: Vec<Pubkey>,
         payer: Pubkey,
         signing_keypairs: &[&Keypair],
     ) -> TransactionResult {
-        let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
-        let project_config_pda = AccountHelper::find_project_pda(&owner, project_id).0;
+        let project_config_pda = AccountHelper::find_project_pda(&creator, project_id).0;
+        let authority_role_pda =
+            AccountHelper::find_project_role_pda(&project_config_pda, &authority).0;
         let treasury_pda = AccountHelper::find_treasury_pda(&project_config_pda).0;
-        let protocol_treasury_pda = AccountHelper::find_treasury_pda(&protocol_config_pda).0;
+        let fee_stats_pda = AccountHelper::find_fee_stats_pda(&project_config_pda).0;
+        // The policy is optional, so only pass it once it has been created
+        let treasury_policy_pda = AccountHelper::find_treasury_policy_pda(&project_config_pda).0;
+        let treasury_policy = svm
+            .get_account(&treasury_policy_pda)
+            .map(|_| treasury_policy_pda);
 
-        let instruction = CreateProjectBuilder::new()
-            .owner(owner)
+        let instruction = TransferProjectFeesBuilder::new()
+            .authority(authority)
+            .authority_role(authority_role_pda)
+            .to(to)
             .project_config(project_config_pda)
-            .protocol_config(protocol_config_pda)
             .treasury(treasury_pda)
-            .protocol_treasury(protocol_treasury_pda)
+            .fee_stats(fee_stats_pda)
+            .treasury_policy(treasury_policy)
             .system_program(SYSTEM_PROGRAM_ID)
-            .project_id(project_id)
-            .name(name)
-            .description(description)
-            .authorities(authorities)
+            .amount(amount)
             .instruction();
 
         utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
     }
 
-    pub fn update_fees(
+    pub fn protocol_fees_transfer(
         svm: &mut LiteSVM,
-        fees: FeesStructure,
+        amount: u64,
         admin: Pubkey,
+        to: Pubkey,
         payer: Pubkey,
         signing_keypairs: &[&Keypair],
     ) -> TransactionResult {
         let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+        let treasury_pda = AccountHelper::find_treasury_pda(&protocol_config_pda).0;
+        let fee_stats_pda = AccountHelper::find_fee_stats_pda(&protocol_config_pda).0;
 
-        let instruction = UpdateFeesBuilder::new()
+        let instruction = TransferProtocolFeesBuilder::new()
             .admin(admin)
+            .to(to)
             .protocol_config(protocol_config_pda)
-            .fees(fees)
+            .treasury(treasury_pda)
+            .fee_stats(fee_stats_pda)
+            .amount(amount)
             .instruction();
 
         utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
     }
 
-    pub fn update_single_fee(
+    pub fn set_revenue_shares(
         svm: &mut LiteSVM,
-        operation: Operation,
-        fee: Fee,
+        shares: Vec<RevenueShare>,
         admin: Pubkey,
         payer: Pubkey,
         signing_keypairs: &[&Keypair],
     ) -> TransactionResult {
         let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
 
-        let instruction = UpdateSingleFeeBuilder::new()
+        let instruction = SetRevenueSharesBuilder::new()
             .admin(admin)
             .protocol_config(protocol_config_pda)
-            .operation(operation)
-            .fee(fee)
+            .shares(shares)
             .instruction();
 
         utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
     }
 
-    pub fn transfer_project_fees(
+    pub fn distribute_protocol_fees(
+        svm: &mut LiteSVM,
+        recipients: &[Pubkey],
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+        let treasury_pda = AccountHelper::find_treasury_pda(&protocol_config_pda).0;
+        let fee_stats_pda = AccountHelper::find_fee_stats_pda(&protocol_config_pda).0;
+        let recipients: Vec<AccountMeta> = recipients
+            .iter()
+            .map(|recipient| AccountMeta::new(*recipient, false))
+            .collect();
+
+        let instruction = DistributeProtocolFeesBuilder::new()
+            .protocol_config(protocol_config_pda)
+            .treasury(treasury_pda)
+            .fee_stats(fee_stats_pda)
+            .add_remaining_accounts(&recipients)
+            .instruction();
+
+        utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
+    }
+
+    pub fn transfer_project_token_fees(
         svm: &mut LiteSVM,
         amount: u64,
         owner: Pubkey,
-        to: Pubkey,
+        mint: Pubkey,
+        treasury_token_account: Pubkey,
+        to_token_account: Pubkey,
         project_id: u64,
         payer: Pubkey,
         signing_keypairs: &[&Keypair],
@@ -128,34 +1176,39 @@ impl Instructions {
         let project_config_pda = AccountHelper::find_project_pda(&owner, project_id).0;
         let treasury_pda = AccountHelper::find_treasury_pda(&project_config_pda).0;
 
-        let instruction = TransferProjectFeesBuilder::new()
+        let instruction = TransferProjectTokenFeesBuilder::new()
             .owner(owner)
-            .to(to)
+            .to_token_account(to_token_account)
             .project_config(project_config_pda)
             .treasury(treasury_pda)
-            .system_program(SYSTEM_PROGRAM_ID)
+            .treasury_token_account(treasury_token_account)
+            .mint(mint)
             .amount(amount)
             .instruction();
 
         utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
     }
 
-    pub fn protocol_fees_transfer(
+    pub fn protocol_token_fees_transfer(
         svm: &mut LiteSVM,
         amount: u64,
         admin: Pubkey,
-        to: Pubkey,
+        mint: Pubkey,
+        treasury_token_account: Pubkey,
+        to_token_account: Pubkey,
         payer: Pubkey,
         signing_keypairs: &[&Keypair],
     ) -> TransactionResult {
         let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
         let treasury_pda = AccountHelper::find_treasury_pda(&protocol_config_pda).0;
 
-        let instruction = TransferProtocolFeesBuilder::new()
+        let instruction = TransferProtocolTokenFeesBuilder::new()
             .admin(admin)
-            .to(to)
+            .to_token_account(to_token_account)
             .protocol_config(protocol_config_pda)
             .treasury(treasury_pda)
+            .treasury_token_account(treasury_token_account)
+            .mint(mint)
             .amount(amount)
             .instruction();
 
@@ -180,7 +1233,15 @@ impl Instructions {
         let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
         let project_config_pda = AccountHelper::find_project_pda(&owner, project_id).0;
         let minter_config_pda = AccountHelper::find_minter_config_pda(&project_config_pda, &name).0;
+        let authority_role_pda =
+            AccountHelper::find_project_role_pda(&project_config_pda, &authority).0;
         let protocol_treasury_pda = AccountHelper::find_treasury_pda(&protocol_config_pda).0;
+        let project_fee_override_pda =
+            AccountHelper::find_project_fee_override_pda(&project_config_pda).0;
+        let subscription_pda = AccountHelper::find_subscription_pda(&project_config_pda).0;
+        let fee_authority_pda = AccountHelper::find_fee_authority_pda().0;
+        let protocol_fee_stats_pda = AccountHelper::find_fee_stats_pda(&protocol_config_pda).0;
+        let project_fee_stats_pda = AccountHelper::find_fee_stats_pda(&project_config_pda).0;
 
         let mut builder = CreateMinterConfigBuilder::new();
 
@@ -190,11 +1251,17 @@ impl Instructions {
             .max_supply(max_supply)
             .payer(payer)
             .authority(authority)
+            .authority_role(authority_role_pda)
             .collection(collection)
             .minter_config(minter_config_pda)
             .project_config(project_config_pda)
             .protocol_config(protocol_config_pda)
-            .protocol_treasury(protocol_treasury_pda);
+            .protocol_treasury(protocol_treasury_pda)
+            .project_fee_override(project_fee_override_pda)
+            .subscription(subscription_pda)
+            .fee_authority(fee_authority_pda)
+            .protocol_fee_stats(protocol_fee_stats_pda)
+            .project_fee_stats(project_fee_stats_pda);
 
         if let Some(assets_config) = assets_config {
             builder.assets_config(assets_config);
@@ -222,13 +1289,25 @@ impl Instructions {
         mint: Pubkey,
         authority: Pubkey,
         collection: Option<Pubkey>,
+        referrer: Option<Pubkey>,
+        allowlist: Option<AllowlistProof>,
         signing_keypairs: &[&Keypair],
     ) -> TransactionResult {
         let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
         let project_config_pda = AccountHelper::find_project_pda(&owner, project_id).0;
         let minter_config_pda =
             AccountHelper::find_minter_config_pda(&project_config_pda, minter_config_name).0;
+        let mint_record_pda =
+            AccountHelper::find_mint_record_pda(&minter_config_pda, &asset_owner).0;
+        let authority_role_pda =
+            AccountHelper::find_project_role_pda(&project_config_pda, &authority).0;
+        let treasury_pda = AccountHelper::find_treasury_pda(&project_config_pda).0;
         let protocol_treasury_pda = AccountHelper::find_treasury_pda(&protocol_config_pda).0;
+        let project_fee_override_pda =
+            AccountHelper::find_project_fee_override_pda(&project_config_pda).0;
+        let fee_authority_pda = AccountHelper::find_fee_authority_pda().0;
+        let protocol_fee_stats_pda = AccountHelper::find_fee_stats_pda(&protocol_config_pda).0;
+        let project_fee_stats_pda = AccountHelper::find_fee_stats_pda(&project_config_pda).0;
 
         let mut builder = MintAssetBuilder::new();
 
@@ -237,25 +1316,164 @@ impl Instructions {
             .owner(asset_owner)
             .mint(mint)
             .authority(authority)
+            .authority_role(authority_role_pda)
             .collection(collection)
             .minter_config(minter_config_pda)
+            .mint_record(Some(mint_record_pda))
             .project_config(project_config_pda)
+            .treasury(treasury_pda)
             .protocol_config(protocol_config_pda)
-            .protocol_treasury(protocol_treasury_pda);
+            .protocol_treasury(protocol_treasury_pda)
+            .project_fee_override(project_fee_override_pda)
+            .referrer(referrer.map(|wallet| AccountHelper::find_referrer_pda(&wallet).0))
+            .fee_authority(fee_authority_pda)
+            .protocol_fee_stats(protocol_fee_stats_pda)
+            .project_fee_stats(project_fee_stats_pda);
 
-        if let Some(name) = name {
-            builder.name(name);
-        }
         if let Some(uri) = uri {
             builder.uri(uri);
         }
         if let Some(plugins) = plugins {
             builder.plugins(plugins);
         }
+        if let Some(allowlist) = allowlist {
+            builder.allowlist(allowlist);
+        }
 
         utils::send_transaction(svm, &[builder.instruction()], &payer, signing_keypairs)
     }
 
+    pub fn set_public_mint(
+        svm: &mut LiteSVM,
+        minter_config_name: &str,
+        public_mint: bool,
+        project_id: u64,
+        owner: Pubkey,
+        authority: Pubkey,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let project_config_pda = AccountHelper::find_project_pda(&owner, project_id).0;
+        let minter_config_pda =
+            AccountHelper::find_minter_config_pda(&project_config_pda, minter_config_name).0;
+        let authority_role_pda =
+            AccountHelper::find_project_role_pda(&project_config_pda, &authority).0;
+
+        let instruction = SetPublicMintBuilder::new()
+            .authority(authority)
+            .authority_role(authority_role_pda)
+            .minter_config(minter_config_pda)
+            .project_config(project_config_pda)
+            .public_mint(public_mint)
+            .instruction();
+
+        utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
+    }
+
+    pub fn set_mint_phases(
+        svm: &mut LiteSVM,
+        minter_config_name: &str,
+        phases: Vec<MintPhase>,
+        project_id: u64,
+        owner: Pubkey,
+        authority: Pubkey,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let project_config_pda = AccountHelper::find_project_pda(&owner, project_id).0;
+        let minter_config_pda =
+            AccountHelper::find_minter_config_pda(&project_config_pda, minter_config_name).0;
+        let authority_role_pda =
+            AccountHelper::find_project_role_pda(&project_config_pda, &authority).0;
+
+        let instruction = SetMintPhasesBuilder::new()
+            .authority(authority)
+            .authority_role(authority_role_pda)
+            .minter_config(minter_config_pda)
+            .project_config(project_config_pda)
+            .phases(phases)
+            .instruction();
+
+        utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
+    }
+
+    pub fn set_wallet_mint_limit(
+        svm: &mut LiteSVM,
+        minter_config_name: &str,
+        wallet_mint_limit: u64,
+        project_id: u64,
+        owner: Pubkey,
+        authority: Pubkey,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let project_config_pda = AccountHelper::find_project_pda(&owner, project_id).0;
+        let minter_config_pda =
+            AccountHelper::find_minter_config_pda(&project_config_pda, minter_config_name).0;
+        let authority_role_pda =
+            AccountHelper::find_project_role_pda(&project_config_pda, &authority).0;
+
+        let instruction = SetWalletMintLimitBuilder::new()
+            .authority(authority)
+            .authority_role(authority_role_pda)
+            .minter_config(minter_config_pda)
+            .project_config(project_config_pda)
+            .wallet_mint_limit(wallet_mint_limit)
+            .instruction();
+
+        utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
+    }
+
+    pub fn close_mint_record(
+        svm: &mut LiteSVM,
+        minter_config_name: &str,
+        project_id: u64,
+        owner: Pubkey,
+        wallet: Pubkey,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let project_config_pda = AccountHelper::find_project_pda(&owner, project_id).0;
+        let minter_config_pda =
+            AccountHelper::find_minter_config_pda(&project_config_pda, minter_config_name).0;
+        let mint_record_pda = AccountHelper::find_mint_record_pda(&minter_config_pda, &wallet).0;
+
+        let instruction = CloseMintRecordBuilder::new()
+            .wallet(wallet)
+            .minter_config(minter_config_pda)
+            .mint_record(mint_record_pda)
+            .instruction();
+
+        utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
+    }
+
+    pub fn close_minter_config(
+        svm: &mut LiteSVM,
+        minter_config_name: &str,
+        project_id: u64,
+        owner: Pubkey,
+        authority: Pubkey,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let project_config_pda = AccountHelper::find_project_pda(&owner, project_id).0;
+        let minter_config_pda =
+            AccountHelper::find_minter_config_pda(&project_config_pda, minter_config_name).0;
+        let authority_role_pda =
+            AccountHelper::find_project_role_pda(&project_config_pda, &authority).0;
+        let fee_authority_pda = AccountHelper::find_fee_authority_pda().0;
+
+        let instruction = CloseMinterConfigBuilder::new()
+            .authority(authority)
+            .authority_role(authority_role_pda)
+            .minter_config(minter_config_pda)
+            .project_config(project_config_pda)
+            .fee_authority(fee_authority_pda)
+            .instruction();
+
+        utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
+    }
+
     pub fn create_trade_hub(
         svm: &mut LiteSVM,
         name: String,
@@ -269,15 +1487,29 @@ impl Instructions {
         let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
         let project_config_pda = AccountHelper::find_project_pda(&owner, project_id).0;
         let trade_hub_pda = AccountHelper::find_trade_hub_pda(&name, &project_config_pda).0;
+        let authority_role_pda =
+            AccountHelper::find_project_role_pda(&project_config_pda, &authority).0;
         let protocol_treasury_pda = AccountHelper::find_treasury_pda(&protocol_config_pda).0;
+        let project_fee_override_pda =
+            AccountHelper::find_project_fee_override_pda(&project_config_pda).0;
+        let subscription_pda = AccountHelper::find_subscription_pda(&project_config_pda).0;
+        let fee_authority_pda = AccountHelper::find_fee_authority_pda().0;
+        let protocol_fee_stats_pda = AccountHelper::find_fee_stats_pda(&protocol_config_pda).0;
+        let project_fee_stats_pda = AccountHelper::find_fee_stats_pda(&project_config_pda).0;
 
         let instruction = CreateTradeHubBuilder::new()
             .payer(payer)
             .authority(authority)
+            .authority_role(authority_role_pda)
             .trade_hub(trade_hub_pda)
             .project_config(project_config_pda)
             .protocol_config(protocol_config_pda)
             .protocol_treasury(protocol_treasury_pda)
+            .project_fee_override(project_fee_override_pda)
+            .subscription(subscription_pda)
+            .fee_authority(fee_authority_pda)
+            .protocol_fee_stats(protocol_fee_stats_pda)
+            .project_fee_stats(project_fee_stats_pda)
             .system_program(SYSTEM_PROGRAM_ID)
             .name(name)
             .fee_bps(fee_bps)
@@ -286,6 +1518,32 @@ impl Instructions {
         utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
     }
 
+    pub fn close_trade_hub(
+        svm: &mut LiteSVM,
+        name: &str,
+        project_id: u64,
+        owner: Pubkey,
+        authority: Pubkey,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let project_config_pda = AccountHelper::find_project_pda(&owner, project_id).0;
+        let trade_hub_pda = AccountHelper::find_trade_hub_pda(name, &project_config_pda).0;
+        let authority_role_pda =
+            AccountHelper::find_project_role_pda(&project_config_pda, &authority).0;
+        let fee_authority_pda = AccountHelper::find_fee_authority_pda().0;
+
+        let instruction = CloseTradeHubBuilder::new()
+            .authority(authority)
+            .authority_role(authority_role_pda)
+            .trade_hub(trade_hub_pda)
+            .project_config(project_config_pda)
+            .fee_authority(fee_authority_pda)
+            .instruction();
+
+        utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
+    }
+
     pub fn list_asset(
         svm: &mut LiteSVM,
         price: u64,
@@ -322,14 +1580,47 @@ impl Instructions {
         trade_hub_name: &str,
         project_config_pda: &Pubkey,
         collection: Option<Pubkey>,
+        referrer: Option<Pubkey>,
         max_price: u64,
         signing_keypairs: &[&Keypair],
     ) -> TransactionResult {
         let trade_hub_pda = AccountHelper::find_trade_hub_pda(trade_hub_name, project_config_pda).0;
-        let listing_pda = AccountHelper::find_listing_pda(&mint, &trade_hub_pda).0;
+
+        Self::purchase_asset_from_trade_hub(
+            svm,
+            buyer,
+            owner,
+            mint,
+            &trade_hub_pda,
+            project_config_pda,
+            collection,
+            referrer,
+            max_price,
+            signing_keypairs,
+        )
+    }
+
+    pub fn purchase_asset_from_trade_hub(
+        svm: &mut LiteSVM,
+        buyer: Pubkey,
+        owner: &Pubkey,
+        mint: &Pubkey,
+        trade_hub_pda: &Pubkey,
+        project_config_pda: &Pubkey,
+        collection: Option<Pubkey>,
+        referrer: Option<Pubkey>,
+        max_price: u64,
+        signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        let listing_pda = AccountHelper::find_listing_pda(mint, trade_hub_pda).0;
         let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
         let treasury_pda = AccountHelper::get_treasury_pda(svm, project_config_pda);
         let protocol_treasury_pda = AccountHelper::find_treasury_pda(&protocol_config_pda).0;
+        let project_fee_override_pda =
+            AccountHelper::find_project_fee_override_pda(project_config_pda).0;
+        let fee_authority_pda = AccountHelper::find_fee_authority_pda().0;
+        let protocol_fee_stats_pda = AccountHelper::find_fee_stats_pda(&protocol_config_pda).0;
+        let project_fee_stats_pda = AccountHelper::find_fee_stats_pda(project_config_pda).0;
 
         let instruction = PurchaseAssetBuilder::new()
             .buyer(buyer)
@@ -337,11 +1628,16 @@ impl Instructions {
             .asset(*mint)
             .collection(collection)
             .listing(listing_pda)
-            .trade_hub(trade_hub_pda)
+            .trade_hub(*trade_hub_pda)
             .treasury(treasury_pda)
             .project_config(*project_config_pda)
             .protocol_config(protocol_config_pda)
             .protocol_treasury(protocol_treasury_pda)
+            .project_fee_override(project_fee_override_pda)
+            .referrer(referrer.map(|wallet| AccountHelper::find_referrer_pda(&wallet).0))
+            .fee_authority(fee_authority_pda)
+            .protocol_fee_stats(protocol_fee_stats_pda)
+            .project_fee_stats(project_fee_stats_pda)
             .system_program(SYSTEM_PROGRAM_ID)
             .max_price(max_price)
             .instruction();
@@ -387,6 +1683,8 @@ impl Instructions {
         let agent_pda = AccountHelper::find_agent_pda(&owner, agent_id).0;
         let task_data_pda = AccountHelper::find_task_data_pda(&agent_pda).0;
 
+        let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+
         let instruction = CreateAgentBuilder::new()
             .payer(payer)
             .owner(owner)
@@ -396,6 +1694,7 @@ impl Instructions {
             .task_data(task_data_pda)
             .system_program(SYSTEM_PROGRAM_ID)
             .compute_node_info(compute_node_info_pda)
+            .protocol_config(protocol_config_pda)
             .instruction();
 
         utils::send_transaction(svm, &[instruction], &payer, signing_keypairs)
@@ -408,15 +1707,38 @@ impl Instructions {
         submitter: Pubkey,
         payer: Pubkey,
         signing_keypairs: &[&Keypair],
+    ) -> TransactionResult {
+        Self::submit_instance_task(
+            svm,
+            0,
+            task_data,
+            agent_pda,
+            submitter,
+            payer,
+            signing_keypairs,
+        )
+    }
+
+    pub fn submit_instance_task(
+        svm: &mut LiteSVM,
+        instance_id: u64,
+        task_data: Vec<u8>,
+        agent_pda: Pubkey,
+        submitter: Pubkey,
+        payer: Pubkey,
+        signing_keypairs: &[&Keypair],
     ) -> TransactionResult {
         let task_data_pda = AccountHelper::find_task_data_pda(&agent_pda).0;
 
+        let protocol_config_pda = AccountHelper::find_protocol_instance_pda(instance_id).0;
+
         let mut builder = SubmitTaskBuilder::new();
         builder
             .payer(payer)
             .submitter(submitter)
             .task_data(task_data_pda)
             .agent(agent_pda)
+            .protocol_config(protocol_config_pda)
             .system_program(SYSTEM_PROGRAM_ID)
             .data(task_data);
 
@@ -432,10 +1754,13 @@ impl Instructions {
     ) -> TransactionResult {
         let compute_node_info_pda = AccountHelper::find_compute_node_info_pda(&node_pubkey).0;
 
+        let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+
         let instruction = RegisterComputeNodeBuilder::new()
             .payer(payer)
             .owner(owner)
             .compute_node_info(compute_node_info_pda)
+            .protocol_config(protocol_config_pda)
             .system_program(SYSTEM_PROGRAM_ID)
             .node_pubkey(node_pubkey)
             .instruction();
@@ -452,10 +1777,13 @@ impl Instructions {
     ) -> TransactionResult {
         let compute_node_info_pda = AccountHelper::find_compute_node_info_pda(&node_pubkey).0;
 
+        let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+
         let instruction = ClaimComputeNodeBuilder::new()
             .payer(payer)
             .compute_node(node_pubkey)
             .compute_node_info(compute_node_info_pda)
+            .protocol_config(protocol_config_pda)
             .system_program(SYSTEM_PROGRAM_ID)
             .node_info_cid(node_info_cid)
             .instruction();
@@ -473,10 +1801,13 @@ impl Instructions {
     ) -> TransactionResult {
         let agent_pda = AccountHelper::find_agent_pda(&owner, agent_id).0;
 
+        let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+
         let instruction = ActivateAgentBuilder::new()
             .payer(payer)
             .compute_node(compute_node)
             .agent(agent_pda)
+            .protocol_config(protocol_config_pda)
             .agent_id(agent_id)
             .instruction();
 
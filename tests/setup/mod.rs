@@ -1,9 +1,11 @@
 #![allow(dead_code)]
 pub mod accounts;
+pub mod config_v0;
 pub mod fixture;
 pub mod instructions;
 pub mod test_data;
 
 pub use accounts::*;
+pub use config_v0::*;
 pub use fixture::*;
 pub use instructions::*;
@@ -9,15 +9,37 @@ pub const DAC_MANAGER_SO_PATH: &str = "target/deploy/dac_manager.so";
 pub const MPL_CORE_PROGRAM_ID: &str = "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d";
 pub const MPL_CORE_PROGRAM_SO_PATH: &str = "tests/programs/mpl_core.so";
 
+pub const TOKEN_PROGRAM_ID: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+
 // Test data constants
-pub const PROJECT_1_ID: u64 = 1u64;
+pub const PROJECT_1_ID: u64 = 0u64;
+pub const PROJECT_2_ID: u64 = 1u64;
 pub const AGENT_ID: u64 = 1u64;
 pub const DEFAULT_PROJECT_NAME: &str = "Test project";
 pub const DEFAULT_PROJECT_DESCRIPTION: &str = "Project description";
+pub const PROTOCOL_INSTANCE_ID: u64 = 7u64;
+
+// Bytes of the fields appended to the protocol config since its version 1 layout
+pub const PROTOCOL_CONFIG_V1_APPENDED_LEN: usize = 12; // instance_id, subscription_plans
+
+// Capacities of the config vectors and strings, to fill accounts to their full size
+pub const MAX_ADMINS: usize = 3;
+pub const MAX_WHITELIST_TRANSFER_ADDRS: usize = 3;
+pub const MAX_PROJECT_NAME_LEN: usize = 64;
+pub const MAX_PROJECT_DESCRIPTION_LEN: usize = 200;
+pub const MAX_PROJECT_AUTHORITIES: usize = 3;
 
 pub const MINTER_NAME: &str = "Minter";
 pub const MINT_PRICE: u64 = 1_000_000_000;
 pub const MAX_SUPPLY: u64 = 5;
+pub const FREE_MINTER_NAME: &str = "Free Minter";
+pub const MINT_FEE_BPS: u64 = 500;
+pub const MINT_PHASE_START: i64 = 100_000;
+pub const MINT_PHASE_DURATION: i64 = 60 * 60;
+pub const ALLOWLIST_PHASE_PRICE: u64 = 500_000_000;
+pub const ALLOWLIST_PHASE_SUPPLY_CAP: u64 = 2;
+pub const ALLOWLIST_ALLOWED_COUNT: u64 = 1;
+pub const WALLET_MINT_LIMIT: u64 = 2;
 pub const COLLECTION_URI: &str = "https://";
 
 pub const ASSET_NAME: &str = "Test Asset";
@@ -33,6 +55,53 @@ pub const FEE_MINT_ASSET_AMOUNT: u64 = 50_000;
 pub const FEE_GENERIC_OPERATION_AMOUNT: u64 = 100_000;
 pub const FEE_CREATE_TRADE_HUB_AMOUNT: u64 = 200_000;
 pub const FEE_TRADE_NFT_AMOUNT: u64 = 150;
+pub const FEE_UPDATE_DELAY: i64 = 24 * 60 * 60;
+pub const PROPOSAL_LIFETIME: i64 = 7 * 24 * 60 * 60;
+
+pub const FEE_TOKEN_DECIMALS: u8 = 6;
+pub const FEE_TOKEN_AMOUNT: u64 = 2_500_000;
+
+pub const FEE_OVERRIDE_EXPIRES_AT: i64 = 1_000;
+
+pub const REFERRER_SHARE_BPS: u16 = 2_000;
+
+pub const SUBSCRIPTION_PLAN_ID: u8 = 1;
+pub const SUBSCRIPTION_PRICE_PER_EPOCH: u64 = 10_000_000;
+pub const SUBSCRIPTION_GRACE_EPOCHS: u64 = 2;
+pub const SUBSCRIPTION_TRIAL_EPOCHS: u64 = 15;
+
+pub fn default_subscription_plan() -> sol_mind_protocol_client::types::SubscriptionPlan {
+    sol_mind_protocol_client::types::SubscriptionPlan {
+        plan_id: SUBSCRIPTION_PLAN_ID,
+        price_per_epoch: SUBSCRIPTION_PRICE_PER_EPOCH,
+        grace_epochs: SUBSCRIPTION_GRACE_EPOCHS,
+        trial_epochs: SUBSCRIPTION_TRIAL_EPOCHS,
+    }
+}
+
+/// An allowlist phase with its own price and supply cap, followed by a public phase at
+/// `MINT_PRICE`.
+pub fn default_mint_phases() -> Vec<sol_mind_protocol_client::nft_operations::types::MintPhase> {
+    use sol_mind_protocol_client::nft_operations::types::MintPhase;
+    vec![
+        MintPhase {
+            price: ALLOWLIST_PHASE_PRICE,
+            start_at: MINT_PHASE_START,
+            end_at: MINT_PHASE_START + MINT_PHASE_DURATION,
+            supply_cap: ALLOWLIST_PHASE_SUPPLY_CAP,
+            minted: 0,
+            merkle_root: None,
+        },
+        MintPhase {
+            price: MINT_PRICE,
+            start_at: MINT_PHASE_START + MINT_PHASE_DURATION,
+            end_at: MINT_PHASE_START + 2 * MINT_PHASE_DURATION,
+            supply_cap: 0,
+            minted: 0,
+            merkle_root: None,
+        },
+    ]
+}
 
 pub fn default_fees_structure() -> sol_mind_protocol_client::types::FeesStructure {
     use sol_mind_protocol_client::types::{Fee, FeeType};
@@ -40,26 +109,32 @@ pub fn default_fees_structure() -> sol_mind_protocol_client::types::FeesStructur
         create_project: Fee {
             amount: FEE_CREATE_PROJECT_AMOUNT,
             fee_type: FeeType::Fixed,
+            mint: None,
         },
         create_minter_config: Fee {
             amount: FEE_CREATE_MINTER_CONFIG_AMOUNT,
             fee_type: FeeType::Fixed,
+            mint: None,
         },
         mint_asset: Fee {
             amount: FEE_MINT_ASSET_AMOUNT,
             fee_type: FeeType::Fixed,
+            mint: None,
         },
         generic_operation: Fee {
             amount: FEE_GENERIC_OPERATION_AMOUNT,
             fee_type: FeeType::Fixed,
+            mint: None,
         },
         create_trade_hub: Fee {
             amount: FEE_CREATE_TRADE_HUB_AMOUNT,
             fee_type: FeeType::Fixed,
+            mint: None,
         },
         trade_nft: Fee {
             amount: FEE_TRADE_NFT_AMOUNT,
             fee_type: FeeType::Percentage,
+            mint: None,
         },
     }
 }
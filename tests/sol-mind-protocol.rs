@@ -1,11 +1,22 @@
 mod setup;
 
-use sol_mind_protocol_client::types::{Fee, FeeType, FeesStructure, Operation};
+use sol_mind_protocol_client::fees;
+use sol_mind_protocol_client::types::{
+    Fee, FeeAdjustment, FeeType, FeesStructure, Operation, OperationFeeOverride, ProposalAction,
+    RevenueShare, Role, ScheduledFeeOverride, SubscriptionPlan,
+};
+use solana_pubkey::Pubkey;
 use solana_sdk::signature::Keypair;
-use solana_sdk::{native_token::LAMPORTS_PER_SOL, signature::Signer};
+use solana_sdk::{
+    clock::Clock, instruction::AccountMeta, native_token::LAMPORTS_PER_SOL, signature::Signer,
+};
+use std::str::FromStr;
 
 use crate::setup::test_data::*;
-use setup::{AccountHelper, Instructions, TestFixture};
+use setup::{
+    AccountHelper, Instructions, ProjectConfigV0, ProtocolConfigV0, TestFixture,
+    PROJECT_CONFIG_V0_SPACE, PROTOCOL_CONFIG_V0_SPACE,
+};
 
 #[test]
 fn test_initialize_protocol() {
@@ -32,7 +43,11 @@ fn test_initialize_protocol() {
 
             assert_eq!(protocol_config.admins, admins);
             assert_eq!(
-                protocol_config.whitelist_transfer_addrs,
+                protocol_config
+                    .whitelist_transfer_addrs
+                    .iter()
+                    .map(|entry| entry.address)
+                    .collect::<Vec<_>>(),
                 whitelist_transfer_addrs
             );
             assert_eq!(
@@ -50,6 +65,182 @@ fn test_initialize_protocol() {
     }
 }
 
+#[test]
+fn test_initialize_protocol_instance() {
+    let mut fixture = TestFixture::new().with_initialize_protocol();
+
+    let admins = vec![fixture.admin_2.pubkey()];
+    let mut fees = default_fees_structure();
+    fees.create_project.amount = 2 * FEE_CREATE_PROJECT_AMOUNT;
+
+    let result = Instructions::initialize_protocol_instance(
+        &mut fixture.svm,
+        PROTOCOL_INSTANCE_ID,
+        admins.clone(),
+        vec![],
+        fees.clone(),
+        Some(fixture.admin_1.pubkey()),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.payer.insecure_clone(),
+            &fixture.admin_1.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let instance_pda = AccountHelper::find_protocol_instance_pda(PROTOCOL_INSTANCE_ID).0;
+            let instance = AccountHelper::get_protocol_instance(&fixture.svm, PROTOCOL_INSTANCE_ID);
+            let default_instance = AccountHelper::get_protocol_config(&fixture.svm);
+            let fee_stats = AccountHelper::get_fee_stats(&fixture.svm, &instance_pda);
+            let legacy_pda = Pubkey::find_program_address(
+                &[b"sol-mind-protocol"],
+                &sol_mind_protocol_client::SOL_MIND_PROTOCOL_ID,
+            )
+            .0;
+
+            assert_eq!(AccountHelper::find_protocol_config_pda().0, legacy_pda);
+            assert_ne!(instance_pda, legacy_pda);
+            assert_eq!(instance.instance_id, PROTOCOL_INSTANCE_ID);
+            assert_eq!(instance.admins, admins);
+            assert_eq!(instance.fees, fees);
+            assert_eq!(fee_stats.owner, instance_pda);
+
+            assert_eq!(default_instance.instance_id, 0);
+            assert_eq!(
+                default_instance.admins,
+                vec![fixture.admin_1.pubkey(), fixture.admin_2.pubkey()]
+            );
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_initialize_protocol_instance_unauthorized() {
+    let mut fixture = TestFixture::new().with_initialize_protocol();
+    let outsider = Keypair::new();
+
+    let result = Instructions::initialize_protocol_instance(
+        &mut fixture.svm,
+        PROTOCOL_INSTANCE_ID,
+        vec![outsider.pubkey()],
+        vec![],
+        default_fees_structure(),
+        Some(outsider.pubkey()),
+        fixture.payer.pubkey(),
+        &[&fixture.payer.insecure_clone(), &outsider],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Expected transaction to fail, but it succeeded");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("RootAdminRequired"),
+                "Expected RootAdminRequired error, got: {:?}",
+                e
+            );
+        }
+    }
+
+    let result = Instructions::initialize_protocol_instance(
+        &mut fixture.svm,
+        PROTOCOL_INSTANCE_ID,
+        vec![outsider.pubkey()],
+        vec![],
+        default_fees_structure(),
+        None,
+        fixture.payer.pubkey(),
+        &[&fixture.payer.insecure_clone()],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Expected transaction to fail, but it succeeded");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("RootAdminRequired"),
+                "Expected RootAdminRequired error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_create_project_in_instance() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_project_created(PROJECT_1_ID);
+
+    Instructions::initialize_protocol_instance(
+        &mut fixture.svm,
+        PROTOCOL_INSTANCE_ID,
+        vec![fixture.admin_2.pubkey()],
+        vec![],
+        default_fees_structure(),
+        Some(fixture.admin_1.pubkey()),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.payer.insecure_clone(),
+            &fixture.admin_1.insecure_clone(),
+        ],
+    )
+    .expect("Failed to initialize protocol instance");
+
+    let result = Instructions::create_instance_project(
+        &mut fixture.svm,
+        PROTOCOL_INSTANCE_ID,
+        DEFAULT_PROJECT_NAME.to_string(),
+        DEFAULT_PROJECT_DESCRIPTION.to_string(),
+        fixture.project_owner.pubkey(),
+        None,
+        fixture.payer.pubkey(),
+        &[
+            &fixture.project_owner.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let instance_pda = AccountHelper::find_protocol_instance_pda(PROTOCOL_INSTANCE_ID).0;
+            let project_config = AccountHelper::get_instance_project_config(
+                &fixture.svm,
+                PROTOCOL_INSTANCE_ID,
+                &fixture.project_owner.pubkey(),
+                0,
+            );
+
+            assert_eq!(project_config.protocol_config, instance_pda);
+            assert_eq!(project_config.project_id, 0);
+            assert_eq!(
+                AccountHelper::get_protocol_instance(&fixture.svm, PROTOCOL_INSTANCE_ID)
+                    .project_count,
+                1
+            );
+            assert_eq!(
+                AccountHelper::get_protocol_config(&fixture.svm).project_count,
+                1
+            );
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
 #[test]
 fn test_create_project() {
     let mut fixture = TestFixture::new().with_initialize_protocol();
@@ -64,18 +255,12 @@ fn test_create_project() {
 
     let project_name = "Test project".to_string();
     let project_description = "Project description".to_string();
-    let authorities = vec![
-        fixture.project_authority_1.pubkey(),
-        fixture.project_authority_2.pubkey(),
-    ];
 
     let result = Instructions::create_project(
         &mut fixture.svm,
-        PROJECT_1_ID,
         project_name.clone(),
         project_description.clone(),
         fixture.project_owner.pubkey(),
-        authorities.clone(),
         fixture.payer.pubkey(),
         &[
             &fixture.project_owner.insecure_clone(),
@@ -105,9 +290,19 @@ fn test_create_project() {
             assert_eq!(project_config.owner, fixture.project_owner.pubkey());
             assert_eq!(project_config.name, project_name);
             assert_eq!(project_config.description, project_description);
-            assert_eq!(project_config.autthorities, authorities);
+            assert!(project_config.autthorities.is_empty());
             assert_eq!(project_config.treasury_bump, treasury_bump);
             assert_eq!(project_config.project_id, PROJECT_1_ID);
+            assert_eq!(
+                project_config.created_epoch,
+                fixture.svm.get_sysvar::<Clock>().epoch
+            );
+            assert_eq!(protocol_config.project_count, PROJECT_1_ID + 1);
+
+            let project_registry = AccountHelper::get_project_registry(&fixture.svm, &project_name)
+                .expect("Project registry entry not found");
+            assert_eq!(project_registry.protocol_config, protocol_config_pda);
+            assert_eq!(project_registry.project_config, project_config_pda);
 
             assert_eq!(
                 protocol_treasury_final_balance,
@@ -128,26 +323,32 @@ fn test_update_fees() {
         create_project: Fee {
             amount: 2_000_000,
             fee_type: FeeType::Fixed,
+            mint: None,
         },
         create_minter_config: Fee {
             amount: 1_000_000,
             fee_type: FeeType::Fixed,
+            mint: None,
         },
         create_trade_hub: Fee {
             amount: 1_000_000,
             fee_type: FeeType::Fixed,
+            mint: None,
         },
         mint_asset: Fee {
             amount: 100_000,
             fee_type: FeeType::Fixed,
+            mint: None,
         },
         trade_nft: Fee {
             amount: 100,
             fee_type: FeeType::Percentage,
+            mint: None,
         },
         generic_operation: Fee {
             amount: 200_000,
             fee_type: FeeType::Fixed,
+            mint: None,
         },
     };
 
@@ -167,21 +368,28 @@ fn test_update_fees() {
             utils::print_transaction_logs(&result);
 
             let protocol_config = AccountHelper::get_protocol_config(&fixture.svm);
+            let pending_fees = protocol_config
+                .pending_fees
+                .expect("Fee update should be pending");
 
             assert_eq!(
                 protocol_config.fees.create_project.amount,
+                FEE_CREATE_PROJECT_AMOUNT
+            );
+            assert_eq!(
+                pending_fees.fees.create_project.amount,
                 new_fees.create_project.amount
             );
             assert_eq!(
-                protocol_config.fees.create_minter_config.amount,
+                pending_fees.fees.create_minter_config.amount,
                 new_fees.create_minter_config.amount
             );
             assert_eq!(
-                protocol_config.fees.mint_asset.amount,
+                pending_fees.fees.mint_asset.amount,
                 new_fees.mint_asset.amount
             );
             assert_eq!(
-                protocol_config.fees.generic_operation.amount,
+                pending_fees.fees.generic_operation.amount,
                 new_fees.generic_operation.amount
             );
         }
@@ -198,6 +406,7 @@ fn test_update_single_fee() {
     let new_fee = Fee {
         amount: 3_000_000,
         fee_type: FeeType::Fixed,
+        mint: None,
     };
 
     let result = Instructions::update_single_fee(
@@ -217,22 +426,23 @@ fn test_update_single_fee() {
             utils::print_transaction_logs(&result);
 
             let protocol_config = AccountHelper::get_protocol_config(&fixture.svm);
+            let pending_fees = protocol_config
+                .pending_fees
+                .expect("Fee update should be pending");
 
-            assert_eq!(protocol_config.fees.create_project.amount, new_fee.amount);
-            assert_eq!(
-                protocol_config.fees.create_project.fee_type,
-                new_fee.fee_type
-            );
             assert_eq!(
-                protocol_config.fees.mint_asset.amount,
-                FEE_MINT_ASSET_AMOUNT
+                protocol_config.fees.create_project.amount,
+                FEE_CREATE_PROJECT_AMOUNT
             );
+            assert_eq!(pending_fees.fees.create_project.amount, new_fee.amount);
+            assert_eq!(pending_fees.fees.create_project.fee_type, new_fee.fee_type);
+            assert_eq!(pending_fees.fees.mint_asset.amount, FEE_MINT_ASSET_AMOUNT);
             assert_eq!(
-                protocol_config.fees.create_minter_config.amount,
+                pending_fees.fees.create_minter_config.amount,
                 FEE_CREATE_MINTER_CONFIG_AMOUNT
             );
             assert_eq!(
-                protocol_config.fees.generic_operation.amount,
+                pending_fees.fees.generic_operation.amount,
                 FEE_GENERIC_OPERATION_AMOUNT
             );
         }
@@ -410,6 +620,7 @@ fn test_project_fees_transfer() {
         &mut fixture.svm,
         transfer_amount,
         fixture.project_owner.pubkey(),
+        fixture.project_owner.pubkey(),
         destination,
         PROJECT_1_ID,
         fixture.payer.pubkey(),
@@ -460,6 +671,7 @@ fn test_project_fees_transfer_by_non_owner() {
     let result = Instructions::transfer_project_fees(
         &mut fixture.svm,
         transfer_amount,
+        fixture.project_owner.pubkey(),
         non_owner.pubkey(),
         destination,
         PROJECT_1_ID,
@@ -474,8 +686,8 @@ fn test_project_fees_transfer_by_non_owner() {
         Err(e) => {
             let error_string = format!("{:?}", e);
             assert!(
-                error_string.contains("AccountNotInitialized."),
-                "Expected AccountNotInitialized, since the PDA seed has the owner, got: {:?}",
+                error_string.contains("Unauthorized"),
+                "Expected Unauthorized error, got: {:?}",
                 e
             );
 
@@ -487,3 +699,5851 @@ fn test_project_fees_transfer_by_non_owner() {
         }
     }
 }
+
+#[test]
+fn test_add_admin() {
+    let mut fixture = TestFixture::new().with_initialize_protocol();
+
+    let new_admin = Keypair::new();
+
+    let result = Instructions::add_admin(
+        &mut fixture.svm,
+        new_admin.pubkey(),
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let protocol_config = AccountHelper::get_protocol_config(&fixture.svm);
+
+            assert_eq!(
+                protocol_config.admins,
+                vec![
+                    fixture.admin_1.pubkey(),
+                    fixture.admin_2.pubkey(),
+                    new_admin.pubkey()
+                ]
+            );
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_add_admin_max_admins_reached() {
+    let mut fixture = TestFixture::new().with_initialize_protocol();
+
+    Instructions::add_admin(
+        &mut fixture.svm,
+        Keypair::new().pubkey(),
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to add admin");
+
+    let result = Instructions::add_admin(
+        &mut fixture.svm,
+        Keypair::new().pubkey(),
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, admin set is full");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("MaxAdminsReached"),
+                "Expected MaxAdminsReached error, got: {:?}",
+                e
+            );
+
+            let protocol_config = AccountHelper::get_protocol_config(&fixture.svm);
+            assert_eq!(protocol_config.admins.len(), 3);
+        }
+    }
+}
+
+#[test]
+fn test_add_admin_non_admin() {
+    let mut fixture = TestFixture::new().with_initialize_protocol();
+
+    let non_admin = Keypair::new();
+
+    let result = Instructions::add_admin(
+        &mut fixture.svm,
+        non_admin.pubkey(),
+        non_admin.pubkey(),
+        fixture.payer.pubkey(),
+        &[&non_admin.insecure_clone(), &fixture.payer.insecure_clone()],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, non-admin cannot add admins");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("Unauthorized"),
+                "Expected Unauthorized error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_remove_admin() {
+    let mut fixture = TestFixture::new().with_initialize_protocol();
+
+    let result = Instructions::remove_admin(
+        &mut fixture.svm,
+        fixture.admin_2.pubkey(),
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let protocol_config = AccountHelper::get_protocol_config(&fixture.svm);
+
+            assert_eq!(protocol_config.admins, vec![fixture.admin_1.pubkey()]);
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_remove_last_admin() {
+    let mut fixture = TestFixture::new().with_initialize_protocol();
+
+    Instructions::remove_admin(
+        &mut fixture.svm,
+        fixture.admin_2.pubkey(),
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to remove admin");
+
+    let result = Instructions::remove_admin(
+        &mut fixture.svm,
+        fixture.admin_1.pubkey(),
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, the last admin cannot be removed");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("CannotRemoveLastAdmin"),
+                "Expected CannotRemoveLastAdmin error, got: {:?}",
+                e
+            );
+
+            let protocol_config = AccountHelper::get_protocol_config(&fixture.svm);
+            assert_eq!(protocol_config.admins, vec![fixture.admin_1.pubkey()]);
+        }
+    }
+}
+
+#[test]
+fn test_rotate_admin() {
+    let mut fixture = TestFixture::new().with_initialize_protocol();
+
+    let new_admin = Keypair::new();
+
+    let result = Instructions::rotate_admin(
+        &mut fixture.svm,
+        fixture.admin_1.pubkey(),
+        new_admin.pubkey(),
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let protocol_config = AccountHelper::get_protocol_config(&fixture.svm);
+
+            assert_eq!(
+                protocol_config.admins,
+                vec![new_admin.pubkey(), fixture.admin_2.pubkey()]
+            );
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_rotate_admin_to_existing_admin() {
+    let mut fixture = TestFixture::new().with_initialize_protocol();
+
+    let result = Instructions::rotate_admin(
+        &mut fixture.svm,
+        fixture.admin_1.pubkey(),
+        fixture.admin_2.pubkey(),
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, new admin is already an admin");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("AdminAlreadyExists"),
+                "Expected AdminAlreadyExists error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_update_fees_requires_approval() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_approval_threshold(2);
+
+    let result = Instructions::update_single_fee(
+        &mut fixture.svm,
+        Operation::CreateProject,
+        Fee {
+            amount: 3_000_000,
+            fee_type: FeeType::Fixed,
+            mint: None,
+        },
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, fee updates require a proposal");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("ApprovalRequired"),
+                "Expected ApprovalRequired error, got: {:?}",
+                e
+            );
+
+            let protocol_config = AccountHelper::get_protocol_config(&fixture.svm);
+            assert_eq!(protocol_config.approval_threshold, 2);
+            assert_eq!(
+                protocol_config.fees.create_project.amount,
+                FEE_CREATE_PROJECT_AMOUNT
+            );
+        }
+    }
+}
+
+#[test]
+fn test_execute_update_single_fee_proposal() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_approval_threshold(2);
+
+    let proposal_id = AccountHelper::get_protocol_config(&fixture.svm).proposal_count;
+    let new_fee = Fee {
+        amount: 3_000_000,
+        fee_type: FeeType::Fixed,
+        mint: None,
+    };
+
+    Instructions::create_proposal(
+        &mut fixture.svm,
+        ProposalAction::UpdateSingleFee {
+            operation: Operation::CreateProject,
+            fee: new_fee.clone(),
+        },
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to create proposal");
+
+    Instructions::approve_proposal(
+        &mut fixture.svm,
+        proposal_id,
+        fixture.admin_2.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_2.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to approve proposal");
+
+    let result = Instructions::execute_proposal(
+        &mut fixture.svm,
+        proposal_id,
+        fixture.admin_1.pubkey(),
+        None,
+        fixture.admin_2.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_2.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let protocol_config = AccountHelper::get_protocol_config(&fixture.svm);
+            let pending_fees = protocol_config
+                .pending_fees
+                .expect("Fee update should be pending");
+
+            assert_eq!(pending_fees.fees.create_project.amount, new_fee.amount);
+            assert!(AccountHelper::get_proposal(&fixture.svm, proposal_id).is_none());
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_execute_proposal_threshold_not_met() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_approval_threshold(2);
+
+    let proposal_id = AccountHelper::get_protocol_config(&fixture.svm).proposal_count;
+
+    Instructions::create_proposal(
+        &mut fixture.svm,
+        ProposalAction::UpdateSingleFee {
+            operation: Operation::CreateProject,
+            fee: Fee {
+                amount: 3_000_000,
+                fee_type: FeeType::Fixed,
+                mint: None,
+            },
+        },
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to create proposal");
+
+    let result = Instructions::execute_proposal(
+        &mut fixture.svm,
+        proposal_id,
+        fixture.admin_1.pubkey(),
+        None,
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, proposal has a single approval");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("ProposalThresholdNotMet"),
+                "Expected ProposalThresholdNotMet error, got: {:?}",
+                e
+            );
+
+            let protocol_config = AccountHelper::get_protocol_config(&fixture.svm);
+            assert_eq!(
+                protocol_config.fees.create_project.amount,
+                FEE_CREATE_PROJECT_AMOUNT
+            );
+        }
+    }
+}
+
+#[test]
+fn test_execute_transfer_protocol_fees_proposal() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_approval_threshold(2);
+
+    let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+    let (protocol_treasury_pda, _) = AccountHelper::find_treasury_pda(&protocol_config_pda);
+
+    fixture
+        .svm
+        .airdrop(&protocol_treasury_pda, 5 * LAMPORTS_PER_SOL)
+        .expect("Failed to fund protocol treasury");
+
+    let initial_balance = utils::get_lamports(&fixture.svm, &protocol_treasury_pda);
+    let proposal_id = AccountHelper::get_protocol_config(&fixture.svm).proposal_count;
+    let transfer_amount = 1 * LAMPORTS_PER_SOL;
+    let destination = fixture.admin_2.pubkey();
+
+    Instructions::create_proposal(
+        &mut fixture.svm,
+        ProposalAction::TransferProtocolFees {
+            to: destination,
+            amount: transfer_amount,
+        },
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to create proposal");
+
+    Instructions::approve_proposal(
+        &mut fixture.svm,
+        proposal_id,
+        fixture.admin_2.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_2.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to approve proposal");
+
+    let result = Instructions::execute_proposal(
+        &mut fixture.svm,
+        proposal_id,
+        fixture.admin_1.pubkey(),
+        Some(destination),
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let final_balance = utils::get_lamports(&fixture.svm, &protocol_treasury_pda);
+            let destination_final_balance = utils::get_lamports(&fixture.svm, &destination);
+
+            assert_eq!(final_balance, initial_balance - transfer_amount);
+            assert_eq!(destination_final_balance, transfer_amount);
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_add_admin_requires_approval() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_approval_threshold(2);
+
+    let new_admin = Keypair::new();
+
+    let result = Instructions::add_admin(
+        &mut fixture.svm,
+        new_admin.pubkey(),
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, admin changes require a proposal");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("ApprovalRequired"),
+                "Expected ApprovalRequired error, got: {:?}",
+                e
+            );
+
+            let protocol_config = AccountHelper::get_protocol_config(&fixture.svm);
+            assert!(!protocol_config.admins.contains(&new_admin.pubkey()));
+        }
+    }
+}
+
+#[test]
+fn test_execute_add_admin_proposal() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_approval_threshold(2);
+
+    let new_admin = Keypair::new();
+    let proposal_id = AccountHelper::get_protocol_config(&fixture.svm).proposal_count;
+
+    Instructions::create_proposal(
+        &mut fixture.svm,
+        ProposalAction::AddAdmin {
+            new_admin: new_admin.pubkey(),
+        },
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to create proposal");
+
+    Instructions::approve_proposal(
+        &mut fixture.svm,
+        proposal_id,
+        fixture.admin_2.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_2.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to approve proposal");
+
+    let result = Instructions::execute_proposal(
+        &mut fixture.svm,
+        proposal_id,
+        fixture.admin_1.pubkey(),
+        None,
+        fixture.admin_2.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_2.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let protocol_config = AccountHelper::get_protocol_config(&fixture.svm);
+
+            assert_eq!(
+                protocol_config.admins,
+                vec![
+                    fixture.admin_1.pubkey(),
+                    fixture.admin_2.pubkey(),
+                    new_admin.pubkey()
+                ]
+            );
+            assert!(AccountHelper::get_proposal(&fixture.svm, proposal_id).is_none());
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_execute_set_protocol_paused_proposal() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_approval_threshold(2);
+
+    let proposal_id = AccountHelper::get_protocol_config(&fixture.svm).proposal_count;
+
+    Instructions::create_proposal(
+        &mut fixture.svm,
+        ProposalAction::SetProtocolPaused { paused: true },
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to create proposal");
+
+    Instructions::approve_proposal(
+        &mut fixture.svm,
+        proposal_id,
+        fixture.admin_2.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_2.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to approve proposal");
+
+    let result = Instructions::execute_proposal(
+        &mut fixture.svm,
+        proposal_id,
+        fixture.admin_1.pubkey(),
+        None,
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let protocol_config = AccountHelper::get_protocol_config(&fixture.svm);
+            assert!(protocol_config.paused);
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_cancel_proposal() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_approval_threshold(2);
+
+    let proposal_id = AccountHelper::get_protocol_config(&fixture.svm).proposal_count;
+
+    Instructions::create_proposal(
+        &mut fixture.svm,
+        ProposalAction::SetProtocolPaused { paused: true },
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to create proposal");
+
+    let result = Instructions::cancel_proposal(
+        &mut fixture.svm,
+        proposal_id,
+        fixture.admin_1.pubkey(),
+        fixture.admin_1.pubkey(),
+        &[],
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            assert!(AccountHelper::get_proposal(&fixture.svm, proposal_id).is_none());
+
+            let protocol_config = AccountHelper::get_protocol_config(&fixture.svm);
+            assert!(!protocol_config.paused);
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_cancel_proposal_threshold_not_met() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_approval_threshold(2);
+
+    let proposal_id = AccountHelper::get_protocol_config(&fixture.svm).proposal_count;
+
+    Instructions::create_proposal(
+        &mut fixture.svm,
+        ProposalAction::SetProtocolPaused { paused: true },
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to create proposal");
+
+    let result = Instructions::cancel_proposal(
+        &mut fixture.svm,
+        proposal_id,
+        fixture.admin_1.pubkey(),
+        fixture.admin_2.pubkey(),
+        &[],
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_2.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, a single admin cannot cancel another admin's proposal");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("ProposalThresholdNotMet"),
+                "Expected ProposalThresholdNotMet error, got: {:?}",
+                e
+            );
+
+            assert!(AccountHelper::get_proposal(&fixture.svm, proposal_id).is_some());
+        }
+    }
+}
+
+#[test]
+fn test_approve_expired_proposal() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_approval_threshold(2);
+
+    let proposal_id = AccountHelper::get_protocol_config(&fixture.svm).proposal_count;
+
+    Instructions::create_proposal(
+        &mut fixture.svm,
+        ProposalAction::SetProtocolPaused { paused: true },
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to create proposal");
+
+    utils::warp_clock(&mut fixture.svm, PROPOSAL_LIFETIME);
+
+    let result = Instructions::approve_proposal(
+        &mut fixture.svm,
+        proposal_id,
+        fixture.admin_2.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_2.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, the proposal has expired");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("ProposalExpired"),
+                "Expected ProposalExpired error, got: {:?}",
+                e
+            );
+        }
+    }
+
+    // Once expired, any admin can clear the proposal
+    let result = Instructions::cancel_proposal(
+        &mut fixture.svm,
+        proposal_id,
+        fixture.admin_1.pubkey(),
+        fixture.admin_2.pubkey(),
+        &[],
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_2.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            assert!(AccountHelper::get_proposal(&fixture.svm, proposal_id).is_none());
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_add_whitelist_transfer_addr() {
+    let mut fixture = TestFixture::new().with_initialize_protocol();
+
+    let destination = Keypair::new().pubkey();
+    let label = "Operations wallet".to_string();
+    let transfer_cap = 2 * LAMPORTS_PER_SOL;
+
+    let result = Instructions::add_whitelist_transfer_addr(
+        &mut fixture.svm,
+        destination,
+        Some(label.clone()),
+        Some(transfer_cap),
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let protocol_config = AccountHelper::get_protocol_config(&fixture.svm);
+            let entry = protocol_config
+                .whitelist_transfer_addrs
+                .iter()
+                .find(|entry| entry.address == destination)
+                .expect("Destination should be whitelisted");
+
+            assert_eq!(entry.label, Some(label));
+            assert_eq!(entry.transfer_cap, Some(transfer_cap));
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_add_whitelist_transfer_addr_already_exists() {
+    let mut fixture = TestFixture::new().with_initialize_protocol();
+
+    let result = Instructions::add_whitelist_transfer_addr(
+        &mut fixture.svm,
+        fixture.admin_2.pubkey(),
+        None,
+        None,
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, address is already whitelisted");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("WhitelistAddressAlreadyExists"),
+                "Expected WhitelistAddressAlreadyExists error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_protocol_fees_transfer_exceeds_transfer_cap() {
+    let mut fixture = TestFixture::new().with_initialize_protocol();
+
+    let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+    let (protocol_treasury_pda, _) = AccountHelper::find_treasury_pda(&protocol_config_pda);
+
+    fixture
+        .svm
+        .airdrop(&protocol_treasury_pda, 5 * LAMPORTS_PER_SOL)
+        .expect("Failed to fund protocol treasury");
+
+    let destination = Keypair::new().pubkey();
+    let transfer_cap = 1 * LAMPORTS_PER_SOL;
+
+    Instructions::add_whitelist_transfer_addr(
+        &mut fixture.svm,
+        destination,
+        None,
+        Some(transfer_cap),
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to whitelist destination");
+
+    let result = Instructions::protocol_fees_transfer(
+        &mut fixture.svm,
+        transfer_cap + 1,
+        fixture.admin_1.pubkey(),
+        destination,
+        fixture.payer.pubkey(),
+        &[
+            &fixture.payer.insecure_clone(),
+            &fixture.admin_1.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, amount exceeds the destination cap");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("TransferCapExceeded"),
+                "Expected TransferCapExceeded error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_remove_whitelist_transfer_addr() {
+    let mut fixture = TestFixture::new().with_initialize_protocol();
+
+    let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+    let (protocol_treasury_pda, _) = AccountHelper::find_treasury_pda(&protocol_config_pda);
+
+    fixture
+        .svm
+        .airdrop(&protocol_treasury_pda, 5 * LAMPORTS_PER_SOL)
+        .expect("Failed to fund protocol treasury");
+
+    let destination = fixture.admin_2.pubkey();
+
+    let result = Instructions::remove_whitelist_transfer_addr(
+        &mut fixture.svm,
+        destination,
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let protocol_config = AccountHelper::get_protocol_config(&fixture.svm);
+            assert!(protocol_config
+                .whitelist_transfer_addrs
+                .iter()
+                .all(|entry| entry.address != destination));
+
+            let transfer_result = Instructions::protocol_fees_transfer(
+                &mut fixture.svm,
+                LAMPORTS_PER_SOL,
+                fixture.admin_1.pubkey(),
+                destination,
+                fixture.payer.pubkey(),
+                &[
+                    &fixture.payer.insecure_clone(),
+                    &fixture.admin_1.insecure_clone(),
+                ],
+            );
+
+            let error_string = format!("{:?}", transfer_result.unwrap_err());
+            assert!(
+                error_string.contains("AddressNotWhiteListed"),
+                "Expected AddressNotWhiteListed error, got: {}",
+                error_string
+            );
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_scheduled_fees_apply_after_delay() {
+    let new_fee = Fee {
+        amount: 3_000_000,
+        fee_type: FeeType::Fixed,
+        mint: None,
+    };
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_update_single_fee(Operation::CreateProject, new_fee.clone());
+
+    let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+    let (protocol_treasury_pda, _) = AccountHelper::find_treasury_pda(&protocol_config_pda);
+    let protocol_treasury_initial_balance = fixture
+        .svm
+        .get_account(&protocol_treasury_pda)
+        .map(|acc| acc.lamports)
+        .unwrap_or(0);
+
+    let result = Instructions::create_project(
+        &mut fixture.svm,
+        DEFAULT_PROJECT_NAME.to_string(),
+        DEFAULT_PROJECT_DESCRIPTION.to_string(),
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.project_owner.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let protocol_treasury_final_balance =
+                utils::get_lamports(&fixture.svm, &protocol_treasury_pda);
+
+            assert_eq!(
+                protocol_treasury_final_balance,
+                protocol_treasury_initial_balance + new_fee.amount
+            );
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_cancel_pending_fees() {
+    let mut fixture = TestFixture::new().with_initialize_protocol();
+
+    Instructions::update_single_fee(
+        &mut fixture.svm,
+        Operation::CreateProject,
+        Fee {
+            amount: 3_000_000,
+            fee_type: FeeType::Fixed,
+            mint: None,
+        },
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to schedule fee update");
+
+    let result = Instructions::cancel_pending_fees(
+        &mut fixture.svm,
+        fixture.admin_2.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_2.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let protocol_config = AccountHelper::get_protocol_config(&fixture.svm);
+
+            assert!(protocol_config.pending_fees.is_none());
+            assert_eq!(
+                protocol_config.fees.create_project.amount,
+                FEE_CREATE_PROJECT_AMOUNT
+            );
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_cancel_pending_fees_without_pending_update() {
+    let mut fixture = TestFixture::new().with_initialize_protocol();
+
+    let result = Instructions::cancel_pending_fees(
+        &mut fixture.svm,
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, there is no pending fee update");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("NoPendingFees"),
+                "Expected NoPendingFees error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_create_project_while_protocol_paused() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_protocol_paused();
+
+    let result = Instructions::create_project(
+        &mut fixture.svm,
+        DEFAULT_PROJECT_NAME.to_string(),
+        DEFAULT_PROJECT_DESCRIPTION.to_string(),
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.project_owner.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, protocol is paused");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("OperationPaused"),
+                "Expected OperationPaused error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_set_operation_paused() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_operation_paused(Operation::CreateProject);
+
+    let paused_result = Instructions::create_project(
+        &mut fixture.svm,
+        DEFAULT_PROJECT_NAME.to_string(),
+        DEFAULT_PROJECT_DESCRIPTION.to_string(),
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.project_owner.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+    let error_string = format!("{:?}", paused_result.unwrap_err());
+    assert!(
+        error_string.contains("OperationPaused"),
+        "Expected OperationPaused error, got: {}",
+        error_string
+    );
+
+    let result = Instructions::set_operation_paused(
+        &mut fixture.svm,
+        Operation::CreateProject,
+        false,
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let protocol_config = AccountHelper::get_protocol_config(&fixture.svm);
+            assert_eq!(protocol_config.paused_operations, 0);
+
+            Instructions::create_project(
+                &mut fixture.svm,
+                DEFAULT_PROJECT_NAME.to_string(),
+                DEFAULT_PROJECT_DESCRIPTION.to_string(),
+                fixture.project_owner.pubkey(),
+                fixture.payer.pubkey(),
+                &[
+                    &fixture.project_owner.insecure_clone(),
+                    &fixture.payer.insecure_clone(),
+                ],
+            )
+            .expect("Project creation should succeed once unpaused");
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_update_project() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_project_created(PROJECT_1_ID);
+
+    let new_description = "Updated description".to_string();
+
+    let result = Instructions::update_project(
+        &mut fixture.svm,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        Some(new_description.clone()),
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.project_owner.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let project_config = AccountHelper::get_project_config(
+                &fixture.svm,
+                &fixture.project_owner.pubkey(),
+                PROJECT_1_ID,
+            );
+
+            assert_eq!(project_config.name, DEFAULT_PROJECT_NAME);
+            assert_eq!(project_config.description, new_description);
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_update_project_by_non_owner() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_project_created(PROJECT_1_ID);
+
+    let non_owner = Keypair::new();
+
+    let result = Instructions::update_project(
+        &mut fixture.svm,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        Some("Hijacked".to_string()),
+        non_owner.pubkey(),
+        fixture.payer.pubkey(),
+        &[&non_owner.insecure_clone(), &fixture.payer.insecure_clone()],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, non-owner cannot update the project");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("Unauthorized"),
+                "Expected Unauthorized error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_create_project_duplicate_name() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_project_created(PROJECT_1_ID);
+
+    let other_owner = Keypair::new();
+    fixture
+        .svm
+        .airdrop(&other_owner.pubkey(), LAMPORTS_PER_SOL)
+        .expect("Failed to fund owner");
+
+    let result = Instructions::create_project(
+        &mut fixture.svm,
+        DEFAULT_PROJECT_NAME.to_string(),
+        DEFAULT_PROJECT_DESCRIPTION.to_string(),
+        other_owner.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &other_owner.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, project name is already taken");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("already in use"),
+                "Expected account already in use error, got: {:?}",
+                e
+            );
+
+            let protocol_config = AccountHelper::get_protocol_config(&fixture.svm);
+            assert_eq!(protocol_config.project_count, PROJECT_1_ID + 1);
+        }
+    }
+}
+
+#[test]
+fn test_create_project_name_too_long() {
+    let mut fixture = TestFixture::new().with_initialize_protocol();
+
+    let name = "a".repeat(MAX_PROJECT_NAME_LEN + 1);
+
+    let result = Instructions::create_project(
+        &mut fixture.svm,
+        name.clone(),
+        DEFAULT_PROJECT_DESCRIPTION.to_string(),
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.project_owner.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, project name is too long");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("ProjectNameTooLong"),
+                "Expected ProjectNameTooLong error, got: {:?}",
+                e
+            );
+            assert!(AccountHelper::get_project_registry(&fixture.svm, &name).is_none());
+        }
+    }
+}
+
+#[test]
+fn test_rename_project() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_project_created(PROJECT_1_ID);
+
+    let owner = fixture.project_owner.pubkey();
+    let new_name = "Renamed project".to_string();
+
+    let result = Instructions::rename_project(
+        &mut fixture.svm,
+        PROJECT_1_ID,
+        owner,
+        new_name.clone(),
+        owner,
+        fixture.payer.pubkey(),
+        &[
+            &fixture.project_owner.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let project_config =
+                AccountHelper::get_project_config(&fixture.svm, &owner, PROJECT_1_ID);
+            let project_config_pda = AccountHelper::find_project_pda(&owner, PROJECT_1_ID).0;
+            assert_eq!(project_config.name, new_name);
+
+            let project_registry = AccountHelper::get_project_registry(&fixture.svm, &new_name)
+                .expect("Project registry entry not found");
+            assert_eq!(project_registry.project_config, project_config_pda);
+            assert!(
+                AccountHelper::get_project_registry(&fixture.svm, DEFAULT_PROJECT_NAME).is_none()
+            );
+
+            // The previous name is released and can be claimed by a new project.
+            Instructions::create_project(
+                &mut fixture.svm,
+                DEFAULT_PROJECT_NAME.to_string(),
+                DEFAULT_PROJECT_DESCRIPTION.to_string(),
+                owner,
+                fixture.payer.pubkey(),
+                &[
+                    &fixture.project_owner.insecure_clone(),
+                    &fixture.payer.insecure_clone(),
+                ],
+            )
+            .expect("Released project name should be available");
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_rename_project_by_non_owner() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_project_created(PROJECT_1_ID);
+
+    let non_owner = Keypair::new();
+
+    let result = Instructions::rename_project(
+        &mut fixture.svm,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        "Hijacked".to_string(),
+        non_owner.pubkey(),
+        fixture.payer.pubkey(),
+        &[&non_owner.insecure_clone(), &fixture.payer.insecure_clone()],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, non-owner cannot rename the project");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("Unauthorized"),
+                "Expected Unauthorized error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_transfer_project_ownership() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_project_created(PROJECT_1_ID);
+
+    let creator = fixture.project_owner.pubkey();
+    let new_owner = Keypair::new();
+
+    Instructions::propose_owner(
+        &mut fixture.svm,
+        PROJECT_1_ID,
+        creator,
+        new_owner.pubkey(),
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.project_owner.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to propose new owner");
+
+    let result = Instructions::accept_owner(
+        &mut fixture.svm,
+        PROJECT_1_ID,
+        creator,
+        new_owner.pubkey(),
+        fixture.payer.pubkey(),
+        &[&new_owner.insecure_clone(), &fixture.payer.insecure_clone()],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let project_config =
+                AccountHelper::get_project_config(&fixture.svm, &creator, PROJECT_1_ID);
+
+            assert_eq!(project_config.owner, new_owner.pubkey());
+            assert_eq!(project_config.creator, creator);
+            assert_eq!(project_config.pending_owner, None);
+
+            let previous_owner_result = Instructions::update_project(
+                &mut fixture.svm,
+                PROJECT_1_ID,
+                creator,
+                Some("Old owner update".to_string()),
+                fixture.project_owner.pubkey(),
+                fixture.payer.pubkey(),
+                &[
+                    &fixture.project_owner.insecure_clone(),
+                    &fixture.payer.insecure_clone(),
+                ],
+            );
+            assert!(
+                previous_owner_result.is_err(),
+                "Previous owner should no longer manage the project"
+            );
+
+            Instructions::update_project(
+                &mut fixture.svm,
+                PROJECT_1_ID,
+                creator,
+                Some("New owner update".to_string()),
+                new_owner.pubkey(),
+                fixture.payer.pubkey(),
+                &[&new_owner.insecure_clone(), &fixture.payer.insecure_clone()],
+            )
+            .expect("New owner should manage the project");
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_accept_owner_not_pending_owner() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_project_created(PROJECT_1_ID);
+
+    let creator = fixture.project_owner.pubkey();
+    let new_owner = Keypair::new();
+    let other = Keypair::new();
+
+    Instructions::propose_owner(
+        &mut fixture.svm,
+        PROJECT_1_ID,
+        creator,
+        new_owner.pubkey(),
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.project_owner.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to propose new owner");
+
+    let result = Instructions::accept_owner(
+        &mut fixture.svm,
+        PROJECT_1_ID,
+        creator,
+        other.pubkey(),
+        fixture.payer.pubkey(),
+        &[&other.insecure_clone(), &fixture.payer.insecure_clone()],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, signer is not the pending owner");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("NotPendingOwner"),
+                "Expected NotPendingOwner error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_close_project() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_project_created(PROJECT_1_ID)
+        .with_default_project_roles_revoked(PROJECT_1_ID);
+
+    let owner = fixture.project_owner.pubkey();
+    let project_config_pda = AccountHelper::find_project_pda(&owner, PROJECT_1_ID).0;
+    let treasury_pda = AccountHelper::find_treasury_pda(&project_config_pda).0;
+
+    fixture
+        .svm
+        .airdrop(&treasury_pda, LAMPORTS_PER_SOL)
+        .expect("Failed to fund treasury");
+
+    let owner_initial_balance = utils::get_lamports(&fixture.svm, &owner);
+    let treasury_balance = utils::get_lamports(&fixture.svm, &treasury_pda);
+    let project_config_rent = utils::get_lamports(&fixture.svm, &project_config_pda);
+    let project_registry_pda = AccountHelper::find_project_registry_pda(DEFAULT_PROJECT_NAME).0;
+    let project_registry_rent = utils::get_lamports(&fixture.svm, &project_registry_pda);
+    let fee_stats_pda = AccountHelper::find_fee_stats_pda(&project_config_pda).0;
+    let fee_stats_rent = utils::get_lamports(&fixture.svm, &fee_stats_pda);
+
+    let result = Instructions::close_project(
+        &mut fixture.svm,
+        PROJECT_1_ID,
+        owner,
+        owner,
+        &[],
+        fixture.payer.pubkey(),
+        &[
+            &fixture.project_owner.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let owner_final_balance = utils::get_lamports(&fixture.svm, &owner);
+
+            assert_eq!(
+                owner_final_balance,
+                owner_initial_balance
+                    + treasury_balance
+                    + project_config_rent
+                    + project_registry_rent
+                    + fee_stats_rent
+            );
+            assert!(
+                AccountHelper::get_project_registry(&fixture.svm, DEFAULT_PROJECT_NAME).is_none()
+            );
+            assert!(fixture
+                .svm
+                .get_account(&project_config_pda)
+                .map_or(true, |account| account.lamports == 0));
+            assert!(fixture
+                .svm
+                .get_account(&treasury_pda)
+                .map_or(true, |account| account.lamports == 0));
+            assert!(fixture
+                .svm
+                .get_account(&fee_stats_pda)
+                .map_or(true, |account| account.lamports == 0));
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_close_project_with_treasury_policy_and_token_account() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_project_created(PROJECT_1_ID)
+        .with_default_project_roles_revoked(PROJECT_1_ID)
+        .with_treasury_policy(PROJECT_1_ID, vec![Keypair::new().pubkey()], None);
+
+    let owner = fixture.project_owner.pubkey();
+    let project_config_pda = AccountHelper::find_project_pda(&owner, PROJECT_1_ID).0;
+    let treasury_pda = AccountHelper::find_treasury_pda(&project_config_pda).0;
+    let treasury_policy_pda = AccountHelper::find_treasury_policy_pda(&project_config_pda).0;
+
+    let fee_mint = fixture.create_fee_mint();
+    let treasury_token_account = fixture.create_token_account(&fee_mint, &treasury_pda);
+    let token_program_id = Pubkey::from_str(TOKEN_PROGRAM_ID).expect("Invalid token program ID");
+
+    let owner_initial_balance = utils::get_lamports(&fixture.svm, &owner);
+    let treasury_policy_rent = utils::get_lamports(&fixture.svm, &treasury_policy_pda);
+    let token_account_rent = utils::get_lamports(&fixture.svm, &treasury_token_account);
+
+    let result = Instructions::close_project(
+        &mut fixture.svm,
+        PROJECT_1_ID,
+        owner,
+        owner,
+        &[
+            AccountMeta::new(treasury_token_account, false),
+            AccountMeta::new_readonly(token_program_id, false),
+        ],
+        fixture.payer.pubkey(),
+        &[
+            &fixture.project_owner.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            assert!(
+                AccountHelper::get_treasury_policy(&fixture.svm, &project_config_pda).is_none()
+            );
+            assert!(fixture
+                .svm
+                .get_account(&treasury_token_account)
+                .map_or(true, |account| account.lamports == 0));
+            assert!(
+                utils::get_lamports(&fixture.svm, &owner)
+                    >= owner_initial_balance + treasury_policy_rent + token_account_rent
+            );
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_close_project_with_roles() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_project_created(PROJECT_1_ID);
+
+    let owner = fixture.project_owner.pubkey();
+
+    let result = Instructions::close_project(
+        &mut fixture.svm,
+        PROJECT_1_ID,
+        owner,
+        owner,
+        &[],
+        fixture.payer.pubkey(),
+        &[
+            &fixture.project_owner.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, the project still has role PDAs");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("ProjectHasRoles"),
+                "Expected ProjectHasRoles error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_close_project_with_fee_override() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_project_created(PROJECT_1_ID)
+        .with_default_project_roles_revoked(PROJECT_1_ID)
+        .with_project_fee_override(
+            PROJECT_1_ID,
+            vec![OperationFeeOverride {
+                operation: Operation::MintAsset,
+                adjustment: FeeAdjustment::Discount { bps: 2_500 },
+            }],
+            None,
+        );
+
+    let owner = fixture.project_owner.pubkey();
+
+    let result = Instructions::close_project(
+        &mut fixture.svm,
+        PROJECT_1_ID,
+        owner,
+        owner,
+        &[],
+        fixture.payer.pubkey(),
+        &[
+            &fixture.project_owner.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, the project still has a fee override");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("ProjectHasFeeOverride"),
+                "Expected ProjectHasFeeOverride error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_create_project_with_token_fee() {
+    let mut fixture = TestFixture::new().with_initialize_protocol();
+
+    let fee_mint = fixture.create_fee_mint();
+    let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+    let protocol_treasury_pda = AccountHelper::find_treasury_pda(&protocol_config_pda).0;
+    let owner = fixture.project_owner.pubkey();
+
+    let owner_token_account = fixture.create_token_account(&fee_mint, &owner);
+    let protocol_treasury_token_account =
+        fixture.create_token_account(&fee_mint, &protocol_treasury_pda);
+    fixture.mint_tokens(&fee_mint, &owner_token_account, 2 * FEE_TOKEN_AMOUNT);
+
+    let mut fixture = fixture.with_update_single_fee(
+        Operation::CreateProject,
+        Fee {
+            amount: FEE_TOKEN_AMOUNT,
+            fee_type: FeeType::Fixed,
+            mint: Some(fee_mint),
+        },
+    );
+
+    let protocol_treasury_initial_lamports =
+        utils::get_lamports(&fixture.svm, &protocol_treasury_pda);
+
+    let result = Instructions::create_project_with_token_fee(
+        &mut fixture.svm,
+        DEFAULT_PROJECT_NAME.to_string(),
+        DEFAULT_PROJECT_DESCRIPTION.to_string(),
+        owner,
+        fee_mint,
+        owner_token_account,
+        protocol_treasury_token_account,
+        fixture.payer.pubkey(),
+        &[
+            &fixture.project_owner.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            assert_eq!(
+                AccountHelper::get_token_balance(&fixture.svm, &protocol_treasury_token_account),
+                FEE_TOKEN_AMOUNT
+            );
+            assert_eq!(
+                AccountHelper::get_token_balance(&fixture.svm, &owner_token_account),
+                FEE_TOKEN_AMOUNT
+            );
+            assert_eq!(
+                utils::get_lamports(&fixture.svm, &protocol_treasury_pda),
+                protocol_treasury_initial_lamports
+            );
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_create_project_with_token_fee_without_token_accounts() {
+    let mut fixture = TestFixture::new().with_initialize_protocol();
+
+    let fee_mint = fixture.create_fee_mint();
+
+    let mut fixture = fixture.with_update_single_fee(
+        Operation::CreateProject,
+        Fee {
+            amount: FEE_TOKEN_AMOUNT,
+            fee_type: FeeType::Fixed,
+            mint: Some(fee_mint),
+        },
+    );
+
+    let result = Instructions::create_project(
+        &mut fixture.svm,
+        DEFAULT_PROJECT_NAME.to_string(),
+        DEFAULT_PROJECT_DESCRIPTION.to_string(),
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.project_owner.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, token fee accounts are missing");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("FeeTokenAccountsRequired"),
+                "Expected FeeTokenAccountsRequired error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_create_project_with_token_fee_wrong_treasury_account() {
+    let mut fixture = TestFixture::new().with_initialize_protocol();
+
+    let fee_mint = fixture.create_fee_mint();
+    let owner = fixture.project_owner.pubkey();
+    let owner_token_account = fixture.create_token_account(&fee_mint, &owner);
+    let attacker_token_account = fixture.create_token_account(&fee_mint, &Keypair::new().pubkey());
+    fixture.mint_tokens(&fee_mint, &owner_token_account, FEE_TOKEN_AMOUNT);
+
+    let mut fixture = fixture.with_update_single_fee(
+        Operation::CreateProject,
+        Fee {
+            amount: FEE_TOKEN_AMOUNT,
+            fee_type: FeeType::Fixed,
+            mint: Some(fee_mint),
+        },
+    );
+
+    let result = Instructions::create_project_with_token_fee(
+        &mut fixture.svm,
+        DEFAULT_PROJECT_NAME.to_string(),
+        DEFAULT_PROJECT_DESCRIPTION.to_string(),
+        owner,
+        fee_mint,
+        owner_token_account,
+        attacker_token_account,
+        fixture.payer.pubkey(),
+        &[
+            &fixture.project_owner.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, fee must go to the protocol treasury");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("InvalidTreasuryTokenAccount"),
+                "Expected InvalidTreasuryTokenAccount error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_transfer_protocol_token_fees() {
+    let mut fixture = TestFixture::new().with_initialize_protocol();
+
+    let fee_mint = fixture.create_fee_mint();
+    let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+    let protocol_treasury_pda = AccountHelper::find_treasury_pda(&protocol_config_pda).0;
+    let whitelisted = fixture.admin_2.pubkey();
+
+    let treasury_token_account = fixture.create_token_account(&fee_mint, &protocol_treasury_pda);
+    let to_token_account = fixture.create_token_account(&fee_mint, &whitelisted);
+    fixture.mint_tokens(&fee_mint, &treasury_token_account, FEE_TOKEN_AMOUNT);
+
+    let result = Instructions::protocol_token_fees_transfer(
+        &mut fixture.svm,
+        FEE_TOKEN_AMOUNT,
+        fixture.admin_1.pubkey(),
+        fee_mint,
+        treasury_token_account,
+        to_token_account,
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            assert_eq!(
+                AccountHelper::get_token_balance(&fixture.svm, &treasury_token_account),
+                0
+            );
+            assert_eq!(
+                AccountHelper::get_token_balance(&fixture.svm, &to_token_account),
+                FEE_TOKEN_AMOUNT
+            );
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_transfer_protocol_token_fees_not_whitelisted() {
+    let mut fixture = TestFixture::new().with_initialize_protocol();
+
+    let fee_mint = fixture.create_fee_mint();
+    let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+    let protocol_treasury_pda = AccountHelper::find_treasury_pda(&protocol_config_pda).0;
+
+    let treasury_token_account = fixture.create_token_account(&fee_mint, &protocol_treasury_pda);
+    let to_token_account = fixture.create_token_account(&fee_mint, &Keypair::new().pubkey());
+    fixture.mint_tokens(&fee_mint, &treasury_token_account, FEE_TOKEN_AMOUNT);
+
+    let result = Instructions::protocol_token_fees_transfer(
+        &mut fixture.svm,
+        FEE_TOKEN_AMOUNT,
+        fixture.admin_1.pubkey(),
+        fee_mint,
+        treasury_token_account,
+        to_token_account,
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, destination owner is not whitelisted");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("AddressNotWhiteListed"),
+                "Expected AddressNotWhiteListed error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_transfer_protocol_token_fees_exceeds_transfer_cap() {
+    let mut fixture = TestFixture::new().with_initialize_protocol();
+
+    let fee_mint = fixture.create_fee_mint();
+    let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+    let protocol_treasury_pda = AccountHelper::find_treasury_pda(&protocol_config_pda).0;
+    let destination = Keypair::new().pubkey();
+
+    Instructions::add_whitelist_transfer_addr(
+        &mut fixture.svm,
+        destination,
+        None,
+        Some(FEE_TOKEN_AMOUNT - 1),
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to whitelist destination");
+
+    let treasury_token_account = fixture.create_token_account(&fee_mint, &protocol_treasury_pda);
+    let to_token_account = fixture.create_token_account(&fee_mint, &destination);
+    fixture.mint_tokens(&fee_mint, &treasury_token_account, FEE_TOKEN_AMOUNT);
+
+    let result = Instructions::protocol_token_fees_transfer(
+        &mut fixture.svm,
+        FEE_TOKEN_AMOUNT,
+        fixture.admin_1.pubkey(),
+        fee_mint,
+        treasury_token_account,
+        to_token_account,
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, amount exceeds the destination transfer cap");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("TransferCapExceeded"),
+                "Expected TransferCapExceeded error, got: {:?}",
+                e
+            );
+            assert_eq!(
+                AccountHelper::get_token_balance(&fixture.svm, &treasury_token_account),
+                FEE_TOKEN_AMOUNT
+            );
+        }
+    }
+}
+
+#[test]
+fn test_execute_transfer_protocol_token_fees_proposal() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_approval_threshold(2);
+
+    let fee_mint = fixture.create_fee_mint();
+    let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+    let protocol_treasury_pda = AccountHelper::find_treasury_pda(&protocol_config_pda).0;
+    let whitelisted = fixture.admin_2.pubkey();
+
+    let treasury_token_account = fixture.create_token_account(&fee_mint, &protocol_treasury_pda);
+    let to_token_account = fixture.create_token_account(&fee_mint, &whitelisted);
+    fixture.mint_tokens(&fee_mint, &treasury_token_account, FEE_TOKEN_AMOUNT);
+
+    let proposal_id = AccountHelper::get_protocol_config(&fixture.svm).proposal_count;
+
+    Instructions::create_proposal(
+        &mut fixture.svm,
+        ProposalAction::TransferProtocolTokenFees {
+            mint: fee_mint,
+            to: to_token_account,
+            amount: FEE_TOKEN_AMOUNT,
+        },
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to create proposal");
+
+    Instructions::approve_proposal(
+        &mut fixture.svm,
+        proposal_id,
+        fixture.admin_2.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_2.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to approve proposal");
+
+    let token_program_id = Pubkey::from_str(TOKEN_PROGRAM_ID).expect("Invalid token program ID");
+
+    let result = Instructions::execute_proposal_with(
+        &mut fixture.svm,
+        proposal_id,
+        fixture.admin_1.pubkey(),
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+        |builder| {
+            builder
+                .mint(Some(fee_mint))
+                .treasury_token_account(Some(treasury_token_account))
+                .to_token_account(Some(to_token_account))
+                .token_program(Some(token_program_id));
+        },
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            assert_eq!(
+                AccountHelper::get_token_balance(&fixture.svm, &treasury_token_account),
+                0
+            );
+            assert_eq!(
+                AccountHelper::get_token_balance(&fixture.svm, &to_token_account),
+                FEE_TOKEN_AMOUNT
+            );
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_transfer_project_token_fees() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_project_created(PROJECT_1_ID);
+
+    let fee_mint = fixture.create_fee_mint();
+    let owner = fixture.project_owner.pubkey();
+    let project_config_pda = AccountHelper::find_project_pda(&owner, PROJECT_1_ID).0;
+    let treasury_pda = AccountHelper::find_treasury_pda(&project_config_pda).0;
+
+    let treasury_token_account = fixture.create_token_account(&fee_mint, &treasury_pda);
+    let owner_token_account = fixture.create_token_account(&fee_mint, &owner);
+    fixture.mint_tokens(&fee_mint, &treasury_token_account, FEE_TOKEN_AMOUNT);
+
+    let result = Instructions::transfer_project_token_fees(
+        &mut fixture.svm,
+        FEE_TOKEN_AMOUNT,
+        owner,
+        fee_mint,
+        treasury_token_account,
+        owner_token_account,
+        PROJECT_1_ID,
+        fixture.payer.pubkey(),
+        &[
+            &fixture.project_owner.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            assert_eq!(
+                AccountHelper::get_token_balance(&fixture.svm, &treasury_token_account),
+                0
+            );
+            assert_eq!(
+                AccountHelper::get_token_balance(&fixture.svm, &owner_token_account),
+                FEE_TOKEN_AMOUNT
+            );
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_create_project_with_hybrid_fee() {
+    let hybrid_fee = Fee {
+        amount: 1_000_000,
+        fee_type: FeeType::Hybrid {
+            bps: 500,
+            min: Some(1_500_000),
+            max: None,
+        },
+        mint: None,
+    };
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_update_single_fee(Operation::CreateProject, hybrid_fee.clone());
+
+    let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+    let protocol_treasury_pda = AccountHelper::find_treasury_pda(&protocol_config_pda).0;
+    let protocol_treasury_initial_balance =
+        utils::get_lamports(&fixture.svm, &protocol_treasury_pda);
+
+    let result = Instructions::create_project(
+        &mut fixture.svm,
+        DEFAULT_PROJECT_NAME.to_string(),
+        DEFAULT_PROJECT_DESCRIPTION.to_string(),
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.project_owner.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            // No base amount for project creation, so the fixed part is raised to the minimum
+            let expected_fee = fees::quote_fee(&hybrid_fee, None).unwrap();
+            assert_eq!(expected_fee, 1_500_000);
+
+            assert_eq!(
+                utils::get_lamports(&fixture.svm, &protocol_treasury_pda),
+                protocol_treasury_initial_balance + expected_fee
+            );
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_update_single_fee_invalid_bps() {
+    let mut fixture = TestFixture::new().with_initialize_protocol();
+
+    let result = Instructions::update_single_fee(
+        &mut fixture.svm,
+        Operation::TradeNFT,
+        Fee {
+            amount: 0,
+            fee_type: FeeType::Hybrid {
+                bps: 10_001,
+                min: None,
+                max: None,
+            },
+            mint: None,
+        },
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, fee bps cannot exceed 10000");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("InvalidFeeBps"),
+                "Expected InvalidFeeBps error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_update_single_fee_percentage_token_fee() {
+    let mut fixture = TestFixture::new().with_initialize_protocol();
+
+    let result = Instructions::update_single_fee(
+        &mut fixture.svm,
+        Operation::TradeNFT,
+        Fee {
+            amount: 250,
+            fee_type: FeeType::Percentage,
+            mint: Some(Keypair::new().pubkey()),
+        },
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, token fees cannot be a percentage of lamports");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("TokenFeeMustBeFixed"),
+                "Expected TokenFeeMustBeFixed error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_update_single_fee_invalid_bounds() {
+    let mut fixture = TestFixture::new().with_initialize_protocol();
+
+    let result = Instructions::update_single_fee(
+        &mut fixture.svm,
+        Operation::MintAsset,
+        Fee {
+            amount: 10_000,
+            fee_type: FeeType::Hybrid {
+                bps: 100,
+                min: Some(50_000),
+                max: Some(20_000),
+            },
+            mint: None,
+        },
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, fee minimum exceeds maximum");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("InvalidFeeBounds"),
+                "Expected InvalidFeeBounds error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_set_project_fee_override() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID);
+
+    let project_config_pda =
+        AccountHelper::find_project_pda(&fixture.project_owner.pubkey(), PROJECT_1_ID).0;
+    let overrides = vec![
+        OperationFeeOverride {
+            operation: Operation::MintAsset,
+            adjustment: FeeAdjustment::Discount { bps: 5_000 },
+        },
+        OperationFeeOverride {
+            operation: Operation::CreateTradeHub,
+            adjustment: FeeAdjustment::Override {
+                fee: Fee {
+                    amount: 0,
+                    fee_type: FeeType::Fixed,
+                    mint: None,
+                },
+            },
+        },
+    ];
+
+    let result = Instructions::set_project_fee_override(
+        &mut fixture.svm,
+        overrides.clone(),
+        Some(FEE_OVERRIDE_EXPIRES_AT),
+        project_config_pda,
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let protocol_config = AccountHelper::get_protocol_config(&fixture.svm);
+            let fee_override =
+                AccountHelper::get_project_fee_override(&fixture.svm, &project_config_pda)
+                    .expect("Project fee override should exist");
+
+            let clock: Clock = fixture.svm.get_sysvar();
+
+            assert_eq!(fee_override.project_config, project_config_pda);
+            assert_eq!(
+                fee_override.overrides,
+                vec![
+                    ScheduledFeeOverride {
+                        operation: overrides[0].operation,
+                        adjustment: overrides[0].adjustment.clone(),
+                        effective_at: clock.unix_timestamp,
+                    },
+                    ScheduledFeeOverride {
+                        operation: overrides[1].operation,
+                        adjustment: overrides[1].adjustment.clone(),
+                        effective_at: clock.unix_timestamp + FEE_UPDATE_DELAY,
+                    },
+                ]
+            );
+            assert_eq!(fee_override.expires_at, Some(FEE_OVERRIDE_EXPIRES_AT));
+
+            let quote = |operation, now| {
+                fees::quote_project_fee(&protocol_config, Some(&fee_override), operation, None, now)
+            };
+            let now = clock.unix_timestamp;
+            assert_eq!(
+                quote(Operation::MintAsset, now),
+                Some(FEE_MINT_ASSET_AMOUNT / 2)
+            );
+            assert_eq!(
+                quote(Operation::CreateTradeHub, now),
+                Some(FEE_CREATE_TRADE_HUB_AMOUNT),
+                "An Override should only apply after the fee update delay"
+            );
+            assert_eq!(
+                quote(Operation::CreateMinterConfig, now),
+                Some(FEE_CREATE_MINTER_CONFIG_AMOUNT)
+            );
+            assert_eq!(
+                quote(Operation::MintAsset, FEE_OVERRIDE_EXPIRES_AT),
+                Some(FEE_MINT_ASSET_AMOUNT),
+                "An expired override should no longer apply"
+            );
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_set_project_fee_override_keeps_unchanged_overrides() {
+    let free_trade_hub = OperationFeeOverride {
+        operation: Operation::CreateTradeHub,
+        adjustment: FeeAdjustment::Override {
+            fee: Fee {
+                amount: 0,
+                fee_type: FeeType::Fixed,
+                mint: None,
+            },
+        },
+    };
+
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_project_fee_override(PROJECT_1_ID, vec![free_trade_hub.clone()], None);
+
+    let project_config_pda =
+        AccountHelper::find_project_pda(&fixture.project_owner.pubkey(), PROJECT_1_ID).0;
+    let scheduled_at = AccountHelper::get_project_fee_override(&fixture.svm, &project_config_pda)
+        .expect("Project fee override should exist")
+        .overrides[0]
+        .effective_at;
+
+    utils::warp_clock(&mut fixture.svm, FEE_UPDATE_DELAY);
+
+    // Adding a discount must not push the active Override back by another delay
+    let result = Instructions::set_project_fee_override(
+        &mut fixture.svm,
+        vec![
+            free_trade_hub,
+            OperationFeeOverride {
+                operation: Operation::MintAsset,
+                adjustment: FeeAdjustment::Discount { bps: 5_000 },
+            },
+        ],
+        None,
+        project_config_pda,
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let protocol_config = AccountHelper::get_protocol_config(&fixture.svm);
+            let fee_override =
+                AccountHelper::get_project_fee_override(&fixture.svm, &project_config_pda)
+                    .expect("Project fee override should exist");
+            let now = fixture.svm.get_sysvar::<Clock>().unix_timestamp;
+
+            assert_eq!(fee_override.overrides[0].effective_at, scheduled_at);
+            assert_eq!(fee_override.overrides[1].effective_at, now);
+
+            let quote = |operation| {
+                fees::quote_project_fee(&protocol_config, Some(&fee_override), operation, None, now)
+            };
+            assert_eq!(quote(Operation::CreateTradeHub), Some(0));
+            assert_eq!(quote(Operation::MintAsset), Some(FEE_MINT_ASSET_AMOUNT / 2));
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_set_project_fee_override_by_non_admin() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID);
+
+    let project_config_pda =
+        AccountHelper::find_project_pda(&fixture.project_owner.pubkey(), PROJECT_1_ID).0;
+
+    let result = Instructions::set_project_fee_override(
+        &mut fixture.svm,
+        vec![OperationFeeOverride {
+            operation: Operation::MintAsset,
+            adjustment: FeeAdjustment::Discount { bps: 10_000 },
+        }],
+        None,
+        project_config_pda,
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.project_owner.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, only admins can set fee overrides");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("Unauthorized"),
+                "Expected Unauthorized error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_set_project_fee_override_on_other_instance_project() {
+    let mut fixture = TestFixture::new().with_initialize_protocol();
+
+    Instructions::initialize_protocol_instance(
+        &mut fixture.svm,
+        PROTOCOL_INSTANCE_ID,
+        vec![fixture.admin_2.pubkey()],
+        vec![],
+        default_fees_structure(),
+        Some(fixture.admin_1.pubkey()),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.payer.insecure_clone(),
+            &fixture.admin_1.insecure_clone(),
+        ],
+    )
+    .expect("Failed to initialize protocol instance");
+    Instructions::create_instance_project(
+        &mut fixture.svm,
+        PROTOCOL_INSTANCE_ID,
+        DEFAULT_PROJECT_NAME.to_string(),
+        DEFAULT_PROJECT_DESCRIPTION.to_string(),
+        fixture.project_owner.pubkey(),
+        None,
+        fixture.payer.pubkey(),
+        &[
+            &fixture.project_owner.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to create instance project");
+
+    let project_config_pda = AccountHelper::find_instance_project_pda(
+        PROTOCOL_INSTANCE_ID,
+        &fixture.project_owner.pubkey(),
+        0,
+    )
+    .0;
+
+    // admin_1 administers the default instance only
+    let result = Instructions::set_project_fee_override(
+        &mut fixture.svm,
+        vec![OperationFeeOverride {
+            operation: Operation::MintAsset,
+            adjustment: FeeAdjustment::Discount { bps: 10_000 },
+        }],
+        None,
+        project_config_pda,
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, the project belongs to another instance");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("InvalidProjectConfig"),
+                "Expected InvalidProjectConfig error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_set_project_fee_override_duplicate_operation() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID);
+
+    let project_config_pda =
+        AccountHelper::find_project_pda(&fixture.project_owner.pubkey(), PROJECT_1_ID).0;
+    let discount = OperationFeeOverride {
+        operation: Operation::MintAsset,
+        adjustment: FeeAdjustment::Discount { bps: 1_000 },
+    };
+
+    let result = Instructions::set_project_fee_override(
+        &mut fixture.svm,
+        vec![discount.clone(), discount],
+        None,
+        project_config_pda,
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, an operation can only be overridden once");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("DuplicateFeeOverride"),
+                "Expected DuplicateFeeOverride error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_close_project_fee_override() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_project_fee_override(
+            PROJECT_1_ID,
+            vec![OperationFeeOverride {
+                operation: Operation::MintAsset,
+                adjustment: FeeAdjustment::Discount { bps: 2_500 },
+            }],
+            None,
+        );
+
+    let project_config_pda =
+        AccountHelper::find_project_pda(&fixture.project_owner.pubkey(), PROJECT_1_ID).0;
+
+    let result = Instructions::close_project_fee_override(
+        &mut fixture.svm,
+        project_config_pda,
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            assert!(
+                AccountHelper::get_project_fee_override(&fixture.svm, &project_config_pda)
+                    .is_none()
+            );
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_execute_set_project_fee_override_proposal() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_approval_threshold(2);
+
+    fixture
+        .svm
+        .airdrop(&fixture.admin_1.pubkey(), LAMPORTS_PER_SOL)
+        .expect("Failed to fund admin");
+
+    let project_config_pda =
+        AccountHelper::find_project_pda(&fixture.project_owner.pubkey(), PROJECT_1_ID).0;
+    let project_fee_override_pda =
+        AccountHelper::find_project_fee_override_pda(&project_config_pda).0;
+    let overrides = vec![OperationFeeOverride {
+        operation: Operation::MintAsset,
+        adjustment: FeeAdjustment::Discount { bps: 5_000 },
+    }];
+
+    let proposal_id = AccountHelper::get_protocol_config(&fixture.svm).proposal_count;
+
+    Instructions::create_proposal(
+        &mut fixture.svm,
+        ProposalAction::SetProjectFeeOverride {
+            project_config: project_config_pda,
+            overrides: overrides.clone(),
+            expires_at: None,
+        },
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to create proposal");
+
+    Instructions::approve_proposal(
+        &mut fixture.svm,
+        proposal_id,
+        fixture.admin_2.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_2.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to approve proposal");
+
+    let result = Instructions::execute_proposal_with(
+        &mut fixture.svm,
+        proposal_id,
+        fixture.admin_1.pubkey(),
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+        |builder| {
+            builder
+                .project_config(Some(project_config_pda))
+                .project_fee_override(Some(project_fee_override_pda));
+        },
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let fee_override =
+                AccountHelper::get_project_fee_override(&fixture.svm, &project_config_pda)
+                    .expect("Project fee override should exist");
+            let clock: Clock = fixture.svm.get_sysvar();
+
+            assert_eq!(fee_override.project_config, project_config_pda);
+            assert_eq!(fee_override.overrides.len(), 1);
+            assert_eq!(fee_override.overrides[0].operation, overrides[0].operation);
+            assert_eq!(
+                fee_override.overrides[0].adjustment,
+                overrides[0].adjustment
+            );
+            assert_eq!(
+                fee_override.overrides[0].effective_at, clock.unix_timestamp,
+                "A discount should apply immediately"
+            );
+            assert_eq!(fee_override.expires_at, None);
+            assert_eq!(fee_override.payer, fixture.admin_1.pubkey());
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_execute_close_project_fee_override_proposal() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID)
+        .with_project_fee_override(
+            PROJECT_1_ID,
+            vec![OperationFeeOverride {
+                operation: Operation::MintAsset,
+                adjustment: FeeAdjustment::Discount { bps: 2_500 },
+            }],
+            None,
+        )
+        .with_approval_threshold(2);
+
+    fixture
+        .svm
+        .airdrop(&fixture.admin_1.pubkey(), LAMPORTS_PER_SOL)
+        .expect("Failed to fund admin");
+
+    let project_config_pda =
+        AccountHelper::find_project_pda(&fixture.project_owner.pubkey(), PROJECT_1_ID).0;
+    let project_fee_override_pda =
+        AccountHelper::find_project_fee_override_pda(&project_config_pda).0;
+    let proposal_id = AccountHelper::get_protocol_config(&fixture.svm).proposal_count;
+
+    Instructions::create_proposal(
+        &mut fixture.svm,
+        ProposalAction::CloseProjectFeeOverride {
+            project_config: project_config_pda,
+        },
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to create proposal");
+
+    Instructions::approve_proposal(
+        &mut fixture.svm,
+        proposal_id,
+        fixture.admin_2.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_2.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to approve proposal");
+
+    let rent = utils::get_lamports(&fixture.svm, &project_fee_override_pda);
+    let payer_initial_balance = utils::get_lamports(&fixture.svm, &fixture.payer.pubkey());
+
+    // The fixture payer created the override and gets the rent back. Only withdrawals
+    // need the fee stats.
+    let result = Instructions::execute_proposal_with(
+        &mut fixture.svm,
+        proposal_id,
+        fixture.admin_1.pubkey(),
+        fixture.admin_1.pubkey(),
+        fixture.admin_1.pubkey(),
+        &[&fixture.admin_1.insecure_clone()],
+        |builder| {
+            builder
+                .to(Some(fixture.payer.pubkey()))
+                .fee_stats(None)
+                .project_config(Some(project_config_pda))
+                .project_fee_override(Some(project_fee_override_pda));
+        },
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            assert!(
+                AccountHelper::get_project_fee_override(&fixture.svm, &project_config_pda)
+                    .is_none()
+            );
+            assert_eq!(
+                utils::get_lamports(&fixture.svm, &fixture.payer.pubkey()),
+                payer_initial_balance + rent
+            );
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_distribute_protocol_fees() {
+    let recipient_1 = Keypair::new().pubkey();
+    let recipient_2 = Keypair::new().pubkey();
+
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_revenue_shares(vec![
+            RevenueShare {
+                recipient: recipient_1,
+                bps: 7_000,
+            },
+            RevenueShare {
+                recipient: recipient_2,
+                bps: 3_000,
+            },
+        ]);
+
+    let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+    let (protocol_treasury_pda, _) = AccountHelper::find_treasury_pda(&protocol_config_pda);
+
+    fixture
+        .svm
+        .airdrop(&protocol_treasury_pda, 5 * LAMPORTS_PER_SOL)
+        .expect("Failed to fund protocol treasury");
+
+    let rent_exempt = fixture.svm.minimum_balance_for_rent_exemption(0);
+    let distributable = utils::get_lamports(&fixture.svm, &protocol_treasury_pda) - rent_exempt;
+
+    // Permissionless: the crank is signed by an account that is neither admin nor recipient
+    let result = Instructions::distribute_protocol_fees(
+        &mut fixture.svm,
+        &[recipient_1, recipient_2],
+        fixture.payer.pubkey(),
+        &[&fixture.payer.insecure_clone()],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let recipient_1_amount = distributable * 7_000 / 10_000;
+            let recipient_2_amount = distributable * 3_000 / 10_000;
+
+            assert_eq!(
+                utils::get_lamports(&fixture.svm, &recipient_1),
+                recipient_1_amount
+            );
+            assert_eq!(
+                utils::get_lamports(&fixture.svm, &recipient_2),
+                recipient_2_amount
+            );
+            assert_eq!(
+                utils::get_lamports(&fixture.svm, &protocol_treasury_pda),
+                rent_exempt + distributable - recipient_1_amount - recipient_2_amount,
+                "Treasury should keep its rent-exempt minimum"
+            );
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_distribute_protocol_fees_recipient_mismatch() {
+    let recipient_1 = Keypair::new().pubkey();
+    let recipient_2 = Keypair::new().pubkey();
+
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_revenue_shares(vec![
+            RevenueShare {
+                recipient: recipient_1,
+                bps: 5_000,
+            },
+            RevenueShare {
+                recipient: recipient_2,
+                bps: 5_000,
+            },
+        ]);
+
+    let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+    let (protocol_treasury_pda, _) = AccountHelper::find_treasury_pda(&protocol_config_pda);
+
+    fixture
+        .svm
+        .airdrop(&protocol_treasury_pda, 5 * LAMPORTS_PER_SOL)
+        .expect("Failed to fund protocol treasury");
+
+    let result = Instructions::distribute_protocol_fees(
+        &mut fixture.svm,
+        &[recipient_1, fixture.payer.pubkey()],
+        fixture.payer.pubkey(),
+        &[&fixture.payer.insecure_clone()],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, recipients must match the revenue shares");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("RevenueRecipientMismatch"),
+                "Expected RevenueRecipientMismatch error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_distribute_protocol_fees_without_revenue_shares() {
+    let mut fixture = TestFixture::new().with_initialize_protocol();
+
+    let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+    let (protocol_treasury_pda, _) = AccountHelper::find_treasury_pda(&protocol_config_pda);
+
+    fixture
+        .svm
+        .airdrop(&protocol_treasury_pda, 5 * LAMPORTS_PER_SOL)
+        .expect("Failed to fund protocol treasury");
+
+    let result = Instructions::distribute_protocol_fees(
+        &mut fixture.svm,
+        &[],
+        fixture.payer.pubkey(),
+        &[&fixture.payer.insecure_clone()],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, no revenue shares are configured");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("NoRevenueShares"),
+                "Expected NoRevenueShares error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_set_revenue_shares_invalid_total() {
+    let mut fixture = TestFixture::new().with_initialize_protocol();
+
+    let result = Instructions::set_revenue_shares(
+        &mut fixture.svm,
+        vec![
+            RevenueShare {
+                recipient: fixture.admin_1.pubkey(),
+                bps: 5_000,
+            },
+            RevenueShare {
+                recipient: fixture.admin_2.pubkey(),
+                bps: 4_000,
+            },
+        ],
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, revenue shares must add up to 10000 bps");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("InvalidRevenueShares"),
+                "Expected InvalidRevenueShares error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_create_project_records_fee_stats() {
+    let mut fixture = TestFixture::new().with_initialize_protocol();
+
+    let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+    let project_config_pda =
+        AccountHelper::find_project_pda(&fixture.project_owner.pubkey(), PROJECT_1_ID).0;
+
+    let result = Instructions::create_project(
+        &mut fixture.svm,
+        DEFAULT_PROJECT_NAME.to_string(),
+        DEFAULT_PROJECT_DESCRIPTION.to_string(),
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.project_owner.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let protocol_fee_stats =
+                AccountHelper::get_fee_stats(&fixture.svm, &protocol_config_pda);
+            let project_fee_stats = AccountHelper::get_fee_stats(&fixture.svm, &project_config_pda);
+
+            assert_eq!(protocol_fee_stats.owner, protocol_config_pda);
+            assert_eq!(protocol_fee_stats.protocol_fees.create_project.count, 1);
+            assert_eq!(
+                protocol_fee_stats.protocol_fees.create_project.lamports,
+                FEE_CREATE_PROJECT_AMOUNT
+            );
+            assert_eq!(protocol_fee_stats.available, FEE_CREATE_PROJECT_AMOUNT);
+            assert_eq!(protocol_fee_stats.withdrawn, 0);
+
+            // The project paid the fee but its treasury did not collect it
+            assert_eq!(project_fee_stats.owner, project_config_pda);
+            assert_eq!(project_fee_stats.protocol_fees.create_project.count, 1);
+            assert_eq!(
+                project_fee_stats.protocol_fees.create_project.lamports,
+                FEE_CREATE_PROJECT_AMOUNT
+            );
+            assert_eq!(project_fee_stats.available, 0);
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_protocol_fees_transfer_records_withdrawal() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_project_created(PROJECT_1_ID);
+
+    let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+    let (protocol_treasury_pda, _) = AccountHelper::find_treasury_pda(&protocol_config_pda);
+
+    fixture
+        .svm
+        .airdrop(&protocol_treasury_pda, LAMPORTS_PER_SOL)
+        .expect("Failed to fund protocol treasury");
+
+    let transfer_amount = FEE_CREATE_PROJECT_AMOUNT / 2;
+
+    let result = Instructions::protocol_fees_transfer(
+        &mut fixture.svm,
+        transfer_amount,
+        fixture.admin_1.pubkey(),
+        fixture.admin_2.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.payer.insecure_clone(),
+            &fixture.admin_1.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let fee_stats = AccountHelper::get_fee_stats(&fixture.svm, &protocol_config_pda);
+
+            assert_eq!(
+                fee_stats.available,
+                FEE_CREATE_PROJECT_AMOUNT - transfer_amount
+            );
+            assert_eq!(fee_stats.withdrawn, transfer_amount);
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_project_fees_transfer_records_withdrawal() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_project_created(PROJECT_1_ID);
+
+    let project_config_pda =
+        AccountHelper::find_project_pda(&fixture.project_owner.pubkey(), PROJECT_1_ID).0;
+    let treasury_pda = AccountHelper::find_treasury_pda(&project_config_pda).0;
+
+    fixture
+        .svm
+        .airdrop(&treasury_pda, 3 * LAMPORTS_PER_SOL)
+        .expect("Failed to fund treasury");
+
+    let transfer_amount = LAMPORTS_PER_SOL;
+
+    let result = Instructions::transfer_project_fees(
+        &mut fixture.svm,
+        transfer_amount,
+        fixture.project_owner.pubkey(),
+        fixture.project_owner.pubkey(),
+        fixture.project_authority_1.pubkey(),
+        PROJECT_1_ID,
+        fixture.payer.pubkey(),
+        &[
+            &fixture.project_owner.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let fee_stats = AccountHelper::get_fee_stats(&fixture.svm, &project_config_pda);
+
+            // Lamports sent to the treasury directly are not fees, available cannot go negative
+            assert_eq!(fee_stats.available, 0);
+            assert_eq!(fee_stats.withdrawn, transfer_amount);
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_migrate_protocol_config() {
+    let mut fixture = TestFixture::new().with_initialize_protocol();
+
+    let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+    let protocol_config = AccountHelper::get_protocol_config(&fixture.svm);
+    let account_len = fixture
+        .svm
+        .get_account(&protocol_config_pda)
+        .unwrap()
+        .data
+        .len();
+    assert_eq!(protocol_config.version, 3);
+
+    AccountHelper::write_v0_config(
+        &mut fixture.svm,
+        &protocol_config_pda,
+        &ProtocolConfigV0::from_config(&protocol_config),
+        PROTOCOL_CONFIG_V0_SPACE,
+    );
+
+    let result = Instructions::migrate_protocol_config(
+        &mut fixture.svm,
+        fixture.admin_1.pubkey(),
+        &[],
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let migrated = AccountHelper::get_protocol_config(&fixture.svm);
+            let account = fixture.svm.get_account(&protocol_config_pda).unwrap();
+
+            assert_eq!(migrated.version, 3);
+            assert_eq!(migrated.instance_id, 0);
+            assert_eq!(migrated.approval_threshold, 1);
+            assert!(migrated.subscription_plans.is_empty());
+            assert_eq!(migrated.admins, protocol_config.admins);
+            assert_eq!(
+                migrated.whitelist_transfer_addrs,
+                protocol_config.whitelist_transfer_addrs
+            );
+            assert_eq!(migrated.fees, protocol_config.fees);
+            assert_eq!(migrated.bump, protocol_config.bump);
+            assert_eq!(account.data.len(), account_len);
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_migrate_protocol_config_from_version_1() {
+    let mut fixture = TestFixture::new().with_initialize_protocol();
+
+    let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+    let mut protocol_config = AccountHelper::get_protocol_config(&fixture.svm);
+    protocol_config.version = 1;
+
+    // Version 1 configs end with the version byte, the fields of later versions came after
+    AccountHelper::write_legacy_config(
+        &mut fixture.svm,
+        &protocol_config_pda,
+        &protocol_config,
+        PROTOCOL_CONFIG_V1_APPENDED_LEN,
+    );
+
+    let result = Instructions::migrate_protocol_config(
+        &mut fixture.svm,
+        fixture.admin_1.pubkey(),
+        &[],
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let migrated = AccountHelper::get_protocol_config(&fixture.svm);
+
+            assert_eq!(migrated.version, 3);
+            assert_eq!(migrated.instance_id, 0);
+            assert_eq!(migrated.admins, protocol_config.admins);
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_migrate_protocol_config_from_version_2() {
+    let mut fixture = TestFixture::new().with_initialize_protocol();
+
+    let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+    let mut protocol_config = AccountHelper::get_protocol_config(&fixture.svm);
+    protocol_config.version = 2;
+
+    // Version 2 configs end with the instance id, the subscription plans vector came after
+    AccountHelper::write_legacy_config(&mut fixture.svm, &protocol_config_pda, &protocol_config, 4);
+
+    let result = Instructions::migrate_protocol_config(
+        &mut fixture.svm,
+        fixture.admin_1.pubkey(),
+        &[],
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let migrated = AccountHelper::get_protocol_config(&fixture.svm);
+
+            assert_eq!(migrated.version, 3);
+            assert!(migrated.subscription_plans.is_empty());
+            assert_eq!(migrated.admins, protocol_config.admins);
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_migrate_protocol_config_already_migrated() {
+    let mut fixture = TestFixture::new().with_initialize_protocol();
+
+    let result = Instructions::migrate_protocol_config(
+        &mut fixture.svm,
+        fixture.admin_1.pubkey(),
+        &[],
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, protocol config is already migrated");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("AlreadyMigrated"),
+                "Expected AlreadyMigrated error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_migrate_protocol_config_unauthorized() {
+    let mut fixture = TestFixture::new().with_initialize_protocol();
+
+    let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+    let protocol_config = AccountHelper::get_protocol_config(&fixture.svm);
+    AccountHelper::write_v0_config(
+        &mut fixture.svm,
+        &protocol_config_pda,
+        &ProtocolConfigV0::from_config(&protocol_config),
+        PROTOCOL_CONFIG_V0_SPACE,
+    );
+
+    let non_admin = Keypair::new();
+    fixture
+        .svm
+        .airdrop(&non_admin.pubkey(), LAMPORTS_PER_SOL)
+        .expect("Failed to fund non-admin");
+
+    let result = Instructions::migrate_protocol_config(
+        &mut fixture.svm,
+        non_admin.pubkey(),
+        &[],
+        fixture.payer.pubkey(),
+        &[&fixture.payer.insecure_clone(), &non_admin.insecure_clone()],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, non-admin cannot migrate the protocol config");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("Unauthorized"),
+                "Expected Unauthorized error, got: {:?}",
+                e
+            );
+            assert_eq!(
+                fixture
+                    .svm
+                    .get_account(&protocol_config_pda)
+                    .unwrap()
+                    .data
+                    .len(),
+                PROTOCOL_CONFIG_V0_SPACE
+            );
+        }
+    }
+}
+
+#[test]
+fn test_migrate_project_config() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID);
+
+    let project_config_pda =
+        AccountHelper::find_project_pda(&fixture.project_owner.pubkey(), PROJECT_1_ID).0;
+    let project_config = AccountHelper::get_project_config(
+        &fixture.svm,
+        &fixture.project_owner.pubkey(),
+        PROJECT_1_ID,
+    );
+    assert_eq!(project_config.version, 3);
+    assert_eq!(project_config.roles_count, 2);
+
+    AccountHelper::write_v0_config(
+        &mut fixture.svm,
+        &project_config_pda,
+        &ProjectConfigV0::from_config(&project_config),
+        PROJECT_CONFIG_V0_SPACE,
+    );
+    // The first release had no project registry
+    let project_registry_pda = AccountHelper::find_project_registry_pda(DEFAULT_PROJECT_NAME).0;
+    AccountHelper::remove_account(&mut fixture.svm, &project_registry_pda);
+    utils::warp_epoch(&mut fixture.svm, SUBSCRIPTION_TRIAL_EPOCHS);
+
+    // The role PDAs granted by the fixture are counted by the migration
+    let role_pdas = [
+        AccountHelper::find_project_role_pda(
+            &project_config_pda,
+            &fixture.project_authority_1.pubkey(),
+        )
+        .0,
+        AccountHelper::find_project_role_pda(
+            &project_config_pda,
+            &fixture.project_authority_2.pubkey(),
+        )
+        .0,
+    ];
+
+    let result = Instructions::migrate_project_config(
+        &mut fixture.svm,
+        project_config_pda,
+        DEFAULT_PROJECT_NAME,
+        fixture.admin_1.pubkey(),
+        &[],
+        &role_pdas,
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let migrated = AccountHelper::get_project_config(
+                &fixture.svm,
+                &fixture.project_owner.pubkey(),
+                PROJECT_1_ID,
+            );
+
+            let clock: Clock = fixture.svm.get_sysvar();
+
+            assert_eq!(migrated.version, 3);
+            assert_eq!(migrated.created_epoch, clock.epoch);
+            assert_eq!(migrated.roles_count, 2);
+            assert_eq!(migrated.minter_configs_count, 0);
+            assert_eq!(migrated.trade_hubs_count, 0);
+            assert_eq!(migrated.protocol_config, project_config.protocol_config);
+            assert_eq!(migrated.project_id, project_config.project_id);
+            assert_eq!(migrated.creator, project_config.owner);
+            assert_eq!(migrated.owner, project_config.owner);
+            assert_eq!(migrated.name, project_config.name);
+            assert_eq!(migrated.description, project_config.description);
+            assert_eq!(migrated.autthorities, project_config.autthorities);
+            assert_eq!(migrated.treasury_bump, project_config.treasury_bump);
+            assert_eq!(migrated.bump, project_config.bump);
+
+            let project_registry =
+                AccountHelper::get_project_registry(&fixture.svm, DEFAULT_PROJECT_NAME)
+                    .expect("Project registry entry not found");
+            assert_eq!(project_registry.project_config, project_config_pda);
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_migrate_project_config_with_registered_name() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID);
+
+    let owner = fixture.project_owner.pubkey();
+    let project_config_pda = AccountHelper::find_project_pda(&owner, PROJECT_1_ID).0;
+    let project_config = AccountHelper::get_project_config(&fixture.svm, &owner, PROJECT_1_ID);
+    AccountHelper::write_v0_config(
+        &mut fixture.svm,
+        &project_config_pda,
+        &ProjectConfigV0::from_config(&project_config),
+        PROJECT_CONFIG_V0_SPACE,
+    );
+    let project_registry_pda = AccountHelper::find_project_registry_pda(DEFAULT_PROJECT_NAME).0;
+    AccountHelper::remove_account(&mut fixture.svm, &project_registry_pda);
+
+    // Without a registry entry, the name of the first-release project is free to register
+    Instructions::create_project(
+        &mut fixture.svm,
+        DEFAULT_PROJECT_NAME.to_string(),
+        DEFAULT_PROJECT_DESCRIPTION.to_string(),
+        owner,
+        fixture.payer.pubkey(),
+        &[
+            &fixture.project_owner.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to create project");
+    let project_2_pda = AccountHelper::find_project_pda(&owner, PROJECT_2_ID).0;
+
+    let result = Instructions::migrate_project_config(
+        &mut fixture.svm,
+        project_config_pda,
+        DEFAULT_PROJECT_NAME,
+        fixture.admin_1.pubkey(),
+        &[],
+        &[],
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let project_registry =
+                AccountHelper::get_project_registry(&fixture.svm, DEFAULT_PROJECT_NAME)
+                    .expect("Project registry entry not found");
+            assert_eq!(project_registry.project_config, project_2_pda);
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+
+    // Renaming registers the new name and leaves the other project's entry in place
+    let new_name = "Renamed project".to_string();
+    Instructions::rename_project(
+        &mut fixture.svm,
+        PROJECT_1_ID,
+        owner,
+        new_name.clone(),
+        owner,
+        fixture.payer.pubkey(),
+        &[
+            &fixture.project_owner.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to rename project");
+
+    let project_registry = AccountHelper::get_project_registry(&fixture.svm, &new_name)
+        .expect("Project registry entry not found");
+    assert_eq!(project_registry.project_config, project_config_pda);
+    let project_registry = AccountHelper::get_project_registry(&fixture.svm, DEFAULT_PROJECT_NAME)
+        .expect("Project registry entry not found");
+    assert_eq!(project_registry.project_config, project_2_pda);
+}
+
+#[test]
+fn test_migrate_project_config_with_duplicate_role() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID);
+
+    let project_config_pda =
+        AccountHelper::find_project_pda(&fixture.project_owner.pubkey(), PROJECT_1_ID).0;
+    let project_config = AccountHelper::get_project_config(
+        &fixture.svm,
+        &fixture.project_owner.pubkey(),
+        PROJECT_1_ID,
+    );
+    AccountHelper::write_v0_config(
+        &mut fixture.svm,
+        &project_config_pda,
+        &ProjectConfigV0::from_config(&project_config),
+        PROJECT_CONFIG_V0_SPACE,
+    );
+
+    let role_pda = AccountHelper::find_project_role_pda(
+        &project_config_pda,
+        &fixture.project_authority_1.pubkey(),
+    )
+    .0;
+
+    // Counting the same role PDA twice would keep the project from ever being closed
+    let result = Instructions::migrate_project_config(
+        &mut fixture.svm,
+        project_config_pda,
+        DEFAULT_PROJECT_NAME,
+        fixture.admin_1.pubkey(),
+        &[],
+        &[role_pda, role_pda],
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, the role PDA is passed twice");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("InvalidProjectAccount"),
+                "Expected InvalidProjectAccount error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_migrate_protocol_config_requires_admin_signers() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_approval_threshold(2);
+
+    let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+    let mut protocol_config = AccountHelper::get_protocol_config(&fixture.svm);
+    protocol_config.version = 1;
+
+    // A first release config converts with an approval threshold of 1, a version 1 config
+    // keeps its own
+    AccountHelper::write_legacy_config(
+        &mut fixture.svm,
+        &protocol_config_pda,
+        &protocol_config,
+        PROTOCOL_CONFIG_V1_APPENDED_LEN,
+    );
+
+    let result = Instructions::migrate_protocol_config(
+        &mut fixture.svm,
+        fixture.admin_1.pubkey(),
+        &[],
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, one admin is below the approval threshold");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("ProposalThresholdNotMet"),
+                "Expected ProposalThresholdNotMet error, got: {:?}",
+                e
+            );
+        }
+    }
+
+    let result = Instructions::migrate_protocol_config(
+        &mut fixture.svm,
+        fixture.admin_1.pubkey(),
+        &[fixture.admin_2.pubkey()],
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.admin_2.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            assert_eq!(AccountHelper::get_protocol_config(&fixture.svm).version, 3);
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_migrate_full_configs() {
+    let mut fixture = TestFixture::new()
+        .with_metaplex_core_program()
+        .with_initialize_protocol()
+        .with_initialize_project(PROJECT_1_ID);
+
+    fixture
+        .svm
+        .airdrop(&fixture.admin_1.pubkey(), LAMPORTS_PER_SOL)
+        .expect("Failed to fund admin");
+
+    let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+    let project_config_pda =
+        AccountHelper::find_project_pda(&fixture.project_owner.pubkey(), PROJECT_1_ID).0;
+    let protocol_config_len = fixture
+        .svm
+        .get_account(&protocol_config_pda)
+        .unwrap()
+        .data
+        .len();
+    let project_config_len = fixture
+        .svm
+        .get_account(&project_config_pda)
+        .unwrap()
+        .data
+        .len();
+
+    // First release configs with their vectors and strings filled, leaving no spare bytes
+    let mut protocol_config =
+        ProtocolConfigV0::from_config(&AccountHelper::get_protocol_config(&fixture.svm));
+    protocol_config.admins = vec![fixture.admin_1.pubkey(), fixture.admin_2.pubkey()];
+    protocol_config
+        .admins
+        .resize_with(MAX_ADMINS, || Keypair::new().pubkey());
+    protocol_config.whitelist_transfer_addrs = (0..MAX_WHITELIST_TRANSFER_ADDRS)
+        .map(|_| Keypair::new().pubkey())
+        .collect();
+
+    let mut project_config = ProjectConfigV0::from_config(&AccountHelper::get_project_config(
+        &fixture.svm,
+        &fixture.project_owner.pubkey(),
+        PROJECT_1_ID,
+    ));
+    project_config.name = "n".repeat(MAX_PROJECT_NAME_LEN);
+    project_config.description = "d".repeat(MAX_PROJECT_DESCRIPTION_LEN);
+    project_config.autthorities = (0..MAX_PROJECT_AUTHORITIES)
+        .map(|_| Keypair::new().pubkey())
+        .collect();
+
+    AccountHelper::write_v0_config(
+        &mut fixture.svm,
+        &protocol_config_pda,
+        &protocol_config,
+        PROTOCOL_CONFIG_V0_SPACE,
+    );
+    AccountHelper::write_v0_config(
+        &mut fixture.svm,
+        &project_config_pda,
+        &project_config,
+        PROJECT_CONFIG_V0_SPACE,
+    );
+
+    // dac-manager only reads the protocol config, which it can before the migration
+    let result = Instructions::register_compute_node(
+        &mut fixture.svm,
+        fixture.compute_node.pubkey(),
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.payer.insecure_clone(),
+            &fixture.project_owner.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+
+    let create_minter_config = |fixture: &mut TestFixture| {
+        Instructions::create_minter_config(
+            &mut fixture.svm,
+            MINTER_NAME.to_string(),
+            MINT_PRICE,
+            MAX_SUPPLY,
+            None,
+            None,
+            None,
+            PROJECT_1_ID,
+            fixture.project_owner.pubkey(),
+            fixture.payer.pubkey(),
+            fixture.project_authority_1.pubkey(),
+            None,
+            &[
+                &fixture.payer.insecure_clone(),
+                &fixture.project_authority_1.insecure_clone(),
+            ],
+        )
+    };
+
+    // Creating a minter config counts it in the project config, which has no room for that
+    match create_minter_config(&mut fixture) {
+        Ok(_) => {
+            panic!("Transaction should have failed, the project config is not migrated");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("ConfigNotMigrated"),
+                "Expected ConfigNotMigrated error, got: {:?}",
+                e
+            );
+        }
+    }
+
+    Instructions::migrate_protocol_config(
+        &mut fixture.svm,
+        fixture.admin_1.pubkey(),
+        &[],
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to migrate protocol config");
+
+    Instructions::migrate_project_config(
+        &mut fixture.svm,
+        project_config_pda,
+        DEFAULT_PROJECT_NAME,
+        fixture.admin_1.pubkey(),
+        &[],
+        &[],
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to migrate project config");
+
+    let migrated_protocol_config = AccountHelper::get_protocol_config(&fixture.svm);
+    let migrated_project_config = AccountHelper::get_project_config(
+        &fixture.svm,
+        &fixture.project_owner.pubkey(),
+        PROJECT_1_ID,
+    );
+
+    assert_eq!(migrated_protocol_config.version, 3);
+    assert_eq!(migrated_protocol_config.admins, protocol_config.admins);
+    assert_eq!(
+        migrated_protocol_config
+            .whitelist_transfer_addrs
+            .iter()
+            .map(|entry| entry.address)
+            .collect::<Vec<_>>(),
+        protocol_config.whitelist_transfer_addrs
+    );
+    assert_eq!(migrated_project_config.version, 3);
+    assert_eq!(migrated_project_config.name, project_config.name);
+    assert_eq!(
+        migrated_project_config.description,
+        project_config.description
+    );
+    assert_eq!(
+        migrated_project_config.autthorities,
+        project_config.autthorities
+    );
+    assert_eq!(
+        fixture
+            .svm
+            .get_account(&protocol_config_pda)
+            .unwrap()
+            .data
+            .len(),
+        protocol_config_len
+    );
+    assert_eq!(
+        fixture
+            .svm
+            .get_account(&project_config_pda)
+            .unwrap()
+            .data
+            .len(),
+        project_config_len
+    );
+
+    let result = create_minter_config(&mut fixture);
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let project_config = AccountHelper::get_project_config(
+                &fixture.svm,
+                &fixture.project_owner.pubkey(),
+                PROJECT_1_ID,
+            );
+            assert_eq!(project_config.minter_configs_count, 1);
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_register_program() {
+    let mut fixture = TestFixture::new().with_initialize_protocol();
+    let program_id = Keypair::new().pubkey();
+
+    let result = Instructions::register_program(
+        &mut fixture.svm,
+        program_id,
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let registered_program =
+                AccountHelper::get_registered_program(&fixture.svm, &program_id)
+                    .expect("Registered program should exist");
+
+            assert_eq!(
+                registered_program.protocol_config,
+                AccountHelper::find_protocol_config_pda().0
+            );
+            assert_eq!(registered_program.program_id, program_id);
+            assert_eq!(registered_program.registered_by, fixture.admin_1.pubkey());
+            assert_eq!(registered_program.payer, fixture.payer.pubkey());
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_register_program_unauthorized() {
+    let mut fixture = TestFixture::new().with_initialize_protocol();
+    let program_id = Keypair::new().pubkey();
+    let non_admin = Keypair::new();
+
+    let result = Instructions::register_program(
+        &mut fixture.svm,
+        program_id,
+        non_admin.pubkey(),
+        fixture.payer.pubkey(),
+        &[&fixture.payer.insecure_clone(), &non_admin.insecure_clone()],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, non-admin cannot register programs");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("Unauthorized"),
+                "Expected Unauthorized error, got: {:?}",
+                e
+            );
+            assert!(AccountHelper::get_registered_program(&fixture.svm, &program_id).is_none());
+        }
+    }
+}
+
+#[test]
+fn test_unregister_program() {
+    let program_id = Keypair::new().pubkey();
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_registered_program(program_id);
+
+    let result = Instructions::unregister_program(
+        &mut fixture.svm,
+        program_id,
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            assert!(AccountHelper::get_registered_program(&fixture.svm, &program_id).is_none());
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_unregister_program_with_other_payer() {
+    let program_id = Keypair::new().pubkey();
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_registered_program(program_id);
+
+    fixture
+        .svm
+        .airdrop(&fixture.admin_1.pubkey(), LAMPORTS_PER_SOL)
+        .expect("Failed to fund admin");
+
+    // The fixture payer paid the rent, so the admin can't collect it
+    let result = Instructions::unregister_program(
+        &mut fixture.svm,
+        program_id,
+        fixture.admin_1.pubkey(),
+        fixture.admin_1.pubkey(),
+        &[&fixture.admin_1.insecure_clone()],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Expected transaction to fail, but it succeeded");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("InvalidRentPayer"),
+                "Expected InvalidRentPayer error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_execute_register_program_proposal() {
+    let program_id = Keypair::new().pubkey();
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_approval_threshold(2);
+
+    fixture
+        .svm
+        .airdrop(&fixture.admin_1.pubkey(), LAMPORTS_PER_SOL)
+        .expect("Failed to fund admin");
+
+    let registered_program_pda = AccountHelper::find_registered_program_pda(&program_id).0;
+    let proposal_id = AccountHelper::get_protocol_config(&fixture.svm).proposal_count;
+
+    Instructions::create_proposal(
+        &mut fixture.svm,
+        ProposalAction::RegisterProgram { program_id },
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to create proposal");
+
+    Instructions::approve_proposal(
+        &mut fixture.svm,
+        proposal_id,
+        fixture.admin_2.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_2.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to approve proposal");
+
+    let result = Instructions::execute_proposal_with(
+        &mut fixture.svm,
+        proposal_id,
+        fixture.admin_1.pubkey(),
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+        |builder| {
+            builder.registered_program(Some(registered_program_pda));
+        },
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let registered_program =
+                AccountHelper::get_registered_program(&fixture.svm, &program_id)
+                    .expect("Registered program should exist");
+
+            assert_eq!(registered_program.program_id, program_id);
+            assert_eq!(registered_program.registered_by, fixture.admin_1.pubkey());
+            assert_eq!(registered_program.payer, fixture.admin_1.pubkey());
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_execute_unregister_program_proposal() {
+    let program_id = Keypair::new().pubkey();
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_registered_program(program_id)
+        .with_approval_threshold(2);
+
+    fixture
+        .svm
+        .airdrop(&fixture.admin_1.pubkey(), LAMPORTS_PER_SOL)
+        .expect("Failed to fund admin");
+
+    let registered_program_pda = AccountHelper::find_registered_program_pda(&program_id).0;
+    let proposal_id = AccountHelper::get_protocol_config(&fixture.svm).proposal_count;
+
+    Instructions::create_proposal(
+        &mut fixture.svm,
+        ProposalAction::UnregisterProgram { program_id },
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to create proposal");
+
+    Instructions::approve_proposal(
+        &mut fixture.svm,
+        proposal_id,
+        fixture.admin_2.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_2.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to approve proposal");
+
+    let rent = utils::get_lamports(&fixture.svm, &registered_program_pda);
+    let payer_initial_balance = utils::get_lamports(&fixture.svm, &fixture.payer.pubkey());
+
+    let result = Instructions::execute_proposal_with(
+        &mut fixture.svm,
+        proposal_id,
+        fixture.admin_1.pubkey(),
+        fixture.admin_1.pubkey(),
+        fixture.admin_1.pubkey(),
+        &[&fixture.admin_1.insecure_clone()],
+        |builder| {
+            builder
+                .to(Some(fixture.payer.pubkey()))
+                .registered_program(Some(registered_program_pda));
+        },
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            assert!(AccountHelper::get_registered_program(&fixture.svm, &program_id).is_none());
+            assert_eq!(
+                utils::get_lamports(&fixture.svm, &fixture.payer.pubkey()),
+                payer_initial_balance + rent
+            );
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_charge_generic_fee_direct_call() {
+    let program_id = Keypair::new().pubkey();
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_registered_program(program_id);
+
+    let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+    let protocol_treasury_pda = AccountHelper::find_treasury_pda(&protocol_config_pda).0;
+    let protocol_treasury_initial_balance =
+        utils::get_lamports(&fixture.svm, &protocol_treasury_pda);
+
+    // Only the registered program may charge the fee, through a CPI.
+    let result = Instructions::charge_generic_fee(
+        &mut fixture.svm,
+        program_id,
+        None,
+        fixture.payer.pubkey(),
+        &[&fixture.payer.insecure_clone()],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, the fee was not charged through a CPI");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("ProgramNotRegistered"),
+                "Expected ProgramNotRegistered error, got: {:?}",
+                e
+            );
+            assert_eq!(
+                utils::get_lamports(&fixture.svm, &protocol_treasury_pda),
+                protocol_treasury_initial_balance
+            );
+        }
+    }
+}
+
+#[test]
+fn test_grant_project_role() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_project_created(PROJECT_1_ID);
+
+    let treasurer = Keypair::new();
+    let project_config_pda =
+        AccountHelper::find_project_pda(&fixture.project_owner.pubkey(), PROJECT_1_ID).0;
+
+    let result = Instructions::grant_project_role(
+        &mut fixture.svm,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        treasurer.pubkey(),
+        Role::Treasurer,
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.project_owner.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let project_role = AccountHelper::get_project_role(
+                &fixture.svm,
+                &project_config_pda,
+                &treasurer.pubkey(),
+            )
+            .expect("Project role should exist");
+
+            assert_eq!(project_role.project_config, project_config_pda);
+            assert_eq!(project_role.grantee, treasurer.pubkey());
+            assert_eq!(project_role.roles, 1 << Role::Treasurer as u8);
+
+            // The fixture already granted roles to both project authorities
+            let project_config = AccountHelper::get_project_config(
+                &fixture.svm,
+                &fixture.project_owner.pubkey(),
+                PROJECT_1_ID,
+            );
+            assert_eq!(project_config.roles_count, 3);
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_grant_project_role_unauthorized() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_project_created(PROJECT_1_ID);
+
+    // Minter and HubManager holders cannot manage roles
+    let result = Instructions::grant_project_role(
+        &mut fixture.svm,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        fixture.project_authority_1.pubkey(),
+        Role::Treasurer,
+        fixture.project_authority_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.project_authority_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, grantee without Admin role cannot grant roles");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("Unauthorized"),
+                "Expected Unauthorized error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_grant_project_role_already_granted() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_project_created(PROJECT_1_ID);
+
+    let result = Instructions::grant_project_role(
+        &mut fixture.svm,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        fixture.project_authority_1.pubkey(),
+        Role::Minter,
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.project_owner.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, role was already granted");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("RoleAlreadyGranted"),
+                "Expected RoleAlreadyGranted error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_project_admin_manages_roles() {
+    let project_admin = Keypair::new();
+    let grantee = Keypair::new();
+
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_project_created(PROJECT_1_ID)
+        .with_project_role(PROJECT_1_ID, project_admin.pubkey(), Role::Admin);
+
+    let project_config_pda =
+        AccountHelper::find_project_pda(&fixture.project_owner.pubkey(), PROJECT_1_ID).0;
+
+    let result = Instructions::grant_project_role(
+        &mut fixture.svm,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        grantee.pubkey(),
+        Role::Minter,
+        project_admin.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &project_admin.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let project_role = AccountHelper::get_project_role(
+                &fixture.svm,
+                &project_config_pda,
+                &grantee.pubkey(),
+            )
+            .expect("Project role should exist");
+            assert_eq!(project_role.roles, 1 << Role::Minter as u8);
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+
+    // Only the project owner grants the Admin role
+    let admin_result = Instructions::grant_project_role(
+        &mut fixture.svm,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        grantee.pubkey(),
+        Role::Admin,
+        project_admin.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &project_admin.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match admin_result {
+        Ok(_) => {
+            panic!("Transaction should have failed, project admins cannot grant Admin");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("Unauthorized"),
+                "Expected Unauthorized error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_revoke_project_role() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_project_created(PROJECT_1_ID);
+
+    let project_config_pda =
+        AccountHelper::find_project_pda(&fixture.project_owner.pubkey(), PROJECT_1_ID).0;
+    let grantee = fixture.project_authority_1.pubkey();
+
+    let result = Instructions::revoke_project_role(
+        &mut fixture.svm,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        grantee,
+        Role::Minter,
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.project_owner.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let project_role =
+                AccountHelper::get_project_role(&fixture.svm, &project_config_pda, &grantee)
+                    .expect("Project role should exist");
+            assert_eq!(project_role.roles, 1 << Role::HubManager as u8);
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+
+    // Revoking the last role closes the PDA
+    Instructions::revoke_project_role(
+        &mut fixture.svm,
+        PROJECT_1_ID,
+        fixture.project_owner.pubkey(),
+        grantee,
+        Role::HubManager,
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.project_owner.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to revoke last project role");
+
+    assert!(AccountHelper::get_project_role(&fixture.svm, &project_config_pda, &grantee).is_none());
+    assert_eq!(
+        AccountHelper::get_project_config(
+            &fixture.svm,
+            &fixture.project_owner.pubkey(),
+            PROJECT_1_ID
+        )
+        .roles_count,
+        1
+    );
+}
+
+#[test]
+fn test_set_treasury_policy() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_project_created(PROJECT_1_ID);
+
+    let project_config_pda =
+        AccountHelper::find_project_pda(&fixture.project_owner.pubkey(), PROJECT_1_ID).0;
+    let destinations = vec![Keypair::new().pubkey(), Keypair::new().pubkey()];
+    let epoch_cap = Some(2 * LAMPORTS_PER_SOL);
+
+    let result = Instructions::set_treasury_policy(
+        &mut fixture.svm,
+        PROJECT_1_ID,
+        destinations.clone(),
+        epoch_cap,
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.project_owner.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let treasury_policy =
+                AccountHelper::get_treasury_policy(&fixture.svm, &project_config_pda)
+                    .expect("Treasury policy should exist");
+
+            assert_eq!(treasury_policy.project_config, project_config_pda);
+            assert_eq!(treasury_policy.destinations, destinations);
+            assert_eq!(treasury_policy.epoch_cap, epoch_cap);
+            assert_eq!(treasury_policy.epoch_spent, 0);
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_treasurer_transfer_project_fees() {
+    let treasurer = Keypair::new();
+    let destination = Keypair::new().pubkey();
+
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_project_created(PROJECT_1_ID)
+        .with_project_role(PROJECT_1_ID, treasurer.pubkey(), Role::Treasurer)
+        .with_treasury_policy(PROJECT_1_ID, vec![destination], Some(2 * LAMPORTS_PER_SOL));
+
+    let project_config_pda =
+        AccountHelper::find_project_pda(&fixture.project_owner.pubkey(), PROJECT_1_ID).0;
+    let treasury_pda = AccountHelper::find_treasury_pda(&project_config_pda).0;
+
+    fixture
+        .svm
+        .airdrop(&treasury_pda, 5 * LAMPORTS_PER_SOL)
+        .expect("Failed to fund treasury");
+
+    let transfer_amount = LAMPORTS_PER_SOL;
+
+    let result = Instructions::transfer_project_fees(
+        &mut fixture.svm,
+        transfer_amount,
+        fixture.project_owner.pubkey(),
+        treasurer.pubkey(),
+        destination,
+        PROJECT_1_ID,
+        fixture.payer.pubkey(),
+        &[&treasurer.insecure_clone(), &fixture.payer.insecure_clone()],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let treasury_policy =
+                AccountHelper::get_treasury_policy(&fixture.svm, &project_config_pda)
+                    .expect("Treasury policy should exist");
+
+            assert_eq!(
+                utils::get_lamports(&fixture.svm, &destination),
+                transfer_amount
+            );
+            assert_eq!(treasury_policy.epoch_spent, transfer_amount);
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_treasurer_transfer_project_fees_without_policy() {
+    let treasurer = Keypair::new();
+
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_project_created(PROJECT_1_ID)
+        .with_project_role(PROJECT_1_ID, treasurer.pubkey(), Role::Treasurer);
+
+    let project_config_pda =
+        AccountHelper::find_project_pda(&fixture.project_owner.pubkey(), PROJECT_1_ID).0;
+    let treasury_pda = AccountHelper::find_treasury_pda(&project_config_pda).0;
+
+    fixture
+        .svm
+        .airdrop(&treasury_pda, 3 * LAMPORTS_PER_SOL)
+        .expect("Failed to fund treasury");
+
+    let result = Instructions::transfer_project_fees(
+        &mut fixture.svm,
+        LAMPORTS_PER_SOL,
+        fixture.project_owner.pubkey(),
+        treasurer.pubkey(),
+        treasurer.pubkey(),
+        PROJECT_1_ID,
+        fixture.payer.pubkey(),
+        &[&treasurer.insecure_clone(), &fixture.payer.insecure_clone()],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, treasurers need a treasury policy");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("TreasuryPolicyRequired"),
+                "Expected TreasuryPolicyRequired error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_treasurer_transfer_project_fees_to_unlisted_destination() {
+    let treasurer = Keypair::new();
+
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_project_created(PROJECT_1_ID)
+        .with_project_role(PROJECT_1_ID, treasurer.pubkey(), Role::Treasurer)
+        .with_treasury_policy(PROJECT_1_ID, vec![Keypair::new().pubkey()], None);
+
+    let project_config_pda =
+        AccountHelper::find_project_pda(&fixture.project_owner.pubkey(), PROJECT_1_ID).0;
+    let treasury_pda = AccountHelper::find_treasury_pda(&project_config_pda).0;
+
+    fixture
+        .svm
+        .airdrop(&treasury_pda, 3 * LAMPORTS_PER_SOL)
+        .expect("Failed to fund treasury");
+
+    let result = Instructions::transfer_project_fees(
+        &mut fixture.svm,
+        LAMPORTS_PER_SOL,
+        fixture.project_owner.pubkey(),
+        treasurer.pubkey(),
+        treasurer.pubkey(),
+        PROJECT_1_ID,
+        fixture.payer.pubkey(),
+        &[&treasurer.insecure_clone(), &fixture.payer.insecure_clone()],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, destination is not allowed by the policy");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("TreasuryDestinationNotAllowed"),
+                "Expected TreasuryDestinationNotAllowed error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_treasurer_transfer_project_fees_epoch_cap() {
+    let treasurer = Keypair::new();
+    let destination = Keypair::new().pubkey();
+    let epoch_cap = 2 * LAMPORTS_PER_SOL;
+
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_project_created(PROJECT_1_ID)
+        .with_project_role(PROJECT_1_ID, treasurer.pubkey(), Role::Treasurer)
+        .with_treasury_policy(PROJECT_1_ID, vec![destination], Some(epoch_cap));
+
+    let project_config_pda =
+        AccountHelper::find_project_pda(&fixture.project_owner.pubkey(), PROJECT_1_ID).0;
+    let treasury_pda = AccountHelper::find_treasury_pda(&project_config_pda).0;
+
+    fixture
+        .svm
+        .airdrop(&treasury_pda, 10 * LAMPORTS_PER_SOL)
+        .expect("Failed to fund treasury");
+
+    Instructions::transfer_project_fees(
+        &mut fixture.svm,
+        epoch_cap,
+        fixture.project_owner.pubkey(),
+        treasurer.pubkey(),
+        destination,
+        PROJECT_1_ID,
+        fixture.payer.pubkey(),
+        &[&treasurer.insecure_clone(), &fixture.payer.insecure_clone()],
+    )
+    .expect("Failed to transfer up to the epoch cap");
+
+    let over_cap_result = Instructions::transfer_project_fees(
+        &mut fixture.svm,
+        LAMPORTS_PER_SOL,
+        fixture.project_owner.pubkey(),
+        treasurer.pubkey(),
+        destination,
+        PROJECT_1_ID,
+        fixture.payer.pubkey(),
+        &[&treasurer.insecure_clone(), &fixture.payer.insecure_clone()],
+    );
+
+    match over_cap_result {
+        Ok(_) => {
+            panic!("Transaction should have failed, the epoch cap was reached");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("TreasuryEpochCapExceeded"),
+                "Expected TreasuryEpochCapExceeded error, got: {:?}",
+                e
+            );
+        }
+    }
+
+    // The cap applies again from zero in the next epoch
+    utils::warp_epoch(&mut fixture.svm, 1);
+
+    Instructions::transfer_project_fees(
+        &mut fixture.svm,
+        LAMPORTS_PER_SOL,
+        fixture.project_owner.pubkey(),
+        treasurer.pubkey(),
+        destination,
+        PROJECT_1_ID,
+        fixture.payer.pubkey(),
+        &[&treasurer.insecure_clone(), &fixture.payer.insecure_clone()],
+    )
+    .expect("Failed to transfer in the next epoch");
+
+    let treasury_policy = AccountHelper::get_treasury_policy(&fixture.svm, &project_config_pda)
+        .expect("Treasury policy should exist");
+    assert_eq!(treasury_policy.epoch_spent, LAMPORTS_PER_SOL);
+}
+
+#[test]
+fn test_register_referrer() {
+    let mut fixture = TestFixture::new().with_initialize_protocol();
+    let wallet = Keypair::new().pubkey();
+
+    let result = Instructions::register_referrer(
+        &mut fixture.svm,
+        wallet,
+        REFERRER_SHARE_BPS,
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let referrer =
+                AccountHelper::get_referrer(&fixture.svm, &wallet).expect("Referrer should exist");
+
+            assert_eq!(
+                referrer.protocol_config,
+                AccountHelper::find_protocol_config_pda().0
+            );
+            assert_eq!(referrer.wallet, wallet);
+            assert_eq!(referrer.share_bps, REFERRER_SHARE_BPS);
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_register_referrer_unauthorized() {
+    let mut fixture = TestFixture::new().with_initialize_protocol();
+    let wallet = Keypair::new().pubkey();
+    let non_admin = Keypair::new();
+
+    let result = Instructions::register_referrer(
+        &mut fixture.svm,
+        wallet,
+        REFERRER_SHARE_BPS,
+        non_admin.pubkey(),
+        fixture.payer.pubkey(),
+        &[&fixture.payer.insecure_clone(), &non_admin.insecure_clone()],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, non-admin cannot register referrers");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("Unauthorized"),
+                "Expected Unauthorized error, got: {:?}",
+                e
+            );
+            assert!(AccountHelper::get_referrer(&fixture.svm, &wallet).is_none());
+        }
+    }
+}
+
+#[test]
+fn test_register_referrer_invalid_share_bps() {
+    let mut fixture = TestFixture::new().with_initialize_protocol();
+    let wallet = Keypair::new().pubkey();
+
+    let result = Instructions::register_referrer(
+        &mut fixture.svm,
+        wallet,
+        5_001,
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, share bps above the referral cap");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("ReferralShareTooHigh"),
+                "Expected ReferralShareTooHigh error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_update_referrer() {
+    let wallet = Keypair::new().pubkey();
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_referrer(wallet);
+
+    let result = Instructions::update_referrer(
+        &mut fixture.svm,
+        wallet,
+        500,
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let referrer =
+                AccountHelper::get_referrer(&fixture.svm, &wallet).expect("Referrer should exist");
+            assert_eq!(referrer.share_bps, 500);
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_create_project_with_referrer() {
+    let wallet = Keypair::new().pubkey();
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_referrer(wallet);
+
+    let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+    let protocol_treasury_pda = AccountHelper::find_treasury_pda(&protocol_config_pda).0;
+    let referrer_pda = AccountHelper::find_referrer_pda(&wallet).0;
+    let protocol_treasury_initial_balance =
+        utils::get_lamports(&fixture.svm, &protocol_treasury_pda);
+    let referrer_initial_balance = utils::get_lamports(&fixture.svm, &referrer_pda);
+    let available_before =
+        AccountHelper::get_fee_stats(&fixture.svm, &protocol_config_pda).available;
+
+    let result = Instructions::create_instance_project(
+        &mut fixture.svm,
+        0,
+        DEFAULT_PROJECT_NAME.to_string(),
+        DEFAULT_PROJECT_DESCRIPTION.to_string(),
+        fixture.project_owner.pubkey(),
+        Some(wallet),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.project_owner.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let referral_amount = FEE_CREATE_PROJECT_AMOUNT * REFERRER_SHARE_BPS as u64 / 10_000;
+            let protocol_fee_stats =
+                AccountHelper::get_fee_stats(&fixture.svm, &protocol_config_pda);
+
+            assert_eq!(
+                utils::get_lamports(&fixture.svm, &referrer_pda),
+                referrer_initial_balance + referral_amount
+            );
+            assert_eq!(
+                utils::get_lamports(&fixture.svm, &protocol_treasury_pda),
+                protocol_treasury_initial_balance + FEE_CREATE_PROJECT_AMOUNT - referral_amount
+            );
+            assert_eq!(
+                protocol_fee_stats.protocol_fees.create_project.lamports,
+                FEE_CREATE_PROJECT_AMOUNT
+            );
+            assert_eq!(
+                protocol_fee_stats.available,
+                available_before + FEE_CREATE_PROJECT_AMOUNT - referral_amount
+            );
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_create_project_with_self_referral() {
+    let mut fixture = TestFixture::new().with_initialize_protocol();
+    let owner = fixture.project_owner.pubkey();
+    fixture = fixture.with_referrer(owner);
+
+    let result = Instructions::create_instance_project(
+        &mut fixture.svm,
+        0,
+        DEFAULT_PROJECT_NAME.to_string(),
+        DEFAULT_PROJECT_DESCRIPTION.to_string(),
+        owner,
+        Some(owner),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.project_owner.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, owner referred its own fee");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("SelfReferral"),
+                "Expected SelfReferral error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_claim_referral_fees() {
+    let wallet = Keypair::new();
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_referrer(wallet.pubkey());
+
+    fixture
+        .svm
+        .airdrop(&wallet.pubkey(), 1 * LAMPORTS_PER_SOL)
+        .expect("Failed to fund referrer wallet");
+
+    Instructions::create_instance_project(
+        &mut fixture.svm,
+        0,
+        DEFAULT_PROJECT_NAME.to_string(),
+        DEFAULT_PROJECT_DESCRIPTION.to_string(),
+        fixture.project_owner.pubkey(),
+        Some(wallet.pubkey()),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.project_owner.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to create project");
+
+    let referrer_pda = AccountHelper::find_referrer_pda(&wallet.pubkey()).0;
+    let referrer_initial_balance = utils::get_lamports(&fixture.svm, &referrer_pda);
+    let wallet_initial_balance = utils::get_lamports(&fixture.svm, &wallet.pubkey());
+
+    let result = Instructions::claim_referral_fees(
+        &mut fixture.svm,
+        wallet.pubkey(),
+        fixture.payer.pubkey(),
+        &[&fixture.payer.insecure_clone(), &wallet.insecure_clone()],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let referral_amount = FEE_CREATE_PROJECT_AMOUNT * REFERRER_SHARE_BPS as u64 / 10_000;
+
+            assert_eq!(
+                utils::get_lamports(&fixture.svm, &referrer_pda),
+                referrer_initial_balance - referral_amount
+            );
+            assert_eq!(
+                utils::get_lamports(&fixture.svm, &wallet.pubkey()),
+                wallet_initial_balance + referral_amount
+            );
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+
+    fixture.svm.expire_blockhash();
+
+    let result = Instructions::claim_referral_fees(
+        &mut fixture.svm,
+        wallet.pubkey(),
+        fixture.payer.pubkey(),
+        &[&fixture.payer.insecure_clone(), &wallet.insecure_clone()],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, nothing left to claim");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("NothingToClaim"),
+                "Expected NothingToClaim error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_remove_referrer() {
+    let wallet = Keypair::new().pubkey();
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_referrer(wallet);
+
+    let referrer_pda = AccountHelper::find_referrer_pda(&wallet).0;
+    let referrer_balance = utils::get_lamports(&fixture.svm, &referrer_pda);
+    let wallet_initial_balance = utils::get_lamports(&fixture.svm, &wallet);
+
+    let result = Instructions::remove_referrer(
+        &mut fixture.svm,
+        wallet,
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            assert!(AccountHelper::get_referrer(&fixture.svm, &wallet).is_none());
+            assert_eq!(
+                utils::get_lamports(&fixture.svm, &wallet),
+                wallet_initial_balance + referrer_balance
+            );
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_execute_register_referrer_proposal() {
+    let wallet = Keypair::new().pubkey();
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_approval_threshold(2);
+
+    fixture
+        .svm
+        .airdrop(&fixture.admin_1.pubkey(), LAMPORTS_PER_SOL)
+        .expect("Failed to fund admin");
+
+    let referrer_pda = AccountHelper::find_referrer_pda(&wallet).0;
+    let proposal_id = AccountHelper::get_protocol_config(&fixture.svm).proposal_count;
+
+    Instructions::create_proposal(
+        &mut fixture.svm,
+        ProposalAction::RegisterReferrer {
+            wallet,
+            share_bps: REFERRER_SHARE_BPS,
+        },
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to create proposal");
+
+    Instructions::approve_proposal(
+        &mut fixture.svm,
+        proposal_id,
+        fixture.admin_2.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_2.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to approve proposal");
+
+    let result = Instructions::execute_proposal_with(
+        &mut fixture.svm,
+        proposal_id,
+        fixture.admin_1.pubkey(),
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+        |builder| {
+            builder.referrer(Some(referrer_pda));
+        },
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let referrer =
+                AccountHelper::get_referrer(&fixture.svm, &wallet).expect("Referrer should exist");
+
+            assert_eq!(
+                referrer.protocol_config,
+                AccountHelper::find_protocol_config_pda().0
+            );
+            assert_eq!(referrer.wallet, wallet);
+            assert_eq!(referrer.share_bps, REFERRER_SHARE_BPS);
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_execute_update_referrer_proposal() {
+    let wallet = Keypair::new().pubkey();
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_referrer(wallet)
+        .with_approval_threshold(2);
+
+    let referrer_pda = AccountHelper::find_referrer_pda(&wallet).0;
+    let proposal_id = AccountHelper::get_protocol_config(&fixture.svm).proposal_count;
+
+    Instructions::create_proposal(
+        &mut fixture.svm,
+        ProposalAction::UpdateReferrer {
+            wallet,
+            share_bps: 500,
+        },
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to create proposal");
+
+    Instructions::approve_proposal(
+        &mut fixture.svm,
+        proposal_id,
+        fixture.admin_2.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_2.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to approve proposal");
+
+    let result = Instructions::execute_proposal_with(
+        &mut fixture.svm,
+        proposal_id,
+        fixture.admin_1.pubkey(),
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+        |builder| {
+            builder.referrer(Some(referrer_pda));
+        },
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let referrer =
+                AccountHelper::get_referrer(&fixture.svm, &wallet).expect("Referrer should exist");
+            assert_eq!(referrer.share_bps, 500);
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_execute_remove_referrer_proposal() {
+    let wallet = Keypair::new().pubkey();
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_referrer(wallet)
+        .with_approval_threshold(2);
+
+    let referrer_pda = AccountHelper::find_referrer_pda(&wallet).0;
+    let proposal_id = AccountHelper::get_protocol_config(&fixture.svm).proposal_count;
+    let referrer_balance = utils::get_lamports(&fixture.svm, &referrer_pda);
+    let wallet_initial_balance = utils::get_lamports(&fixture.svm, &wallet);
+
+    Instructions::create_proposal(
+        &mut fixture.svm,
+        ProposalAction::RemoveReferrer { wallet },
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to create proposal");
+
+    Instructions::approve_proposal(
+        &mut fixture.svm,
+        proposal_id,
+        fixture.admin_2.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_2.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to approve proposal");
+
+    let result = Instructions::execute_proposal_with(
+        &mut fixture.svm,
+        proposal_id,
+        fixture.admin_1.pubkey(),
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+        |builder| {
+            builder.to(Some(wallet)).referrer(Some(referrer_pda));
+        },
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            assert!(AccountHelper::get_referrer(&fixture.svm, &wallet).is_none());
+            assert_eq!(
+                utils::get_lamports(&fixture.svm, &wallet),
+                wallet_initial_balance + referrer_balance
+            );
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_set_subscription_plan() {
+    let mut fixture = TestFixture::new().with_initialize_protocol();
+
+    let result = Instructions::set_subscription_plan(
+        &mut fixture.svm,
+        default_subscription_plan(),
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let protocol_config = AccountHelper::get_protocol_config(&fixture.svm);
+            assert_eq!(
+                protocol_config.subscription_plans,
+                vec![default_subscription_plan()]
+            );
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+
+    // Setting a plan with the same id replaces it
+    let updated_plan = SubscriptionPlan {
+        price_per_epoch: SUBSCRIPTION_PRICE_PER_EPOCH * 2,
+        ..default_subscription_plan()
+    };
+
+    Instructions::set_subscription_plan(
+        &mut fixture.svm,
+        updated_plan.clone(),
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to update subscription plan");
+
+    let protocol_config = AccountHelper::get_protocol_config(&fixture.svm);
+    assert_eq!(protocol_config.subscription_plans, vec![updated_plan]);
+}
+
+#[test]
+fn test_set_subscription_plan_unauthorized() {
+    let mut fixture = TestFixture::new().with_initialize_protocol();
+    let non_admin = Keypair::new();
+
+    let result = Instructions::set_subscription_plan(
+        &mut fixture.svm,
+        default_subscription_plan(),
+        non_admin.pubkey(),
+        fixture.payer.pubkey(),
+        &[&fixture.payer.insecure_clone(), &non_admin.insecure_clone()],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, non-admin cannot set subscription plans");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("Unauthorized"),
+                "Expected Unauthorized error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_remove_subscription_plan() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_subscription_plan();
+
+    let result = Instructions::remove_subscription_plan(
+        &mut fixture.svm,
+        SUBSCRIPTION_PLAN_ID,
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let protocol_config = AccountHelper::get_protocol_config(&fixture.svm);
+            assert!(protocol_config.subscription_plans.is_empty());
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_execute_set_subscription_plan_proposal() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_approval_threshold(2);
+
+    let proposal_id = AccountHelper::get_protocol_config(&fixture.svm).proposal_count;
+
+    Instructions::create_proposal(
+        &mut fixture.svm,
+        ProposalAction::SetSubscriptionPlan {
+            plan: default_subscription_plan(),
+        },
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to create proposal");
+
+    Instructions::approve_proposal(
+        &mut fixture.svm,
+        proposal_id,
+        fixture.admin_2.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_2.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to approve proposal");
+
+    let result = Instructions::execute_proposal(
+        &mut fixture.svm,
+        proposal_id,
+        fixture.admin_1.pubkey(),
+        None,
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let protocol_config = AccountHelper::get_protocol_config(&fixture.svm);
+            assert_eq!(
+                protocol_config.subscription_plans,
+                vec![default_subscription_plan()]
+            );
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_execute_remove_subscription_plan_proposal() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_subscription_plan()
+        .with_approval_threshold(2);
+
+    let proposal_id = AccountHelper::get_protocol_config(&fixture.svm).proposal_count;
+
+    Instructions::create_proposal(
+        &mut fixture.svm,
+        ProposalAction::RemoveSubscriptionPlan {
+            plan_id: SUBSCRIPTION_PLAN_ID,
+        },
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to create proposal");
+
+    Instructions::approve_proposal(
+        &mut fixture.svm,
+        proposal_id,
+        fixture.admin_2.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_2.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to approve proposal");
+
+    let result = Instructions::execute_proposal(
+        &mut fixture.svm,
+        proposal_id,
+        fixture.admin_1.pubkey(),
+        None,
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let protocol_config = AccountHelper::get_protocol_config(&fixture.svm);
+            assert!(protocol_config.subscription_plans.is_empty());
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_pay_subscription() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_subscription_plan()
+        .with_project_created(PROJECT_1_ID);
+
+    let protocol_config_pda = AccountHelper::find_protocol_config_pda().0;
+    let protocol_treasury_pda = AccountHelper::find_treasury_pda(&protocol_config_pda).0;
+    let project_config_pda =
+        AccountHelper::find_project_pda(&fixture.project_owner.pubkey(), PROJECT_1_ID).0;
+    let protocol_treasury_initial_balance =
+        utils::get_lamports(&fixture.svm, &protocol_treasury_pda);
+    let available_before =
+        AccountHelper::get_fee_stats(&fixture.svm, &protocol_config_pda).available;
+
+    utils::warp_epoch(&mut fixture.svm, 5);
+    let epoch = fixture.svm.get_sysvar::<Clock>().epoch;
+
+    let result = Instructions::pay_subscription(
+        &mut fixture.svm,
+        PROJECT_1_ID,
+        SUBSCRIPTION_PLAN_ID,
+        3,
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.project_owner.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(result) => {
+            utils::print_transaction_logs(&result);
+
+            let subscription = AccountHelper::get_subscription(&fixture.svm, &project_config_pda)
+                .expect("Subscription should exist");
+
+            assert_eq!(subscription.project_config, project_config_pda);
+            assert_eq!(subscription.plan_id, SUBSCRIPTION_PLAN_ID);
+            assert_eq!(subscription.paid_until_epoch, epoch + 3);
+            assert_eq!(
+                utils::get_lamports(&fixture.svm, &protocol_treasury_pda),
+                protocol_treasury_initial_balance + SUBSCRIPTION_PRICE_PER_EPOCH * 3
+            );
+            assert_eq!(
+                AccountHelper::get_fee_stats(&fixture.svm, &protocol_config_pda).available,
+                available_before + SUBSCRIPTION_PRICE_PER_EPOCH * 3
+            );
+        }
+        Err(e) => {
+            panic!("Transaction failed: {:?}", e);
+        }
+    }
+
+    // Renewing before the period ends extends it
+    Instructions::pay_subscription(
+        &mut fixture.svm,
+        PROJECT_1_ID,
+        SUBSCRIPTION_PLAN_ID,
+        2,
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.project_owner.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to renew subscription");
+
+    let subscription = AccountHelper::get_subscription(&fixture.svm, &project_config_pda)
+        .expect("Subscription should exist");
+    assert_eq!(subscription.paid_until_epoch, epoch + 5);
+}
+
+#[test]
+fn test_pay_subscription_unknown_plan() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_project_created(PROJECT_1_ID);
+
+    let result = Instructions::pay_subscription(
+        &mut fixture.svm,
+        PROJECT_1_ID,
+        SUBSCRIPTION_PLAN_ID,
+        1,
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.project_owner.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, the plan does not exist");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("SubscriptionPlanNotFound"),
+                "Expected SubscriptionPlanNotFound error, got: {:?}",
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_pay_subscription_change_plan_while_active() {
+    let mut fixture = TestFixture::new()
+        .with_initialize_protocol()
+        .with_subscription_plan()
+        .with_project_created(PROJECT_1_ID)
+        .with_subscription(PROJECT_1_ID, 2);
+
+    let other_plan = SubscriptionPlan {
+        plan_id: SUBSCRIPTION_PLAN_ID + 1,
+        ..default_subscription_plan()
+    };
+    Instructions::set_subscription_plan(
+        &mut fixture.svm,
+        other_plan.clone(),
+        fixture.admin_1.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.admin_1.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    )
+    .expect("Failed to set subscription plan");
+
+    let result = Instructions::pay_subscription(
+        &mut fixture.svm,
+        PROJECT_1_ID,
+        other_plan.plan_id,
+        1,
+        fixture.project_owner.pubkey(),
+        fixture.payer.pubkey(),
+        &[
+            &fixture.project_owner.insecure_clone(),
+            &fixture.payer.insecure_clone(),
+        ],
+    );
+
+    match result {
+        Ok(_) => {
+            panic!("Transaction should have failed, the paid period has not ended");
+        }
+        Err(e) => {
+            let error_string = format!("{:?}", e);
+            assert!(
+                error_string.contains("SubscriptionPlanChangeNotAllowed"),
+                "Expected SubscriptionPlanChangeNotAllowed error, got: {:?}",
+                e
+            );
+        }
+    }
+}
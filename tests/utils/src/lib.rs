@@ -49,6 +49,16 @@ pub fn print_transaction_logs(result: &TransactionMetadata) {
     }
 }
 
+/// Data of every inner instruction, which includes the self-invocations made by `emit_cpi!`.
+pub fn inner_instructions_data(result: &TransactionMetadata) -> Vec<Vec<u8>> {
+    result
+        .inner_instructions
+        .iter()
+        .flatten()
+        .map(|inner| inner.instruction.data.clone())
+        .collect()
+}
+
 pub fn send_transaction(
     svm: &mut LiteSVM,
     instructions: &[Instruction],
@@ -67,6 +77,18 @@ pub fn send_transaction(
     result
 }
 
+pub fn warp_clock(svm: &mut LiteSVM, seconds: i64) {
+    let mut clock: Clock = svm.get_sysvar();
+    clock.unix_timestamp += seconds;
+    svm.set_sysvar(&clock);
+}
+
+pub fn warp_epoch(svm: &mut LiteSVM, epochs: u64) {
+    let mut clock: Clock = svm.get_sysvar();
+    clock.epoch += epochs;
+    svm.set_sysvar(&clock);
+}
+
 pub fn get_lamports(svm: &LiteSVM, address: &Pubkey) -> u64 {
     svm.get_account(address)
         .unwrap_or_else(|| panic!("Account not found: {}", address))